@@ -37,6 +37,15 @@ enum Command {
         #[arg(short, long)]
         tag: Option<String>,
     },
+    /// Blocking methods
+    Blocking {
+        /// Generate blocking methods without tags
+        #[arg(short, long, default_value_t = false)]
+        no_tag: bool,
+        /// Generate blocking methods with specific tag (kebab variant of tag name)
+        #[arg(short, long)]
+        tag: Option<String>,
+    },
 }
 
 #[derive(Clone, Default, Parser, ValueEnum)]
@@ -46,6 +55,7 @@ enum TagsFormat {
     Kebab,
     ModResource,
     ModRest,
+    ModBlocking,
 }
 
 #[derive(Debug)]
@@ -81,7 +91,7 @@ mod openapi {
     use indexmap::IndexMap;
     use serde::Deserialize;
 
-    use keycloak::prelude::reqwest;
+    use keycloak::prelude::http;
 
     use crate::{RESERVED_WORDS, TAG_NONE};
 
@@ -246,9 +256,9 @@ mod openapi {
 
         fn to_rust_reqwest_body_call(&self, body_name: &str) -> Option<String> {
             self.as_json()
-                .map(|_| format!("json(&{body_name})"))
-                .or(self.as_text_plain().map(|_| format!("body({body_name})")))
-                .or(self.as_html_form().map(|_| format!("form(&{body_name})")))
+                .map(|_| format!("json(&{body_name})?"))
+                .or(self.as_text_plain().map(|_| format!("text({body_name})")))
+                .or(self.as_html_form().map(|_| format!("form(&{body_name})?")))
         }
 
         fn to_rust_reqwest_parse_body_call(&self) -> Option<(Cow<'_, str>, Option<Cow<'_, str>>)> {
@@ -258,8 +268,8 @@ mod openapi {
                 .or(self
                     .as_binary_text()
                     .or(self.as_text_plain())
-                    .map(|_| ("text", Some(".map(From::from)"))))
-                .or(self.as_binary_blob().map(|_| ("bytes", None)))
+                    .map(|_| ("text", Some(".into()"))))
+                .or(self.as_binary_blob().map(|_| ("bytes", Some(".into()"))))
                 .or(self.as_html_form().map(|_| ("form", None)))
                 .map(|(method, conv)| (method.into(), conv.map(From::from)))
         }
@@ -295,13 +305,13 @@ mod openapi {
     }
 
     impl Responses {
-        fn to_reqwest_status_response(&self) -> Option<(reqwest::StatusCode, &Response)> {
+        fn to_reqwest_status_response(&self) -> Option<(http::StatusCode, &Response)> {
             self.0
                 .iter()
                 .find(|(status, _)| *status == "200")
                 .or_else(|| self.0.iter().find(|(status, _)| status.starts_with("2")))
                 .and_then(|(status, response)| {
-                    let status = reqwest::StatusCode::from_str(status.as_str()).ok()?;
+                    let status = http::StatusCode::from_str(status.as_str()).ok()?;
                     Some((status, response))
                 })
         }
@@ -453,6 +463,26 @@ mod openapi {
                 output.push("#[allow(clippy::too_many_arguments)]".into());
             }
 
+            output.push(r#"#[cfg_attr(feature = "tracing", tracing::instrument("#.into());
+            output.push("    skip_all,".into());
+            output.push("    fields(".into());
+            output.push(format!(
+                r#"        http.request.method = "{}","#,
+                method_string_lc.to_uppercase()
+            ));
+            output.push(format!(r#"        http.route = "{path}","#));
+            if parameters
+                .iter()
+                .any(|(parameter, _)| parameter.name == "realm")
+            {
+                output.push("        realm = %realm,".into());
+            }
+            output.push("        http.response.status_code,".into());
+            output.push("        latency_ms,".into());
+            output.push("        error,".into());
+            output.push("    )".into());
+            output.push("))]".into());
+
             output.push(format!("pub async fn {method_name}("));
             output.push("    &self,".into());
 
@@ -501,16 +531,10 @@ mod openapi {
                 }
             }
 
-            output.push(
-                if has_query_parameters {
-                    "    let mut builder = self"
-                } else {
-                    "    let builder = self"
-                }
-                .into(),
-            );
-            output.push("        .client".into());
-            output.push(format!("        .{method_string_lc}(format!("));
+            output.push(format!(
+                "    let {}builder = KeycloakRequest::{method_string_lc}(format!(",
+                if has_query_parameters { "mut " } else { "" }
+            ));
             output.push(format!(r#"            "{{}}{path_snake_case}","#));
             output.push("            self.url".into());
             output.push("        ))".into());
@@ -519,16 +543,20 @@ mod openapi {
                     body_return_type.map(|return_type: ReturnType<'_>| {
                         return_type
                             .body
-                            .unwrap_or_else(|| format!("json(&{body_parameter_name})").into())
+                            .unwrap_or_else(|| format!("json(&{body_parameter_name})?").into())
                     })
                 else {
                     panic!("could not convert reqwest body: {reqwest_body:?}")
                 };
                 output.push(format!("        .{reqwest_body_call}"));
             } else if matches!(method, Method::Put) {
-                output.push(r#"        .header(CONTENT_LENGTH, "0")"#.into());
+                output.push(
+                    r#"        .header(CONTENT_LENGTH, HeaderValue::from_static("0"))"#.into(),
+                );
+            }
+            if let Some(last) = output.last_mut() {
+                last.push(';');
             }
-            output.push("        .bearer_auth(self.token_supplier.get(&self.url).await?);".into());
 
             output.extend(query_parameters.into_iter().flat_map(
                 |(query_parameter, query_parameter_name)| {
@@ -541,26 +569,26 @@ mod openapi {
                     };
                     [
                         format!("if let Some(v) = {query_parameter_name} {{"),
-                        format!(r#"    builder = builder.query(&{value});"#,),
+                        format!(r#"    builder = builder.query(&{value})?;"#,),
                         "}".into(),
                     ]
                     .map(|line| format!("    {line}"))
                 },
             ));
 
+            output.push(format!(
+                r#"    let response = self.call("{path}", builder).await?;"#
+            ));
             if let Some(ReturnType { body, convert, .. }) = result_type.as_ref() {
                 let body = body.as_deref().unwrap_or("json");
-                output.push("    let response = builder.send().await?;".into());
-                output.push(format!(
-                    "    Ok(error_check(response).await?.{body}().await{}?)",
-                    convert.as_deref().unwrap_or_default()
-                ));
+                output.push(match convert.as_deref() {
+                    Some(convert) => format!("    Ok(response.{body}()?{convert})"),
+                    None => format!("    response.{body}()"),
+                });
             } else if use_default_response {
-                output.push("    let response = builder.send().await?;".into());
-                output.push("    error_check(response).await.map(From::from)".into());
+                output.push("    response.check().map(From::from)".into());
             } else {
-                output.push("    let response = builder.send().await?;".into());
-                output.push("    error_check(response).await?;".into());
+                output.push("    response.check()?;".into());
                 output.push("    Ok(())".into());
             }
 
@@ -1385,6 +1413,7 @@ fn main() {
         Command::Types => generate_types(&specs),
         Command::Rest { no_tag, tag } => generate_rest(&specs, no_tag, tag),
         Command::Methods { no_tag, tag } => generate_methods(&specs, no_tag, tag),
+        Command::Blocking { no_tag, tag } => generate_blocking(&specs, no_tag, tag),
         Command::Tags { format } => match format.unwrap_or_default() {
             TagsFormat::Cargo => list_tags_for_cargo(&specs),
             TagsFormat::Kebab => list_tags_as_kebab(&specs),
@@ -1402,6 +1431,13 @@ fn main() {
                     .as_deref()
                     .unwrap_or("other_methods"),
             ),
+            TagsFormat::ModBlocking => list_tags_for_mod_blocking(
+                &specs,
+                std::env::var("OTHER_METHODS_MOD")
+                    .ok()
+                    .as_deref()
+                    .unwrap_or("other_methods"),
+            ),
         },
         Command::Specs => {
             println!("{specs:#?}");
@@ -1529,6 +1565,107 @@ fn generate_methods(spec: &openapi::Spec, no_tag: bool, tag_as_kebab: Option<Str
     generate_method_builder(add_cfg, &tag_realm_methods);
 }
 
+fn generate_blocking(spec: &openapi::Spec, no_tag: bool, tag_as_kebab: Option<String>) {
+    let (add_cfg, tag_paths) = collect_tag_paths(spec, no_tag, tag_as_kebab);
+
+    let tag_realm_methods: Vec<_> = tag_paths
+        .into_iter()
+        .map(|(tag, paths)| {
+            (
+                tag,
+                paths
+                    .into_iter()
+                    .flat_map(|(path, path_spec)| path_spec.to_rust_realm_methods(path))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+
+    print!(
+        r###"use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {{
+"###
+    );
+    generate_blocking_methods(add_cfg, &tag_realm_methods, false);
+    println!("}}\n");
+
+    println!(r###"impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {{"###);
+    generate_blocking_methods(add_cfg, &tag_realm_methods, true);
+    println!("}}");
+}
+
+fn generate_blocking_methods(
+    add_cfg: bool,
+    tag_realm_methods: &[(&Cow<'_, str>, Vec<RealmMethod>)],
+    realm_admin: bool,
+) {
+    for (tag, realm_methods) in tag_realm_methods {
+        println!("    // <h4>{tag}</h4>\n");
+
+        for RealmMethod {
+            name,
+            comments,
+            real_fn_name,
+            tags,
+            deprecated,
+            parameters,
+            returns,
+            ..
+        } in realm_methods
+        {
+            let no_realm_parameter = !parameters.iter().any(|p| p.name == "realm");
+            if realm_admin && no_realm_parameter {
+                continue;
+            }
+            for comment in comments {
+                println!("    {comment}");
+            }
+            if add_cfg {
+                let tag = tags
+                    .as_ref()
+                    .and_then(|tags| tags.first())
+                    .map(|tag| tag.to_kebab_case());
+                let tag_str = tag.as_deref().unwrap_or("none");
+                println!("    #[cfg(feature = \"tag-{tag_str}\")]",);
+            }
+            if *deprecated {
+                println!("    #[deprecated]");
+                println!("    #[allow(deprecated)]");
+            }
+            let method_parameters = parameters
+                .iter()
+                .filter(|p| !realm_admin || p.name != "realm")
+                .collect::<Vec<_>>();
+            if method_parameters.len() > 6 {
+                println!("    #[allow(clippy::too_many_arguments)]");
+            }
+            let fn_name = if realm_admin { name } else { real_fn_name };
+            println!("    pub fn {fn_name}(");
+            println!("        &self,");
+            for parameter in &method_parameters {
+                println!("        {}: {},", parameter.name, parameter.rust_type);
+            }
+            println!("    ) -> Result<{returns}, KeycloakError> {{");
+            if realm_admin {
+                println!("        self.admin.{real_fn_name}(");
+                println!("            self.realm,");
+            } else {
+                println!("        self.block_on(self.inner.{real_fn_name}(");
+            }
+            for parameter in &method_parameters {
+                println!("            {},", parameter.name);
+            }
+            if realm_admin {
+                println!("        )");
+            } else {
+                println!("        ))");
+            }
+            println!("    }}\n");
+        }
+    }
+}
+
 fn collect_tag_paths<'s>(
     spec: &'s openapi::Spec<'s>,
     no_tag: bool,
@@ -1701,7 +1838,7 @@ fn generate_method_structs(add_cfg: bool, tag_realm_methods: &[(&Cow<'_, str>, V
             if add_cfg {
                 println!("#[cfg(feature = \"tag-{tag_str}\")]",);
             }
-            println!("#[derive(Default)]");
+            println!("#[derive(Clone, Default)]");
             println!("pub struct {struct_name}Args {{");
             for parameter in &optional_parameters {
                 if let Some(comment) = &parameter.description {
@@ -1757,6 +1894,51 @@ fn generate_method_structs(add_cfg: bool, tag_realm_methods: &[(&Cow<'_, str>, V
             println!("        Box::pin(self.opts(Default::default()))");
             println!("    }}");
             println!("}}\n");
+
+            let paged_item = returns
+                .strip_prefix("TypeVec<")
+                .and_then(|r| r.strip_suffix('>'));
+            let has_pagination = ["first", "max"]
+                .iter()
+                .all(|name| optional_parameters.iter().any(|p| p.name == *name));
+            let Some(paged_item) = paged_item.filter(|_| has_pagination) else {
+                continue;
+            };
+            if add_cfg {
+                println!("#[cfg(feature = \"tag-{tag_str}\")]",);
+            }
+            println!("impl<TS: KeycloakTokenSupplier> Clone for {struct_name}<'_, TS> {{");
+            println!("    fn clone(&self) -> Self {{");
+            println!("        Self {{");
+            println!("            realm_admin: self.realm_admin,");
+            for parameter in &required_parameters {
+                println!("            {0}: self.{0},", parameter.name);
+            }
+            println!("        }}");
+            println!("    }}");
+            println!("}}\n");
+            if add_cfg {
+                println!("#[cfg(feature = \"tag-{tag_str}\")]",);
+            }
+            println!("impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod");
+            println!("    for {struct_name}<'_, TS>");
+            println!("{{");
+            println!("    type Item = {paged_item};");
+            println!();
+            let other_args = optional_parameters.len() > 2;
+            let args_name = if other_args { "args" } else { "_args" };
+            println!(
+                "    fn with_page({args_name}: Self::Args, first: i32, max: i32) -> Self::Args {{"
+            );
+            println!("        Self::Args {{");
+            println!("            first: Some(first),");
+            println!("            max: Some(max),");
+            if other_args {
+                println!("            ..args");
+            }
+            println!("        }}");
+            println!("    }}");
+            println!("}}\n");
         }
     }
 }
@@ -1880,7 +2062,7 @@ use serde_json::Value;
 
 use crate::{{
     types::*, DefaultResponse, KeycloakError, KeycloakRealmAdmin, KeycloakRealmAdminMethod,
-    KeycloakTokenSupplier,
+    KeycloakRealmAdminPagedMethod, KeycloakTokenSupplier,
 }};
 "
     );
@@ -1897,7 +2079,7 @@ use crate::{{
 fn list_tags_for_mod_rest(spec: &openapi::Spec, other_methods: &str) {
     use heck::{ToKebabCase, ToSnakeCase};
     println!(
-        "use reqwest::header::CONTENT_LENGTH;
+        "use http::header::{{HeaderValue, CONTENT_LENGTH}};
 use serde_json::Value;
 
 use super::{{url_enc::encode_url_param as p, *}};
@@ -1912,3 +2094,21 @@ use super::{{url_enc::encode_url_param as p, *}};
     println!("#[cfg(feature = \"{TAG_NONE}\")]");
     println!("pub mod {other_methods};");
 }
+
+fn list_tags_for_mod_blocking(spec: &openapi::Spec, other_methods: &str) {
+    use heck::{ToKebabCase, ToSnakeCase};
+    println!(
+        "use serde_json::Value;
+
+use super::*;
+"
+    );
+    for tag in &spec.tags {
+        println!("/// {}", tag.name);
+        println!("#[cfg(feature = \"tag-{}\")]", tag.name.to_kebab_case());
+        println!("pub mod {};", tag.name.to_snake_case());
+    }
+    println!("/// Other (non tagged) methods");
+    println!("#[cfg(feature = \"{TAG_NONE}\")]");
+    println!("pub mod {other_methods};");
+}
@@ -0,0 +1,171 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::de::DeserializeOwned;
+
+use crate::{error::KeycloakTokenError, types::AccessToken, KeycloakAdminToken, KeycloakError};
+
+/// Decodes claims of JWT `token` without verifying its signature.
+pub(crate) fn decode_claims_unverified<T: DeserializeOwned>(
+    token: &str,
+) -> Result<T, KeycloakError> {
+    let malformed =
+        |reason: String| KeycloakError::TokenFailure(KeycloakTokenError::Malformed(reason));
+    let mut parts = token.split('.');
+    let (Some(_header), Some(payload), Some(_signature), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(malformed("expected three dot separated parts".into()));
+    };
+    let payload = URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .map_err(|e| malformed(format!("payload is not base64url: {e}")))?;
+    serde_json::from_slice(&payload).map_err(|e| malformed(format!("invalid claims: {e}")))
+}
+
+impl AccessToken {
+    /// Decodes claims of JWT access `token` without verifying its signature.
+    ///
+    /// Use it to inspect tokens this client obtained itself (e.g. to check
+    /// expiry or roles before a call). Tokens received from third parties
+    /// must be verified instead.
+    pub fn decode_unverified(token: &str) -> Result<Self, KeycloakError> {
+        decode_claims_unverified(token)
+    }
+
+    /// Returns the expiration time (`exp` claim), if present.
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.exp
+            .and_then(|exp| u64::try_from(exp).ok())
+            .map(|exp| UNIX_EPOCH + Duration::from_secs(exp))
+    }
+
+    /// Returns `true` if the token expires within `leeway` from now.
+    pub fn is_expired(&self, leeway: Duration) -> bool {
+        self.expires_at()
+            .is_some_and(|expires_at| expires_at <= SystemTime::now() + leeway)
+    }
+
+    /// Returns realm roles (`realm_access` claim).
+    pub fn realm_roles(&self) -> impl Iterator<Item = &str> {
+        self.realm_access
+            .iter()
+            .flat_map(|access| access.roles.iter().flat_map(|roles| roles.iter()))
+            .map(String::as_str)
+    }
+
+    /// Returns roles of client `client_id` (`resource_access` claim).
+    ///
+    /// Admin roles of realm users are roles of the `realm-management` client,
+    /// admin roles of `master` realm users for realm `xxx` are roles of the
+    /// `xxx-realm` client.
+    pub fn client_roles<'a>(&'a self, client_id: &'a str) -> impl Iterator<Item = &'a str> {
+        self.resource_access
+            .iter()
+            .flat_map(move |resource_access| resource_access.get(client_id))
+            .flat_map(|access| access.roles.iter().flat_map(|roles| roles.iter()))
+            .map(String::as_str)
+    }
+
+    /// Returns `true` if the token carries realm role `role`.
+    pub fn has_realm_role(&self, role: &str) -> bool {
+        self.realm_roles().any(|r| r == role)
+    }
+
+    /// Returns `true` if the token carries role `role` of client `client_id`.
+    pub fn has_client_role(&self, client_id: &str, role: &str) -> bool {
+        self.client_roles(client_id).any(|r| r == role)
+    }
+
+    /// Fails with [`KeycloakTokenError::Expired`] if the token expires within `leeway` from now.
+    pub fn ensure_not_expired(&self, leeway: Duration) -> Result<(), KeycloakError> {
+        if self.is_expired(leeway) {
+            return Err(KeycloakError::TokenFailure(KeycloakTokenError::Expired));
+        }
+        Ok(())
+    }
+
+    /// Fails with [`KeycloakTokenError::MissingRole`] if the token does not carry realm role `role`.
+    pub fn ensure_realm_role(&self, role: &str) -> Result<(), KeycloakError> {
+        if !self.has_realm_role(role) {
+            return Err(KeycloakError::TokenFailure(
+                KeycloakTokenError::MissingRole {
+                    client_id: None,
+                    role: role.into(),
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    /// Fails with [`KeycloakTokenError::MissingRole`] if the token does not
+    /// carry role `role` of client `client_id`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn doc(admin_token: keycloak::KeycloakAdminToken) -> Result<(), keycloak::KeycloakError> {
+    /// let claims = admin_token.decode_access_token()?;
+    /// claims.ensure_client_role("realm-management", "manage-users")?;
+    /// # Ok(()) }
+    /// ```
+    pub fn ensure_client_role(&self, client_id: &str, role: &str) -> Result<(), KeycloakError> {
+        if !self.has_client_role(client_id, role) {
+            return Err(KeycloakError::TokenFailure(
+                KeycloakTokenError::MissingRole {
+                    client_id: Some(client_id.into()),
+                    role: role.into(),
+                },
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl KeycloakAdminToken {
+    /// Decodes claims of the access token without verifying its signature.
+    pub fn decode_access_token(&self) -> Result<AccessToken, KeycloakError> {
+        AccessToken::decode_unverified(self.access_token())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+    use crate::{error::KeycloakTokenError, types::AccessToken, KeycloakError};
+
+    #[test]
+    fn decode_roles_and_expiry() {
+        let payload = URL_SAFE_NO_PAD.encode(
+            serde_json::json!({
+                "exp": 1,
+                "azp": "admin-cli",
+                "realm_access": { "roles": ["offline_access"] },
+                "resource_access": { "realm-management": { "roles": ["view-users"] } },
+            })
+            .to_string(),
+        );
+        let token = AccessToken::decode_unverified(&format!("e30.{payload}.c2ln")).unwrap();
+
+        assert_eq!(token.azp.as_deref(), Some("admin-cli"));
+        assert!(token.is_expired(Duration::ZERO));
+        assert!(token.has_realm_role("offline_access"));
+        assert!(token.has_client_role("realm-management", "view-users"));
+        assert!(!token.has_client_role("realm-management", "manage-users"));
+        assert!(matches!(
+            token.ensure_client_role("realm-management", "manage-users"),
+            Err(KeycloakError::TokenFailure(
+                KeycloakTokenError::MissingRole { .. }
+            ))
+        ));
+        assert!(matches!(
+            AccessToken::decode_unverified("not a token"),
+            Err(KeycloakError::TokenFailure(KeycloakTokenError::Malformed(
+                _
+            )))
+        ));
+    }
+}
@@ -0,0 +1,420 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Client Attribute Certificate</h4>
+
+    /// Get key info
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `attr`
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidcertificatesattr>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}`
+    pub fn realm_clients_with_client_uuid_certificates_with_attr_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        attr: &str,
+    ) -> Result<CertificateRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_certificates_with_attr_get(
+                    realm,
+                    client_uuid,
+                    attr,
+                ),
+        )
+    }
+
+    /// Get a keystore file for the client, containing private key and public certificate
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `attr`
+    /// - `body`
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/download`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrdownload>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/download`
+    pub fn realm_clients_with_client_uuid_certificates_with_attr_download_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        attr: &str,
+        body: KeyStoreConfig,
+    ) -> Result<TypeString, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_certificates_with_attr_download_post(
+                    realm,
+                    client_uuid,
+                    attr,
+                    body,
+                ),
+        )
+    }
+
+    /// Generate a new certificate with new key pair
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `attr`
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/generate`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrgenerate>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/generate`
+    pub fn realm_clients_with_client_uuid_certificates_with_attr_generate_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        attr: &str,
+    ) -> Result<CertificateRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_certificates_with_attr_generate_post(
+                    realm,
+                    client_uuid,
+                    attr,
+                ),
+        )
+    }
+
+    /// Generate a new keypair and certificate, and get the private key file
+    ///
+    /// Generates a keypair and certificate and serves the private key in a specified keystore format.
+    /// Only generated public certificate is saved in Keycloak DB - the private key is not.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `attr`
+    /// - `body`
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/generate-and-download`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrgenerate_and_download>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/generate-and-download`
+    pub fn realm_clients_with_client_uuid_certificates_with_attr_generate_and_download_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        attr: &str,
+        body: KeyStoreConfig,
+    ) -> Result<TypeString, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_certificates_with_attr_generate_and_download_post(
+                    realm,
+                    client_uuid,
+                    attr,
+                    body,
+                ),
+        )
+    }
+
+    /// Upload certificate and eventually private key
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `attr`
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/upload`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrupload>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/upload`
+    pub fn realm_clients_with_client_uuid_certificates_with_attr_upload_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        attr: &str,
+    ) -> Result<CertificateRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_certificates_with_attr_upload_post(
+                    realm,
+                    client_uuid,
+                    attr,
+                ),
+        )
+    }
+
+    /// Upload only certificate, not private key
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `attr`
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/upload-certificate`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrupload_certificate>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/upload-certificate`
+    pub fn realm_clients_with_client_uuid_certificates_with_attr_upload_certificate_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        attr: &str,
+    ) -> Result<CertificateRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_certificates_with_attr_upload_certificate_post(
+                    realm,
+                    client_uuid,
+                    attr,
+                ),
+        )
+    }
+
+    /// Uploads a certificate, prepares the jwks or public key associated, and returns the certificate representation.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `POST /admin/realms/{realm}/identity-provider/upload-certificate`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerupload_certificate>
+    pub fn realm_identity_provider_upload_certificate_post(
+        &self,
+        realm: &str,
+    ) -> Result<CertificateRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_upload_certificate_post(realm),
+        )
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Client Attribute Certificate</h4>
+
+    /// Get key info
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `attr`
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidcertificatesattr>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}`
+    pub fn clients_with_client_uuid_certificates_with_attr_get(
+        &self,
+        client_uuid: &str,
+        attr: &str,
+    ) -> Result<CertificateRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_certificates_with_attr_get(
+                self.realm,
+                client_uuid,
+                attr,
+            )
+    }
+
+    /// Get a keystore file for the client, containing private key and public certificate
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `attr`
+    /// - `body`
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/download`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrdownload>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/download`
+    pub fn clients_with_client_uuid_certificates_with_attr_download_post(
+        &self,
+        client_uuid: &str,
+        attr: &str,
+        body: KeyStoreConfig,
+    ) -> Result<TypeString, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_certificates_with_attr_download_post(
+                self.realm,
+                client_uuid,
+                attr,
+                body,
+            )
+    }
+
+    /// Generate a new certificate with new key pair
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `attr`
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/generate`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrgenerate>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/generate`
+    pub fn clients_with_client_uuid_certificates_with_attr_generate_post(
+        &self,
+        client_uuid: &str,
+        attr: &str,
+    ) -> Result<CertificateRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_certificates_with_attr_generate_post(
+                self.realm,
+                client_uuid,
+                attr,
+            )
+    }
+
+    /// Generate a new keypair and certificate, and get the private key file
+    ///
+    /// Generates a keypair and certificate and serves the private key in a specified keystore format.
+    /// Only generated public certificate is saved in Keycloak DB - the private key is not.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `attr`
+    /// - `body`
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/generate-and-download`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrgenerate_and_download>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/generate-and-download`
+    pub fn clients_with_client_uuid_certificates_with_attr_generate_and_download_post(
+        &self,
+        client_uuid: &str,
+        attr: &str,
+        body: KeyStoreConfig,
+    ) -> Result<TypeString, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_certificates_with_attr_generate_and_download_post(
+                self.realm,
+                client_uuid,
+                attr,
+                body,
+            )
+    }
+
+    /// Upload certificate and eventually private key
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `attr`
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/upload`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrupload>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/upload`
+    pub fn clients_with_client_uuid_certificates_with_attr_upload_post(
+        &self,
+        client_uuid: &str,
+        attr: &str,
+    ) -> Result<CertificateRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_certificates_with_attr_upload_post(
+                self.realm,
+                client_uuid,
+                attr,
+            )
+    }
+
+    /// Upload only certificate, not private key
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `attr`
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/upload-certificate`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrupload_certificate>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/upload-certificate`
+    pub fn clients_with_client_uuid_certificates_with_attr_upload_certificate_post(
+        &self,
+        client_uuid: &str,
+        attr: &str,
+    ) -> Result<CertificateRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_certificates_with_attr_upload_certificate_post(
+                self.realm,
+                client_uuid,
+                attr,
+            )
+    }
+
+    /// Uploads a certificate, prepares the jwks or public key associated, and returns the certificate representation.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Client Attribute Certificate`
+    ///
+    /// `POST /admin/realms/{realm}/identity-provider/upload-certificate`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerupload_certificate>
+    pub fn identity_provider_upload_certificate_post(
+        &self,
+    ) -> Result<CertificateRepresentation, KeycloakError> {
+        self.admin
+            .realm_identity_provider_upload_certificate_post(self.realm)
+    }
+}
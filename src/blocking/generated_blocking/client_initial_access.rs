@@ -0,0 +1,123 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Client Initial Access</h4>
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Client Initial Access`
+    ///
+    /// `GET /admin/realms/{realm}/clients-initial-access`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclients_initial_access>
+    pub fn realm_clients_initial_access_get(
+        &self,
+        realm: &str,
+    ) -> Result<TypeVec<ClientInitialAccessPresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_clients_initial_access_get(realm))
+    }
+
+    /// Create a new initial access token.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Resource: `Client Initial Access`
+    ///
+    /// `POST /admin/realms/{realm}/clients-initial-access`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclients_initial_access>
+    pub fn realm_clients_initial_access_post(
+        &self,
+        realm: &str,
+        body: ClientInitialAccessCreatePresentation,
+    ) -> Result<ClientInitialAccessCreatePresentation, KeycloakError> {
+        self.block_on(self.inner.realm_clients_initial_access_post(realm, body))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Client Initial Access`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients-initial-access/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclients_initial_accessid>
+    pub fn realm_clients_initial_access_with_id_delete(
+        &self,
+        realm: &str,
+        id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_initial_access_with_id_delete(realm, id),
+        )
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Client Initial Access</h4>
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Client Initial Access`
+    ///
+    /// `GET /admin/realms/{realm}/clients-initial-access`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclients_initial_access>
+    pub fn clients_initial_access_get(
+        &self,
+    ) -> Result<TypeVec<ClientInitialAccessPresentation>, KeycloakError> {
+        self.admin.realm_clients_initial_access_get(self.realm)
+    }
+
+    /// Create a new initial access token.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Resource: `Client Initial Access`
+    ///
+    /// `POST /admin/realms/{realm}/clients-initial-access`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclients_initial_access>
+    pub fn clients_initial_access_post(
+        &self,
+        body: ClientInitialAccessCreatePresentation,
+    ) -> Result<ClientInitialAccessCreatePresentation, KeycloakError> {
+        self.admin
+            .realm_clients_initial_access_post(self.realm, body)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Client Initial Access`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients-initial-access/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclients_initial_accessid>
+    pub fn clients_initial_access_with_id_delete(
+        &self,
+        id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_initial_access_with_id_delete(self.realm, id)
+    }
+}
@@ -0,0 +1,48 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Client Registration Policy</h4>
+
+    /// Base path for retrieve providers with the configProperties properly filled
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Client Registration Policy`
+    ///
+    /// `GET /admin/realms/{realm}/client-registration-policy/providers`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_registration_policyproviders>
+    pub fn realm_client_registration_policy_providers_get(
+        &self,
+        realm: &str,
+    ) -> Result<TypeVec<ComponentTypeRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_registration_policy_providers_get(realm),
+        )
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Client Registration Policy</h4>
+
+    /// Base path for retrieve providers with the configProperties properly filled
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Client Registration Policy`
+    ///
+    /// `GET /admin/realms/{realm}/client-registration-policy/providers`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_registration_policyproviders>
+    pub fn client_registration_policy_providers_get(
+        &self,
+    ) -> Result<TypeVec<ComponentTypeRepresentation>, KeycloakError> {
+        self.admin
+            .realm_client_registration_policy_providers_get(self.realm)
+    }
+}
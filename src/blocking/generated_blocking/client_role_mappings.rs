@@ -0,0 +1,611 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Client Role Mappings</h4>
+
+    /// Get client-level role mappings for the user or group, and the app
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `client_id`: client id (not clientId!)
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}`
+    pub fn realm_groups_with_group_id_role_mappings_clients_with_client_id_get(
+        &self,
+        realm: &str,
+        group_id: &str,
+        client_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_role_mappings_clients_with_client_id_get(
+                    realm, group_id, client_id,
+                ),
+        )
+    }
+
+    /// Add client-level roles to the user or group role mapping
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `client_id`: client id (not clientId!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_id>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}`
+    pub fn realm_groups_with_group_id_role_mappings_clients_with_client_id_post(
+        &self,
+        realm: &str,
+        group_id: &str,
+        client_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_role_mappings_clients_with_client_id_post(
+                    realm, group_id, client_id, body,
+                ),
+        )
+    }
+
+    /// Delete client-level roles from user or group role mapping
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `client_id`: client id (not clientId!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_id>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}`
+    pub fn realm_groups_with_group_id_role_mappings_clients_with_client_id_delete(
+        &self,
+        realm: &str,
+        group_id: &str,
+        client_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_role_mappings_clients_with_client_id_delete(
+                    realm, group_id, client_id, body,
+                ),
+        )
+    }
+
+    /// Get available client-level roles that can be mapped to the user or group
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `client_id`: client id (not clientId!)
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_idavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}/available`
+    pub fn realm_groups_with_group_id_role_mappings_clients_with_client_id_available_get(
+        &self,
+        realm: &str,
+        group_id: &str,
+        client_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_role_mappings_clients_with_client_id_available_get(
+                    realm, group_id, client_id,
+                ),
+        )
+    }
+
+    /// Get effective client-level role mappings This recurses any composite roles
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `client_id`: client id (not clientId!)
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_idcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}/composite`
+    pub fn realm_groups_with_group_id_role_mappings_clients_with_client_id_composite_get(
+        &self,
+        realm: &str,
+        group_id: &str,
+        client_id: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_role_mappings_clients_with_client_id_composite_get(
+                    realm,
+                    group_id,
+                    client_id,
+                    brief_representation,
+                ),
+        )
+    }
+
+    /// Get client-level role mappings for the user or group, and the app
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `client_id`: client id (not clientId!)
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsclientsclient_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}`
+    pub fn realm_users_with_user_id_role_mappings_clients_with_client_id_get(
+        &self,
+        realm: &str,
+        user_id: &str,
+        client_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_users_with_user_id_role_mappings_clients_with_client_id_get(
+                    realm, user_id, client_id,
+                ),
+        )
+    }
+
+    /// Add client-level roles to the user or group role mapping
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `client_id`: client id (not clientId!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmusersuser_idrole_mappingsclientsclient_id>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}`
+    pub fn realm_users_with_user_id_role_mappings_clients_with_client_id_post(
+        &self,
+        realm: &str,
+        user_id: &str,
+        client_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_users_with_user_id_role_mappings_clients_with_client_id_post(
+                    realm, user_id, client_id, body,
+                ),
+        )
+    }
+
+    /// Delete client-level roles from user or group role mapping
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `client_id`: client id (not clientId!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmusersuser_idrole_mappingsclientsclient_id>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}`
+    pub fn realm_users_with_user_id_role_mappings_clients_with_client_id_delete(
+        &self,
+        realm: &str,
+        user_id: &str,
+        client_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_users_with_user_id_role_mappings_clients_with_client_id_delete(
+                    realm, user_id, client_id, body,
+                ),
+        )
+    }
+
+    /// Get available client-level roles that can be mapped to the user or group
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `client_id`: client id (not clientId!)
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsclientsclient_idavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}/available`
+    pub fn realm_users_with_user_id_role_mappings_clients_with_client_id_available_get(
+        &self,
+        realm: &str,
+        user_id: &str,
+        client_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_users_with_user_id_role_mappings_clients_with_client_id_available_get(
+                    realm, user_id, client_id,
+                ),
+        )
+    }
+
+    /// Get effective client-level role mappings This recurses any composite roles
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `client_id`: client id (not clientId!)
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsclientsclient_idcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}/composite`
+    pub fn realm_users_with_user_id_role_mappings_clients_with_client_id_composite_get(
+        &self,
+        realm: &str,
+        user_id: &str,
+        client_id: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_users_with_user_id_role_mappings_clients_with_client_id_composite_get(
+                    realm,
+                    user_id,
+                    client_id,
+                    brief_representation,
+                ),
+        )
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Client Role Mappings</h4>
+
+    /// Get client-level role mappings for the user or group, and the app
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `client_id`: client id (not clientId!)
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}`
+    pub fn groups_with_group_id_role_mappings_clients_with_client_id_get(
+        &self,
+        group_id: &str,
+        client_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_role_mappings_clients_with_client_id_get(
+                self.realm, group_id, client_id,
+            )
+    }
+
+    /// Add client-level roles to the user or group role mapping
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `client_id`: client id (not clientId!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_id>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}`
+    pub fn groups_with_group_id_role_mappings_clients_with_client_id_post(
+        &self,
+        group_id: &str,
+        client_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_role_mappings_clients_with_client_id_post(
+                self.realm, group_id, client_id, body,
+            )
+    }
+
+    /// Delete client-level roles from user or group role mapping
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `client_id`: client id (not clientId!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_id>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}`
+    pub fn groups_with_group_id_role_mappings_clients_with_client_id_delete(
+        &self,
+        group_id: &str,
+        client_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_role_mappings_clients_with_client_id_delete(
+                self.realm, group_id, client_id, body,
+            )
+    }
+
+    /// Get available client-level roles that can be mapped to the user or group
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `client_id`: client id (not clientId!)
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_idavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}/available`
+    pub fn groups_with_group_id_role_mappings_clients_with_client_id_available_get(
+        &self,
+        group_id: &str,
+        client_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_role_mappings_clients_with_client_id_available_get(
+                self.realm, group_id, client_id,
+            )
+    }
+
+    /// Get effective client-level role mappings This recurses any composite roles
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `client_id`: client id (not clientId!)
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_idcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}/composite`
+    pub fn groups_with_group_id_role_mappings_clients_with_client_id_composite_get(
+        &self,
+        group_id: &str,
+        client_id: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_role_mappings_clients_with_client_id_composite_get(
+                self.realm,
+                group_id,
+                client_id,
+                brief_representation,
+            )
+    }
+
+    /// Get client-level role mappings for the user or group, and the app
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `client_id`: client id (not clientId!)
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsclientsclient_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}`
+    pub fn users_with_user_id_role_mappings_clients_with_client_id_get(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_users_with_user_id_role_mappings_clients_with_client_id_get(
+                self.realm, user_id, client_id,
+            )
+    }
+
+    /// Add client-level roles to the user or group role mapping
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `client_id`: client id (not clientId!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmusersuser_idrole_mappingsclientsclient_id>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}`
+    pub fn users_with_user_id_role_mappings_clients_with_client_id_post(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_users_with_user_id_role_mappings_clients_with_client_id_post(
+                self.realm, user_id, client_id, body,
+            )
+    }
+
+    /// Delete client-level roles from user or group role mapping
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `client_id`: client id (not clientId!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmusersuser_idrole_mappingsclientsclient_id>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}`
+    pub fn users_with_user_id_role_mappings_clients_with_client_id_delete(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_users_with_user_id_role_mappings_clients_with_client_id_delete(
+                self.realm, user_id, client_id, body,
+            )
+    }
+
+    /// Get available client-level roles that can be mapped to the user or group
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `client_id`: client id (not clientId!)
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsclientsclient_idavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}/available`
+    pub fn users_with_user_id_role_mappings_clients_with_client_id_available_get(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_users_with_user_id_role_mappings_clients_with_client_id_available_get(
+                self.realm, user_id, client_id,
+            )
+    }
+
+    /// Get effective client-level role mappings This recurses any composite roles
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `client_id`: client id (not clientId!)
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Client Role Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsclientsclient_idcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}/composite`
+    pub fn users_with_user_id_role_mappings_clients_with_client_id_composite_get(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_users_with_user_id_role_mappings_clients_with_client_id_composite_get(
+                self.realm,
+                user_id,
+                client_id,
+                brief_representation,
+            )
+    }
+}
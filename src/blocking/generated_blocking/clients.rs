@@ -0,0 +1,1868 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Clients</h4>
+
+    /// Get clients belonging to the realm.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_id`: filter by clientId
+    /// - `first`: the first result
+    /// - `max`: the max results to return
+    /// - `q`
+    /// - `search`: whether this is a search query or a getClientById query
+    /// - `viewable_only`: filter clients that cannot be viewed in full by admin
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclients>
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_clients_get(
+        &self,
+        realm: &str,
+        client_id: Option<String>,
+        first: Option<i32>,
+        max: Option<i32>,
+        q: Option<String>,
+        search: Option<bool>,
+        viewable_only: Option<bool>,
+    ) -> Result<TypeVec<ClientRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_clients_get(
+            realm,
+            client_id,
+            first,
+            max,
+            q,
+            search,
+            viewable_only,
+        ))
+    }
+
+    /// Create a new client Client’s client_id must be unique!
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `POST /admin/realms/{realm}/clients`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclients>
+    pub fn realm_clients_post(
+        &self,
+        realm: &str,
+        body: ClientRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_clients_post(realm, body))
+    }
+
+    /// Get representation of the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuid>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}`
+    pub fn realm_clients_with_client_uuid_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<ClientRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_get(realm, client_uuid),
+        )
+    }
+
+    /// Update the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuid>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}`
+    pub fn realm_clients_with_client_uuid_put(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        body: ClientRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_put(realm, client_uuid, body),
+        )
+    }
+
+    /// Delete the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuid>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}`
+    pub fn realm_clients_with_client_uuid_delete(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_delete(realm, client_uuid),
+        )
+    }
+
+    /// Get the client secret
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/client-secret`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidclient_secret>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/client-secret`
+    pub fn realm_clients_with_client_uuid_client_secret_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<CredentialRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_client_secret_get(realm, client_uuid),
+        )
+    }
+
+    /// Generate a new secret for the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/client-secret`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidclient_secret>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/client-secret`
+    pub fn realm_clients_with_client_uuid_client_secret_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<CredentialRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_client_secret_post(realm, client_uuid),
+        )
+    }
+
+    /// Get the rotated client secret
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/client-secret/rotated`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidclient_secretrotated>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/client-secret/rotated`
+    pub fn realm_clients_with_client_uuid_client_secret_rotated_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<CredentialRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_client_secret_rotated_get(realm, client_uuid),
+        )
+    }
+
+    /// Invalidate the rotated secret for the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/client-secret/rotated`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidclient_secretrotated>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/client-secret/rotated`
+    pub fn realm_clients_with_client_uuid_client_secret_rotated_delete(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_client_secret_rotated_delete(realm, client_uuid),
+        )
+    }
+
+    /// Get default client scopes.  Only name and ids are returned.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/default-client-scopes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuiddefault_client_scopes>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/default-client-scopes`
+    pub fn realm_clients_with_client_uuid_default_client_scopes_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_default_client_scopes_get(realm, client_uuid),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/default-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuiddefault_client_scopesclientscopeid>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/default-client-scopes/{clientScopeId}`
+    pub fn realm_clients_with_client_uuid_default_client_scopes_with_client_scope_id_put(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_default_client_scopes_with_client_scope_id_put(
+                    realm,
+                    client_uuid,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/default-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuiddefault_client_scopesclientscopeid>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/default-client-scopes/{clientScopeId}`
+    pub fn realm_clients_with_client_uuid_default_client_scopes_with_client_scope_id_delete(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_default_client_scopes_with_client_scope_id_delete(
+                    realm,
+                    client_uuid,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Create JSON with payload of example access token
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `audience`
+    /// - `scope`
+    /// - `user_id`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/generate-example-access-token`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesgenerate_example_access_token>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-access-token`
+    pub fn realm_clients_with_client_uuid_evaluate_scopes_generate_example_access_token_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        audience: Option<String>,
+        scope: Option<String>,
+        user_id: Option<String>,
+    ) -> Result<AccessToken, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_evaluate_scopes_generate_example_access_token_get(
+                    realm,
+                    client_uuid,
+                    audience,
+                    scope,
+                    user_id,
+                ),
+        )
+    }
+
+    /// Create JSON with payload of example id token
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `audience`
+    /// - `scope`
+    /// - `user_id`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/generate-example-id-token`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesgenerate_example_id_token>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-id-token`
+    pub fn realm_clients_with_client_uuid_evaluate_scopes_generate_example_id_token_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        audience: Option<String>,
+        scope: Option<String>,
+        user_id: Option<String>,
+    ) -> Result<IDToken, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_evaluate_scopes_generate_example_id_token_get(
+                    realm,
+                    client_uuid,
+                    audience,
+                    scope,
+                    user_id,
+                ),
+        )
+    }
+
+    /// Create JSON with payload of example user info
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope`
+    /// - `user_id`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/generate-example-userinfo`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesgenerate_example_userinfo>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-userinfo`
+    pub fn realm_clients_with_client_uuid_evaluate_scopes_generate_example_userinfo_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        scope: Option<String>,
+        user_id: Option<String>,
+    ) -> Result<Value, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_evaluate_scopes_generate_example_userinfo_get(
+                    realm,
+                    client_uuid,
+                    scope,
+                    user_id,
+                ),
+        )
+    }
+
+    /// Return list of all protocol mappers, which will be used when generating tokens issued for particular client.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/protocol-mappers`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesprotocol_mappers>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/protocol-mappers`
+    pub fn realm_clients_with_client_uuid_evaluate_scopes_protocol_mappers_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        scope: Option<String>,
+    ) -> Result<TypeVec<ProtocolMapperEvaluationRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_evaluate_scopes_protocol_mappers_get(
+                    realm,
+                    client_uuid,
+                    scope,
+                ),
+        )
+    }
+
+    /// Get effective scope mapping of all roles of particular role container, which this client is defacto allowed to have in the accessToken issued for him.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_container_id`: either realm name OR client UUID
+    /// - `scope`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/scope-mappings/{role_container_id}/granted`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesscope_mappingsrolecontaineridgranted>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/scope-mappings/{roleContainerId}/granted`
+    pub fn realm_clients_with_client_uuid_evaluate_scopes_scope_mappings_with_role_container_id_granted_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_container_id: &str,
+        scope: Option<String>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_clients_with_client_uuid_evaluate_scopes_scope_mappings_with_role_container_id_granted_get(
+            realm,
+            client_uuid,
+            role_container_id,
+            scope,
+        ))
+    }
+
+    /// Get roles, which this client doesn't have scope for and can't have them in the accessToken issued for him.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_container_id`: either realm name OR client UUID
+    /// - `scope`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/scope-mappings/{role_container_id}/not-granted`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesscope_mappingsrolecontaineridnot_granted>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/scope-mappings/{roleContainerId}/not-granted`
+    pub fn realm_clients_with_client_uuid_evaluate_scopes_scope_mappings_with_role_container_id_not_granted_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_container_id: &str,
+        scope: Option<String>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_clients_with_client_uuid_evaluate_scopes_scope_mappings_with_role_container_id_not_granted_get(
+            realm,
+            client_uuid,
+            role_container_id,
+            scope,
+        ))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `provider_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/installation/providers/{provider_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidinstallationprovidersproviderid>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/installation/providers/{providerId}`
+    pub fn realm_clients_with_client_uuid_installation_providers_with_provider_id_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        provider_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_installation_providers_with_provider_id_get(
+                    realm,
+                    client_uuid,
+                    provider_id,
+                ),
+        )
+    }
+
+    /// Return object stating whether client Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidmanagementpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/management/permissions`
+    pub fn realm_clients_with_client_uuid_management_permissions_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_management_permissions_get(realm, client_uuid),
+        )
+    }
+
+    /// Return object stating whether client Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidmanagementpermissions>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/management/permissions`
+    pub fn realm_clients_with_client_uuid_management_permissions_put(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_management_permissions_put(
+                    realm,
+                    client_uuid,
+                    body,
+                ),
+        )
+    }
+
+    /// Register a cluster node with the client Manually register cluster node to this client - usually it’s not needed to call this directly as adapter should handle by sending registration request to Keycloak
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/nodes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidnodes>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/nodes`
+    pub fn realm_clients_with_client_uuid_nodes_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        body: TypeMap<String, String>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_clients_with_client_uuid_nodes_post(
+            realm,
+            client_uuid,
+            body,
+        ))
+    }
+
+    /// Unregister a cluster node from the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `node`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/nodes/{node}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidnodesnode>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/nodes/{node}`
+    pub fn realm_clients_with_client_uuid_nodes_with_node_delete(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        node: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_nodes_with_node_delete(realm, client_uuid, node),
+        )
+    }
+
+    /// Get application offline session count Returns a number of offline user sessions associated with this client { "count": number }
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/offline-session-count`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidoffline_session_count>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/offline-session-count`
+    pub fn realm_clients_with_client_uuid_offline_session_count_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<TypeMap<String, i64>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_offline_session_count_get(realm, client_uuid),
+        )
+    }
+
+    /// Get offline sessions for client Returns a list of offline user sessions associated with this client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `first`: Paging offset
+    /// - `max`: Maximum results size (defaults to 100)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/offline-sessions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidoffline_sessions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/offline-sessions`
+    pub fn realm_clients_with_client_uuid_offline_sessions_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserSessionRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_offline_sessions_get(
+                    realm,
+                    client_uuid,
+                    first,
+                    max,
+                ),
+        )
+    }
+
+    /// Get optional client scopes.  Only name and ids are returned.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/optional-client-scopes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidoptional_client_scopes>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes`
+    pub fn realm_clients_with_client_uuid_optional_client_scopes_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_optional_client_scopes_get(realm, client_uuid),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/optional-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidoptional_client_scopesclientscopeid>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes/{clientScopeId}`
+    pub fn realm_clients_with_client_uuid_optional_client_scopes_with_client_scope_id_put(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_optional_client_scopes_with_client_scope_id_put(
+                    realm,
+                    client_uuid,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/optional-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidoptional_client_scopesclientscopeid>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes/{clientScopeId}`
+    pub fn realm_clients_with_client_uuid_optional_client_scopes_with_client_scope_id_delete(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_optional_client_scopes_with_client_scope_id_delete(
+                    realm,
+                    client_uuid,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Push the client's revocation policy to its admin URL If the client has an admin URL, push revocation policy to it.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/push-revocation`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidpush_revocation>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/push-revocation`
+    pub fn realm_clients_with_client_uuid_push_revocation_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<GlobalRequestResult, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_push_revocation_post(realm, client_uuid),
+        )
+    }
+
+    /// Generate a new registration access token for the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/registration-access-token`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidregistration_access_token>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/registration-access-token`
+    pub fn realm_clients_with_client_uuid_registration_access_token_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<ClientRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_registration_access_token_post(realm, client_uuid),
+        )
+    }
+
+    /// Get a user dedicated to the service account
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/service-account-user`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidservice_account_user>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/service-account-user`
+    pub fn realm_clients_with_client_uuid_service_account_user_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<UserRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_service_account_user_get(realm, client_uuid),
+        )
+    }
+
+    /// Get application session count Returns a number of user sessions associated with this client { "count": number }
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/session-count`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidsession_count>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/session-count`
+    pub fn realm_clients_with_client_uuid_session_count_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<TypeMap<String, i64>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_session_count_get(realm, client_uuid),
+        )
+    }
+
+    /// Test if registered cluster nodes are available Tests availability by sending 'ping' request to all cluster nodes.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/test-nodes-available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidtest_nodes_available>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/test-nodes-available`
+    pub fn realm_clients_with_client_uuid_test_nodes_available_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<GlobalRequestResult, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_test_nodes_available_get(realm, client_uuid),
+        )
+    }
+
+    /// Get user sessions for client Returns a list of user sessions associated with this client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `first`: Paging offset
+    /// - `max`: Maximum results size (defaults to 100)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/user-sessions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuiduser_sessions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/user-sessions`
+    pub fn realm_clients_with_client_uuid_user_sessions_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserSessionRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_clients_with_client_uuid_user_sessions_get(
+            realm,
+            client_uuid,
+            first,
+            max,
+        ))
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Clients</h4>
+
+    /// Get clients belonging to the realm.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_id`: filter by clientId
+    /// - `first`: the first result
+    /// - `max`: the max results to return
+    /// - `q`
+    /// - `search`: whether this is a search query or a getClientById query
+    /// - `viewable_only`: filter clients that cannot be viewed in full by admin
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclients>
+    pub fn clients_get(
+        &self,
+        client_id: Option<String>,
+        first: Option<i32>,
+        max: Option<i32>,
+        q: Option<String>,
+        search: Option<bool>,
+        viewable_only: Option<bool>,
+    ) -> Result<TypeVec<ClientRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_get(self.realm, client_id, first, max, q, search, viewable_only)
+    }
+
+    /// Create a new client Client’s client_id must be unique!
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `POST /admin/realms/{realm}/clients`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclients>
+    pub fn clients_post(
+        &self,
+        body: ClientRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_clients_post(self.realm, body)
+    }
+
+    /// Get representation of the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuid>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}`
+    pub fn clients_with_client_uuid_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<ClientRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_get(self.realm, client_uuid)
+    }
+
+    /// Update the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuid>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}`
+    pub fn clients_with_client_uuid_put(
+        &self,
+        client_uuid: &str,
+        body: ClientRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_put(self.realm, client_uuid, body)
+    }
+
+    /// Delete the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuid>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}`
+    pub fn clients_with_client_uuid_delete(
+        &self,
+        client_uuid: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_delete(self.realm, client_uuid)
+    }
+
+    /// Get the client secret
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/client-secret`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidclient_secret>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/client-secret`
+    pub fn clients_with_client_uuid_client_secret_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<CredentialRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_client_secret_get(self.realm, client_uuid)
+    }
+
+    /// Generate a new secret for the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/client-secret`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidclient_secret>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/client-secret`
+    pub fn clients_with_client_uuid_client_secret_post(
+        &self,
+        client_uuid: &str,
+    ) -> Result<CredentialRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_client_secret_post(self.realm, client_uuid)
+    }
+
+    /// Get the rotated client secret
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/client-secret/rotated`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidclient_secretrotated>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/client-secret/rotated`
+    pub fn clients_with_client_uuid_client_secret_rotated_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<CredentialRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_client_secret_rotated_get(self.realm, client_uuid)
+    }
+
+    /// Invalidate the rotated secret for the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/client-secret/rotated`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidclient_secretrotated>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/client-secret/rotated`
+    pub fn clients_with_client_uuid_client_secret_rotated_delete(
+        &self,
+        client_uuid: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_client_secret_rotated_delete(self.realm, client_uuid)
+    }
+
+    /// Get default client scopes.  Only name and ids are returned.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/default-client-scopes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuiddefault_client_scopes>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/default-client-scopes`
+    pub fn clients_with_client_uuid_default_client_scopes_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_default_client_scopes_get(self.realm, client_uuid)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/default-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuiddefault_client_scopesclientscopeid>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/default-client-scopes/{clientScopeId}`
+    pub fn clients_with_client_uuid_default_client_scopes_with_client_scope_id_put(
+        &self,
+        client_uuid: &str,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_default_client_scopes_with_client_scope_id_put(
+                self.realm,
+                client_uuid,
+                client_scope_id,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/default-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuiddefault_client_scopesclientscopeid>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/default-client-scopes/{clientScopeId}`
+    pub fn clients_with_client_uuid_default_client_scopes_with_client_scope_id_delete(
+        &self,
+        client_uuid: &str,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_default_client_scopes_with_client_scope_id_delete(
+                self.realm,
+                client_uuid,
+                client_scope_id,
+            )
+    }
+
+    /// Create JSON with payload of example access token
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `audience`
+    /// - `scope`
+    /// - `user_id`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/generate-example-access-token`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesgenerate_example_access_token>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-access-token`
+    pub fn clients_with_client_uuid_evaluate_scopes_generate_example_access_token_get(
+        &self,
+        client_uuid: &str,
+        audience: Option<String>,
+        scope: Option<String>,
+        user_id: Option<String>,
+    ) -> Result<AccessToken, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_evaluate_scopes_generate_example_access_token_get(
+                self.realm,
+                client_uuid,
+                audience,
+                scope,
+                user_id,
+            )
+    }
+
+    /// Create JSON with payload of example id token
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `audience`
+    /// - `scope`
+    /// - `user_id`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/generate-example-id-token`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesgenerate_example_id_token>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-id-token`
+    pub fn clients_with_client_uuid_evaluate_scopes_generate_example_id_token_get(
+        &self,
+        client_uuid: &str,
+        audience: Option<String>,
+        scope: Option<String>,
+        user_id: Option<String>,
+    ) -> Result<IDToken, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_evaluate_scopes_generate_example_id_token_get(
+                self.realm,
+                client_uuid,
+                audience,
+                scope,
+                user_id,
+            )
+    }
+
+    /// Create JSON with payload of example user info
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope`
+    /// - `user_id`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/generate-example-userinfo`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesgenerate_example_userinfo>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-userinfo`
+    pub fn clients_with_client_uuid_evaluate_scopes_generate_example_userinfo_get(
+        &self,
+        client_uuid: &str,
+        scope: Option<String>,
+        user_id: Option<String>,
+    ) -> Result<Value, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_evaluate_scopes_generate_example_userinfo_get(
+                self.realm,
+                client_uuid,
+                scope,
+                user_id,
+            )
+    }
+
+    /// Return list of all protocol mappers, which will be used when generating tokens issued for particular client.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/protocol-mappers`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesprotocol_mappers>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/protocol-mappers`
+    pub fn clients_with_client_uuid_evaluate_scopes_protocol_mappers_get(
+        &self,
+        client_uuid: &str,
+        scope: Option<String>,
+    ) -> Result<TypeVec<ProtocolMapperEvaluationRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_evaluate_scopes_protocol_mappers_get(
+                self.realm,
+                client_uuid,
+                scope,
+            )
+    }
+
+    /// Get effective scope mapping of all roles of particular role container, which this client is defacto allowed to have in the accessToken issued for him.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_container_id`: either realm name OR client UUID
+    /// - `scope`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/scope-mappings/{role_container_id}/granted`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesscope_mappingsrolecontaineridgranted>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/scope-mappings/{roleContainerId}/granted`
+    pub fn clients_with_client_uuid_evaluate_scopes_scope_mappings_with_role_container_id_granted_get(
+        &self,
+        client_uuid: &str,
+        role_container_id: &str,
+        scope: Option<String>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin.realm_clients_with_client_uuid_evaluate_scopes_scope_mappings_with_role_container_id_granted_get(
+            self.realm,
+            client_uuid,
+            role_container_id,
+            scope,
+        )
+    }
+
+    /// Get roles, which this client doesn't have scope for and can't have them in the accessToken issued for him.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_container_id`: either realm name OR client UUID
+    /// - `scope`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/scope-mappings/{role_container_id}/not-granted`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesscope_mappingsrolecontaineridnot_granted>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/scope-mappings/{roleContainerId}/not-granted`
+    pub fn clients_with_client_uuid_evaluate_scopes_scope_mappings_with_role_container_id_not_granted_get(
+        &self,
+        client_uuid: &str,
+        role_container_id: &str,
+        scope: Option<String>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin.realm_clients_with_client_uuid_evaluate_scopes_scope_mappings_with_role_container_id_not_granted_get(
+            self.realm,
+            client_uuid,
+            role_container_id,
+            scope,
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `provider_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/installation/providers/{provider_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidinstallationprovidersproviderid>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/installation/providers/{providerId}`
+    pub fn clients_with_client_uuid_installation_providers_with_provider_id_get(
+        &self,
+        client_uuid: &str,
+        provider_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_installation_providers_with_provider_id_get(
+                self.realm,
+                client_uuid,
+                provider_id,
+            )
+    }
+
+    /// Return object stating whether client Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidmanagementpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/management/permissions`
+    pub fn clients_with_client_uuid_management_permissions_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_management_permissions_get(self.realm, client_uuid)
+    }
+
+    /// Return object stating whether client Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidmanagementpermissions>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/management/permissions`
+    pub fn clients_with_client_uuid_management_permissions_put(
+        &self,
+        client_uuid: &str,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_management_permissions_put(
+                self.realm,
+                client_uuid,
+                body,
+            )
+    }
+
+    /// Register a cluster node with the client Manually register cluster node to this client - usually it’s not needed to call this directly as adapter should handle by sending registration request to Keycloak
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/nodes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidnodes>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/nodes`
+    pub fn clients_with_client_uuid_nodes_post(
+        &self,
+        client_uuid: &str,
+        body: TypeMap<String, String>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_nodes_post(self.realm, client_uuid, body)
+    }
+
+    /// Unregister a cluster node from the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `node`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/nodes/{node}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidnodesnode>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/nodes/{node}`
+    pub fn clients_with_client_uuid_nodes_with_node_delete(
+        &self,
+        client_uuid: &str,
+        node: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_nodes_with_node_delete(self.realm, client_uuid, node)
+    }
+
+    /// Get application offline session count Returns a number of offline user sessions associated with this client { "count": number }
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/offline-session-count`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidoffline_session_count>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/offline-session-count`
+    pub fn clients_with_client_uuid_offline_session_count_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<TypeMap<String, i64>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_offline_session_count_get(self.realm, client_uuid)
+    }
+
+    /// Get offline sessions for client Returns a list of offline user sessions associated with this client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `first`: Paging offset
+    /// - `max`: Maximum results size (defaults to 100)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/offline-sessions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidoffline_sessions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/offline-sessions`
+    pub fn clients_with_client_uuid_offline_sessions_get(
+        &self,
+        client_uuid: &str,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserSessionRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_offline_sessions_get(
+                self.realm,
+                client_uuid,
+                first,
+                max,
+            )
+    }
+
+    /// Get optional client scopes.  Only name and ids are returned.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/optional-client-scopes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidoptional_client_scopes>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes`
+    pub fn clients_with_client_uuid_optional_client_scopes_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_optional_client_scopes_get(self.realm, client_uuid)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/optional-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidoptional_client_scopesclientscopeid>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes/{clientScopeId}`
+    pub fn clients_with_client_uuid_optional_client_scopes_with_client_scope_id_put(
+        &self,
+        client_uuid: &str,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_optional_client_scopes_with_client_scope_id_put(
+                self.realm,
+                client_uuid,
+                client_scope_id,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/optional-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidoptional_client_scopesclientscopeid>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes/{clientScopeId}`
+    pub fn clients_with_client_uuid_optional_client_scopes_with_client_scope_id_delete(
+        &self,
+        client_uuid: &str,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_optional_client_scopes_with_client_scope_id_delete(
+                self.realm,
+                client_uuid,
+                client_scope_id,
+            )
+    }
+
+    /// Push the client's revocation policy to its admin URL If the client has an admin URL, push revocation policy to it.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/push-revocation`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidpush_revocation>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/push-revocation`
+    pub fn clients_with_client_uuid_push_revocation_post(
+        &self,
+        client_uuid: &str,
+    ) -> Result<GlobalRequestResult, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_push_revocation_post(self.realm, client_uuid)
+    }
+
+    /// Generate a new registration access token for the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/registration-access-token`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidregistration_access_token>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/registration-access-token`
+    pub fn clients_with_client_uuid_registration_access_token_post(
+        &self,
+        client_uuid: &str,
+    ) -> Result<ClientRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_registration_access_token_post(self.realm, client_uuid)
+    }
+
+    /// Get a user dedicated to the service account
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/service-account-user`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidservice_account_user>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/service-account-user`
+    pub fn clients_with_client_uuid_service_account_user_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<UserRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_service_account_user_get(self.realm, client_uuid)
+    }
+
+    /// Get application session count Returns a number of user sessions associated with this client { "count": number }
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/session-count`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidsession_count>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/session-count`
+    pub fn clients_with_client_uuid_session_count_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<TypeMap<String, i64>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_session_count_get(self.realm, client_uuid)
+    }
+
+    /// Test if registered cluster nodes are available Tests availability by sending 'ping' request to all cluster nodes.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/test-nodes-available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidtest_nodes_available>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/test-nodes-available`
+    pub fn clients_with_client_uuid_test_nodes_available_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<GlobalRequestResult, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_test_nodes_available_get(self.realm, client_uuid)
+    }
+
+    /// Get user sessions for client Returns a list of user sessions associated with this client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `first`: Paging offset
+    /// - `max`: Maximum results size (defaults to 100)
+    ///
+    /// Resource: `Clients`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/user-sessions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuiduser_sessions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/user-sessions`
+    pub fn clients_with_client_uuid_user_sessions_get(
+        &self,
+        client_uuid: &str,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserSessionRepresentation>, KeycloakError> {
+        self.admin.realm_clients_with_client_uuid_user_sessions_get(
+            self.realm,
+            client_uuid,
+            first,
+            max,
+        )
+    }
+}
@@ -0,0 +1,261 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Component</h4>
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `name`
+    /// - `parent`
+    /// - `provider_id`
+    /// - `type_`
+    ///
+    /// Resource: `Component`
+    ///
+    /// `GET /admin/realms/{realm}/components`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmcomponents>
+    pub fn realm_components_get(
+        &self,
+        realm: &str,
+        name: Option<String>,
+        parent: Option<String>,
+        provider_id: Option<String>,
+        type_: Option<String>,
+    ) -> Result<TypeVec<ComponentRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_components_get(realm, name, parent, provider_id, type_),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Component`
+    ///
+    /// `POST /admin/realms/{realm}/components`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmcomponents>
+    pub fn realm_components_post(
+        &self,
+        realm: &str,
+        body: ComponentRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_components_post(realm, body))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`
+    ///
+    /// Resource: `Component`
+    ///
+    /// `GET /admin/realms/{realm}/components/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmcomponentsid>
+    pub fn realm_components_with_id_get(
+        &self,
+        realm: &str,
+        id: &str,
+    ) -> Result<ComponentRepresentation, KeycloakError> {
+        self.block_on(self.inner.realm_components_with_id_get(realm, id))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Component`
+    ///
+    /// `PUT /admin/realms/{realm}/components/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmcomponentsid>
+    pub fn realm_components_with_id_put(
+        &self,
+        realm: &str,
+        id: &str,
+        body: ComponentRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_components_with_id_put(realm, id, body))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Component`
+    ///
+    /// `DELETE /admin/realms/{realm}/components/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmcomponentsid>
+    pub fn realm_components_with_id_delete(
+        &self,
+        realm: &str,
+        id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_components_with_id_delete(realm, id))
+    }
+
+    /// List of subcomponent types that are available to configure for a particular parent component.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`
+    /// - `type_`
+    ///
+    /// Resource: `Component`
+    ///
+    /// `GET /admin/realms/{realm}/components/{id}/sub-component-types`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmcomponentsidsub_component_types>
+    pub fn realm_components_with_id_sub_component_types_get(
+        &self,
+        realm: &str,
+        id: &str,
+        type_: Option<String>,
+    ) -> Result<TypeVec<ComponentTypeRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_components_with_id_sub_component_types_get(realm, id, type_),
+        )
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Component</h4>
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `name`
+    /// - `parent`
+    /// - `provider_id`
+    /// - `type_`
+    ///
+    /// Resource: `Component`
+    ///
+    /// `GET /admin/realms/{realm}/components`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmcomponents>
+    pub fn components_get(
+        &self,
+        name: Option<String>,
+        parent: Option<String>,
+        provider_id: Option<String>,
+        type_: Option<String>,
+    ) -> Result<TypeVec<ComponentRepresentation>, KeycloakError> {
+        self.admin
+            .realm_components_get(self.realm, name, parent, provider_id, type_)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Component`
+    ///
+    /// `POST /admin/realms/{realm}/components`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmcomponents>
+    pub fn components_post(
+        &self,
+        body: ComponentRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_components_post(self.realm, body)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`
+    ///
+    /// Resource: `Component`
+    ///
+    /// `GET /admin/realms/{realm}/components/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmcomponentsid>
+    pub fn components_with_id_get(
+        &self,
+        id: &str,
+    ) -> Result<ComponentRepresentation, KeycloakError> {
+        self.admin.realm_components_with_id_get(self.realm, id)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Component`
+    ///
+    /// `PUT /admin/realms/{realm}/components/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmcomponentsid>
+    pub fn components_with_id_put(
+        &self,
+        id: &str,
+        body: ComponentRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_components_with_id_put(self.realm, id, body)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Component`
+    ///
+    /// `DELETE /admin/realms/{realm}/components/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmcomponentsid>
+    pub fn components_with_id_delete(&self, id: &str) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_components_with_id_delete(self.realm, id)
+    }
+
+    /// List of subcomponent types that are available to configure for a particular parent component.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`
+    /// - `type_`
+    ///
+    /// Resource: `Component`
+    ///
+    /// `GET /admin/realms/{realm}/components/{id}/sub-component-types`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmcomponentsidsub_component_types>
+    pub fn components_with_id_sub_component_types_get(
+        &self,
+        id: &str,
+        type_: Option<String>,
+    ) -> Result<TypeVec<ComponentTypeRepresentation>, KeycloakError> {
+        self.admin
+            .realm_components_with_id_sub_component_types_get(self.realm, id, type_)
+    }
+}
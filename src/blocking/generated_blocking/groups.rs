@@ -0,0 +1,630 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Groups</h4>
+
+    /// Get group hierarchy.  Only `name` and `id` are returned.  `subGroups` are only returned when using the `search` or `q` parameter. If none of these parameters is provided, the top-level groups are returned without `subGroups` being filled.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `brief_representation`
+    /// - `exact`
+    /// - `first`
+    /// - `max`
+    /// - `populate_hierarchy`
+    /// - `q`
+    /// - `search`
+    /// - `sub_groups_count`: Boolean which defines whether to return the count of subgroups for each group (default: true
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `GET /admin/realms/{realm}/groups`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroups>
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_groups_get(
+        &self,
+        realm: &str,
+        brief_representation: Option<bool>,
+        exact: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+        populate_hierarchy: Option<bool>,
+        q: Option<String>,
+        search: Option<String>,
+        sub_groups_count: Option<bool>,
+    ) -> Result<TypeVec<GroupRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_groups_get(
+            realm,
+            brief_representation,
+            exact,
+            first,
+            max,
+            populate_hierarchy,
+            q,
+            search,
+            sub_groups_count,
+        ))
+    }
+
+    /// create or add a top level realm groupSet or create child.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `POST /admin/realms/{realm}/groups`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmgroups>
+    pub fn realm_groups_post(
+        &self,
+        realm: &str,
+        body: GroupRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_groups_post(realm, body))
+    }
+
+    /// Returns the groups counts.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `search`
+    /// - `top`
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `GET /admin/realms/{realm}/groups/count`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupscount>
+    pub fn realm_groups_count_get(
+        &self,
+        realm: &str,
+        search: Option<String>,
+        top: Option<bool>,
+    ) -> Result<TypeMap<String, i64>, KeycloakError> {
+        self.block_on(self.inner.realm_groups_count_get(realm, search, top))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}`
+    pub fn realm_groups_with_group_id_get(
+        &self,
+        realm: &str,
+        group_id: &str,
+    ) -> Result<GroupRepresentation, KeycloakError> {
+        self.block_on(self.inner.realm_groups_with_group_id_get(realm, group_id))
+    }
+
+    /// Update group, ignores subgroups.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `PUT /admin/realms/{realm}/groups/{group_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmgroupsgroup_id>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/groups/{group-id}`
+    pub fn realm_groups_with_group_id_put(
+        &self,
+        realm: &str,
+        group_id: &str,
+        body: GroupRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_put(realm, group_id, body),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `DELETE /admin/realms/{realm}/groups/{group_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmgroupsgroup_id>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/groups/{group-id}`
+    pub fn realm_groups_with_group_id_delete(
+        &self,
+        realm: &str,
+        group_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_delete(realm, group_id),
+        )
+    }
+
+    /// Return a paginated list of subgroups that have a parent group corresponding to the group on the URL
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `brief_representation`: Boolean which defines whether brief groups representations are returned or not (default: false)
+    /// - `exact`: Boolean which defines whether the params "search" must match exactly or not
+    /// - `first`: The position of the first result to be returned (pagination offset).
+    /// - `max`: The maximum number of results that are to be returned. Defaults to 10
+    /// - `search`: A String representing either an exact group name or a partial name, defaults to prefix search.
+    /// - `sub_groups_count`: Boolean which defines whether to return the count of subgroups for each subgroup of this group (default: true)
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/children`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idchildren>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/children`
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_groups_with_group_id_children_get(
+        &self,
+        realm: &str,
+        group_id: &str,
+        brief_representation: Option<bool>,
+        exact: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+        search: Option<String>,
+        sub_groups_count: Option<bool>,
+    ) -> Result<TypeVec<GroupRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_groups_with_group_id_children_get(
+            realm,
+            group_id,
+            brief_representation,
+            exact,
+            first,
+            max,
+            search,
+            sub_groups_count,
+        ))
+    }
+
+    /// Set or create child.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `POST /admin/realms/{realm}/groups/{group_id}/children`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmgroupsgroup_idchildren>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/groups/{group-id}/children`
+    pub fn realm_groups_with_group_id_children_post(
+        &self,
+        realm: &str,
+        group_id: &str,
+        body: GroupRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_children_post(realm, group_id, body),
+        )
+    }
+
+    /// Return object stating whether client Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idmanagementpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/management/permissions`
+    pub fn realm_groups_with_group_id_management_permissions_get(
+        &self,
+        realm: &str,
+        group_id: &str,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_management_permissions_get(realm, group_id),
+        )
+    }
+
+    /// Return object stating whether client Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `body`
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `PUT /admin/realms/{realm}/groups/{group_id}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmgroupsgroup_idmanagementpermissions>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/groups/{group-id}/management/permissions`
+    pub fn realm_groups_with_group_id_management_permissions_put(
+        &self,
+        realm: &str,
+        group_id: &str,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_management_permissions_put(realm, group_id, body),
+        )
+    }
+
+    /// Get users Returns a stream of users, filtered according to query parameters
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `brief_representation`: Only return basic information (only guaranteed to return id, username, created, first and last name, email, enabled state, email verification state, federation link, and access. Note that it means that namely user attributes, required actions, and not before are not returned.)
+    /// - `first`: Pagination offset
+    /// - `max`: Maximum results size (defaults to 100)
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/members`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idmembers>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/members`
+    pub fn realm_groups_with_group_id_members_get(
+        &self,
+        realm: &str,
+        group_id: &str,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_groups_with_group_id_members_get(
+            realm,
+            group_id,
+            brief_representation,
+            first,
+            max,
+        ))
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Groups</h4>
+
+    /// Get group hierarchy.  Only `name` and `id` are returned.  `subGroups` are only returned when using the `search` or `q` parameter. If none of these parameters is provided, the top-level groups are returned without `subGroups` being filled.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `brief_representation`
+    /// - `exact`
+    /// - `first`
+    /// - `max`
+    /// - `populate_hierarchy`
+    /// - `q`
+    /// - `search`
+    /// - `sub_groups_count`: Boolean which defines whether to return the count of subgroups for each group (default: true
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `GET /admin/realms/{realm}/groups`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroups>
+    #[allow(clippy::too_many_arguments)]
+    pub fn groups_get(
+        &self,
+        brief_representation: Option<bool>,
+        exact: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+        populate_hierarchy: Option<bool>,
+        q: Option<String>,
+        search: Option<String>,
+        sub_groups_count: Option<bool>,
+    ) -> Result<TypeVec<GroupRepresentation>, KeycloakError> {
+        self.admin.realm_groups_get(
+            self.realm,
+            brief_representation,
+            exact,
+            first,
+            max,
+            populate_hierarchy,
+            q,
+            search,
+            sub_groups_count,
+        )
+    }
+
+    /// create or add a top level realm groupSet or create child.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `POST /admin/realms/{realm}/groups`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmgroups>
+    pub fn groups_post(&self, body: GroupRepresentation) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_groups_post(self.realm, body)
+    }
+
+    /// Returns the groups counts.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `search`
+    /// - `top`
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `GET /admin/realms/{realm}/groups/count`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupscount>
+    pub fn groups_count_get(
+        &self,
+        search: Option<String>,
+        top: Option<bool>,
+    ) -> Result<TypeMap<String, i64>, KeycloakError> {
+        self.admin.realm_groups_count_get(self.realm, search, top)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}`
+    pub fn groups_with_group_id_get(
+        &self,
+        group_id: &str,
+    ) -> Result<GroupRepresentation, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_get(self.realm, group_id)
+    }
+
+    /// Update group, ignores subgroups.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `PUT /admin/realms/{realm}/groups/{group_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmgroupsgroup_id>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/groups/{group-id}`
+    pub fn groups_with_group_id_put(
+        &self,
+        group_id: &str,
+        body: GroupRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_put(self.realm, group_id, body)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `DELETE /admin/realms/{realm}/groups/{group_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmgroupsgroup_id>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/groups/{group-id}`
+    pub fn groups_with_group_id_delete(
+        &self,
+        group_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_delete(self.realm, group_id)
+    }
+
+    /// Return a paginated list of subgroups that have a parent group corresponding to the group on the URL
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `brief_representation`: Boolean which defines whether brief groups representations are returned or not (default: false)
+    /// - `exact`: Boolean which defines whether the params "search" must match exactly or not
+    /// - `first`: The position of the first result to be returned (pagination offset).
+    /// - `max`: The maximum number of results that are to be returned. Defaults to 10
+    /// - `search`: A String representing either an exact group name or a partial name, defaults to prefix search.
+    /// - `sub_groups_count`: Boolean which defines whether to return the count of subgroups for each subgroup of this group (default: true)
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/children`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idchildren>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/children`
+    #[allow(clippy::too_many_arguments)]
+    pub fn groups_with_group_id_children_get(
+        &self,
+        group_id: &str,
+        brief_representation: Option<bool>,
+        exact: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+        search: Option<String>,
+        sub_groups_count: Option<bool>,
+    ) -> Result<TypeVec<GroupRepresentation>, KeycloakError> {
+        self.admin.realm_groups_with_group_id_children_get(
+            self.realm,
+            group_id,
+            brief_representation,
+            exact,
+            first,
+            max,
+            search,
+            sub_groups_count,
+        )
+    }
+
+    /// Set or create child.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `POST /admin/realms/{realm}/groups/{group_id}/children`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmgroupsgroup_idchildren>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/groups/{group-id}/children`
+    pub fn groups_with_group_id_children_post(
+        &self,
+        group_id: &str,
+        body: GroupRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_children_post(self.realm, group_id, body)
+    }
+
+    /// Return object stating whether client Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idmanagementpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/management/permissions`
+    pub fn groups_with_group_id_management_permissions_get(
+        &self,
+        group_id: &str,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_management_permissions_get(self.realm, group_id)
+    }
+
+    /// Return object stating whether client Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `body`
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `PUT /admin/realms/{realm}/groups/{group_id}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmgroupsgroup_idmanagementpermissions>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/groups/{group-id}/management/permissions`
+    pub fn groups_with_group_id_management_permissions_put(
+        &self,
+        group_id: &str,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_management_permissions_put(self.realm, group_id, body)
+    }
+
+    /// Get users Returns a stream of users, filtered according to query parameters
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `brief_representation`: Only return basic information (only guaranteed to return id, username, created, first and last name, email, enabled state, email verification state, federation link, and access. Note that it means that namely user attributes, required actions, and not before are not returned.)
+    /// - `first`: Pagination offset
+    /// - `max`: Maximum results size (defaults to 100)
+    ///
+    /// Resource: `Groups`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/members`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idmembers>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/members`
+    pub fn groups_with_group_id_members_get(
+        &self,
+        group_id: &str,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
+        self.admin.realm_groups_with_group_id_members_get(
+            self.realm,
+            group_id,
+            brief_representation,
+            first,
+            max,
+        )
+    }
+}
@@ -0,0 +1,856 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Identity Providers</h4>
+
+    /// Import identity provider from JSON body
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `POST /admin/realms/{realm}/identity-provider/import-config`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerimport_config>
+    pub fn realm_identity_provider_import_config_post(
+        &self,
+        realm: &str,
+        body: TypeMap<String, Value>,
+    ) -> Result<TypeMap<String, TypeString>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_import_config_post(realm, body),
+        )
+    }
+
+    /// List identity providers
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `brief_representation`: Boolean which defines whether brief representations are returned (default: false)
+    /// - `capability`: Filter by identity providers capability
+    /// - `first`: Pagination offset
+    /// - `max`: Maximum results size (defaults to 100)
+    /// - `realm_only`: Boolean which defines if only realm-level IDPs (not associated with orgs) should be returned (default: false)
+    /// - `search`: Filter specific providers by name. Search can be prefix (name*), contains (*name*) or exact ("name"). Default prefixed.
+    /// - `type_`: Filter by identity providers type
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstances>
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_identity_provider_instances_get(
+        &self,
+        realm: &str,
+        brief_representation: Option<bool>,
+        capability: Option<String>,
+        first: Option<i32>,
+        max: Option<i32>,
+        realm_only: Option<bool>,
+        search: Option<String>,
+        type_: Option<String>,
+    ) -> Result<TypeVec<IdentityProviderRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_identity_provider_instances_get(
+            realm,
+            brief_representation,
+            capability,
+            first,
+            max,
+            realm_only,
+            search,
+            type_,
+        ))
+    }
+
+    /// Create a new identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `POST /admin/realms/{realm}/identity-provider/instances`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerinstances>
+    pub fn realm_identity_provider_instances_post(
+        &self,
+        realm: &str,
+        body: IdentityProviderRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_post(realm, body),
+        )
+    }
+
+    /// Get the identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesalias>
+    pub fn realm_identity_provider_instances_with_alias_get(
+        &self,
+        realm: &str,
+        alias: &str,
+    ) -> Result<IdentityProviderRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_with_alias_get(realm, alias),
+        )
+    }
+
+    /// Update the identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `PUT /admin/realms/{realm}/identity-provider/instances/{alias}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmidentity_providerinstancesalias>
+    pub fn realm_identity_provider_instances_with_alias_put(
+        &self,
+        realm: &str,
+        alias: &str,
+        body: IdentityProviderRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_with_alias_put(realm, alias, body),
+        )
+    }
+
+    /// Delete the identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `DELETE /admin/realms/{realm}/identity-provider/instances/{alias}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmidentity_providerinstancesalias>
+    pub fn realm_identity_provider_instances_with_alias_delete(
+        &self,
+        realm: &str,
+        alias: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_with_alias_delete(realm, alias),
+        )
+    }
+
+    /// Export public broker configuration for identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `format`: Format to use
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/export`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasexport>
+    pub fn realm_identity_provider_instances_with_alias_export_get(
+        &self,
+        realm: &str,
+        alias: &str,
+        format: Option<String>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_with_alias_export_get(realm, alias, format),
+        )
+    }
+
+    /// Return object stating whether client Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasmanagementpermissions>
+    pub fn realm_identity_provider_instances_with_alias_management_permissions_get(
+        &self,
+        realm: &str,
+        alias: &str,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_with_alias_management_permissions_get(
+                    realm, alias,
+                ),
+        )
+    }
+
+    /// Return object stating whether client Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `body`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `PUT /admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmidentity_providerinstancesaliasmanagementpermissions>
+    pub fn realm_identity_provider_instances_with_alias_management_permissions_put(
+        &self,
+        realm: &str,
+        alias: &str,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_with_alias_management_permissions_put(
+                    realm, alias, body,
+                ),
+        )
+    }
+
+    /// Get mapper types for identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/mapper-types`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasmapper_types>
+    pub fn realm_identity_provider_instances_with_alias_mapper_types_get(
+        &self,
+        realm: &str,
+        alias: &str,
+    ) -> Result<TypeMap<String, IdentityProviderMapperTypeRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_with_alias_mapper_types_get(realm, alias),
+        )
+    }
+
+    /// Get mappers for identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/mappers`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasmappers>
+    pub fn realm_identity_provider_instances_with_alias_mappers_get(
+        &self,
+        realm: &str,
+        alias: &str,
+    ) -> Result<TypeVec<IdentityProviderMapperRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_with_alias_mappers_get(realm, alias),
+        )
+    }
+
+    /// Add a mapper to identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `POST /admin/realms/{realm}/identity-provider/instances/{alias}/mappers`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerinstancesaliasmappers>
+    pub fn realm_identity_provider_instances_with_alias_mappers_post(
+        &self,
+        realm: &str,
+        alias: &str,
+        body: IdentityProviderMapperRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_with_alias_mappers_post(realm, alias, body),
+        )
+    }
+
+    /// Get mapper by id for the identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `id`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasmappersid>
+    pub fn realm_identity_provider_instances_with_alias_mappers_with_id_get(
+        &self,
+        realm: &str,
+        alias: &str,
+        id: &str,
+    ) -> Result<IdentityProviderMapperRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_with_alias_mappers_with_id_get(realm, alias, id),
+        )
+    }
+
+    /// Update a mapper for the identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `id`: Mapper id
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `PUT /admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmidentity_providerinstancesaliasmappersid>
+    pub fn realm_identity_provider_instances_with_alias_mappers_with_id_put(
+        &self,
+        realm: &str,
+        alias: &str,
+        id: &str,
+        body: IdentityProviderMapperRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_with_alias_mappers_with_id_put(
+                    realm, alias, id, body,
+                ),
+        )
+    }
+
+    /// Delete a mapper for the identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `id`: Mapper id
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `DELETE /admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmidentity_providerinstancesaliasmappersid>
+    pub fn realm_identity_provider_instances_with_alias_mappers_with_id_delete(
+        &self,
+        realm: &str,
+        alias: &str,
+        id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_with_alias_mappers_with_id_delete(
+                    realm, alias, id,
+                ),
+        )
+    }
+
+    /// Reaload keys for the identity provider if the provider supports it, "true" is returned if reload was performed, "false" if not.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/reload-keys`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasreload_keys>
+    pub fn realm_identity_provider_instances_with_alias_reload_keys_get(
+        &self,
+        realm: &str,
+        alias: &str,
+    ) -> Result<bool, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_instances_with_alias_reload_keys_get(realm, alias),
+        )
+    }
+
+    /// Get the identity provider factory for that provider id
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `provider_id`: The provider id to get the factory
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/providers/{provider_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerprovidersprovider_id>
+    pub fn realm_identity_provider_providers_with_provider_id_get(
+        &self,
+        realm: &str,
+        provider_id: &str,
+    ) -> Result<IdentityProviderRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_identity_provider_providers_with_provider_id_get(realm, provider_id),
+        )
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Identity Providers</h4>
+
+    /// Import identity provider from JSON body
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `POST /admin/realms/{realm}/identity-provider/import-config`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerimport_config>
+    pub fn identity_provider_import_config_post(
+        &self,
+        body: TypeMap<String, Value>,
+    ) -> Result<TypeMap<String, TypeString>, KeycloakError> {
+        self.admin
+            .realm_identity_provider_import_config_post(self.realm, body)
+    }
+
+    /// List identity providers
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `brief_representation`: Boolean which defines whether brief representations are returned (default: false)
+    /// - `capability`: Filter by identity providers capability
+    /// - `first`: Pagination offset
+    /// - `max`: Maximum results size (defaults to 100)
+    /// - `realm_only`: Boolean which defines if only realm-level IDPs (not associated with orgs) should be returned (default: false)
+    /// - `search`: Filter specific providers by name. Search can be prefix (name*), contains (*name*) or exact ("name"). Default prefixed.
+    /// - `type_`: Filter by identity providers type
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstances>
+    #[allow(clippy::too_many_arguments)]
+    pub fn identity_provider_instances_get(
+        &self,
+        brief_representation: Option<bool>,
+        capability: Option<String>,
+        first: Option<i32>,
+        max: Option<i32>,
+        realm_only: Option<bool>,
+        search: Option<String>,
+        type_: Option<String>,
+    ) -> Result<TypeVec<IdentityProviderRepresentation>, KeycloakError> {
+        self.admin.realm_identity_provider_instances_get(
+            self.realm,
+            brief_representation,
+            capability,
+            first,
+            max,
+            realm_only,
+            search,
+            type_,
+        )
+    }
+
+    /// Create a new identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `POST /admin/realms/{realm}/identity-provider/instances`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerinstances>
+    pub fn identity_provider_instances_post(
+        &self,
+        body: IdentityProviderRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_post(self.realm, body)
+    }
+
+    /// Get the identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesalias>
+    pub fn identity_provider_instances_with_alias_get(
+        &self,
+        alias: &str,
+    ) -> Result<IdentityProviderRepresentation, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_with_alias_get(self.realm, alias)
+    }
+
+    /// Update the identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `PUT /admin/realms/{realm}/identity-provider/instances/{alias}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmidentity_providerinstancesalias>
+    pub fn identity_provider_instances_with_alias_put(
+        &self,
+        alias: &str,
+        body: IdentityProviderRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_with_alias_put(self.realm, alias, body)
+    }
+
+    /// Delete the identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `DELETE /admin/realms/{realm}/identity-provider/instances/{alias}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmidentity_providerinstancesalias>
+    pub fn identity_provider_instances_with_alias_delete(
+        &self,
+        alias: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_with_alias_delete(self.realm, alias)
+    }
+
+    /// Export public broker configuration for identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `format`: Format to use
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/export`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasexport>
+    pub fn identity_provider_instances_with_alias_export_get(
+        &self,
+        alias: &str,
+        format: Option<String>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_with_alias_export_get(self.realm, alias, format)
+    }
+
+    /// Return object stating whether client Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasmanagementpermissions>
+    pub fn identity_provider_instances_with_alias_management_permissions_get(
+        &self,
+        alias: &str,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_with_alias_management_permissions_get(
+                self.realm, alias,
+            )
+    }
+
+    /// Return object stating whether client Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `body`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `PUT /admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmidentity_providerinstancesaliasmanagementpermissions>
+    pub fn identity_provider_instances_with_alias_management_permissions_put(
+        &self,
+        alias: &str,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_with_alias_management_permissions_put(
+                self.realm, alias, body,
+            )
+    }
+
+    /// Get mapper types for identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/mapper-types`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasmapper_types>
+    pub fn identity_provider_instances_with_alias_mapper_types_get(
+        &self,
+        alias: &str,
+    ) -> Result<TypeMap<String, IdentityProviderMapperTypeRepresentation>, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_with_alias_mapper_types_get(self.realm, alias)
+    }
+
+    /// Get mappers for identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/mappers`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasmappers>
+    pub fn identity_provider_instances_with_alias_mappers_get(
+        &self,
+        alias: &str,
+    ) -> Result<TypeVec<IdentityProviderMapperRepresentation>, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_with_alias_mappers_get(self.realm, alias)
+    }
+
+    /// Add a mapper to identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `POST /admin/realms/{realm}/identity-provider/instances/{alias}/mappers`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerinstancesaliasmappers>
+    pub fn identity_provider_instances_with_alias_mappers_post(
+        &self,
+        alias: &str,
+        body: IdentityProviderMapperRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_with_alias_mappers_post(self.realm, alias, body)
+    }
+
+    /// Get mapper by id for the identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `id`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasmappersid>
+    pub fn identity_provider_instances_with_alias_mappers_with_id_get(
+        &self,
+        alias: &str,
+        id: &str,
+    ) -> Result<IdentityProviderMapperRepresentation, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_with_alias_mappers_with_id_get(self.realm, alias, id)
+    }
+
+    /// Update a mapper for the identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `id`: Mapper id
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `PUT /admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmidentity_providerinstancesaliasmappersid>
+    pub fn identity_provider_instances_with_alias_mappers_with_id_put(
+        &self,
+        alias: &str,
+        id: &str,
+        body: IdentityProviderMapperRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_with_alias_mappers_with_id_put(
+                self.realm, alias, id, body,
+            )
+    }
+
+    /// Delete a mapper for the identity provider
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    /// - `id`: Mapper id
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `DELETE /admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmidentity_providerinstancesaliasmappersid>
+    pub fn identity_provider_instances_with_alias_mappers_with_id_delete(
+        &self,
+        alias: &str,
+        id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_with_alias_mappers_with_id_delete(
+                self.realm, alias, id,
+            )
+    }
+
+    /// Reaload keys for the identity provider if the provider supports it, "true" is returned if reload was performed, "false" if not.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `alias`
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/reload-keys`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasreload_keys>
+    pub fn identity_provider_instances_with_alias_reload_keys_get(
+        &self,
+        alias: &str,
+    ) -> Result<bool, KeycloakError> {
+        self.admin
+            .realm_identity_provider_instances_with_alias_reload_keys_get(self.realm, alias)
+    }
+
+    /// Get the identity provider factory for that provider id
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `provider_id`: The provider id to get the factory
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `GET /admin/realms/{realm}/identity-provider/providers/{provider_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerprovidersprovider_id>
+    pub fn identity_provider_providers_with_provider_id_get(
+        &self,
+        provider_id: &str,
+    ) -> Result<IdentityProviderRepresentation, KeycloakError> {
+        self.admin
+            .realm_identity_provider_providers_with_provider_id_get(self.realm, provider_id)
+    }
+}
@@ -0,0 +1,35 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Key</h4>
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Key`
+    ///
+    /// `GET /admin/realms/{realm}/keys`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmkeys>
+    pub fn realm_keys_get(&self, realm: &str) -> Result<KeysMetadataRepresentation, KeycloakError> {
+        self.block_on(self.inner.realm_keys_get(realm))
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Key</h4>
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Key`
+    ///
+    /// `GET /admin/realms/{realm}/keys`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmkeys>
+    pub fn keys_get(&self) -> Result<KeysMetadataRepresentation, KeycloakError> {
+        self.admin.realm_keys_get(self.realm)
+    }
+}
@@ -0,0 +1,2336 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>default</h4>
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_server>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<ResourceServerRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_get(realm, client_uuid),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidauthzresource_server>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_put(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        body: ResourceServerRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_put(realm, client_uuid, body),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/import`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverimport>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/import`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_import_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        body: ResourceServerRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_import_post(
+                    realm,
+                    client_uuid,
+                    body,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `fields`
+    /// - `first`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `permission`
+    /// - `policy_id`
+    /// - `resource`
+    /// - `resource_type`
+    /// - `scope`
+    /// - `type_`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpermission>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission`
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_clients_with_client_uuid_authz_resource_server_permission_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        fields: Option<String>,
+        first: Option<i32>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        permission: Option<bool>,
+        policy_id: Option<String>,
+        resource: Option<String>,
+        resource_type: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+    ) -> Result<TypeVec<AbstractPolicyRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_permission_get(
+                    realm,
+                    client_uuid,
+                    fields,
+                    first,
+                    max,
+                    name,
+                    owner,
+                    permission,
+                    policy_id,
+                    resource,
+                    resource_type,
+                    scope,
+                    type_,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverpermission>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_permission_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        body: String,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_permission_post(
+                    realm,
+                    client_uuid,
+                    body,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission/evaluate`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverpermissionevaluate>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/evaluate`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_permission_evaluate_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        body: PolicyEvaluationRequest,
+    ) -> Result<PolicyEvaluationResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_permission_evaluate_post(
+                    realm,
+                    client_uuid,
+                    body,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission/providers`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpermissionproviders>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/providers`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_permission_providers_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<TypeVec<PolicyProviderRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_permission_providers_get(
+                    realm,
+                    client_uuid,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `fields`
+    /// - `name`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission/search`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpermissionsearch>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/search`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_permission_search_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        fields: Option<String>,
+        name: Option<String>,
+    ) -> Result<AbstractPolicyRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_permission_search_get(
+                    realm,
+                    client_uuid,
+                    fields,
+                    name,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `fields`
+    /// - `first`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `permission`
+    /// - `policy_id`
+    /// - `resource`
+    /// - `resource_type`
+    /// - `scope`
+    /// - `type_`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicy>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy`
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_clients_with_client_uuid_authz_resource_server_policy_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        fields: Option<String>,
+        first: Option<i32>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        permission: Option<bool>,
+        policy_id: Option<String>,
+        resource: Option<String>,
+        resource_type: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+    ) -> Result<TypeVec<AbstractPolicyRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_policy_get(
+                    realm,
+                    client_uuid,
+                    fields,
+                    first,
+                    max,
+                    name,
+                    owner,
+                    permission,
+                    policy_id,
+                    resource,
+                    resource_type,
+                    scope,
+                    type_,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicy>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_policy_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        body: String,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_policy_post(
+                    realm,
+                    client_uuid,
+                    body,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy/evaluate`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicyevaluate>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/evaluate`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_policy_evaluate_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        body: PolicyEvaluationRequest,
+    ) -> Result<PolicyEvaluationResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_policy_evaluate_post(
+                    realm,
+                    client_uuid,
+                    body,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy/providers`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicyproviders>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/providers`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_policy_providers_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<TypeVec<PolicyProviderRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_policy_providers_get(
+                    realm,
+                    client_uuid,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `fields`
+    /// - `name`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy/search`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicysearch>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/search`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_policy_search_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        fields: Option<String>,
+        name: Option<String>,
+    ) -> Result<AbstractPolicyRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_policy_search_get(
+                    realm,
+                    client_uuid,
+                    fields,
+                    name,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverresource>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource`
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_clients_with_client_uuid_authz_resource_server_resource_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+    ) -> Result<TypeVec<ResourceRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_resource_get(
+                    realm,
+                    client_uuid,
+                    id,
+                    deep,
+                    exact_name,
+                    first,
+                    matching_uri,
+                    max,
+                    name,
+                    owner,
+                    scope,
+                    type_,
+                    uri,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `body`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverresource>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource`
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_clients_with_client_uuid_authz_resource_server_resource_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        body: ResourceRepresentation,
+    ) -> Result<ResourceRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_resource_post(
+                    realm,
+                    client_uuid,
+                    id,
+                    deep,
+                    exact_name,
+                    first,
+                    matching_uri,
+                    max,
+                    name,
+                    owner,
+                    scope,
+                    type_,
+                    uri,
+                    body,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/search`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverresourcesearch>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/search`
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_clients_with_client_uuid_authz_resource_server_resource_search_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+    ) -> Result<ResourceRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_resource_search_get(
+                    realm,
+                    client_uuid,
+                    id,
+                    deep,
+                    exact_name,
+                    first,
+                    matching_uri,
+                    max,
+                    name,
+                    owner,
+                    scope,
+                    type_,
+                    uri,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `resource_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverresourceresource_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}`
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        resource_id: &str,
+    ) -> Result<ResourceRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_get(
+                    realm,
+                    client_uuid,
+                    id,
+                    deep,
+                    exact_name,
+                    first,
+                    matching_uri,
+                    max,
+                    name,
+                    owner,
+                    scope,
+                    type_,
+                    uri,
+                    resource_id,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `resource_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidauthzresource_serverresourceresource_id>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}`
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_put(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        resource_id: &str,
+        body: ResourceRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_put(
+                    realm,
+                    client_uuid,
+                    id,
+                    deep,
+                    exact_name,
+                    first,
+                    matching_uri,
+                    max,
+                    name,
+                    owner,
+                    scope,
+                    type_,
+                    uri,
+                    resource_id,
+                    body,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `resource_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidauthzresource_serverresourceresource_id>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}`
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_delete(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        resource_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_delete(
+            realm,
+            client_uuid,
+            id,
+            deep,
+            exact_name,
+            first,
+            matching_uri,
+            max,
+            name,
+            owner,
+            scope,
+            type_,
+            uri,
+            resource_id,
+        ))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `resource_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}/attributes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverresourceresource_idattributes>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/attributes`
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_attributes_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        resource_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_attributes_get(
+            realm,
+            client_uuid,
+            id,
+            deep,
+            exact_name,
+            first,
+            matching_uri,
+            max,
+            name,
+            owner,
+            scope,
+            type_,
+            uri,
+            resource_id,
+        ))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `resource_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverresourceresource_idpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/permissions`
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_permissions_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        resource_id: &str,
+    ) -> Result<TypeVec<PolicyRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_permissions_get(
+            realm,
+            client_uuid,
+            id,
+            deep,
+            exact_name,
+            first,
+            matching_uri,
+            max,
+            name,
+            owner,
+            scope,
+            type_,
+            uri,
+            resource_id,
+        ))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `resource_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}/scopes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverresourceresource_idscopes>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/scopes`
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_scopes_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        resource_id: &str,
+    ) -> Result<TypeVec<ScopeRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_scopes_get(
+            realm,
+            client_uuid,
+            id,
+            deep,
+            exact_name,
+            first,
+            matching_uri,
+            max,
+            name,
+            owner,
+            scope,
+            type_,
+            uri,
+            resource_id,
+        ))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `first`
+    /// - `max`
+    /// - `name`
+    /// - `scope_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscope>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_scope_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        first: Option<i32>,
+        max: Option<i32>,
+        name: Option<String>,
+        scope_id: Option<String>,
+    ) -> Result<TypeVec<ScopeRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_scope_get(
+                    realm,
+                    client_uuid,
+                    first,
+                    max,
+                    name,
+                    scope_id,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverscope>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_scope_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        body: ScopeRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_scope_post(
+                    realm,
+                    client_uuid,
+                    body,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `name`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/search`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscopesearch>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/search`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_scope_search_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        name: Option<String>,
+    ) -> Result<TypeVec<ScopeRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_scope_search_get(
+                    realm,
+                    client_uuid,
+                    name,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        scope_id: &str,
+    ) -> Result<ScopeRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_get(
+                    realm,
+                    client_uuid,
+                    scope_id,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_id>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_put(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        scope_id: &str,
+        body: ScopeRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_put(
+                    realm,
+                    client_uuid,
+                    scope_id,
+                    body,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_id>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_delete(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_delete(
+                    realm,
+                    client_uuid,
+                    scope_id,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_idpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}/permissions`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_permissions_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        scope_id: &str,
+    ) -> Result<TypeVec<PolicyRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_permissions_get(
+            realm,
+            client_uuid,
+            scope_id,
+        ))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}/resources`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_idresources>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}/resources`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_resources_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        scope_id: &str,
+    ) -> Result<TypeVec<ResourceRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_resources_get(
+            realm,
+            client_uuid,
+            scope_id,
+        ))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/settings`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serversettings>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/settings`
+    pub fn realm_clients_with_client_uuid_authz_resource_server_settings_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<ResourceServerRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_authz_resource_server_settings_get(
+                    realm,
+                    client_uuid,
+                ),
+        )
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>default</h4>
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_server>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server`
+    pub fn clients_with_client_uuid_authz_resource_server_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<ResourceServerRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_get(self.realm, client_uuid)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidauthzresource_server>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server`
+    pub fn clients_with_client_uuid_authz_resource_server_put(
+        &self,
+        client_uuid: &str,
+        body: ResourceServerRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_put(self.realm, client_uuid, body)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/import`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverimport>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/import`
+    pub fn clients_with_client_uuid_authz_resource_server_import_post(
+        &self,
+        client_uuid: &str,
+        body: ResourceServerRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_import_post(
+                self.realm,
+                client_uuid,
+                body,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `fields`
+    /// - `first`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `permission`
+    /// - `policy_id`
+    /// - `resource`
+    /// - `resource_type`
+    /// - `scope`
+    /// - `type_`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpermission>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission`
+    #[allow(clippy::too_many_arguments)]
+    pub fn clients_with_client_uuid_authz_resource_server_permission_get(
+        &self,
+        client_uuid: &str,
+        fields: Option<String>,
+        first: Option<i32>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        permission: Option<bool>,
+        policy_id: Option<String>,
+        resource: Option<String>,
+        resource_type: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+    ) -> Result<TypeVec<AbstractPolicyRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_permission_get(
+                self.realm,
+                client_uuid,
+                fields,
+                first,
+                max,
+                name,
+                owner,
+                permission,
+                policy_id,
+                resource,
+                resource_type,
+                scope,
+                type_,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverpermission>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission`
+    pub fn clients_with_client_uuid_authz_resource_server_permission_post(
+        &self,
+        client_uuid: &str,
+        body: String,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_permission_post(
+                self.realm,
+                client_uuid,
+                body,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission/evaluate`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverpermissionevaluate>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/evaluate`
+    pub fn clients_with_client_uuid_authz_resource_server_permission_evaluate_post(
+        &self,
+        client_uuid: &str,
+        body: PolicyEvaluationRequest,
+    ) -> Result<PolicyEvaluationResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_permission_evaluate_post(
+                self.realm,
+                client_uuid,
+                body,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission/providers`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpermissionproviders>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/providers`
+    pub fn clients_with_client_uuid_authz_resource_server_permission_providers_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<TypeVec<PolicyProviderRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_permission_providers_get(
+                self.realm,
+                client_uuid,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `fields`
+    /// - `name`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission/search`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpermissionsearch>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/search`
+    pub fn clients_with_client_uuid_authz_resource_server_permission_search_get(
+        &self,
+        client_uuid: &str,
+        fields: Option<String>,
+        name: Option<String>,
+    ) -> Result<AbstractPolicyRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_permission_search_get(
+                self.realm,
+                client_uuid,
+                fields,
+                name,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `fields`
+    /// - `first`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `permission`
+    /// - `policy_id`
+    /// - `resource`
+    /// - `resource_type`
+    /// - `scope`
+    /// - `type_`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicy>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy`
+    #[allow(clippy::too_many_arguments)]
+    pub fn clients_with_client_uuid_authz_resource_server_policy_get(
+        &self,
+        client_uuid: &str,
+        fields: Option<String>,
+        first: Option<i32>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        permission: Option<bool>,
+        policy_id: Option<String>,
+        resource: Option<String>,
+        resource_type: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+    ) -> Result<TypeVec<AbstractPolicyRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_policy_get(
+                self.realm,
+                client_uuid,
+                fields,
+                first,
+                max,
+                name,
+                owner,
+                permission,
+                policy_id,
+                resource,
+                resource_type,
+                scope,
+                type_,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicy>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy`
+    pub fn clients_with_client_uuid_authz_resource_server_policy_post(
+        &self,
+        client_uuid: &str,
+        body: String,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_policy_post(
+                self.realm,
+                client_uuid,
+                body,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy/evaluate`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicyevaluate>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/evaluate`
+    pub fn clients_with_client_uuid_authz_resource_server_policy_evaluate_post(
+        &self,
+        client_uuid: &str,
+        body: PolicyEvaluationRequest,
+    ) -> Result<PolicyEvaluationResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_policy_evaluate_post(
+                self.realm,
+                client_uuid,
+                body,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy/providers`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicyproviders>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/providers`
+    pub fn clients_with_client_uuid_authz_resource_server_policy_providers_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<TypeVec<PolicyProviderRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_policy_providers_get(
+                self.realm,
+                client_uuid,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `fields`
+    /// - `name`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy/search`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicysearch>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/search`
+    pub fn clients_with_client_uuid_authz_resource_server_policy_search_get(
+        &self,
+        client_uuid: &str,
+        fields: Option<String>,
+        name: Option<String>,
+    ) -> Result<AbstractPolicyRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_policy_search_get(
+                self.realm,
+                client_uuid,
+                fields,
+                name,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverresource>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource`
+    #[allow(clippy::too_many_arguments)]
+    pub fn clients_with_client_uuid_authz_resource_server_resource_get(
+        &self,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+    ) -> Result<TypeVec<ResourceRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_resource_get(
+                self.realm,
+                client_uuid,
+                id,
+                deep,
+                exact_name,
+                first,
+                matching_uri,
+                max,
+                name,
+                owner,
+                scope,
+                type_,
+                uri,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `body`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverresource>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource`
+    #[allow(clippy::too_many_arguments)]
+    pub fn clients_with_client_uuid_authz_resource_server_resource_post(
+        &self,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        body: ResourceRepresentation,
+    ) -> Result<ResourceRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_resource_post(
+                self.realm,
+                client_uuid,
+                id,
+                deep,
+                exact_name,
+                first,
+                matching_uri,
+                max,
+                name,
+                owner,
+                scope,
+                type_,
+                uri,
+                body,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/search`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverresourcesearch>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/search`
+    #[allow(clippy::too_many_arguments)]
+    pub fn clients_with_client_uuid_authz_resource_server_resource_search_get(
+        &self,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+    ) -> Result<ResourceRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_resource_search_get(
+                self.realm,
+                client_uuid,
+                id,
+                deep,
+                exact_name,
+                first,
+                matching_uri,
+                max,
+                name,
+                owner,
+                scope,
+                type_,
+                uri,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `resource_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverresourceresource_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}`
+    #[allow(clippy::too_many_arguments)]
+    pub fn clients_with_client_uuid_authz_resource_server_resource_with_resource_id_get(
+        &self,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        resource_id: &str,
+    ) -> Result<ResourceRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_get(
+                self.realm,
+                client_uuid,
+                id,
+                deep,
+                exact_name,
+                first,
+                matching_uri,
+                max,
+                name,
+                owner,
+                scope,
+                type_,
+                uri,
+                resource_id,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `resource_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidauthzresource_serverresourceresource_id>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}`
+    #[allow(clippy::too_many_arguments)]
+    pub fn clients_with_client_uuid_authz_resource_server_resource_with_resource_id_put(
+        &self,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        resource_id: &str,
+        body: ResourceRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_put(
+                self.realm,
+                client_uuid,
+                id,
+                deep,
+                exact_name,
+                first,
+                matching_uri,
+                max,
+                name,
+                owner,
+                scope,
+                type_,
+                uri,
+                resource_id,
+                body,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `resource_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidauthzresource_serverresourceresource_id>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}`
+    #[allow(clippy::too_many_arguments)]
+    pub fn clients_with_client_uuid_authz_resource_server_resource_with_resource_id_delete(
+        &self,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        resource_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_delete(
+                self.realm,
+                client_uuid,
+                id,
+                deep,
+                exact_name,
+                first,
+                matching_uri,
+                max,
+                name,
+                owner,
+                scope,
+                type_,
+                uri,
+                resource_id,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `resource_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}/attributes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverresourceresource_idattributes>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/attributes`
+    #[allow(clippy::too_many_arguments)]
+    pub fn clients_with_client_uuid_authz_resource_server_resource_with_resource_id_attributes_get(
+        &self,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        resource_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_attributes_get(
+            self.realm,
+            client_uuid,
+            id,
+            deep,
+            exact_name,
+            first,
+            matching_uri,
+            max,
+            name,
+            owner,
+            scope,
+            type_,
+            uri,
+            resource_id,
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `resource_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverresourceresource_idpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/permissions`
+    #[allow(clippy::too_many_arguments)]
+    pub fn clients_with_client_uuid_authz_resource_server_resource_with_resource_id_permissions_get(
+        &self,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        resource_id: &str,
+    ) -> Result<TypeVec<PolicyRepresentation>, KeycloakError> {
+        self.admin.realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_permissions_get(
+            self.realm,
+            client_uuid,
+            id,
+            deep,
+            exact_name,
+            first,
+            matching_uri,
+            max,
+            name,
+            owner,
+            scope,
+            type_,
+            uri,
+            resource_id,
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `id`
+    /// - `deep`
+    /// - `exact_name`
+    /// - `first`
+    /// - `matching_uri`
+    /// - `max`
+    /// - `name`
+    /// - `owner`
+    /// - `scope`
+    /// - `type_`
+    /// - `uri`
+    /// - `resource_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}/scopes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverresourceresource_idscopes>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/scopes`
+    #[allow(clippy::too_many_arguments)]
+    pub fn clients_with_client_uuid_authz_resource_server_resource_with_resource_id_scopes_get(
+        &self,
+        client_uuid: &str,
+        id: Option<String>,
+        deep: Option<bool>,
+        exact_name: Option<bool>,
+        first: Option<i32>,
+        matching_uri: Option<bool>,
+        max: Option<i32>,
+        name: Option<String>,
+        owner: Option<String>,
+        scope: Option<String>,
+        type_: Option<String>,
+        uri: Option<String>,
+        resource_id: &str,
+    ) -> Result<TypeVec<ScopeRepresentation>, KeycloakError> {
+        self.admin.realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_scopes_get(
+            self.realm,
+            client_uuid,
+            id,
+            deep,
+            exact_name,
+            first,
+            matching_uri,
+            max,
+            name,
+            owner,
+            scope,
+            type_,
+            uri,
+            resource_id,
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `first`
+    /// - `max`
+    /// - `name`
+    /// - `scope_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscope>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope`
+    pub fn clients_with_client_uuid_authz_resource_server_scope_get(
+        &self,
+        client_uuid: &str,
+        first: Option<i32>,
+        max: Option<i32>,
+        name: Option<String>,
+        scope_id: Option<String>,
+    ) -> Result<TypeVec<ScopeRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_scope_get(
+                self.realm,
+                client_uuid,
+                first,
+                max,
+                name,
+                scope_id,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverscope>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope`
+    pub fn clients_with_client_uuid_authz_resource_server_scope_post(
+        &self,
+        client_uuid: &str,
+        body: ScopeRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_scope_post(
+                self.realm,
+                client_uuid,
+                body,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `name`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/search`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscopesearch>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/search`
+    pub fn clients_with_client_uuid_authz_resource_server_scope_search_get(
+        &self,
+        client_uuid: &str,
+        name: Option<String>,
+    ) -> Result<TypeVec<ScopeRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_scope_search_get(
+                self.realm,
+                client_uuid,
+                name,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}`
+    pub fn clients_with_client_uuid_authz_resource_server_scope_with_scope_id_get(
+        &self,
+        client_uuid: &str,
+        scope_id: &str,
+    ) -> Result<ScopeRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_get(
+                self.realm,
+                client_uuid,
+                scope_id,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_id>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}`
+    pub fn clients_with_client_uuid_authz_resource_server_scope_with_scope_id_put(
+        &self,
+        client_uuid: &str,
+        scope_id: &str,
+        body: ScopeRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_put(
+                self.realm,
+                client_uuid,
+                scope_id,
+                body,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_id>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}`
+    pub fn clients_with_client_uuid_authz_resource_server_scope_with_scope_id_delete(
+        &self,
+        client_uuid: &str,
+        scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_delete(
+                self.realm,
+                client_uuid,
+                scope_id,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_idpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}/permissions`
+    pub fn clients_with_client_uuid_authz_resource_server_scope_with_scope_id_permissions_get(
+        &self,
+        client_uuid: &str,
+        scope_id: &str,
+    ) -> Result<TypeVec<PolicyRepresentation>, KeycloakError> {
+        self.admin.realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_permissions_get(
+            self.realm,
+            client_uuid,
+            scope_id,
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `scope_id`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}/resources`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_idresources>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}/resources`
+    pub fn clients_with_client_uuid_authz_resource_server_scope_with_scope_id_resources_get(
+        &self,
+        client_uuid: &str,
+        scope_id: &str,
+    ) -> Result<TypeVec<ResourceRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_resources_get(
+                self.realm,
+                client_uuid,
+                scope_id,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/settings`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serversettings>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/settings`
+    pub fn clients_with_client_uuid_authz_resource_server_settings_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<ResourceServerRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_authz_resource_server_settings_get(
+                self.realm,
+                client_uuid,
+            )
+    }
+}
@@ -0,0 +1,1890 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Realms Admin</h4>
+
+    /// Get accessible realms Returns a list of accessible realms. The list is filtered based on what realms the caller is allowed to view.
+    ///
+    /// Parameters:
+    ///
+    /// - `brief_representation`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealms>
+    pub fn get(
+        &self,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RealmRepresentation>, KeycloakError> {
+        self.block_on(self.inner.get(brief_representation))
+    }
+
+    /// Import a realm. Imports a realm from a full representation of that realm.
+    ///
+    /// Parameters:
+    ///
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealms>
+    pub fn post(&self, body: RealmRepresentation) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.post(body))
+    }
+
+    /// Get the top-level representation of the realm It will not include nested information like User and Client representations.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealm>
+    pub fn realm_get(&self, realm: &str) -> Result<RealmRepresentation, KeycloakError> {
+        self.block_on(self.inner.realm_get(realm))
+    }
+
+    /// Update the top-level information of the realm Any user, roles or client information in the representation will be ignored.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealm>
+    pub fn realm_put(
+        &self,
+        realm: &str,
+        body: RealmRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_put(realm, body))
+    }
+
+    /// Delete the realm
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealm>
+    pub fn realm_delete(&self, realm: &str) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_delete(realm))
+    }
+
+    /// Get admin events Returns all admin events, or filters events based on URL query parameters listed here
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `auth_client`
+    /// - `auth_ip_address`
+    /// - `auth_realm`
+    /// - `auth_user`: user id
+    /// - `date_from`: From (inclusive) date (yyyy-MM-dd) or time in Epoch timestamp millis (number of milliseconds since January 1, 1970, 00:00:00 GMT)
+    /// - `date_to`: To (inclusive) date (yyyy-MM-dd) or time in Epoch timestamp millis (number of milliseconds since January 1, 1970, 00:00:00 GMT)
+    /// - `direction`: The direction to sort events by (asc or desc)
+    /// - `first`
+    /// - `max`: Maximum results size (defaults to 100)
+    /// - `operation_types`
+    /// - `resource_path`
+    /// - `resource_types`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/admin-events`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmadmin_events>
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_admin_events_get(
+        &self,
+        realm: &str,
+        auth_client: Option<String>,
+        auth_ip_address: Option<String>,
+        auth_realm: Option<String>,
+        auth_user: Option<String>,
+        date_from: Option<String>,
+        date_to: Option<String>,
+        direction: Option<String>,
+        first: Option<i32>,
+        max: Option<i32>,
+        operation_types: Option<Vec<String>>,
+        resource_path: Option<String>,
+        resource_types: Option<Vec<String>>,
+    ) -> Result<TypeVec<AdminEventRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_admin_events_get(
+            realm,
+            auth_client,
+            auth_ip_address,
+            auth_realm,
+            auth_user,
+            date_from,
+            date_to,
+            direction,
+            first,
+            max,
+            operation_types,
+            resource_path,
+            resource_types,
+        ))
+    }
+
+    /// Delete all admin events
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/admin-events`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmadmin_events>
+    pub fn realm_admin_events_delete(&self, realm: &str) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_admin_events_delete(realm))
+    }
+
+    /// Base path for importing clients under this realm.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/client-description-converter`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_description_converter>
+    pub fn realm_client_description_converter_post(
+        &self,
+        realm: &str,
+        body: String,
+    ) -> Result<ClientRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_description_converter_post(realm, body),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `include_global_policies`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/client-policies/policies`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_policiespolicies>
+    pub fn realm_client_policies_policies_get(
+        &self,
+        realm: &str,
+        include_global_policies: Option<bool>,
+    ) -> Result<ClientPoliciesRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_policies_policies_get(realm, include_global_policies),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/client-policies/policies`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclient_policiespolicies>
+    pub fn realm_client_policies_policies_put(
+        &self,
+        realm: &str,
+        body: ClientPoliciesRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_client_policies_policies_put(realm, body))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `include_global_profiles`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/client-policies/profiles`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_policiesprofiles>
+    pub fn realm_client_policies_profiles_get(
+        &self,
+        realm: &str,
+        include_global_profiles: Option<bool>,
+    ) -> Result<ClientProfilesRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_policies_profiles_get(realm, include_global_profiles),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/client-policies/profiles`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclient_policiesprofiles>
+    pub fn realm_client_policies_profiles_put(
+        &self,
+        realm: &str,
+        body: ClientProfilesRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_client_policies_profiles_put(realm, body))
+    }
+
+    /// Get client session stats Returns a JSON map.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/client-session-stats`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_session_stats>
+    pub fn realm_client_session_stats_get(
+        &self,
+        realm: &str,
+    ) -> Result<TypeVec<TypeMap<String, String>>, KeycloakError> {
+        self.block_on(self.inner.realm_client_session_stats_get(realm))
+    }
+
+    /// List all client types available in the current realm
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/client-types`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_types>
+    pub fn realm_client_types_get(
+        &self,
+        realm: &str,
+    ) -> Result<ClientTypesRepresentation, KeycloakError> {
+        self.block_on(self.inner.realm_client_types_get(realm))
+    }
+
+    /// Update a client type
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/client-types`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclient_types>
+    pub fn realm_client_types_put(
+        &self,
+        realm: &str,
+        body: ClientTypesRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_client_types_put(realm, body))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/credential-registrators`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmcredential_registrators>
+    pub fn realm_credential_registrators_get(
+        &self,
+        realm: &str,
+    ) -> Result<TypeVec<String>, KeycloakError> {
+        self.block_on(self.inner.realm_credential_registrators_get(realm))
+    }
+
+    /// Get realm default client scopes. Only name and ids are returned.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/default-default-client-scopes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmdefault_default_client_scopes>
+    pub fn realm_default_default_client_scopes_get(
+        &self,
+        realm: &str,
+    ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_default_default_client_scopes_get(realm))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/default-default-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmdefault_default_client_scopesclientscopeid>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/default-default-client-scopes/{clientScopeId}`
+    pub fn realm_default_default_client_scopes_with_client_scope_id_put(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_default_default_client_scopes_with_client_scope_id_put(
+                    realm,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/default-default-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmdefault_default_client_scopesclientscopeid>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/default-default-client-scopes/{clientScopeId}`
+    pub fn realm_default_default_client_scopes_with_client_scope_id_delete(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_default_default_client_scopes_with_client_scope_id_delete(
+                    realm,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Get group hierarchy.  Only name and ids are returned.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/default-groups`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmdefault_groups>
+    pub fn realm_default_groups_get(
+        &self,
+        realm: &str,
+    ) -> Result<TypeVec<GroupRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_default_groups_get(realm))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/default-groups/{group_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmdefault_groupsgroupid>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/default-groups/{groupId}`
+    pub fn realm_default_groups_with_group_id_put(
+        &self,
+        realm: &str,
+        group_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_default_groups_with_group_id_put(realm, group_id),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/default-groups/{group_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmdefault_groupsgroupid>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/default-groups/{groupId}`
+    pub fn realm_default_groups_with_group_id_delete(
+        &self,
+        realm: &str,
+        group_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_default_groups_with_group_id_delete(realm, group_id),
+        )
+    }
+
+    /// Get realm optional client scopes. Only name and ids are returned.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/default-optional-client-scopes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmdefault_optional_client_scopes>
+    pub fn realm_default_optional_client_scopes_get(
+        &self,
+        realm: &str,
+    ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_default_optional_client_scopes_get(realm))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/default-optional-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmdefault_optional_client_scopesclientscopeid>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/default-optional-client-scopes/{clientScopeId}`
+    pub fn realm_default_optional_client_scopes_with_client_scope_id_put(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_default_optional_client_scopes_with_client_scope_id_put(
+                    realm,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/default-optional-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmdefault_optional_client_scopesclientscopeid>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/default-optional-client-scopes/{clientScopeId}`
+    pub fn realm_default_optional_client_scopes_with_client_scope_id_delete(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_default_optional_client_scopes_with_client_scope_id_delete(
+                    realm,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Get events Returns all events, or filters them based on URL query parameters listed here
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client`: App or oauth client name
+    /// - `date_from`: From (inclusive) date (yyyy-MM-dd) or time in Epoch timestamp millis (number of milliseconds since January 1, 1970, 00:00:00 GMT)
+    /// - `date_to`: To (inclusive) date (yyyy-MM-dd) or time in Epoch timestamp millis (number of milliseconds since January 1, 1970, 00:00:00 GMT)
+    /// - `direction`: The direction to sort events by (asc or desc)
+    /// - `first`: Paging offset
+    /// - `ip_address`: IP Address
+    /// - `max`: Maximum results size (defaults to 100)
+    /// - `type_`: The types of events to return
+    /// - `user`: User id
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/events`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmevents>
+    #[allow(clippy::too_many_arguments)]
+    pub fn realm_events_get(
+        &self,
+        realm: &str,
+        client: Option<String>,
+        date_from: Option<String>,
+        date_to: Option<String>,
+        direction: Option<String>,
+        first: Option<i32>,
+        ip_address: Option<String>,
+        max: Option<i32>,
+        type_: Option<Vec<String>>,
+        user: Option<String>,
+    ) -> Result<TypeVec<EventRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_events_get(
+            realm, client, date_from, date_to, direction, first, ip_address, max, type_, user,
+        ))
+    }
+
+    /// Delete all events
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/events`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmevents>
+    pub fn realm_events_delete(&self, realm: &str) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_events_delete(realm))
+    }
+
+    /// Get the events provider configuration Returns JSON object with events provider configuration
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/events/config`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmeventsconfig>
+    pub fn realm_events_config_get(
+        &self,
+        realm: &str,
+    ) -> Result<RealmEventsConfigRepresentation, KeycloakError> {
+        self.block_on(self.inner.realm_events_config_get(realm))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/events/config`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmeventsconfig>
+    pub fn realm_events_config_put(
+        &self,
+        realm: &str,
+        body: RealmEventsConfigRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_events_config_put(realm, body))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `path`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/group-by-path/{path}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroup_by_pathpath>
+    pub fn realm_group_by_path_with_path_get(
+        &self,
+        realm: &str,
+        path: &str,
+    ) -> Result<GroupRepresentation, KeycloakError> {
+        self.block_on(self.inner.realm_group_by_path_with_path_get(realm, path))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/localization`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmlocalization>
+    pub fn realm_localization_get(&self, realm: &str) -> Result<TypeVec<String>, KeycloakError> {
+        self.block_on(self.inner.realm_localization_get(realm))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `locale`
+    /// - `use_realm_default_locale_fallback`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/localization/{locale}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmlocalizationlocale>
+    pub fn realm_localization_with_locale_get(
+        &self,
+        realm: &str,
+        locale: &str,
+        use_realm_default_locale_fallback: Option<bool>,
+    ) -> Result<TypeMap<String, TypeString>, KeycloakError> {
+        self.block_on(self.inner.realm_localization_with_locale_get(
+            realm,
+            locale,
+            use_realm_default_locale_fallback,
+        ))
+    }
+
+    /// Import localization from uploaded JSON file
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `locale`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/localization/{locale}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmlocalizationlocale>
+    pub fn realm_localization_with_locale_post(
+        &self,
+        realm: &str,
+        locale: &str,
+        body: TypeMap<String, String>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_localization_with_locale_post(realm, locale, body),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `locale`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/localization/{locale}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmlocalizationlocale>
+    pub fn realm_localization_with_locale_delete(
+        &self,
+        realm: &str,
+        locale: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_localization_with_locale_delete(realm, locale),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `key`
+    /// - `locale`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/localization/{locale}/{key}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmlocalizationlocalekey>
+    pub fn realm_localization_with_locale_with_key_get(
+        &self,
+        realm: &str,
+        key: &str,
+        locale: &str,
+    ) -> Result<TypeString, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_localization_with_locale_with_key_get(realm, key, locale),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `key`
+    /// - `locale`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/localization/{locale}/{key}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmlocalizationlocalekey>
+    pub fn realm_localization_with_locale_with_key_put(
+        &self,
+        realm: &str,
+        key: &str,
+        locale: &str,
+        body: String,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_localization_with_locale_with_key_put(realm, key, locale, body),
+        )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `key`
+    /// - `locale`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/localization/{locale}/{key}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmlocalizationlocalekey>
+    pub fn realm_localization_with_locale_with_key_delete(
+        &self,
+        realm: &str,
+        key: &str,
+        locale: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_localization_with_locale_with_key_delete(realm, key, locale),
+        )
+    }
+
+    /// Removes all user sessions.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/logout-all`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmlogout_all>
+    pub fn realm_logout_all_post(&self, realm: &str) -> Result<GlobalRequestResult, KeycloakError> {
+        self.block_on(self.inner.realm_logout_all_post(realm))
+    }
+
+    /// Partial export of existing realm into a JSON file.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `export_clients`
+    /// - `export_groups_and_roles`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/partial-export`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmpartial_export>
+    pub fn realm_partial_export_post(
+        &self,
+        realm: &str,
+        export_clients: Option<bool>,
+        export_groups_and_roles: Option<bool>,
+    ) -> Result<RealmRepresentation, KeycloakError> {
+        self.block_on(self.inner.realm_partial_export_post(
+            realm,
+            export_clients,
+            export_groups_and_roles,
+        ))
+    }
+
+    /// Partial import from a JSON file to an existing realm.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/partialImport`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmpartialimport>
+    pub fn realm_partial_import_post(
+        &self,
+        realm: &str,
+        body: RealmRepresentation,
+    ) -> Result<Value, KeycloakError> {
+        self.block_on(self.inner.realm_partial_import_post(realm, body))
+    }
+
+    /// Push the realm's revocation policy to any client that has an admin url associated with it.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/push-revocation`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmpush_revocation>
+    pub fn realm_push_revocation_post(
+        &self,
+        realm: &str,
+    ) -> Result<GlobalRequestResult, KeycloakError> {
+        self.block_on(self.inner.realm_push_revocation_post(realm))
+    }
+
+    /// Remove a specific user session.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `session`
+    /// - `is_offline`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/sessions/{session}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmsessionssession>
+    pub fn realm_sessions_with_session_delete(
+        &self,
+        realm: &str,
+        session: &str,
+        is_offline: Option<bool>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_sessions_with_session_delete(realm, session, is_offline),
+        )
+    }
+
+    /// Test SMTP connection with current logged in user
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/testSMTPConnection`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmtestsmtpconnection>
+    #[deprecated]
+    #[allow(deprecated)]
+    pub fn realm_test_smtp_connection_post(
+        &self,
+        realm: &str,
+        body: TypeMap<String, String>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_test_smtp_connection_post(realm, body))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/users-management-permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusers_management_permissions>
+    pub fn realm_users_management_permissions_get(
+        &self,
+        realm: &str,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(self.inner.realm_users_management_permissions_get(realm))
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/users-management-permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmusers_management_permissions>
+    pub fn realm_users_management_permissions_put(
+        &self,
+        realm: &str,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_users_management_permissions_put(realm, body),
+        )
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Realms Admin</h4>
+
+    /// Get the top-level representation of the realm It will not include nested information like User and Client representations.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealm>
+    pub fn get(&self) -> Result<RealmRepresentation, KeycloakError> {
+        self.admin.realm_get(self.realm)
+    }
+
+    /// Update the top-level information of the realm Any user, roles or client information in the representation will be ignored.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealm>
+    pub fn put(&self, body: RealmRepresentation) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_put(self.realm, body)
+    }
+
+    /// Delete the realm
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealm>
+    pub fn delete(&self) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_delete(self.realm)
+    }
+
+    /// Get admin events Returns all admin events, or filters events based on URL query parameters listed here
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `auth_client`
+    /// - `auth_ip_address`
+    /// - `auth_realm`
+    /// - `auth_user`: user id
+    /// - `date_from`: From (inclusive) date (yyyy-MM-dd) or time in Epoch timestamp millis (number of milliseconds since January 1, 1970, 00:00:00 GMT)
+    /// - `date_to`: To (inclusive) date (yyyy-MM-dd) or time in Epoch timestamp millis (number of milliseconds since January 1, 1970, 00:00:00 GMT)
+    /// - `direction`: The direction to sort events by (asc or desc)
+    /// - `first`
+    /// - `max`: Maximum results size (defaults to 100)
+    /// - `operation_types`
+    /// - `resource_path`
+    /// - `resource_types`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/admin-events`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmadmin_events>
+    #[allow(clippy::too_many_arguments)]
+    pub fn admin_events_get(
+        &self,
+        auth_client: Option<String>,
+        auth_ip_address: Option<String>,
+        auth_realm: Option<String>,
+        auth_user: Option<String>,
+        date_from: Option<String>,
+        date_to: Option<String>,
+        direction: Option<String>,
+        first: Option<i32>,
+        max: Option<i32>,
+        operation_types: Option<Vec<String>>,
+        resource_path: Option<String>,
+        resource_types: Option<Vec<String>>,
+    ) -> Result<TypeVec<AdminEventRepresentation>, KeycloakError> {
+        self.admin.realm_admin_events_get(
+            self.realm,
+            auth_client,
+            auth_ip_address,
+            auth_realm,
+            auth_user,
+            date_from,
+            date_to,
+            direction,
+            first,
+            max,
+            operation_types,
+            resource_path,
+            resource_types,
+        )
+    }
+
+    /// Delete all admin events
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/admin-events`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmadmin_events>
+    pub fn admin_events_delete(&self) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_admin_events_delete(self.realm)
+    }
+
+    /// Base path for importing clients under this realm.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/client-description-converter`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_description_converter>
+    pub fn client_description_converter_post(
+        &self,
+        body: String,
+    ) -> Result<ClientRepresentation, KeycloakError> {
+        self.admin
+            .realm_client_description_converter_post(self.realm, body)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `include_global_policies`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/client-policies/policies`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_policiespolicies>
+    pub fn client_policies_policies_get(
+        &self,
+        include_global_policies: Option<bool>,
+    ) -> Result<ClientPoliciesRepresentation, KeycloakError> {
+        self.admin
+            .realm_client_policies_policies_get(self.realm, include_global_policies)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/client-policies/policies`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclient_policiespolicies>
+    pub fn client_policies_policies_put(
+        &self,
+        body: ClientPoliciesRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_client_policies_policies_put(self.realm, body)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `include_global_profiles`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/client-policies/profiles`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_policiesprofiles>
+    pub fn client_policies_profiles_get(
+        &self,
+        include_global_profiles: Option<bool>,
+    ) -> Result<ClientProfilesRepresentation, KeycloakError> {
+        self.admin
+            .realm_client_policies_profiles_get(self.realm, include_global_profiles)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/client-policies/profiles`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclient_policiesprofiles>
+    pub fn client_policies_profiles_put(
+        &self,
+        body: ClientProfilesRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_client_policies_profiles_put(self.realm, body)
+    }
+
+    /// Get client session stats Returns a JSON map.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/client-session-stats`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_session_stats>
+    pub fn client_session_stats_get(
+        &self,
+    ) -> Result<TypeVec<TypeMap<String, String>>, KeycloakError> {
+        self.admin.realm_client_session_stats_get(self.realm)
+    }
+
+    /// List all client types available in the current realm
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/client-types`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_types>
+    pub fn client_types_get(&self) -> Result<ClientTypesRepresentation, KeycloakError> {
+        self.admin.realm_client_types_get(self.realm)
+    }
+
+    /// Update a client type
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/client-types`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclient_types>
+    pub fn client_types_put(
+        &self,
+        body: ClientTypesRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_client_types_put(self.realm, body)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/credential-registrators`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmcredential_registrators>
+    pub fn credential_registrators_get(&self) -> Result<TypeVec<String>, KeycloakError> {
+        self.admin.realm_credential_registrators_get(self.realm)
+    }
+
+    /// Get realm default client scopes. Only name and ids are returned.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/default-default-client-scopes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmdefault_default_client_scopes>
+    pub fn default_default_client_scopes_get(
+        &self,
+    ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
+        self.admin
+            .realm_default_default_client_scopes_get(self.realm)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/default-default-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmdefault_default_client_scopesclientscopeid>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/default-default-client-scopes/{clientScopeId}`
+    pub fn default_default_client_scopes_with_client_scope_id_put(
+        &self,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_default_default_client_scopes_with_client_scope_id_put(
+                self.realm,
+                client_scope_id,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/default-default-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmdefault_default_client_scopesclientscopeid>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/default-default-client-scopes/{clientScopeId}`
+    pub fn default_default_client_scopes_with_client_scope_id_delete(
+        &self,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_default_default_client_scopes_with_client_scope_id_delete(
+                self.realm,
+                client_scope_id,
+            )
+    }
+
+    /// Get group hierarchy.  Only name and ids are returned.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/default-groups`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmdefault_groups>
+    pub fn default_groups_get(&self) -> Result<TypeVec<GroupRepresentation>, KeycloakError> {
+        self.admin.realm_default_groups_get(self.realm)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/default-groups/{group_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmdefault_groupsgroupid>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/default-groups/{groupId}`
+    pub fn default_groups_with_group_id_put(
+        &self,
+        group_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_default_groups_with_group_id_put(self.realm, group_id)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/default-groups/{group_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmdefault_groupsgroupid>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/default-groups/{groupId}`
+    pub fn default_groups_with_group_id_delete(
+        &self,
+        group_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_default_groups_with_group_id_delete(self.realm, group_id)
+    }
+
+    /// Get realm optional client scopes. Only name and ids are returned.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/default-optional-client-scopes`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmdefault_optional_client_scopes>
+    pub fn default_optional_client_scopes_get(
+        &self,
+    ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
+        self.admin
+            .realm_default_optional_client_scopes_get(self.realm)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/default-optional-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmdefault_optional_client_scopesclientscopeid>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/default-optional-client-scopes/{clientScopeId}`
+    pub fn default_optional_client_scopes_with_client_scope_id_put(
+        &self,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_default_optional_client_scopes_with_client_scope_id_put(
+                self.realm,
+                client_scope_id,
+            )
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/default-optional-client-scopes/{client_scope_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmdefault_optional_client_scopesclientscopeid>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/default-optional-client-scopes/{clientScopeId}`
+    pub fn default_optional_client_scopes_with_client_scope_id_delete(
+        &self,
+        client_scope_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_default_optional_client_scopes_with_client_scope_id_delete(
+                self.realm,
+                client_scope_id,
+            )
+    }
+
+    /// Get events Returns all events, or filters them based on URL query parameters listed here
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client`: App or oauth client name
+    /// - `date_from`: From (inclusive) date (yyyy-MM-dd) or time in Epoch timestamp millis (number of milliseconds since January 1, 1970, 00:00:00 GMT)
+    /// - `date_to`: To (inclusive) date (yyyy-MM-dd) or time in Epoch timestamp millis (number of milliseconds since January 1, 1970, 00:00:00 GMT)
+    /// - `direction`: The direction to sort events by (asc or desc)
+    /// - `first`: Paging offset
+    /// - `ip_address`: IP Address
+    /// - `max`: Maximum results size (defaults to 100)
+    /// - `type_`: The types of events to return
+    /// - `user`: User id
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/events`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmevents>
+    #[allow(clippy::too_many_arguments)]
+    pub fn events_get(
+        &self,
+        client: Option<String>,
+        date_from: Option<String>,
+        date_to: Option<String>,
+        direction: Option<String>,
+        first: Option<i32>,
+        ip_address: Option<String>,
+        max: Option<i32>,
+        type_: Option<Vec<String>>,
+        user: Option<String>,
+    ) -> Result<TypeVec<EventRepresentation>, KeycloakError> {
+        self.admin.realm_events_get(
+            self.realm, client, date_from, date_to, direction, first, ip_address, max, type_, user,
+        )
+    }
+
+    /// Delete all events
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/events`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmevents>
+    pub fn events_delete(&self) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_events_delete(self.realm)
+    }
+
+    /// Get the events provider configuration Returns JSON object with events provider configuration
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/events/config`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmeventsconfig>
+    pub fn events_config_get(&self) -> Result<RealmEventsConfigRepresentation, KeycloakError> {
+        self.admin.realm_events_config_get(self.realm)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/events/config`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmeventsconfig>
+    pub fn events_config_put(
+        &self,
+        body: RealmEventsConfigRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_events_config_put(self.realm, body)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `path`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/group-by-path/{path}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroup_by_pathpath>
+    pub fn group_by_path_with_path_get(
+        &self,
+        path: &str,
+    ) -> Result<GroupRepresentation, KeycloakError> {
+        self.admin
+            .realm_group_by_path_with_path_get(self.realm, path)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/localization`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmlocalization>
+    pub fn localization_get(&self) -> Result<TypeVec<String>, KeycloakError> {
+        self.admin.realm_localization_get(self.realm)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `locale`
+    /// - `use_realm_default_locale_fallback`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/localization/{locale}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmlocalizationlocale>
+    pub fn localization_with_locale_get(
+        &self,
+        locale: &str,
+        use_realm_default_locale_fallback: Option<bool>,
+    ) -> Result<TypeMap<String, TypeString>, KeycloakError> {
+        self.admin.realm_localization_with_locale_get(
+            self.realm,
+            locale,
+            use_realm_default_locale_fallback,
+        )
+    }
+
+    /// Import localization from uploaded JSON file
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `locale`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/localization/{locale}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmlocalizationlocale>
+    pub fn localization_with_locale_post(
+        &self,
+        locale: &str,
+        body: TypeMap<String, String>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_localization_with_locale_post(self.realm, locale, body)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `locale`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/localization/{locale}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmlocalizationlocale>
+    pub fn localization_with_locale_delete(
+        &self,
+        locale: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_localization_with_locale_delete(self.realm, locale)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `key`
+    /// - `locale`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/localization/{locale}/{key}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmlocalizationlocalekey>
+    pub fn localization_with_locale_with_key_get(
+        &self,
+        key: &str,
+        locale: &str,
+    ) -> Result<TypeString, KeycloakError> {
+        self.admin
+            .realm_localization_with_locale_with_key_get(self.realm, key, locale)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `key`
+    /// - `locale`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/localization/{locale}/{key}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmlocalizationlocalekey>
+    pub fn localization_with_locale_with_key_put(
+        &self,
+        key: &str,
+        locale: &str,
+        body: String,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_localization_with_locale_with_key_put(self.realm, key, locale, body)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `key`
+    /// - `locale`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/localization/{locale}/{key}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmlocalizationlocalekey>
+    pub fn localization_with_locale_with_key_delete(
+        &self,
+        key: &str,
+        locale: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_localization_with_locale_with_key_delete(self.realm, key, locale)
+    }
+
+    /// Removes all user sessions.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/logout-all`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmlogout_all>
+    pub fn logout_all_post(&self) -> Result<GlobalRequestResult, KeycloakError> {
+        self.admin.realm_logout_all_post(self.realm)
+    }
+
+    /// Partial export of existing realm into a JSON file.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `export_clients`
+    /// - `export_groups_and_roles`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/partial-export`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmpartial_export>
+    pub fn partial_export_post(
+        &self,
+        export_clients: Option<bool>,
+        export_groups_and_roles: Option<bool>,
+    ) -> Result<RealmRepresentation, KeycloakError> {
+        self.admin
+            .realm_partial_export_post(self.realm, export_clients, export_groups_and_roles)
+    }
+
+    /// Partial import from a JSON file to an existing realm.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/partialImport`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmpartialimport>
+    pub fn partial_import_post(&self, body: RealmRepresentation) -> Result<Value, KeycloakError> {
+        self.admin.realm_partial_import_post(self.realm, body)
+    }
+
+    /// Push the realm's revocation policy to any client that has an admin url associated with it.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/push-revocation`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmpush_revocation>
+    pub fn push_revocation_post(&self) -> Result<GlobalRequestResult, KeycloakError> {
+        self.admin.realm_push_revocation_post(self.realm)
+    }
+
+    /// Remove a specific user session.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `session`
+    /// - `is_offline`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `DELETE /admin/realms/{realm}/sessions/{session}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmsessionssession>
+    pub fn sessions_with_session_delete(
+        &self,
+        session: &str,
+        is_offline: Option<bool>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_sessions_with_session_delete(self.realm, session, is_offline)
+    }
+
+    /// Test SMTP connection with current logged in user
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `POST /admin/realms/{realm}/testSMTPConnection`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmtestsmtpconnection>
+    #[deprecated]
+    #[allow(deprecated)]
+    pub fn test_smtp_connection_post(
+        &self,
+        body: TypeMap<String, String>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_test_smtp_connection_post(self.realm, body)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `GET /admin/realms/{realm}/users-management-permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusers_management_permissions>
+    pub fn users_management_permissions_get(
+        &self,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_users_management_permissions_get(self.realm)
+    }
+
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Resource: `Realms Admin`
+    ///
+    /// `PUT /admin/realms/{realm}/users-management-permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmusers_management_permissions>
+    pub fn users_management_permissions_put(
+        &self,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_users_management_permissions_put(self.realm, body)
+    }
+}
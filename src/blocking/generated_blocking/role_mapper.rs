@@ -0,0 +1,629 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Role Mapper</h4>
+
+    /// Get role mappings
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappings>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings`
+    pub fn realm_groups_with_group_id_role_mappings_get(
+        &self,
+        realm: &str,
+        group_id: &str,
+    ) -> Result<MappingsRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_role_mappings_get(realm, group_id),
+        )
+    }
+
+    /// Get realm-level role mappings
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/realm`
+    pub fn realm_groups_with_group_id_role_mappings_realm_get(
+        &self,
+        realm: &str,
+        group_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_role_mappings_realm_get(realm, group_id),
+        )
+    }
+
+    /// Add realm-level role mappings to the user
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `POST /admin/realms/{realm}/groups/{group_id}/role-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmgroupsgroup_idrole_mappingsrealm>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/groups/{group-id}/role-mappings/realm`
+    pub fn realm_groups_with_group_id_role_mappings_realm_post(
+        &self,
+        realm: &str,
+        group_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_role_mappings_realm_post(realm, group_id, body),
+        )
+    }
+
+    /// Delete realm-level role mappings
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `DELETE /admin/realms/{realm}/groups/{group_id}/role-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmgroupsgroup_idrole_mappingsrealm>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/groups/{group-id}/role-mappings/realm`
+    pub fn realm_groups_with_group_id_role_mappings_realm_delete(
+        &self,
+        realm: &str,
+        group_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_role_mappings_realm_delete(realm, group_id, body),
+        )
+    }
+
+    /// Get realm-level roles that can be mapped
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings/realm/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsrealmavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/realm/available`
+    pub fn realm_groups_with_group_id_role_mappings_realm_available_get(
+        &self,
+        realm: &str,
+        group_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_role_mappings_realm_available_get(realm, group_id),
+        )
+    }
+
+    /// Get effective realm-level role mappings This will recurse all composite roles to get the result.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings/realm/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsrealmcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/realm/composite`
+    pub fn realm_groups_with_group_id_role_mappings_realm_composite_get(
+        &self,
+        realm: &str,
+        group_id: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_groups_with_group_id_role_mappings_realm_composite_get(
+                    realm,
+                    group_id,
+                    brief_representation,
+                ),
+        )
+    }
+
+    /// Get role mappings
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappings>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings`
+    pub fn realm_users_with_user_id_role_mappings_get(
+        &self,
+        realm: &str,
+        user_id: &str,
+    ) -> Result<MappingsRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_users_with_user_id_role_mappings_get(realm, user_id),
+        )
+    }
+
+    /// Get realm-level role mappings
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/realm`
+    pub fn realm_users_with_user_id_role_mappings_realm_get(
+        &self,
+        realm: &str,
+        user_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_users_with_user_id_role_mappings_realm_get(realm, user_id),
+        )
+    }
+
+    /// Add realm-level role mappings to the user
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `POST /admin/realms/{realm}/users/{user_id}/role-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmusersuser_idrole_mappingsrealm>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/users/{user-id}/role-mappings/realm`
+    pub fn realm_users_with_user_id_role_mappings_realm_post(
+        &self,
+        realm: &str,
+        user_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_users_with_user_id_role_mappings_realm_post(realm, user_id, body),
+        )
+    }
+
+    /// Delete realm-level role mappings
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `DELETE /admin/realms/{realm}/users/{user_id}/role-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmusersuser_idrole_mappingsrealm>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/users/{user-id}/role-mappings/realm`
+    pub fn realm_users_with_user_id_role_mappings_realm_delete(
+        &self,
+        realm: &str,
+        user_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_users_with_user_id_role_mappings_realm_delete(realm, user_id, body),
+        )
+    }
+
+    /// Get realm-level roles that can be mapped
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings/realm/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsrealmavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/realm/available`
+    pub fn realm_users_with_user_id_role_mappings_realm_available_get(
+        &self,
+        realm: &str,
+        user_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_users_with_user_id_role_mappings_realm_available_get(realm, user_id),
+        )
+    }
+
+    /// Get effective realm-level role mappings This will recurse all composite roles to get the result.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings/realm/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsrealmcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/realm/composite`
+    pub fn realm_users_with_user_id_role_mappings_realm_composite_get(
+        &self,
+        realm: &str,
+        user_id: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_users_with_user_id_role_mappings_realm_composite_get(
+                    realm,
+                    user_id,
+                    brief_representation,
+                ),
+        )
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Role Mapper</h4>
+
+    /// Get role mappings
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappings>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings`
+    pub fn groups_with_group_id_role_mappings_get(
+        &self,
+        group_id: &str,
+    ) -> Result<MappingsRepresentation, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_role_mappings_get(self.realm, group_id)
+    }
+
+    /// Get realm-level role mappings
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/realm`
+    pub fn groups_with_group_id_role_mappings_realm_get(
+        &self,
+        group_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_role_mappings_realm_get(self.realm, group_id)
+    }
+
+    /// Add realm-level role mappings to the user
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `POST /admin/realms/{realm}/groups/{group_id}/role-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmgroupsgroup_idrole_mappingsrealm>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/groups/{group-id}/role-mappings/realm`
+    pub fn groups_with_group_id_role_mappings_realm_post(
+        &self,
+        group_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_role_mappings_realm_post(self.realm, group_id, body)
+    }
+
+    /// Delete realm-level role mappings
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `DELETE /admin/realms/{realm}/groups/{group_id}/role-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmgroupsgroup_idrole_mappingsrealm>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/groups/{group-id}/role-mappings/realm`
+    pub fn groups_with_group_id_role_mappings_realm_delete(
+        &self,
+        group_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_role_mappings_realm_delete(self.realm, group_id, body)
+    }
+
+    /// Get realm-level roles that can be mapped
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings/realm/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsrealmavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/realm/available`
+    pub fn groups_with_group_id_role_mappings_realm_available_get(
+        &self,
+        group_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_role_mappings_realm_available_get(self.realm, group_id)
+    }
+
+    /// Get effective realm-level role mappings This will recurse all composite roles to get the result.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `group_id`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/groups/{group_id}/role-mappings/realm/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsrealmcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/realm/composite`
+    pub fn groups_with_group_id_role_mappings_realm_composite_get(
+        &self,
+        group_id: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_groups_with_group_id_role_mappings_realm_composite_get(
+                self.realm,
+                group_id,
+                brief_representation,
+            )
+    }
+
+    /// Get role mappings
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappings>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings`
+    pub fn users_with_user_id_role_mappings_get(
+        &self,
+        user_id: &str,
+    ) -> Result<MappingsRepresentation, KeycloakError> {
+        self.admin
+            .realm_users_with_user_id_role_mappings_get(self.realm, user_id)
+    }
+
+    /// Get realm-level role mappings
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/realm`
+    pub fn users_with_user_id_role_mappings_realm_get(
+        &self,
+        user_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_users_with_user_id_role_mappings_realm_get(self.realm, user_id)
+    }
+
+    /// Add realm-level role mappings to the user
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `POST /admin/realms/{realm}/users/{user_id}/role-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmusersuser_idrole_mappingsrealm>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/users/{user-id}/role-mappings/realm`
+    pub fn users_with_user_id_role_mappings_realm_post(
+        &self,
+        user_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_users_with_user_id_role_mappings_realm_post(self.realm, user_id, body)
+    }
+
+    /// Delete realm-level role mappings
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `DELETE /admin/realms/{realm}/users/{user_id}/role-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmusersuser_idrole_mappingsrealm>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/users/{user-id}/role-mappings/realm`
+    pub fn users_with_user_id_role_mappings_realm_delete(
+        &self,
+        user_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_users_with_user_id_role_mappings_realm_delete(self.realm, user_id, body)
+    }
+
+    /// Get realm-level roles that can be mapped
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings/realm/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsrealmavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/realm/available`
+    pub fn users_with_user_id_role_mappings_realm_available_get(
+        &self,
+        user_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_users_with_user_id_role_mappings_realm_available_get(self.realm, user_id)
+    }
+
+    /// Get effective realm-level role mappings This will recurse all composite roles to get the result.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `user_id`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Role Mapper`
+    ///
+    /// `GET /admin/realms/{realm}/users/{user_id}/role-mappings/realm/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsrealmcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/realm/composite`
+    pub fn users_with_user_id_role_mappings_realm_composite_get(
+        &self,
+        user_id: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_users_with_user_id_role_mappings_realm_composite_get(
+                self.realm,
+                user_id,
+                brief_representation,
+            )
+    }
+}
@@ -0,0 +1,1664 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Roles</h4>
+
+    /// Get all roles for the realm or client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `brief_representation`
+    /// - `first`
+    /// - `max`
+    /// - `search`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidroles>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles`
+    pub fn realm_clients_with_client_uuid_roles_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+        search: Option<String>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_clients_with_client_uuid_roles_get(
+            realm,
+            client_uuid,
+            brief_representation,
+            first,
+            max,
+            search,
+        ))
+    }
+
+    /// Create a new role for the realm or client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/roles`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidroles>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/roles`
+    pub fn realm_clients_with_client_uuid_roles_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        body: RoleRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_clients_with_client_uuid_roles_post(
+            realm,
+            client_uuid,
+            body,
+        ))
+    }
+
+    /// Get a role by name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_name>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}`
+    pub fn realm_clients_with_client_uuid_roles_with_role_name_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_name: &str,
+    ) -> Result<RoleRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_roles_with_role_name_get(
+                    realm,
+                    client_uuid,
+                    role_name,
+                ),
+        )
+    }
+
+    /// Update a role by name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidrolesrole_name>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}`
+    pub fn realm_clients_with_client_uuid_roles_with_role_name_put(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_name: &str,
+        body: RoleRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_roles_with_role_name_put(
+                    realm,
+                    client_uuid,
+                    role_name,
+                    body,
+                ),
+        )
+    }
+
+    /// Delete a role by name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidrolesrole_name>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}`
+    pub fn realm_clients_with_client_uuid_roles_with_role_name_delete(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_name: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_roles_with_role_name_delete(
+                    realm,
+                    client_uuid,
+                    role_name,
+                ),
+        )
+    }
+
+    /// Get composites of the role
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namecomposites>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites`
+    pub fn realm_clients_with_client_uuid_roles_with_role_name_composites_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_name: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_roles_with_role_name_composites_get(
+                    realm,
+                    client_uuid,
+                    role_name,
+                ),
+        )
+    }
+
+    /// Add a composite to the role
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidrolesrole_namecomposites>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites`
+    pub fn realm_clients_with_client_uuid_roles_with_role_name_composites_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_name: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_roles_with_role_name_composites_post(
+                    realm,
+                    client_uuid,
+                    role_name,
+                    body,
+                ),
+        )
+    }
+
+    /// Remove roles from the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidrolesrole_namecomposites>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites`
+    pub fn realm_clients_with_client_uuid_roles_with_role_name_composites_delete(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_name: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_roles_with_role_name_composites_delete(
+                    realm,
+                    client_uuid,
+                    role_name,
+                    body,
+                ),
+        )
+    }
+
+    /// Get client-level roles for the client that are in the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `target_client_uuid`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites/clients/{target_client_uuid}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namecompositesclientstargetclientuuid>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites/clients/{targetClientUuid}`
+    pub fn realm_clients_with_client_uuid_roles_with_role_name_composites_clients_with_target_client_uuid_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_name: &str,
+        target_client_uuid: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_clients_with_client_uuid_roles_with_role_name_composites_clients_with_target_client_uuid_get(
+            realm,
+            client_uuid,
+            role_name,
+            target_client_uuid,
+        ))
+    }
+
+    /// Get realm-level roles of the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namecompositesrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites/realm`
+    pub fn realm_clients_with_client_uuid_roles_with_role_name_composites_realm_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_name: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_roles_with_role_name_composites_realm_get(
+                    realm,
+                    client_uuid,
+                    role_name,
+                ),
+        )
+    }
+
+    /// Returns a stream of groups that have the specified role name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: the role name.
+    /// - `brief_representation`: if false, return a full representation of the {@code GroupRepresentation} objects.
+    /// - `first`: first result to return. Ignored if negative or {@code null}.
+    /// - `max`: maximum number of results to return. Ignored if negative or {@code null}.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/groups`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namegroups>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/groups`
+    pub fn realm_clients_with_client_uuid_roles_with_role_name_groups_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_name: &str,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_roles_with_role_name_groups_get(
+                    realm,
+                    client_uuid,
+                    role_name,
+                    brief_representation,
+                    first,
+                    max,
+                ),
+        )
+    }
+
+    /// Return object stating whether role Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namemanagementpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/management/permissions`
+    pub fn realm_clients_with_client_uuid_roles_with_role_name_management_permissions_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_name: &str,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_roles_with_role_name_management_permissions_get(
+                    realm,
+                    client_uuid,
+                    role_name,
+                ),
+        )
+    }
+
+    /// Return object stating whether role Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`
+    /// - `body`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidrolesrole_namemanagementpermissions>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/management/permissions`
+    pub fn realm_clients_with_client_uuid_roles_with_role_name_management_permissions_put(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_name: &str,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_roles_with_role_name_management_permissions_put(
+                    realm,
+                    client_uuid,
+                    role_name,
+                    body,
+                ),
+        )
+    }
+
+    /// Returns a stream of users that have the specified role name.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: the role name.
+    /// - `brief_representation`: Boolean which defines whether brief representations are returned (default: false)
+    /// - `first`: first result to return. Ignored if negative or {@code null}.
+    /// - `max`: maximum number of results to return. Ignored if negative or {@code null}.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/users`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_nameusers>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/users`
+    pub fn realm_clients_with_client_uuid_roles_with_role_name_users_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_name: &str,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_roles_with_role_name_users_get(
+                    realm,
+                    client_uuid,
+                    role_name,
+                    brief_representation,
+                    first,
+                    max,
+                ),
+        )
+    }
+
+    /// Get all roles for the realm or client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `brief_representation`
+    /// - `first`
+    /// - `max`
+    /// - `search`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles>
+    pub fn realm_roles_get(
+        &self,
+        realm: &str,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+        search: Option<String>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_get(realm, brief_representation, first, max, search),
+        )
+    }
+
+    /// Create a new role for the realm or client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `POST /admin/realms/{realm}/roles`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmroles>
+    pub fn realm_roles_post(
+        &self,
+        realm: &str,
+        body: RoleRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_roles_post(realm, body))
+    }
+
+    /// Get a role by name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_name>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}`
+    pub fn realm_roles_with_role_name_get(
+        &self,
+        realm: &str,
+        role_name: &str,
+    ) -> Result<RoleRepresentation, KeycloakError> {
+        self.block_on(self.inner.realm_roles_with_role_name_get(realm, role_name))
+    }
+
+    /// Update a role by name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `PUT /admin/realms/{realm}/roles/{role_name}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmrolesrole_name>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/roles/{role-name}`
+    pub fn realm_roles_with_role_name_put(
+        &self,
+        realm: &str,
+        role_name: &str,
+        body: RoleRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_with_role_name_put(realm, role_name, body),
+        )
+    }
+
+    /// Delete a role by name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `DELETE /admin/realms/{realm}/roles/{role_name}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmrolesrole_name>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/roles/{role-name}`
+    pub fn realm_roles_with_role_name_delete(
+        &self,
+        realm: &str,
+        role_name: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_with_role_name_delete(realm, role_name),
+        )
+    }
+
+    /// Get composites of the role
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namecomposites>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/composites`
+    pub fn realm_roles_with_role_name_composites_get(
+        &self,
+        realm: &str,
+        role_name: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_with_role_name_composites_get(realm, role_name),
+        )
+    }
+
+    /// Add a composite to the role
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `POST /admin/realms/{realm}/roles/{role_name}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmrolesrole_namecomposites>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/roles/{role-name}/composites`
+    pub fn realm_roles_with_role_name_composites_post(
+        &self,
+        realm: &str,
+        role_name: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_with_role_name_composites_post(realm, role_name, body),
+        )
+    }
+
+    /// Remove roles from the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `DELETE /admin/realms/{realm}/roles/{role_name}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmrolesrole_namecomposites>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/roles/{role-name}/composites`
+    pub fn realm_roles_with_role_name_composites_delete(
+        &self,
+        realm: &str,
+        role_name: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_with_role_name_composites_delete(realm, role_name, body),
+        )
+    }
+
+    /// Get client-level roles for the client that are in the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `target_client_uuid`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}/composites/clients/{target_client_uuid}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namecompositesclientstargetclientuuid>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/composites/clients/{targetClientUuid}`
+    pub fn realm_roles_with_role_name_composites_clients_with_target_client_uuid_get(
+        &self,
+        realm: &str,
+        role_name: &str,
+        target_client_uuid: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_with_role_name_composites_clients_with_target_client_uuid_get(
+                    realm,
+                    role_name,
+                    target_client_uuid,
+                ),
+        )
+    }
+
+    /// Get realm-level roles of the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}/composites/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namecompositesrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/composites/realm`
+    pub fn realm_roles_with_role_name_composites_realm_get(
+        &self,
+        realm: &str,
+        role_name: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_with_role_name_composites_realm_get(realm, role_name),
+        )
+    }
+
+    /// Returns a stream of groups that have the specified role name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: the role name.
+    /// - `brief_representation`: if false, return a full representation of the {@code GroupRepresentation} objects.
+    /// - `first`: first result to return. Ignored if negative or {@code null}.
+    /// - `max`: maximum number of results to return. Ignored if negative or {@code null}.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}/groups`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namegroups>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/groups`
+    pub fn realm_roles_with_role_name_groups_get(
+        &self,
+        realm: &str,
+        role_name: &str,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_roles_with_role_name_groups_get(
+            realm,
+            role_name,
+            brief_representation,
+            first,
+            max,
+        ))
+    }
+
+    /// Return object stating whether role Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namemanagementpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/management/permissions`
+    pub fn realm_roles_with_role_name_management_permissions_get(
+        &self,
+        realm: &str,
+        role_name: &str,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_with_role_name_management_permissions_get(realm, role_name),
+        )
+    }
+
+    /// Return object stating whether role Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`
+    /// - `body`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `PUT /admin/realms/{realm}/roles/{role_name}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmrolesrole_namemanagementpermissions>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/roles/{role-name}/management/permissions`
+    pub fn realm_roles_with_role_name_management_permissions_put(
+        &self,
+        realm: &str,
+        role_name: &str,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_with_role_name_management_permissions_put(realm, role_name, body),
+        )
+    }
+
+    /// Returns a stream of users that have the specified role name.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: the role name.
+    /// - `brief_representation`: Boolean which defines whether brief representations are returned (default: false)
+    /// - `first`: first result to return. Ignored if negative or {@code null}.
+    /// - `max`: maximum number of results to return. Ignored if negative or {@code null}.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}/users`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_nameusers>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/users`
+    pub fn realm_roles_with_role_name_users_get(
+        &self,
+        realm: &str,
+        role_name: &str,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_roles_with_role_name_users_get(
+            realm,
+            role_name,
+            brief_representation,
+            first,
+            max,
+        ))
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Roles</h4>
+
+    /// Get all roles for the realm or client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `brief_representation`
+    /// - `first`
+    /// - `max`
+    /// - `search`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidroles>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles`
+    pub fn clients_with_client_uuid_roles_get(
+        &self,
+        client_uuid: &str,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+        search: Option<String>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin.realm_clients_with_client_uuid_roles_get(
+            self.realm,
+            client_uuid,
+            brief_representation,
+            first,
+            max,
+            search,
+        )
+    }
+
+    /// Create a new role for the realm or client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/roles`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidroles>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/roles`
+    pub fn clients_with_client_uuid_roles_post(
+        &self,
+        client_uuid: &str,
+        body: RoleRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_roles_post(self.realm, client_uuid, body)
+    }
+
+    /// Get a role by name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_name>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}`
+    pub fn clients_with_client_uuid_roles_with_role_name_get(
+        &self,
+        client_uuid: &str,
+        role_name: &str,
+    ) -> Result<RoleRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_roles_with_role_name_get(
+                self.realm,
+                client_uuid,
+                role_name,
+            )
+    }
+
+    /// Update a role by name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidrolesrole_name>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}`
+    pub fn clients_with_client_uuid_roles_with_role_name_put(
+        &self,
+        client_uuid: &str,
+        role_name: &str,
+        body: RoleRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_roles_with_role_name_put(
+                self.realm,
+                client_uuid,
+                role_name,
+                body,
+            )
+    }
+
+    /// Delete a role by name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidrolesrole_name>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}`
+    pub fn clients_with_client_uuid_roles_with_role_name_delete(
+        &self,
+        client_uuid: &str,
+        role_name: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_roles_with_role_name_delete(
+                self.realm,
+                client_uuid,
+                role_name,
+            )
+    }
+
+    /// Get composites of the role
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namecomposites>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites`
+    pub fn clients_with_client_uuid_roles_with_role_name_composites_get(
+        &self,
+        client_uuid: &str,
+        role_name: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_roles_with_role_name_composites_get(
+                self.realm,
+                client_uuid,
+                role_name,
+            )
+    }
+
+    /// Add a composite to the role
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidrolesrole_namecomposites>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites`
+    pub fn clients_with_client_uuid_roles_with_role_name_composites_post(
+        &self,
+        client_uuid: &str,
+        role_name: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_roles_with_role_name_composites_post(
+                self.realm,
+                client_uuid,
+                role_name,
+                body,
+            )
+    }
+
+    /// Remove roles from the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidrolesrole_namecomposites>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites`
+    pub fn clients_with_client_uuid_roles_with_role_name_composites_delete(
+        &self,
+        client_uuid: &str,
+        role_name: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_roles_with_role_name_composites_delete(
+                self.realm,
+                client_uuid,
+                role_name,
+                body,
+            )
+    }
+
+    /// Get client-level roles for the client that are in the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `target_client_uuid`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites/clients/{target_client_uuid}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namecompositesclientstargetclientuuid>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites/clients/{targetClientUuid}`
+    pub fn clients_with_client_uuid_roles_with_role_name_composites_clients_with_target_client_uuid_get(
+        &self,
+        client_uuid: &str,
+        role_name: &str,
+        target_client_uuid: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin.realm_clients_with_client_uuid_roles_with_role_name_composites_clients_with_target_client_uuid_get(
+            self.realm,
+            client_uuid,
+            role_name,
+            target_client_uuid,
+        )
+    }
+
+    /// Get realm-level roles of the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namecompositesrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites/realm`
+    pub fn clients_with_client_uuid_roles_with_role_name_composites_realm_get(
+        &self,
+        client_uuid: &str,
+        role_name: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_roles_with_role_name_composites_realm_get(
+                self.realm,
+                client_uuid,
+                role_name,
+            )
+    }
+
+    /// Returns a stream of groups that have the specified role name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: the role name.
+    /// - `brief_representation`: if false, return a full representation of the {@code GroupRepresentation} objects.
+    /// - `first`: first result to return. Ignored if negative or {@code null}.
+    /// - `max`: maximum number of results to return. Ignored if negative or {@code null}.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/groups`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namegroups>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/groups`
+    pub fn clients_with_client_uuid_roles_with_role_name_groups_get(
+        &self,
+        client_uuid: &str,
+        role_name: &str,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_roles_with_role_name_groups_get(
+                self.realm,
+                client_uuid,
+                role_name,
+                brief_representation,
+                first,
+                max,
+            )
+    }
+
+    /// Return object stating whether role Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namemanagementpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/management/permissions`
+    pub fn clients_with_client_uuid_roles_with_role_name_management_permissions_get(
+        &self,
+        client_uuid: &str,
+        role_name: &str,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_roles_with_role_name_management_permissions_get(
+                self.realm,
+                client_uuid,
+                role_name,
+            )
+    }
+
+    /// Return object stating whether role Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`
+    /// - `body`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `PUT /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidrolesrole_namemanagementpermissions>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/management/permissions`
+    pub fn clients_with_client_uuid_roles_with_role_name_management_permissions_put(
+        &self,
+        client_uuid: &str,
+        role_name: &str,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_roles_with_role_name_management_permissions_put(
+                self.realm,
+                client_uuid,
+                role_name,
+                body,
+            )
+    }
+
+    /// Returns a stream of users that have the specified role name.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `role_name`: the role name.
+    /// - `brief_representation`: Boolean which defines whether brief representations are returned (default: false)
+    /// - `first`: first result to return. Ignored if negative or {@code null}.
+    /// - `max`: maximum number of results to return. Ignored if negative or {@code null}.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/users`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_nameusers>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/users`
+    pub fn clients_with_client_uuid_roles_with_role_name_users_get(
+        &self,
+        client_uuid: &str,
+        role_name: &str,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_roles_with_role_name_users_get(
+                self.realm,
+                client_uuid,
+                role_name,
+                brief_representation,
+                first,
+                max,
+            )
+    }
+
+    /// Get all roles for the realm or client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `brief_representation`
+    /// - `first`
+    /// - `max`
+    /// - `search`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles>
+    pub fn roles_get(
+        &self,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+        search: Option<String>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_roles_get(self.realm, brief_representation, first, max, search)
+    }
+
+    /// Create a new role for the realm or client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `POST /admin/realms/{realm}/roles`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmroles>
+    pub fn roles_post(&self, body: RoleRepresentation) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_roles_post(self.realm, body)
+    }
+
+    /// Get a role by name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_name>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}`
+    pub fn roles_with_role_name_get(
+        &self,
+        role_name: &str,
+    ) -> Result<RoleRepresentation, KeycloakError> {
+        self.admin
+            .realm_roles_with_role_name_get(self.realm, role_name)
+    }
+
+    /// Update a role by name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `PUT /admin/realms/{realm}/roles/{role_name}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmrolesrole_name>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/roles/{role-name}`
+    pub fn roles_with_role_name_put(
+        &self,
+        role_name: &str,
+        body: RoleRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_roles_with_role_name_put(self.realm, role_name, body)
+    }
+
+    /// Delete a role by name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `DELETE /admin/realms/{realm}/roles/{role_name}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmrolesrole_name>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/roles/{role-name}`
+    pub fn roles_with_role_name_delete(
+        &self,
+        role_name: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_roles_with_role_name_delete(self.realm, role_name)
+    }
+
+    /// Get composites of the role
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namecomposites>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/composites`
+    pub fn roles_with_role_name_composites_get(
+        &self,
+        role_name: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_roles_with_role_name_composites_get(self.realm, role_name)
+    }
+
+    /// Add a composite to the role
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `POST /admin/realms/{realm}/roles/{role_name}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmrolesrole_namecomposites>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/roles/{role-name}/composites`
+    pub fn roles_with_role_name_composites_post(
+        &self,
+        role_name: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_roles_with_role_name_composites_post(self.realm, role_name, body)
+    }
+
+    /// Remove roles from the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `DELETE /admin/realms/{realm}/roles/{role_name}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmrolesrole_namecomposites>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/roles/{role-name}/composites`
+    pub fn roles_with_role_name_composites_delete(
+        &self,
+        role_name: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_roles_with_role_name_composites_delete(self.realm, role_name, body)
+    }
+
+    /// Get client-level roles for the client that are in the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    /// - `target_client_uuid`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}/composites/clients/{target_client_uuid}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namecompositesclientstargetclientuuid>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/composites/clients/{targetClientUuid}`
+    pub fn roles_with_role_name_composites_clients_with_target_client_uuid_get(
+        &self,
+        role_name: &str,
+        target_client_uuid: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_roles_with_role_name_composites_clients_with_target_client_uuid_get(
+                self.realm,
+                role_name,
+                target_client_uuid,
+            )
+    }
+
+    /// Get realm-level roles of the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: role's name (not id!)
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}/composites/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namecompositesrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/composites/realm`
+    pub fn roles_with_role_name_composites_realm_get(
+        &self,
+        role_name: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_roles_with_role_name_composites_realm_get(self.realm, role_name)
+    }
+
+    /// Returns a stream of groups that have the specified role name
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: the role name.
+    /// - `brief_representation`: if false, return a full representation of the {@code GroupRepresentation} objects.
+    /// - `first`: first result to return. Ignored if negative or {@code null}.
+    /// - `max`: maximum number of results to return. Ignored if negative or {@code null}.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}/groups`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namegroups>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/groups`
+    pub fn roles_with_role_name_groups_get(
+        &self,
+        role_name: &str,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
+        self.admin.realm_roles_with_role_name_groups_get(
+            self.realm,
+            role_name,
+            brief_representation,
+            first,
+            max,
+        )
+    }
+
+    /// Return object stating whether role Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namemanagementpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/management/permissions`
+    pub fn roles_with_role_name_management_permissions_get(
+        &self,
+        role_name: &str,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_roles_with_role_name_management_permissions_get(self.realm, role_name)
+    }
+
+    /// Return object stating whether role Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`
+    /// - `body`
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `PUT /admin/realms/{realm}/roles/{role_name}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmrolesrole_namemanagementpermissions>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/roles/{role-name}/management/permissions`
+    pub fn roles_with_role_name_management_permissions_put(
+        &self,
+        role_name: &str,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_roles_with_role_name_management_permissions_put(self.realm, role_name, body)
+    }
+
+    /// Returns a stream of users that have the specified role name.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_name`: the role name.
+    /// - `brief_representation`: Boolean which defines whether brief representations are returned (default: false)
+    /// - `first`: first result to return. Ignored if negative or {@code null}.
+    /// - `max`: maximum number of results to return. Ignored if negative or {@code null}.
+    ///
+    /// Resource: `Roles`
+    ///
+    /// `GET /admin/realms/{realm}/roles/{role_name}/users`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_nameusers>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/users`
+    pub fn roles_with_role_name_users_get(
+        &self,
+        role_name: &str,
+        brief_representation: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+    ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
+        self.admin.realm_roles_with_role_name_users_get(
+            self.realm,
+            role_name,
+            brief_representation,
+            first,
+            max,
+        )
+    }
+}
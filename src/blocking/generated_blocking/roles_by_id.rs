@@ -0,0 +1,535 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Roles (by ID)</h4>
+
+    /// Get a specific role's representation
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`: id of role
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `GET /admin/realms/{realm}/roles-by-id/{role_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}`
+    pub fn realm_roles_by_id_with_role_id_get(
+        &self,
+        realm: &str,
+        role_id: &str,
+    ) -> Result<RoleRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_by_id_with_role_id_get(realm, role_id),
+        )
+    }
+
+    /// Update the role
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`: id of role
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `PUT /admin/realms/{realm}/roles-by-id/{role_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmroles_by_idrole_id>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/roles-by-id/{role-id}`
+    pub fn realm_roles_by_id_with_role_id_put(
+        &self,
+        realm: &str,
+        role_id: &str,
+        body: RoleRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_by_id_with_role_id_put(realm, role_id, body),
+        )
+    }
+
+    /// Delete the role
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`: id of role
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `DELETE /admin/realms/{realm}/roles-by-id/{role_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmroles_by_idrole_id>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/roles-by-id/{role-id}`
+    pub fn realm_roles_by_id_with_role_id_delete(
+        &self,
+        realm: &str,
+        role_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_by_id_with_role_id_delete(realm, role_id),
+        )
+    }
+
+    /// Get role's children Returns a set of role's children provided the role is a composite.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`
+    /// - `first`
+    /// - `max`
+    /// - `search`
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `GET /admin/realms/{realm}/roles-by-id/{role_id}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_idcomposites>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}/composites`
+    pub fn realm_roles_by_id_with_role_id_composites_get(
+        &self,
+        realm: &str,
+        role_id: &str,
+        first: Option<i32>,
+        max: Option<i32>,
+        search: Option<String>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_by_id_with_role_id_composites_get(realm, role_id, first, max, search),
+        )
+    }
+
+    /// Make the role a composite role by associating some child roles
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `POST /admin/realms/{realm}/roles-by-id/{role_id}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmroles_by_idrole_idcomposites>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/roles-by-id/{role-id}/composites`
+    pub fn realm_roles_by_id_with_role_id_composites_post(
+        &self,
+        realm: &str,
+        role_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_by_id_with_role_id_composites_post(realm, role_id, body),
+        )
+    }
+
+    /// Remove a set of roles from the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`: Role id
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `DELETE /admin/realms/{realm}/roles-by-id/{role_id}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmroles_by_idrole_idcomposites>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/roles-by-id/{role-id}/composites`
+    pub fn realm_roles_by_id_with_role_id_composites_delete(
+        &self,
+        realm: &str,
+        role_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_by_id_with_role_id_composites_delete(realm, role_id, body),
+        )
+    }
+
+    /// Get client-level roles for the client that are in the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`
+    /// - `role_id`
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `GET /admin/realms/{realm}/roles-by-id/{role_id}/composites/clients/{client_uuid}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_idcompositesclientsclientuuid>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}/composites/clients/{clientUuid}`
+    pub fn realm_roles_by_id_with_role_id_composites_clients_with_client_uuid_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        role_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_by_id_with_role_id_composites_clients_with_client_uuid_get(
+                    realm,
+                    client_uuid,
+                    role_id,
+                ),
+        )
+    }
+
+    /// Get realm-level roles that are in the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `GET /admin/realms/{realm}/roles-by-id/{role_id}/composites/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_idcompositesrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}/composites/realm`
+    pub fn realm_roles_by_id_with_role_id_composites_realm_get(
+        &self,
+        realm: &str,
+        role_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_by_id_with_role_id_composites_realm_get(realm, role_id),
+        )
+    }
+
+    /// Return object stating whether role Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `GET /admin/realms/{realm}/roles-by-id/{role_id}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_idmanagementpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}/management/permissions`
+    pub fn realm_roles_by_id_with_role_id_management_permissions_get(
+        &self,
+        realm: &str,
+        role_id: &str,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_by_id_with_role_id_management_permissions_get(realm, role_id),
+        )
+    }
+
+    /// Return object stating whether role Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`
+    /// - `body`
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `PUT /admin/realms/{realm}/roles-by-id/{role_id}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmroles_by_idrole_idmanagementpermissions>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/roles-by-id/{role-id}/management/permissions`
+    pub fn realm_roles_by_id_with_role_id_management_permissions_put(
+        &self,
+        realm: &str,
+        role_id: &str,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_roles_by_id_with_role_id_management_permissions_put(realm, role_id, body),
+        )
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Roles (by ID)</h4>
+
+    /// Get a specific role's representation
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`: id of role
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `GET /admin/realms/{realm}/roles-by-id/{role_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}`
+    pub fn roles_by_id_with_role_id_get(
+        &self,
+        role_id: &str,
+    ) -> Result<RoleRepresentation, KeycloakError> {
+        self.admin
+            .realm_roles_by_id_with_role_id_get(self.realm, role_id)
+    }
+
+    /// Update the role
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`: id of role
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `PUT /admin/realms/{realm}/roles-by-id/{role_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmroles_by_idrole_id>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/roles-by-id/{role-id}`
+    pub fn roles_by_id_with_role_id_put(
+        &self,
+        role_id: &str,
+        body: RoleRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_roles_by_id_with_role_id_put(self.realm, role_id, body)
+    }
+
+    /// Delete the role
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`: id of role
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `DELETE /admin/realms/{realm}/roles-by-id/{role_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmroles_by_idrole_id>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/roles-by-id/{role-id}`
+    pub fn roles_by_id_with_role_id_delete(
+        &self,
+        role_id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_roles_by_id_with_role_id_delete(self.realm, role_id)
+    }
+
+    /// Get role's children Returns a set of role's children provided the role is a composite.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`
+    /// - `first`
+    /// - `max`
+    /// - `search`
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `GET /admin/realms/{realm}/roles-by-id/{role_id}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_idcomposites>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}/composites`
+    pub fn roles_by_id_with_role_id_composites_get(
+        &self,
+        role_id: &str,
+        first: Option<i32>,
+        max: Option<i32>,
+        search: Option<String>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_roles_by_id_with_role_id_composites_get(self.realm, role_id, first, max, search)
+    }
+
+    /// Make the role a composite role by associating some child roles
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `POST /admin/realms/{realm}/roles-by-id/{role_id}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmroles_by_idrole_idcomposites>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/roles-by-id/{role-id}/composites`
+    pub fn roles_by_id_with_role_id_composites_post(
+        &self,
+        role_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_roles_by_id_with_role_id_composites_post(self.realm, role_id, body)
+    }
+
+    /// Remove a set of roles from the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`: Role id
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `DELETE /admin/realms/{realm}/roles-by-id/{role_id}/composites`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmroles_by_idrole_idcomposites>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/roles-by-id/{role-id}/composites`
+    pub fn roles_by_id_with_role_id_composites_delete(
+        &self,
+        role_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_roles_by_id_with_role_id_composites_delete(self.realm, role_id, body)
+    }
+
+    /// Get client-level roles for the client that are in the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`
+    /// - `role_id`
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `GET /admin/realms/{realm}/roles-by-id/{role_id}/composites/clients/{client_uuid}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_idcompositesclientsclientuuid>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}/composites/clients/{clientUuid}`
+    pub fn roles_by_id_with_role_id_composites_clients_with_client_uuid_get(
+        &self,
+        client_uuid: &str,
+        role_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_roles_by_id_with_role_id_composites_clients_with_client_uuid_get(
+                self.realm,
+                client_uuid,
+                role_id,
+            )
+    }
+
+    /// Get realm-level roles that are in the role's composite
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `GET /admin/realms/{realm}/roles-by-id/{role_id}/composites/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_idcompositesrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}/composites/realm`
+    pub fn roles_by_id_with_role_id_composites_realm_get(
+        &self,
+        role_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_roles_by_id_with_role_id_composites_realm_get(self.realm, role_id)
+    }
+
+    /// Return object stating whether role Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `GET /admin/realms/{realm}/roles-by-id/{role_id}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_idmanagementpermissions>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}/management/permissions`
+    pub fn roles_by_id_with_role_id_management_permissions_get(
+        &self,
+        role_id: &str,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_roles_by_id_with_role_id_management_permissions_get(self.realm, role_id)
+    }
+
+    /// Return object stating whether role Authorization permissions have been initialized or not and a reference
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `role_id`
+    /// - `body`
+    ///
+    /// Resource: `Roles (by ID)`
+    ///
+    /// `PUT /admin/realms/{realm}/roles-by-id/{role_id}/management/permissions`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmroles_by_idrole_idmanagementpermissions>
+    ///
+    /// REST method: `PUT /admin/realms/{realm}/roles-by-id/{role-id}/management/permissions`
+    pub fn roles_by_id_with_role_id_management_permissions_put(
+        &self,
+        role_id: &str,
+        body: ManagementPermissionReference,
+    ) -> Result<ManagementPermissionReference, KeycloakError> {
+        self.admin
+            .realm_roles_by_id_with_role_id_management_permissions_put(self.realm, role_id, body)
+    }
+}
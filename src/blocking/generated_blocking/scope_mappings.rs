@@ -0,0 +1,1974 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Scope Mappings</h4>
+
+    /// Get all scope mappings for the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappings>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings`
+    #[deprecated]
+    #[allow(deprecated)]
+    pub fn realm_client_scopes_with_client_scope_id_scope_mappings_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+    ) -> Result<MappingsRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_scopes_with_client_scope_id_scope_mappings_get(
+                    realm,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Get the roles associated with a client's scope Returns roles for the client.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclient>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}`
+    pub fn realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        client: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_get(
+                    realm,
+                    client_scope_id,
+                    client,
+                ),
+        )
+    }
+
+    /// Add client-level roles to the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclient>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}`
+    pub fn realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_post(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        client: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_post(
+                    realm,
+                    client_scope_id,
+                    client,
+                    body,
+                ),
+        )
+    }
+
+    /// Remove client-level roles from the client's scope.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclient>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}`
+    pub fn realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_delete(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        client: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_delete(
+                    realm,
+                    client_scope_id,
+                    client,
+                    body,
+                ),
+        )
+    }
+
+    /// The available client-level roles Returns the roles for the client that can be associated with the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclientavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}/available`
+    pub fn realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_available_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        client: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_available_get(
+            realm,
+            client_scope_id,
+            client,
+        ))
+    }
+
+    /// Get effective client roles Returns the roles for the client that are associated with the client's scope.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclientcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}/composite`
+    pub fn realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_composite_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        client: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_composite_get(
+            realm,
+            client_scope_id,
+            client,
+            brief_representation,
+        ))
+    }
+
+    /// Get realm-level roles associated with the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm`
+    pub fn realm_client_scopes_with_client_scope_id_scope_mappings_realm_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_scopes_with_client_scope_id_scope_mappings_realm_get(
+                    realm,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Add a set of realm-level roles to the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealm>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm`
+    pub fn realm_client_scopes_with_client_scope_id_scope_mappings_realm_post(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_scopes_with_client_scope_id_scope_mappings_realm_post(
+                    realm,
+                    client_scope_id,
+                    body,
+                ),
+        )
+    }
+
+    /// Remove a set of realm-level roles from the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealm>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm`
+    pub fn realm_client_scopes_with_client_scope_id_scope_mappings_realm_delete(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_scopes_with_client_scope_id_scope_mappings_realm_delete(
+                    realm,
+                    client_scope_id,
+                    body,
+                ),
+        )
+    }
+
+    /// Get realm-level roles that are available to attach to this client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealmavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm/available`
+    pub fn realm_client_scopes_with_client_scope_id_scope_mappings_realm_available_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_scopes_with_client_scope_id_scope_mappings_realm_available_get(
+                    realm,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Get effective realm-level roles associated with the client’s scope What this does is recurse any composite roles associated with the client’s scope and adds the roles to this lists.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealmcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm/composite`
+    pub fn realm_client_scopes_with_client_scope_id_scope_mappings_realm_composite_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_scopes_with_client_scope_id_scope_mappings_realm_composite_get(
+                    realm,
+                    client_scope_id,
+                    brief_representation,
+                ),
+        )
+    }
+
+    /// Get all scope mappings for the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappings>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings`
+    #[deprecated]
+    #[allow(deprecated)]
+    pub fn realm_client_templates_with_client_scope_id_scope_mappings_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+    ) -> Result<MappingsRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_templates_with_client_scope_id_scope_mappings_get(
+                    realm,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Get the roles associated with a client's scope Returns roles for the client.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclient>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}`
+    pub fn realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        client: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_get(
+                    realm,
+                    client_scope_id,
+                    client,
+                ),
+        )
+    }
+
+    /// Add client-level roles to the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclient>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}`
+    pub fn realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_post(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        client: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_post(
+            realm,
+            client_scope_id,
+            client,
+            body,
+        ))
+    }
+
+    /// Remove client-level roles from the client's scope.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclient>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}`
+    pub fn realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_delete(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        client: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_delete(
+            realm,
+            client_scope_id,
+            client,
+            body,
+        ))
+    }
+
+    /// The available client-level roles Returns the roles for the client that can be associated with the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclientavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}/available`
+    pub fn realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_available_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        client: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_available_get(
+            realm,
+            client_scope_id,
+            client,
+        ))
+    }
+
+    /// Get effective client roles Returns the roles for the client that are associated with the client's scope.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclientcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}/composite`
+    pub fn realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_composite_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        client: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(self.inner.realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_composite_get(
+            realm,
+            client_scope_id,
+            client,
+            brief_representation,
+        ))
+    }
+
+    /// Get realm-level roles associated with the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm`
+    pub fn realm_client_templates_with_client_scope_id_scope_mappings_realm_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_templates_with_client_scope_id_scope_mappings_realm_get(
+                    realm,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Add a set of realm-level roles to the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealm>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm`
+    pub fn realm_client_templates_with_client_scope_id_scope_mappings_realm_post(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_templates_with_client_scope_id_scope_mappings_realm_post(
+                    realm,
+                    client_scope_id,
+                    body,
+                ),
+        )
+    }
+
+    /// Remove a set of realm-level roles from the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealm>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm`
+    pub fn realm_client_templates_with_client_scope_id_scope_mappings_realm_delete(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_templates_with_client_scope_id_scope_mappings_realm_delete(
+                    realm,
+                    client_scope_id,
+                    body,
+                ),
+        )
+    }
+
+    /// Get realm-level roles that are available to attach to this client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealmavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm/available`
+    pub fn realm_client_templates_with_client_scope_id_scope_mappings_realm_available_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_templates_with_client_scope_id_scope_mappings_realm_available_get(
+                    realm,
+                    client_scope_id,
+                ),
+        )
+    }
+
+    /// Get effective realm-level roles associated with the client’s scope What this does is recurse any composite roles associated with the client’s scope and adds the roles to this lists.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealmcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm/composite`
+    pub fn realm_client_templates_with_client_scope_id_scope_mappings_realm_composite_get(
+        &self,
+        realm: &str,
+        client_scope_id: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_client_templates_with_client_scope_id_scope_mappings_realm_composite_get(
+                    realm,
+                    client_scope_id,
+                    brief_representation,
+                ),
+        )
+    }
+
+    /// Get all scope mappings for the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappings>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings`
+    #[deprecated]
+    #[allow(deprecated)]
+    pub fn realm_clients_with_client_uuid_scope_mappings_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<MappingsRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_scope_mappings_get(realm, client_uuid),
+        )
+    }
+
+    /// Get the roles associated with a client's scope Returns roles for the client.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclient>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}`
+    pub fn realm_clients_with_client_uuid_scope_mappings_clients_with_client_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        client: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_scope_mappings_clients_with_client_get(
+                    realm,
+                    client_uuid,
+                    client,
+                ),
+        )
+    }
+
+    /// Add client-level roles to the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclient>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}`
+    pub fn realm_clients_with_client_uuid_scope_mappings_clients_with_client_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        client: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_scope_mappings_clients_with_client_post(
+                    realm,
+                    client_uuid,
+                    client,
+                    body,
+                ),
+        )
+    }
+
+    /// Remove client-level roles from the client's scope.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclient>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}`
+    pub fn realm_clients_with_client_uuid_scope_mappings_clients_with_client_delete(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        client: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_scope_mappings_clients_with_client_delete(
+                    realm,
+                    client_uuid,
+                    client,
+                    body,
+                ),
+        )
+    }
+
+    /// The available client-level roles Returns the roles for the client that can be associated with the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclientavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}/available`
+    pub fn realm_clients_with_client_uuid_scope_mappings_clients_with_client_available_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        client: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_scope_mappings_clients_with_client_available_get(
+                    realm,
+                    client_uuid,
+                    client,
+                ),
+        )
+    }
+
+    /// Get effective client roles Returns the roles for the client that are associated with the client's scope.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclientcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}/composite`
+    pub fn realm_clients_with_client_uuid_scope_mappings_clients_with_client_composite_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        client: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_scope_mappings_clients_with_client_composite_get(
+                    realm,
+                    client_uuid,
+                    client,
+                    brief_representation,
+                ),
+        )
+    }
+
+    /// Get realm-level roles associated with the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm`
+    pub fn realm_clients_with_client_uuid_scope_mappings_realm_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_scope_mappings_realm_get(realm, client_uuid),
+        )
+    }
+
+    /// Add a set of realm-level roles to the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidscope_mappingsrealm>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm`
+    pub fn realm_clients_with_client_uuid_scope_mappings_realm_post(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_scope_mappings_realm_post(realm, client_uuid, body),
+        )
+    }
+
+    /// Remove a set of realm-level roles from the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidscope_mappingsrealm>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm`
+    pub fn realm_clients_with_client_uuid_scope_mappings_realm_delete(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_scope_mappings_realm_delete(
+                    realm,
+                    client_uuid,
+                    body,
+                ),
+        )
+    }
+
+    /// Get realm-level roles that are available to attach to this client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsrealmavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm/available`
+    pub fn realm_clients_with_client_uuid_scope_mappings_realm_available_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_scope_mappings_realm_available_get(
+                    realm,
+                    client_uuid,
+                ),
+        )
+    }
+
+    /// Get effective realm-level roles associated with the client’s scope What this does is recurse any composite roles associated with the client’s scope and adds the roles to this lists.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsrealmcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm/composite`
+    pub fn realm_clients_with_client_uuid_scope_mappings_realm_composite_get(
+        &self,
+        realm: &str,
+        client_uuid: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_clients_with_client_uuid_scope_mappings_realm_composite_get(
+                    realm,
+                    client_uuid,
+                    brief_representation,
+                ),
+        )
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Scope Mappings</h4>
+
+    /// Get all scope mappings for the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappings>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings`
+    #[deprecated]
+    #[allow(deprecated)]
+    pub fn client_scopes_with_client_scope_id_scope_mappings_get(
+        &self,
+        client_scope_id: &str,
+    ) -> Result<MappingsRepresentation, KeycloakError> {
+        self.admin
+            .realm_client_scopes_with_client_scope_id_scope_mappings_get(
+                self.realm,
+                client_scope_id,
+            )
+    }
+
+    /// Get the roles associated with a client's scope Returns roles for the client.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclient>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}`
+    pub fn client_scopes_with_client_scope_id_scope_mappings_clients_with_client_get(
+        &self,
+        client_scope_id: &str,
+        client: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_get(
+                self.realm,
+                client_scope_id,
+                client,
+            )
+    }
+
+    /// Add client-level roles to the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclient>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}`
+    pub fn client_scopes_with_client_scope_id_scope_mappings_clients_with_client_post(
+        &self,
+        client_scope_id: &str,
+        client: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_post(
+                self.realm,
+                client_scope_id,
+                client,
+                body,
+            )
+    }
+
+    /// Remove client-level roles from the client's scope.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclient>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}`
+    pub fn client_scopes_with_client_scope_id_scope_mappings_clients_with_client_delete(
+        &self,
+        client_scope_id: &str,
+        client: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_delete(
+                self.realm,
+                client_scope_id,
+                client,
+                body,
+            )
+    }
+
+    /// The available client-level roles Returns the roles for the client that can be associated with the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclientavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}/available`
+    pub fn client_scopes_with_client_scope_id_scope_mappings_clients_with_client_available_get(
+        &self,
+        client_scope_id: &str,
+        client: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin.realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_available_get(
+            self.realm,
+            client_scope_id,
+            client,
+        )
+    }
+
+    /// Get effective client roles Returns the roles for the client that are associated with the client's scope.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclientcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}/composite`
+    pub fn client_scopes_with_client_scope_id_scope_mappings_clients_with_client_composite_get(
+        &self,
+        client_scope_id: &str,
+        client: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin.realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_composite_get(
+            self.realm,
+            client_scope_id,
+            client,
+            brief_representation,
+        )
+    }
+
+    /// Get realm-level roles associated with the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm`
+    pub fn client_scopes_with_client_scope_id_scope_mappings_realm_get(
+        &self,
+        client_scope_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_client_scopes_with_client_scope_id_scope_mappings_realm_get(
+                self.realm,
+                client_scope_id,
+            )
+    }
+
+    /// Add a set of realm-level roles to the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealm>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm`
+    pub fn client_scopes_with_client_scope_id_scope_mappings_realm_post(
+        &self,
+        client_scope_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_client_scopes_with_client_scope_id_scope_mappings_realm_post(
+                self.realm,
+                client_scope_id,
+                body,
+            )
+    }
+
+    /// Remove a set of realm-level roles from the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealm>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm`
+    pub fn client_scopes_with_client_scope_id_scope_mappings_realm_delete(
+        &self,
+        client_scope_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_client_scopes_with_client_scope_id_scope_mappings_realm_delete(
+                self.realm,
+                client_scope_id,
+                body,
+            )
+    }
+
+    /// Get realm-level roles that are available to attach to this client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealmavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm/available`
+    pub fn client_scopes_with_client_scope_id_scope_mappings_realm_available_get(
+        &self,
+        client_scope_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_client_scopes_with_client_scope_id_scope_mappings_realm_available_get(
+                self.realm,
+                client_scope_id,
+            )
+    }
+
+    /// Get effective realm-level roles associated with the client’s scope What this does is recurse any composite roles associated with the client’s scope and adds the roles to this lists.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealmcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm/composite`
+    pub fn client_scopes_with_client_scope_id_scope_mappings_realm_composite_get(
+        &self,
+        client_scope_id: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_client_scopes_with_client_scope_id_scope_mappings_realm_composite_get(
+                self.realm,
+                client_scope_id,
+                brief_representation,
+            )
+    }
+
+    /// Get all scope mappings for the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappings>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings`
+    #[deprecated]
+    #[allow(deprecated)]
+    pub fn client_templates_with_client_scope_id_scope_mappings_get(
+        &self,
+        client_scope_id: &str,
+    ) -> Result<MappingsRepresentation, KeycloakError> {
+        self.admin
+            .realm_client_templates_with_client_scope_id_scope_mappings_get(
+                self.realm,
+                client_scope_id,
+            )
+    }
+
+    /// Get the roles associated with a client's scope Returns roles for the client.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclient>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}`
+    pub fn client_templates_with_client_scope_id_scope_mappings_clients_with_client_get(
+        &self,
+        client_scope_id: &str,
+        client: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_get(
+                self.realm,
+                client_scope_id,
+                client,
+            )
+    }
+
+    /// Add client-level roles to the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclient>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}`
+    pub fn client_templates_with_client_scope_id_scope_mappings_clients_with_client_post(
+        &self,
+        client_scope_id: &str,
+        client: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_post(
+                self.realm,
+                client_scope_id,
+                client,
+                body,
+            )
+    }
+
+    /// Remove client-level roles from the client's scope.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclient>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}`
+    pub fn client_templates_with_client_scope_id_scope_mappings_clients_with_client_delete(
+        &self,
+        client_scope_id: &str,
+        client: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_delete(
+                self.realm,
+                client_scope_id,
+                client,
+                body,
+            )
+    }
+
+    /// The available client-level roles Returns the roles for the client that can be associated with the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclientavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}/available`
+    pub fn client_templates_with_client_scope_id_scope_mappings_clients_with_client_available_get(
+        &self,
+        client_scope_id: &str,
+        client: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin.realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_available_get(
+            self.realm,
+            client_scope_id,
+            client,
+        )
+    }
+
+    /// Get effective client roles Returns the roles for the client that are associated with the client's scope.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `client`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclientcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}/composite`
+    pub fn client_templates_with_client_scope_id_scope_mappings_clients_with_client_composite_get(
+        &self,
+        client_scope_id: &str,
+        client: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin.realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_composite_get(
+            self.realm,
+            client_scope_id,
+            client,
+            brief_representation,
+        )
+    }
+
+    /// Get realm-level roles associated with the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm`
+    pub fn client_templates_with_client_scope_id_scope_mappings_realm_get(
+        &self,
+        client_scope_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_client_templates_with_client_scope_id_scope_mappings_realm_get(
+                self.realm,
+                client_scope_id,
+            )
+    }
+
+    /// Add a set of realm-level roles to the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealm>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm`
+    pub fn client_templates_with_client_scope_id_scope_mappings_realm_post(
+        &self,
+        client_scope_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_client_templates_with_client_scope_id_scope_mappings_realm_post(
+                self.realm,
+                client_scope_id,
+                body,
+            )
+    }
+
+    /// Remove a set of realm-level roles from the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealm>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm`
+    pub fn client_templates_with_client_scope_id_scope_mappings_realm_delete(
+        &self,
+        client_scope_id: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_client_templates_with_client_scope_id_scope_mappings_realm_delete(
+                self.realm,
+                client_scope_id,
+                body,
+            )
+    }
+
+    /// Get realm-level roles that are available to attach to this client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealmavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm/available`
+    pub fn client_templates_with_client_scope_id_scope_mappings_realm_available_get(
+        &self,
+        client_scope_id: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_client_templates_with_client_scope_id_scope_mappings_realm_available_get(
+                self.realm,
+                client_scope_id,
+            )
+    }
+
+    /// Get effective realm-level roles associated with the client’s scope What this does is recurse any composite roles associated with the client’s scope and adds the roles to this lists.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_scope_id`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealmcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm/composite`
+    pub fn client_templates_with_client_scope_id_scope_mappings_realm_composite_get(
+        &self,
+        client_scope_id: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_client_templates_with_client_scope_id_scope_mappings_realm_composite_get(
+                self.realm,
+                client_scope_id,
+                brief_representation,
+            )
+    }
+
+    /// Get all scope mappings for the client
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappings>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings`
+    #[deprecated]
+    #[allow(deprecated)]
+    pub fn clients_with_client_uuid_scope_mappings_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<MappingsRepresentation, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_scope_mappings_get(self.realm, client_uuid)
+    }
+
+    /// Get the roles associated with a client's scope Returns roles for the client.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclient>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}`
+    pub fn clients_with_client_uuid_scope_mappings_clients_with_client_get(
+        &self,
+        client_uuid: &str,
+        client: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_scope_mappings_clients_with_client_get(
+                self.realm,
+                client_uuid,
+                client,
+            )
+    }
+
+    /// Add client-level roles to the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclient>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}`
+    pub fn clients_with_client_uuid_scope_mappings_clients_with_client_post(
+        &self,
+        client_uuid: &str,
+        client: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_scope_mappings_clients_with_client_post(
+                self.realm,
+                client_uuid,
+                client,
+                body,
+            )
+    }
+
+    /// Remove client-level roles from the client's scope.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client`
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclient>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}`
+    pub fn clients_with_client_uuid_scope_mappings_clients_with_client_delete(
+        &self,
+        client_uuid: &str,
+        client: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_scope_mappings_clients_with_client_delete(
+                self.realm,
+                client_uuid,
+                client,
+                body,
+            )
+    }
+
+    /// The available client-level roles Returns the roles for the client that can be associated with the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client`
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclientavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}/available`
+    pub fn clients_with_client_uuid_scope_mappings_clients_with_client_available_get(
+        &self,
+        client_uuid: &str,
+        client: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_scope_mappings_clients_with_client_available_get(
+                self.realm,
+                client_uuid,
+                client,
+            )
+    }
+
+    /// Get effective client roles Returns the roles for the client that are associated with the client's scope.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `client`
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclientcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}/composite`
+    pub fn clients_with_client_uuid_scope_mappings_clients_with_client_composite_get(
+        &self,
+        client_uuid: &str,
+        client: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_scope_mappings_clients_with_client_composite_get(
+                self.realm,
+                client_uuid,
+                client,
+                brief_representation,
+            )
+    }
+
+    /// Get realm-level roles associated with the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsrealm>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm`
+    pub fn clients_with_client_uuid_scope_mappings_realm_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_scope_mappings_realm_get(self.realm, client_uuid)
+    }
+
+    /// Add a set of realm-level roles to the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `POST /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidscope_mappingsrealm>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm`
+    pub fn clients_with_client_uuid_scope_mappings_realm_post(
+        &self,
+        client_uuid: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_scope_mappings_realm_post(self.realm, client_uuid, body)
+    }
+
+    /// Remove a set of realm-level roles from the client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `DELETE /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidscope_mappingsrealm>
+    ///
+    /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm`
+    pub fn clients_with_client_uuid_scope_mappings_realm_delete(
+        &self,
+        client_uuid: &str,
+        body: Vec<RoleRepresentation>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_scope_mappings_realm_delete(
+                self.realm,
+                client_uuid,
+                body,
+            )
+    }
+
+    /// Get realm-level roles that are available to attach to this client's scope
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm/available`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsrealmavailable>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm/available`
+    pub fn clients_with_client_uuid_scope_mappings_realm_available_get(
+        &self,
+        client_uuid: &str,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_scope_mappings_realm_available_get(
+                self.realm,
+                client_uuid,
+            )
+    }
+
+    /// Get effective realm-level roles associated with the client’s scope What this does is recurse any composite roles associated with the client’s scope and adds the roles to this lists.
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `client_uuid`: id of client (not client-id!)
+    /// - `brief_representation`: if false, return roles with their attributes
+    ///
+    /// Resource: `Scope Mappings`
+    ///
+    /// `GET /admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm/composite`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsrealmcomposite>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm/composite`
+    pub fn clients_with_client_uuid_scope_mappings_realm_composite_get(
+        &self,
+        client_uuid: &str,
+        brief_representation: Option<bool>,
+    ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
+        self.admin
+            .realm_clients_with_client_uuid_scope_mappings_realm_composite_get(
+                self.realm,
+                client_uuid,
+                brief_representation,
+            )
+    }
+}
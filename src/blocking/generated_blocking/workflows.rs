@@ -0,0 +1,480 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    // <h4>Workflows</h4>
+
+    /// List workflows
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `exact`: Boolean which defines whether the param 'search' must match exactly or not
+    /// - `first`: The position of the first result to be processed (pagination offset)
+    /// - `max`: The maximum number of results to be returned - defaults to 10
+    /// - `search`: A String representing the workflow name - either partial or exact
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `GET /admin/realms/{realm}/workflows`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmworkflows>
+    pub fn realm_workflows_get(
+        &self,
+        realm: &str,
+        exact: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+        search: Option<String>,
+    ) -> Result<WorkflowRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_workflows_get(realm, exact, first, max, search),
+        )
+    }
+
+    /// Create workflow
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `POST /admin/realms/{realm}/workflows`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmworkflows>
+    pub fn realm_workflows_post(
+        &self,
+        realm: &str,
+        body: WorkflowRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_workflows_post(realm, body))
+    }
+
+    /// Migrate scheduled resources from one step to another
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `from`: A String representing the id of the step to migrate from
+    /// - `to`: A String representing the id of the step to migrate to
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `POST /admin/realms/{realm}/workflows/migrate`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmworkflowsmigrate>
+    pub fn realm_workflows_migrate_post(
+        &self,
+        realm: &str,
+        from: Option<String>,
+        to: Option<String>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_workflows_migrate_post(realm, from, to))
+    }
+
+    /// List scheduled workflows for resource
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `resource_id`: Identifier of the resource associated with the scheduled workflows
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `GET /admin/realms/{realm}/workflows/scheduled/{resource_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmworkflowsscheduledresource_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/workflows/scheduled/{resource-id}`
+    pub fn realm_workflows_scheduled_with_resource_id_get(
+        &self,
+        realm: &str,
+        resource_id: &str,
+    ) -> Result<WorkflowRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_workflows_scheduled_with_resource_id_get(realm, resource_id),
+        )
+    }
+
+    /// Get workflow
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`: Workflow identifier
+    /// - `include_id`: Indicates whether the workflow and step ids should be included in the representation or not - defaults to true
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `GET /admin/realms/{realm}/workflows/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmworkflowsid>
+    pub fn realm_workflows_with_id_get(
+        &self,
+        realm: &str,
+        id: &str,
+        include_id: Option<bool>,
+    ) -> Result<WorkflowRepresentation, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_workflows_with_id_get(realm, id, include_id),
+        )
+    }
+
+    /// Update workflow
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`: Workflow identifier
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `PUT /admin/realms/{realm}/workflows/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmworkflowsid>
+    pub fn realm_workflows_with_id_put(
+        &self,
+        realm: &str,
+        id: &str,
+        body: WorkflowRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_workflows_with_id_put(realm, id, body))
+    }
+
+    /// Delete workflow
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`: Workflow identifier
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `DELETE /admin/realms/{realm}/workflows/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmworkflowsid>
+    pub fn realm_workflows_with_id_delete(
+        &self,
+        realm: &str,
+        id: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(self.inner.realm_workflows_with_id_delete(realm, id))
+    }
+
+    /// Activate workflow for resource
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`: Workflow identifier
+    /// - `resource_id`: Resource identifier
+    /// - `type_`: Resource type
+    /// - `not_before`: Optional value representing the time to schedule the first workflow step. The value is either an integer representing the seconds from now, an integer followed by 'ms' representing milliseconds from now, or an ISO-8601 date string.
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `POST /admin/realms/{realm}/workflows/{id}/activate/{type_}/{resource_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmworkflowsidactivatetyperesourceid>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/workflows/{id}/activate/{type}/{resourceId}`
+    pub fn realm_workflows_with_id_activate_with_type_with_resource_id_post(
+        &self,
+        realm: &str,
+        id: &str,
+        resource_id: &str,
+        type_: &str,
+        not_before: Option<String>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_workflows_with_id_activate_with_type_with_resource_id_post(
+                    realm,
+                    id,
+                    resource_id,
+                    type_,
+                    not_before,
+                ),
+        )
+    }
+
+    /// Deactivate workflow for resource
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`: Workflow identifier
+    /// - `resource_id`: Resource identifier
+    /// - `type_`: Resource type
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `POST /admin/realms/{realm}/workflows/{id}/deactivate/{type_}/{resource_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmworkflowsiddeactivatetyperesourceid>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/workflows/{id}/deactivate/{type}/{resourceId}`
+    pub fn realm_workflows_with_id_deactivate_with_type_with_resource_id_post(
+        &self,
+        realm: &str,
+        id: &str,
+        resource_id: &str,
+        type_: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.block_on(
+            self.inner
+                .realm_workflows_with_id_deactivate_with_type_with_resource_id_post(
+                    realm,
+                    id,
+                    resource_id,
+                    type_,
+                ),
+        )
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    // <h4>Workflows</h4>
+
+    /// List workflows
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `exact`: Boolean which defines whether the param 'search' must match exactly or not
+    /// - `first`: The position of the first result to be processed (pagination offset)
+    /// - `max`: The maximum number of results to be returned - defaults to 10
+    /// - `search`: A String representing the workflow name - either partial or exact
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `GET /admin/realms/{realm}/workflows`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmworkflows>
+    pub fn workflows_get(
+        &self,
+        exact: Option<bool>,
+        first: Option<i32>,
+        max: Option<i32>,
+        search: Option<String>,
+    ) -> Result<WorkflowRepresentation, KeycloakError> {
+        self.admin
+            .realm_workflows_get(self.realm, exact, first, max, search)
+    }
+
+    /// Create workflow
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `POST /admin/realms/{realm}/workflows`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmworkflows>
+    pub fn workflows_post(
+        &self,
+        body: WorkflowRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_workflows_post(self.realm, body)
+    }
+
+    /// Migrate scheduled resources from one step to another
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `from`: A String representing the id of the step to migrate from
+    /// - `to`: A String representing the id of the step to migrate to
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `POST /admin/realms/{realm}/workflows/migrate`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmworkflowsmigrate>
+    pub fn workflows_migrate_post(
+        &self,
+        from: Option<String>,
+        to: Option<String>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_workflows_migrate_post(self.realm, from, to)
+    }
+
+    /// List scheduled workflows for resource
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `resource_id`: Identifier of the resource associated with the scheduled workflows
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `GET /admin/realms/{realm}/workflows/scheduled/{resource_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmworkflowsscheduledresource_id>
+    ///
+    /// REST method: `GET /admin/realms/{realm}/workflows/scheduled/{resource-id}`
+    pub fn workflows_scheduled_with_resource_id_get(
+        &self,
+        resource_id: &str,
+    ) -> Result<WorkflowRepresentation, KeycloakError> {
+        self.admin
+            .realm_workflows_scheduled_with_resource_id_get(self.realm, resource_id)
+    }
+
+    /// Get workflow
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`: Workflow identifier
+    /// - `include_id`: Indicates whether the workflow and step ids should be included in the representation or not - defaults to true
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `GET /admin/realms/{realm}/workflows/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmworkflowsid>
+    pub fn workflows_with_id_get(
+        &self,
+        id: &str,
+        include_id: Option<bool>,
+    ) -> Result<WorkflowRepresentation, KeycloakError> {
+        self.admin
+            .realm_workflows_with_id_get(self.realm, id, include_id)
+    }
+
+    /// Update workflow
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`: Workflow identifier
+    /// - `body`
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `PUT /admin/realms/{realm}/workflows/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmworkflowsid>
+    pub fn workflows_with_id_put(
+        &self,
+        id: &str,
+        body: WorkflowRepresentation,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_workflows_with_id_put(self.realm, id, body)
+    }
+
+    /// Delete workflow
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`: Workflow identifier
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `DELETE /admin/realms/{realm}/workflows/{id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmworkflowsid>
+    pub fn workflows_with_id_delete(&self, id: &str) -> Result<DefaultResponse, KeycloakError> {
+        self.admin.realm_workflows_with_id_delete(self.realm, id)
+    }
+
+    /// Activate workflow for resource
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`: Workflow identifier
+    /// - `resource_id`: Resource identifier
+    /// - `type_`: Resource type
+    /// - `not_before`: Optional value representing the time to schedule the first workflow step. The value is either an integer representing the seconds from now, an integer followed by 'ms' representing milliseconds from now, or an ISO-8601 date string.
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `POST /admin/realms/{realm}/workflows/{id}/activate/{type_}/{resource_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmworkflowsidactivatetyperesourceid>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/workflows/{id}/activate/{type}/{resourceId}`
+    pub fn workflows_with_id_activate_with_type_with_resource_id_post(
+        &self,
+        id: &str,
+        resource_id: &str,
+        type_: &str,
+        not_before: Option<String>,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_workflows_with_id_activate_with_type_with_resource_id_post(
+                self.realm,
+                id,
+                resource_id,
+                type_,
+                not_before,
+            )
+    }
+
+    /// Deactivate workflow for resource
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `id`: Workflow identifier
+    /// - `resource_id`: Resource identifier
+    /// - `type_`: Resource type
+    ///
+    /// Returns response for future processing.
+    ///
+    /// Resource: `Workflows`
+    ///
+    /// `POST /admin/realms/{realm}/workflows/{id}/deactivate/{type_}/{resource_id}`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmworkflowsiddeactivatetyperesourceid>
+    ///
+    /// REST method: `POST /admin/realms/{realm}/workflows/{id}/deactivate/{type}/{resourceId}`
+    pub fn workflows_with_id_deactivate_with_type_with_resource_id_post(
+        &self,
+        id: &str,
+        resource_id: &str,
+        type_: &str,
+    ) -> Result<DefaultResponse, KeycloakError> {
+        self.admin
+            .realm_workflows_with_id_deactivate_with_type_with_resource_id_post(
+                self.realm,
+                id,
+                resource_id,
+                type_,
+            )
+    }
+}
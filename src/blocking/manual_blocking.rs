@@ -0,0 +1,52 @@
+use super::*;
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    /// Import identity provider from FORM MULTIPART body
+    ///
+    /// Parameters:
+    ///
+    /// - `realm`: realm name (not id!)
+    /// - `provider_id`: identity provider type (for example `saml`)
+    /// - `file`: metadata to parse (XML IDP Metadata for `saml`)
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `POST /admin/realms/{realm}/identity-provider/import-config`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.2.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerimport_config>
+    pub fn realm_identity_provider_import_config_post_form(
+        &self,
+        realm: &str,
+        provider_id: String,
+        file: Vec<u8>,
+    ) -> Result<TypeMap<String, TypeString>, KeycloakError> {
+        self.block_on(self.inner.realm_identity_provider_import_config_post_form(
+            realm,
+            provider_id,
+            file,
+        ))
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    /// Import identity provider from FORM MULTIPART body
+    ///
+    /// Parameters:
+    ///
+    /// - `provider_id`: identity provider type (for example `saml`)
+    /// - `file`: metadata to parse (XML IDP Metadata for `saml`)
+    ///
+    /// Resource: `Identity Providers`
+    ///
+    /// `POST /admin/realms/{realm}/identity-provider/import-config`
+    ///
+    /// Documentation: <https://www.keycloak.org/docs-api/26.2.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerimport_config>
+    pub fn identity_provider_import_config_post_form(
+        &self,
+        provider_id: String,
+        file: Vec<u8>,
+    ) -> Result<TypeMap<String, TypeString>, KeycloakError> {
+        self.admin
+            .realm_identity_provider_import_config_post_form(self.realm, provider_id, file)
+    }
+}
@@ -0,0 +1,243 @@
+//! Synchronous Admin API client.
+//!
+//! [`KeycloakAdmin`] and [`KeycloakRealmAdmin`] mirror the methods of the
+//! async [`crate::KeycloakAdmin`] and return their results directly. Calls are
+//! driven by a runtime owned by the client, so types, errors, transports and
+//! token suppliers are the same as for the async API.
+//!
+//! The blocking client must not be created, used or dropped within an async
+//! runtime, as it would block the runtime and panics in that case.
+
+use std::future::Future;
+
+use http::Method;
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{
+    types::*, DefaultResponse, KeycloakAdminToken, KeycloakError, KeycloakLimiter,
+    KeycloakMiddleware, KeycloakRetryPolicy, KeycloakServerLocation, KeycloakTokenSupplier,
+    KeycloakTransport,
+};
+
+mod generated_blocking;
+#[cfg(all(feature = "tag-identity-providers", feature = "multipart"))]
+mod manual_blocking;
+
+pub struct KeycloakAdmin<TS: KeycloakTokenSupplier = KeycloakAdminToken> {
+    inner: crate::KeycloakAdmin<TS>,
+    runtime: Runtime,
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    /// Creates a blocking admin client for the server at `url`.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    /// * `token_supplier` - Supplier of the access tokens used for the Admin API calls.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the Admin API calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime driving the calls cannot be created.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn doc() -> Result<(), keycloak::KeycloakError> {
+    /// use keycloak::{
+    ///     blocking::KeycloakAdmin, prelude::reqwest, types::*, KeycloakPasswordAdminTokenRetriever,
+    /// };
+    ///
+    /// let client = reqwest::Client::new();
+    /// let url = "https://keycloak.example.com";
+    ///
+    /// let retriever = KeycloakPasswordAdminTokenRetriever::create("admin", "password", client.clone());
+    /// let admin = KeycloakAdmin::new(url, retriever, client);
+    ///
+    /// admin.realm("test").users_post(UserRepresentation {
+    ///     username: Some("user".into()),
+    ///     ..Default::default()
+    /// })?;
+    /// # Ok(()) }
+    /// ```
+    pub fn new(url: &str, token_supplier: TS, client: impl KeycloakTransport + 'static) -> Self {
+        crate::KeycloakAdmin::new(url, token_supplier, client).into()
+    }
+
+    /// Creates a blocking admin client for the server at `location`.
+    ///
+    /// See [`crate::KeycloakAdmin::new_with_location`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime driving the calls cannot be created.
+    pub fn new_with_location(
+        location: KeycloakServerLocation,
+        token_supplier: TS,
+        client: impl KeycloakTransport + 'static,
+    ) -> Self {
+        crate::KeycloakAdmin::new_with_location(location, token_supplier, client).into()
+    }
+
+    /// Sets the policy for retrying requests after transient failures.
+    ///
+    /// See [`crate::KeycloakAdmin::with_retry_policy`].
+    pub fn with_retry_policy(mut self, retry_policy: KeycloakRetryPolicy) -> Self {
+        self.inner = self.inner.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Sets the limiter of the requests sent to Keycloak.
+    ///
+    /// See [`crate::KeycloakAdmin::with_limiter`].
+    pub fn with_limiter(mut self, limiter: KeycloakLimiter) -> Self {
+        self.inner = self.inner.with_limiter(limiter);
+        self
+    }
+
+    /// Adds a middleware running around every request.
+    ///
+    /// See [`crate::KeycloakAdmin::with_middleware`].
+    pub fn with_middleware(mut self, middleware: impl KeycloakMiddleware + 'static) -> Self {
+        self.inner = self.inner.with_middleware(middleware);
+        self
+    }
+
+    /// Returns the location of the Keycloak server.
+    pub fn location(&self) -> &KeycloakServerLocation {
+        self.inner.location()
+    }
+
+    /// Returns the async client the calls are delegated to.
+    pub fn as_async(&self) -> &crate::KeycloakAdmin<TS> {
+        &self.inner
+    }
+
+    /// Runs `future` to completion on the runtime of the client.
+    ///
+    /// Useful for async calls without blocking counterpart, e.g. logging out
+    /// a token supplier.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    pub fn realm<'a>(&'a self, realm: &'a str) -> KeycloakRealmAdmin<'a, TS> {
+        KeycloakRealmAdmin { realm, admin: self }
+    }
+
+    /// Creates a request to the Admin API endpoint at `path`.
+    ///
+    /// See [`crate::KeycloakAdmin::request`].
+    pub fn request(&self, method: Method, path: &str) -> KeycloakAdminRequest<'_, TS> {
+        KeycloakAdminRequest {
+            inner: self.inner.request(method, path),
+            runtime: &self.runtime,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> From<crate::KeycloakAdmin<TS>> for KeycloakAdmin<TS> {
+    /// Wraps an async admin client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime driving the calls cannot be created.
+    fn from(inner: crate::KeycloakAdmin<TS>) -> Self {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to create runtime of blocking Keycloak admin client");
+        Self { inner, runtime }
+    }
+}
+
+pub struct KeycloakRealmAdmin<'a, TS: KeycloakTokenSupplier> {
+    pub realm: &'a str,
+    admin: &'a KeycloakAdmin<TS>,
+}
+
+impl<'a, TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'a, TS> {
+    /// Creates a request to the Admin API endpoint at `path`, with the
+    /// `{realm}` placeholder set to the realm.
+    ///
+    /// See [`crate::KeycloakRealmAdmin::request`].
+    pub fn request(&self, method: Method, path: &str) -> KeycloakAdminRequest<'a, TS> {
+        self.admin
+            .request(method, path)
+            .path_param("realm", self.realm)
+    }
+}
+
+/// Blocking [`crate::KeycloakAdminRequest`].
+pub struct KeycloakAdminRequest<'a, TS: KeycloakTokenSupplier> {
+    inner: crate::KeycloakAdminRequest<'a, TS>,
+    runtime: &'a Runtime,
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdminRequest<'_, TS> {
+    /// Sets the value of placeholder `{name}` in the path, the value is percent-encoded.
+    pub fn path_param(mut self, name: &str, value: &str) -> Self {
+        self.inner = self.inner.path_param(name, value);
+        self
+    }
+
+    /// Appends query parameters, e.g. `&[("first", 0)]`.
+    pub fn query<Q: Serialize + ?Sized>(mut self, query: &Q) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets JSON body.
+    pub fn json<B: Serialize + ?Sized>(mut self, body: &B) -> Self {
+        self.inner = self.inner.json(body);
+        self
+    }
+
+    /// Sends the request and deserializes the JSON response.
+    pub fn send<T: DeserializeOwned>(self) -> Result<T, KeycloakError> {
+        self.runtime.block_on(self.inner.send())
+    }
+
+    /// Sends the request and returns the response for further processing.
+    pub fn send_default(self) -> Result<DefaultResponse, KeycloakError> {
+        self.runtime.block_on(self.inner.send_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use http::{header::AUTHORIZATION, StatusCode};
+
+    use super::KeycloakAdmin;
+    use crate::{
+        test_util::StaticToken, KeycloakError, KeycloakRequest, KeycloakResponse, KeycloakTransport,
+    };
+
+    struct Realm;
+
+    #[async_trait]
+    impl KeycloakTransport for Realm {
+        async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+            assert_eq!(
+                request.url_with_query(),
+                "http://keycloak/admin/realms/test"
+            );
+            assert_eq!(request.headers()[AUTHORIZATION], "Bearer token");
+            Ok(KeycloakResponse::new(
+                StatusCode::OK,
+                Default::default(),
+                br#"{"realm":"test"}"#.to_vec(),
+            ))
+        }
+    }
+
+    #[test]
+    fn realm_call() {
+        let admin = KeycloakAdmin::new("http://keycloak", StaticToken, Realm);
+        let realm = admin.realm("test").get().unwrap();
+        assert_eq!(realm.realm.as_deref(), Some("test"));
+    }
+}
@@ -32,3 +32,37 @@ where
         Box::pin(self.method.opts(self.args))
     }
 }
+
+#[cfg(feature = "resource")]
+impl<'m, M> Builder<'m, M>
+where
+    M: 'm + crate::KeycloakRealmAdminPagedMethod,
+{
+    /// Stream of pages fetched with the builder arguments, see
+    /// [`KeycloakRealmAdminPagedMethod::pages`](crate::KeycloakRealmAdminPagedMethod::pages).
+    pub fn pages(
+        self,
+        page_size: i32,
+    ) -> impl futures_util::Stream<Item = Result<Vec<M::Item>, KeycloakError>> + Send {
+        self.method.pages(self.args, page_size)
+    }
+
+    /// Stream of items fetched with the builder arguments, see
+    /// [`KeycloakRealmAdminPagedMethod::stream`](crate::KeycloakRealmAdminPagedMethod::stream).
+    pub fn stream(
+        self,
+        page_size: i32,
+    ) -> impl futures_util::Stream<Item = Result<M::Item, KeycloakError>> + Send {
+        self.method.stream(self.args, page_size)
+    }
+
+    /// Collects at most `limit` items fetched with the builder arguments, see
+    /// [`KeycloakRealmAdminPagedMethod::collect_all`](crate::KeycloakRealmAdminPagedMethod::collect_all).
+    pub async fn collect_all(
+        self,
+        page_size: i32,
+        limit: usize,
+    ) -> Result<Vec<M::Item>, KeycloakError> {
+        self.method.collect_all(self.args, page_size, limit).await
+    }
+}
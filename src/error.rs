@@ -1,38 +1,384 @@
 use std::{borrow::Cow, error::Error, fmt::Display};
 
+use http::Method;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
+use crate::types::ErrorRepresentation;
+
+#[cfg(any(feature = "reqwest12", feature = "reqwest13"))]
 use crate::prelude::reqwest;
 
 #[derive(Debug)]
 pub enum KeycloakError {
+    #[cfg(any(feature = "reqwest12", feature = "reqwest13"))]
     ReqwestFailure(reqwest::Error),
     HttpFailure {
         status: u16,
         body: Option<KeycloakHttpError>,
         text: String,
+        /// Admin API endpoint which answered with the error, `None` for other calls (e.g. token requests).
+        endpoint: Option<Box<KeycloakEndpoint>>,
+    },
+    TokenFailure(KeycloakTokenError),
+    #[cfg(feature = "jwt")]
+    JwtFailure(jsonwebtoken::errors::Error),
+    /// JSON body could not be serialized or deserialized.
+    JsonFailure(serde_json::Error),
+    /// Request could not be built, e.g. query parameters could not be encoded.
+    InvalidRequest(String),
+    /// Failure of a custom [`KeycloakTransport`](crate::KeycloakTransport).
+    TransportFailure(Box<dyn Error + Send + Sync>),
+    /// Response of an Admin API endpoint could not be deserialized.
+    DecodeFailure {
+        endpoint: KeycloakEndpoint,
+        source: serde_json::Error,
+    },
+    /// Call of an Admin API endpoint failed without error response, e.g. the
+    /// connection failed or no token could be acquired. `source` is the
+    /// failure as it would be returned without endpoint, e.g. a
+    /// [`KeycloakError::TransportFailure`].
+    ///
+    /// Breaking change: these failures were returned unwrapped before, use
+    /// [`KeycloakError::into_inner`] to match on them.
+    RequestFailure {
+        endpoint: KeycloakEndpoint,
+        source: Box<KeycloakError>,
     },
 }
 
+impl KeycloakError {
+    /// Returns the HTTP status of error responses.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            KeycloakError::HttpFailure { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns the Admin API endpoint which failed.
+    pub fn endpoint(&self) -> Option<&KeycloakEndpoint> {
+        match self {
+            KeycloakError::HttpFailure { endpoint, .. } => endpoint.as_deref(),
+            KeycloakError::DecodeFailure { endpoint, .. }
+            | KeycloakError::RequestFailure { endpoint, .. } => Some(endpoint),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` for `400 Bad Request` responses.
+    pub fn is_bad_request(&self) -> bool {
+        self.status() == Some(400)
+    }
+
+    /// Returns `true` for `401 Unauthorized` responses.
+    pub fn is_unauthorized(&self) -> bool {
+        self.status() == Some(401)
+    }
+
+    /// Returns `true` for `403 Forbidden` responses.
+    pub fn is_forbidden(&self) -> bool {
+        self.status() == Some(403)
+    }
+
+    /// Returns `true` for `404 Not Found` responses.
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(404)
+    }
+
+    /// Returns `true` for `409 Conflict` responses, e.g. when creating a resource which exists already.
+    pub fn is_conflict(&self) -> bool {
+        self.status() == Some(409)
+    }
+
+    /// Returns `true` for `5xx` responses.
+    pub fn is_server_error(&self) -> bool {
+        self.status()
+            .is_some_and(|status| (500..600).contains(&status))
+    }
+
+    /// Returns `true` if the request failed without response, e.g. because the connection failed.
+    pub fn is_transport(&self) -> bool {
+        match self {
+            #[cfg(any(feature = "reqwest12", feature = "reqwest13"))]
+            KeycloakError::ReqwestFailure(_) => true,
+            KeycloakError::TransportFailure(_) => true,
+            KeycloakError::RequestFailure { source, .. } => source.is_transport(),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if a JSON body could not be deserialized, e.g. the
+    /// response of an Admin API endpoint or of the token endpoint.
+    pub fn is_decode(&self) -> bool {
+        match self {
+            KeycloakError::DecodeFailure { .. } | KeycloakError::JsonFailure(_) => true,
+            KeycloakError::RequestFailure { source, .. } => source.is_decode(),
+            _ => false,
+        }
+    }
+
+    /// Returns the failure wrapped by [`KeycloakError::RequestFailure`],
+    /// other errors unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn doc(admin: keycloak::KeycloakAdmin) {
+    /// use keycloak::KeycloakError;
+    ///
+    /// if let Err(e) = admin.realm_get("test").await {
+    ///     match e.into_inner() {
+    ///         KeycloakError::TokenFailure(e) => println!("no token: {e}"),
+    ///         e => println!("{e}"),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn into_inner(self) -> Self {
+        match self {
+            KeycloakError::RequestFailure { source, .. } => *source,
+            e => e,
+        }
+    }
+
+    /// Returns the body of error responses as [`ErrorRepresentation`], if it is one.
+    ///
+    /// Keycloak answers with an `ErrorRepresentation` e.g. if a user fails
+    /// the validation of the user profile, with nested `errors` if several
+    /// fields are invalid.
+    pub fn error_representation(&self) -> Option<ErrorRepresentation> {
+        let KeycloakError::HttpFailure { text, .. } = self else {
+            return None;
+        };
+        let representation: ErrorRepresentation = serde_json::from_str(text).ok()?;
+        let matches = representation.error_message.is_some()
+            || representation.field.is_some()
+            || representation.errors.is_some();
+        matches.then_some(representation)
+    }
+
+    /// Returns the violations of fields of an [`ErrorRepresentation`] body,
+    /// including the nested errors.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn doc(admin: keycloak::KeycloakAdmin) {
+    /// use keycloak::types::UserRepresentation;
+    ///
+    /// let user = UserRepresentation {
+    ///     username: Some("alice".into()),
+    ///     email: Some("not an email".into()),
+    ///     ..Default::default()
+    /// };
+    /// if let Err(e) = admin.realm_users_post("test", user).await {
+    ///     for violation in e.field_violations() {
+    ///         // e.g. `email: invalidEmailMessage ["email", "not an email"]`
+    ///         println!("{}: {} {:?}", violation.field, violation.message, violation.params);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn field_violations(&self) -> impl Iterator<Item = KeycloakFieldViolation> {
+        let mut violations = Vec::new();
+        let mut pending: Vec<ErrorRepresentation> =
+            self.error_representation().into_iter().collect();
+        while let Some(error) = pending.pop() {
+            if let Some(field) = &error.field {
+                violations.push(KeycloakFieldViolation {
+                    field: field.to_string(),
+                    message: error.error_message.as_deref().unwrap_or_default().into(),
+                    params: error
+                        .params
+                        .iter()
+                        .flat_map(|params| params.iter())
+                        .cloned()
+                        .collect(),
+                });
+            }
+            pending.extend(
+                error
+                    .errors
+                    .iter()
+                    .flat_map(|errors| errors.iter().rev())
+                    .cloned(),
+            );
+        }
+        violations.into_iter()
+    }
+
+    /// Attaches `endpoint` to errors of a request sent to it.
+    pub(crate) fn with_endpoint(self, endpoint: &KeycloakEndpoint) -> Self {
+        match self {
+            KeycloakError::HttpFailure {
+                status,
+                body,
+                text,
+                endpoint: None,
+            } => KeycloakError::HttpFailure {
+                status,
+                body,
+                text,
+                endpoint: Some(Box::new(endpoint.clone())),
+            },
+            e @ (KeycloakError::HttpFailure { .. }
+            | KeycloakError::DecodeFailure { .. }
+            | KeycloakError::RequestFailure { .. }) => e,
+            e => KeycloakError::RequestFailure {
+                endpoint: endpoint.clone(),
+                source: Box::new(e),
+            },
+        }
+    }
+
+    /// Kind of the failure, shown in parentheses by [`Display`].
+    fn kind(&self) -> &'static str {
+        match self {
+            #[cfg(any(feature = "reqwest12", feature = "reqwest13"))]
+            KeycloakError::ReqwestFailure(_) => "network",
+            KeycloakError::HttpFailure { .. } => "rest",
+            KeycloakError::TokenFailure(_) => "token",
+            #[cfg(feature = "jwt")]
+            KeycloakError::JwtFailure(_) => "jwt",
+            KeycloakError::JsonFailure(_) => "json",
+            KeycloakError::InvalidRequest(_) => "request",
+            KeycloakError::TransportFailure(_) => "transport",
+            KeycloakError::DecodeFailure { .. } => "decode",
+            KeycloakError::RequestFailure { source, .. } => source.kind(),
+        }
+    }
+
+    /// Writes the failure shown by [`Display`] after kind and endpoint.
+    fn fmt_message(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(any(feature = "reqwest12", feature = "reqwest13"))]
+            KeycloakError::ReqwestFailure(e) => write!(f, "{e}"),
+            KeycloakError::HttpFailure {
+                status, body, text, ..
+            } => write!(
+                f,
+                "{status} {}",
+                body.as_ref()
+                    .and_then(|e| e.message())
+                    .unwrap_or_else(|| Cow::from(text))
+            ),
+            KeycloakError::TokenFailure(e) => write!(f, "{e}"),
+            #[cfg(feature = "jwt")]
+            KeycloakError::JwtFailure(e) => write!(f, "{e}"),
+            KeycloakError::JsonFailure(e) => write!(f, "{e}"),
+            KeycloakError::InvalidRequest(e) => write!(f, "{e}"),
+            KeycloakError::TransportFailure(e) => write!(f, "{e}"),
+            KeycloakError::DecodeFailure { source, .. } => write!(f, "{source}"),
+            KeycloakError::RequestFailure { source, .. } => source.fmt_message(f),
+        }
+    }
+}
+
+/// Invalid field reported in an [`ErrorRepresentation`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeycloakFieldViolation {
+    /// Name of the field, e.g. `email` or a user profile attribute.
+    pub field: String,
+    /// Message key, e.g. `invalidEmailMessage`, or the message.
+    pub message: String,
+    /// Parameters of the message, usually starting with the field name.
+    pub params: Vec<Value>,
+}
+
+/// Admin API endpoint, the HTTP method and the path with placeholders,
+/// e.g. `GET /admin/realms/{realm}/users/{user-id}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeycloakEndpoint {
+    pub method: Method,
+    pub path: Cow<'static, str>,
+}
+
+impl Display for KeycloakEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.method, self.path)
+    }
+}
+
+#[cfg(any(feature = "reqwest12", feature = "reqwest13"))]
 impl From<reqwest::Error> for KeycloakError {
     fn from(value: reqwest::Error) -> Self {
         KeycloakError::ReqwestFailure(value)
     }
 }
 
-impl Error for KeycloakError {}
+#[cfg(feature = "jwt")]
+impl From<jsonwebtoken::errors::Error> for KeycloakError {
+    fn from(value: jsonwebtoken::errors::Error) -> Self {
+        KeycloakError::JwtFailure(value)
+    }
+}
+
+impl From<serde_json::Error> for KeycloakError {
+    fn from(value: serde_json::Error) -> Self {
+        KeycloakError::JsonFailure(value)
+    }
+}
+
+impl Error for KeycloakError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KeycloakError::DecodeFailure { source, .. } => Some(source),
+            KeycloakError::RequestFailure { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 impl Display for KeycloakError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "keycloak error ({}): ", self.kind())?;
+        if let Some(endpoint) = self.endpoint() {
+            write!(f, "{endpoint}: ")?;
+        }
+        self.fmt_message(f)
+    }
+}
+
+/// Reason why a token can not be used, detected without calling Keycloak.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeycloakTokenError {
+    /// Token is not a well-formed JWT.
+    Malformed(String),
+    /// Token is expired.
+    Expired,
+    /// Token does not carry a required role, `client_id` is `None` for realm roles.
+    MissingRole {
+        client_id: Option<String>,
+        role: String,
+    },
+    /// Token is signed with a key unknown to the realm, `None` if the token has no `kid`.
+    UnknownKey(Option<String>),
+    /// Token was issued to a client (`azp` claim) which is not accepted.
+    UnauthorizedParty(Option<String>),
+}
+
+impl Display for KeycloakTokenError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            KeycloakError::ReqwestFailure(e) => write!(f, "keycloak error (network): {e}"),
-            KeycloakError::HttpFailure { status, body, text } => write!(
-                f,
-                "keycloak error (rest): {status} {}",
-                body.as_ref()
-                    .and_then(|e| e.message())
-                    .unwrap_or_else(|| Cow::from(text))
-            ),
+            KeycloakTokenError::Malformed(reason) => write!(f, "malformed token: {reason}"),
+            KeycloakTokenError::Expired => write!(f, "token expired"),
+            KeycloakTokenError::MissingRole {
+                client_id: Some(client_id),
+                role,
+            } => write!(f, "token has no role {role} of client {client_id}"),
+            KeycloakTokenError::MissingRole {
+                client_id: None,
+                role,
+            } => write!(f, "token has no realm role {role}"),
+            KeycloakTokenError::UnknownKey(Some(kid)) => write!(f, "unknown signing key {kid}"),
+            KeycloakTokenError::UnknownKey(None) => write!(f, "token has no key id"),
+            KeycloakTokenError::UnauthorizedParty(Some(azp)) => {
+                write!(f, "token issued to unauthorized party {azp}")
+            }
+            KeycloakTokenError::UnauthorizedParty(None) => {
+                write!(f, "token has no authorized party")
+            }
         }
     }
 }
@@ -64,3 +410,105 @@ impl KeycloakHttpError {
             .or_else(|| self.error_description.as_deref().map(Cow::from))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{KeycloakEndpoint, KeycloakError, KeycloakFieldViolation};
+
+    #[test]
+    fn field_violations() {
+        let error = KeycloakError::HttpFailure {
+            status: 400,
+            body: None,
+            text: json!({
+                "errors": [
+                    {
+                        "field": "email",
+                        "errorMessage": "invalidEmailMessage",
+                        "params": ["email", "not an email"]
+                    },
+                    {
+                        "field": "firstName",
+                        "errorMessage": "error-user-attribute-required",
+                        "params": ["firstName"]
+                    }
+                ]
+            })
+            .to_string(),
+            endpoint: None,
+        };
+        assert!(error.error_representation().is_some());
+        let violations: Vec<_> = error.field_violations().collect();
+        assert_eq!(
+            violations,
+            [
+                KeycloakFieldViolation {
+                    field: "email".into(),
+                    message: "invalidEmailMessage".into(),
+                    params: vec![json!("email"), json!("not an email")],
+                },
+                KeycloakFieldViolation {
+                    field: "firstName".into(),
+                    message: "error-user-attribute-required".into(),
+                    params: vec![json!("firstName")],
+                },
+            ]
+        );
+
+        let error = KeycloakError::HttpFailure {
+            status: 404,
+            body: None,
+            text: r#"{"error":"User not found"}"#.into(),
+            endpoint: None,
+        };
+        assert!(error.error_representation().is_none());
+        assert_eq!(error.field_violations().count(), 0);
+    }
+
+    #[test]
+    fn request_failure() {
+        let endpoint = KeycloakEndpoint {
+            method: http::Method::GET,
+            path: "/admin/realms/{realm}/users".into(),
+        };
+        let error =
+            KeycloakError::TransportFailure("connection refused".into()).with_endpoint(&endpoint);
+        assert_eq!(
+            error.to_string(),
+            "keycloak error (transport): GET /admin/realms/{realm}/users: connection refused"
+        );
+        assert!(error.is_transport());
+        assert!(!error.is_decode());
+        assert_eq!(error.endpoint(), Some(&endpoint));
+        assert!(matches!(
+            error.into_inner(),
+            KeycloakError::TransportFailure(_)
+        ));
+
+        let json = serde_json::from_str::<u32>("").unwrap_err();
+        let error = KeycloakError::JsonFailure(json).with_endpoint(&endpoint);
+        assert!(error.is_decode());
+        assert!(!error.is_transport());
+        assert!(error.to_string().starts_with(
+            "keycloak error (json): GET /admin/realms/{realm}/users: EOF while parsing"
+        ));
+
+        let error = KeycloakError::HttpFailure {
+            status: 404,
+            body: None,
+            text: "Not Found".into(),
+            endpoint: None,
+        }
+        .with_endpoint(&endpoint);
+        assert_eq!(
+            error.to_string(),
+            "keycloak error (rest): GET /admin/realms/{realm}/users: 404 Not Found"
+        );
+        assert!(matches!(
+            error.into_inner(),
+            KeycloakError::HttpFailure { .. }
+        ));
+    }
+}
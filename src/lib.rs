@@ -16,12 +16,19 @@ Default flags: `tags-all`, `resource-builder`, `reqwest`.
 - `rc`: use `Arc` for deserialization.
 - `schemars`: add [schemars](https://crates.io/crates/schemars) support.
 - `multipart`: add multipart support to reqwest, enabling extra methods in API.
+- `jwt`: add signed JWT client authentication (`private_key_jwt`, `client_secret_jwt`) and JWKS-based access token verification with [jsonwebtoken](https://crates.io/crates/jsonwebtoken).
+- `blocking`: add synchronous `blocking::KeycloakAdmin` client, driving the async one on an internal [tokio](https://crates.io/crates/tokio) runtime.
+- `tracing`: add [tracing](https://crates.io/crates/tracing) spans to Admin API calls and token acquisition, recording method, route, realm, status code, latency and error message (never secrets or bodies).
+- `mock-server`: add `mock_server::KeycloakMockServer`, a local in-memory fake of the Admin API (realms, users, groups, roles, role mappings, clients, client scopes and the token endpoint) for hermetic tests.
+- `reconcile`: add `reconcile::KeycloakReconciler`, computing a dry-run plan of create / update / delete operations from a desired-state `RealmRepresentation` (clients, roles, composites, groups, client scopes, protocol mappers, identity providers, authentication flows and required actions) and applying it in dependency order.
 - `tags-all`: activate all tags (resource groups) in REST API, it is default behavior. Disable default features and use individual `tag-xxx` features to activate only required resource groups. For a full list reference the [Cargo.toml](Cargo.toml).
-- `resource-builder`: add resource builder support.
+- `resource-builder`: add resource builder support, including auto-paginating `pages()`, `stream()` and `collect_all()` for list methods with `first` / `max` arguments.
 - `reqwest`: use up to date [reqwest](https://crates.io/crates/reqwest) version (`reqwest 0.13.x`).
 - `reqwest12`: use `reqwest 0.12.x`.
 - `reqwest13`: use `reqwest 0.13.x`.
 
+Without any of the reqwest features, pass an own `KeycloakTransport` implementation wherever a `client` is expected.
+
 ## Usage
 
 Requires Rust version >= `1.87.0`.
@@ -101,23 +108,45 @@ Example: official version `13.0.1` is `13.0.100` for crate version. `13.0.102` m
 To update current version use provided [update.ts](./update.ts) `deno` script:
 
 ```sh
-deno run --allow-env=KEYCLOAK_RUST_VERSION,KEYCLOAK_VERSION,KEYCLOAK_RUST_MAJOR_VERSION --allow-read=Cargo.toml --allow-write=Cargo.toml,api/openapi.json,src/types.rs,src/rest/generated_rest,src/resource --allow-net=keycloak.org,www.keycloak.org --allow-run=cargo,gh,git,handlebars-magic update.ts
+deno run --allow-env=KEYCLOAK_RUST_VERSION,KEYCLOAK_VERSION,KEYCLOAK_RUST_MAJOR_VERSION --allow-read=Cargo.toml --allow-write=Cargo.toml,api/openapi.json,src/types.rs,src/rest/generated_rest,src/resource,src/blocking/generated_blocking --allow-net=keycloak.org,www.keycloak.org --allow-run=cargo,gh,git,handlebars-magic update.ts
 ```
 
 */
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 #[cfg(feature = "builder")]
 pub mod builder;
+#[cfg(feature = "mock-server")]
+pub mod mock_server;
+#[cfg(feature = "reconcile")]
+pub mod reconcile;
 #[cfg(feature = "resource")]
 pub mod resource;
 pub mod types;
 
+mod access_token;
 mod error;
+#[cfg(feature = "resource")]
+mod paged;
 pub mod prelude;
 mod rest;
+#[cfg(test)]
+mod test_util;
 
-pub use error::KeycloakError;
+pub use error::{KeycloakEndpoint, KeycloakError, KeycloakFieldViolation, KeycloakTokenError};
+#[cfg(feature = "resource")]
+pub use paged::KeycloakRealmAdminPagedMethod;
+#[cfg(feature = "jwt")]
+pub use rest::KeycloakTokenVerifier;
 pub use rest::{
-    DefaultResponse, KeycloakAdmin, KeycloakAdminToken, KeycloakRealmAdmin,
-    KeycloakRealmAdminMethod, KeycloakServiceAccountAdminTokenRetriever, KeycloakTokenSupplier,
+    DefaultResponse, KeycloakAdmin, KeycloakAdminRequest, KeycloakAdminToken, KeycloakCassette,
+    KeycloakClientAuth, KeycloakDeviceAuthorization, KeycloakDeviceFlow, KeycloakEnsureAction,
+    KeycloakEnsured, KeycloakInteraction, KeycloakLimiter, KeycloakMiddleware,
+    KeycloakMultipartPart, KeycloakNext, KeycloakOpenIdConfiguration,
+    KeycloakPasswordAdminTokenRetriever, KeycloakRealmAdmin, KeycloakRealmAdminMethod,
+    KeycloakRecordedRequest, KeycloakRecordedResponse, KeycloakRecorder, KeycloakReplay,
+    KeycloakRequest, KeycloakRequestBody, KeycloakResponse, KeycloakRetryPolicy,
+    KeycloakServerLocation, KeycloakServiceAccountAdminTokenRetriever,
+    KeycloakTokenExchangeRetriever, KeycloakTokenSupplier, KeycloakTransport,
 };
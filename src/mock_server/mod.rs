@@ -0,0 +1,542 @@
+//! In-memory fake of the Keycloak Admin API for tests.
+//!
+//! [`KeycloakMockServer`] emulates a coherent subset of the Admin API:
+//!
+//! - realms
+//! - users, their groups and role mappings
+//! - groups with subgroups, their members and role mappings
+//! - realm roles, client roles and composite roles
+//! - clients with their default and optional client scopes
+//! - client scopes
+//! - the token endpoint with `password`, `client_credentials` and `refresh_token` grants
+//!
+//! Resources get generated ids, creation responds with `201 Created` and a
+//! `Location` header, missing resources with `404 Not Found` and duplicates
+//! with `409 Conflict`, like Keycloak does. Admin API calls require a token
+//! issued by the token endpoint.
+//!
+//! Nested resources of realm representations (e.g. `users` or `clients`) are
+//! ignored on realm creation, create them with their own endpoints.
+//!
+//! The listening server speaks plain HTTP/1.1 with keep-alive, request bodies
+//! are read according to `Content-Length` or `Transfer-Encoding: chunked`.
+
+mod state;
+
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use http::{
+    header::{CONNECTION, CONTENT_LENGTH, TRANSFER_ENCODING},
+    HeaderMap, HeaderName, HeaderValue,
+};
+use tokio::{
+    io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    task::{JoinHandle, JoinSet},
+};
+
+use crate::{
+    KeycloakError, KeycloakRequest, KeycloakRequestBody, KeycloakResponse, KeycloakTransport,
+};
+use state::{MockRequest, MockState};
+
+/// Local HTTP server emulating Keycloak, see the [module](self) documentation.
+///
+/// The server listens on a random port of `127.0.0.1` until it is dropped.
+/// The `master` realm contains the admin user with username and password `admin`.
+///
+/// The server is a [`KeycloakTransport`] too, serving requests in-process
+/// regardless of the host of their URL.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+/// use keycloak::{
+///     mock_server::KeycloakMockServer, prelude::reqwest, types::*, KeycloakAdmin,
+///     KeycloakAdminToken,
+/// };
+///
+/// let server = KeycloakMockServer::start().await?;
+/// let client = reqwest::Client::new();
+/// let admin_token = KeycloakAdminToken::acquire(server.url(), "admin", "admin", &client).await?;
+/// let admin = KeycloakAdmin::new(server.url(), admin_token, client);
+///
+/// admin
+///     .post(RealmRepresentation {
+///         realm: Some("test".into()),
+///         ..Default::default()
+///     })
+///     .await?;
+/// # Ok(()) }
+/// ```
+pub struct KeycloakMockServer {
+    url: String,
+    state: Arc<Mutex<MockState>>,
+    server: Option<JoinHandle<()>>,
+}
+
+impl KeycloakMockServer {
+    /// Default username of the admin user.
+    pub const ADMIN_USERNAME: &'static str = "admin";
+    /// Default password of the admin user.
+    pub const ADMIN_PASSWORD: &'static str = "admin";
+
+    /// Starts a server with the default admin user.
+    pub async fn start() -> io::Result<Self> {
+        Self::start_with_admin(Self::ADMIN_USERNAME, Self::ADMIN_PASSWORD).await
+    }
+
+    /// Starts a server with the admin user `username` in the `master` realm.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidInput`] if `username` is not a valid
+    /// username, e.g. if it is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Username of the admin user.
+    /// * `password` - Password of the admin user for the `password` grant.
+    pub async fn start_with_admin(username: &str, password: &str) -> io::Result<Self> {
+        let state = MockState::new(username, password)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let state = Arc::new(Mutex::new(state));
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let server = tokio::spawn(serve(listener, url.clone(), state.clone()));
+        Ok(Self {
+            url,
+            state,
+            server: Some(server),
+        })
+    }
+
+    /// Creates an in-process server without listening socket, usable as [`KeycloakTransport`] only.
+    pub fn in_process() -> Self {
+        Self {
+            url: "http://keycloak.mock".into(),
+            state: Arc::new(Mutex::new(
+                MockState::new(Self::ADMIN_USERNAME, Self::ADMIN_PASSWORD)
+                    .expect("default admin user is valid"),
+            )),
+            server: None,
+        }
+    }
+
+    /// Returns the base URL of the server, e.g. `http://127.0.0.1:35367`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+#[cfg(all(test, feature = "tag-realms-admin"))]
+impl KeycloakMockServer {
+    /// Admin client of an in-process server, authenticated as the admin user,
+    /// with the realm `realm` created.
+    pub(crate) async fn admin_with_realm(realm: &str) -> crate::KeycloakAdmin {
+        let server = Self::in_process();
+        let url = server.url().to_string();
+        let token = crate::KeycloakAdminToken::acquire(
+            &url,
+            Self::ADMIN_USERNAME,
+            Self::ADMIN_PASSWORD,
+            &server,
+        )
+        .await
+        .unwrap();
+        let admin = crate::KeycloakAdmin::new(&url, token, server);
+        admin
+            .post(crate::types::RealmRepresentation {
+                realm: Some(realm.into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        admin
+    }
+}
+
+impl Drop for KeycloakMockServer {
+    /// Stops the server, closing the open connections.
+    fn drop(&mut self) {
+        if let Some(server) = &self.server {
+            server.abort();
+        }
+    }
+}
+
+#[async_trait]
+impl KeycloakTransport for KeycloakMockServer {
+    async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+        let url = request.url();
+        let (url, url_query) = url.split_once('?').unwrap_or((url, ""));
+        let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+        let (base_url, path) = match without_scheme.find('/') {
+            Some(start) => url.split_at(url.len() - without_scheme.len() + start),
+            None => (url, "/"),
+        };
+        let mut query: Vec<(String, String)> = form_urlencoded::parse(url_query.as_bytes())
+            .into_owned()
+            .collect();
+        query.extend_from_slice(request.query_pairs());
+        let body = match request.body() {
+            KeycloakRequestBody::Json(json) => json.clone(),
+            KeycloakRequestBody::Form(text) | KeycloakRequestBody::Text(text) => {
+                text.clone().into_bytes()
+            }
+            KeycloakRequestBody::Empty | KeycloakRequestBody::Multipart(_) => Vec::new(),
+        };
+        let response = self.state.lock().expect("mock state lock poisoned").handle(
+            base_url,
+            MockRequest {
+                method: request.method().as_str(),
+                path,
+                query: &query,
+                headers: request.headers(),
+                body: &body,
+            },
+        );
+        Ok(response)
+    }
+}
+
+async fn serve(listener: TcpListener, url: String, state: Arc<Mutex<MockState>>) {
+    // aborting the server drops the set, which aborts the connections
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let Ok((stream, _)) = accepted else {
+                    return;
+                };
+                let url = url.clone();
+                let state = state.clone();
+                connections.spawn(async move {
+                    // errors only close the connection
+                    let _ = connection(stream, &url, &state).await;
+                });
+            }
+            Some(_) = connections.join_next() => {}
+        }
+    }
+}
+
+/// Serves the HTTP/1.1 requests of a connection until the client closes it.
+async fn connection(stream: TcpStream, url: &str, state: &Mutex<MockState>) -> io::Result<()> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message);
+    let mut stream = BufReader::new(stream);
+    loop {
+        let mut line = String::new();
+        if stream.read_line(&mut line).await? == 0 {
+            return Ok(());
+        }
+        let mut request_line = line.split_whitespace();
+        let (Some(method), Some(target)) = (request_line.next(), request_line.next()) else {
+            return Err(invalid("invalid request line"));
+        };
+        let (method, target) = (method.to_string(), target.to_string());
+        let mut headers = HeaderMap::new();
+        loop {
+            line.clear();
+            stream.read_line(&mut line).await?;
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            let (name, value) = header
+                .split_once(':')
+                .ok_or_else(|| invalid("invalid header"))?;
+            headers.append(
+                HeaderName::try_from(name.trim()).map_err(|_| invalid("invalid header name"))?,
+                HeaderValue::try_from(value.trim()).map_err(|_| invalid("invalid header value"))?,
+            );
+        }
+        let chunked = headers
+            .get(TRANSFER_ENCODING)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.to_ascii_lowercase().ends_with("chunked"));
+        let body = if chunked {
+            chunked_body(&mut stream).await?
+        } else {
+            let length = headers
+                .get(CONTENT_LENGTH)
+                .and_then(|length| length.to_str().ok()?.parse().ok())
+                .unwrap_or(0);
+            let mut body = vec![0; length];
+            stream.read_exact(&mut body).await?;
+            body
+        };
+
+        let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+        let query: Vec<(String, String)> = form_urlencoded::parse(query.as_bytes())
+            .into_owned()
+            .collect();
+        let response = state.lock().expect("mock state lock poisoned").handle(
+            url,
+            MockRequest {
+                method: &method,
+                path,
+                query: &query,
+                headers: &headers,
+                body: &body,
+            },
+        );
+
+        let status = response.status();
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\ncontent-length: {}\r\n",
+            status.as_str(),
+            status.canonical_reason().unwrap_or_default(),
+            response.body().len()
+        );
+        for (name, value) in response.headers() {
+            head.push_str(&format!(
+                "{name}: {}\r\n",
+                value.to_str().unwrap_or_default()
+            ));
+        }
+        head.push_str("\r\n");
+        let stream = stream.get_mut();
+        stream.write_all(head.as_bytes()).await?;
+        stream.write_all(response.body()).await?;
+        stream.flush().await?;
+        if headers
+            .get(CONNECTION)
+            .is_some_and(|value| value == "close")
+        {
+            return Ok(());
+        }
+    }
+}
+
+/// Reads a body with `Transfer-Encoding: chunked`, ignoring chunk extensions and trailers.
+async fn chunked_body(stream: &mut (impl AsyncBufRead + Unpin)) -> io::Result<Vec<u8>> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "invalid chunk size");
+    let mut body = Vec::new();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        stream.read_line(&mut line).await?;
+        let size = line.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size, 16).map_err(|_| invalid())?;
+        if size == 0 {
+            break;
+        }
+        let start = body.len();
+        body.resize(start + size, 0);
+        stream.read_exact(&mut body[start..]).await?;
+        line.clear();
+        stream.read_line(&mut line).await?;
+    }
+    loop {
+        line.clear();
+        if stream.read_line(&mut line).await? == 0 || line.trim_end().is_empty() {
+            return Ok(body);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+    use serde_json::{json, Value};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpStream,
+    };
+
+    use super::KeycloakMockServer;
+    use crate::{types::*, KeycloakAdmin, KeycloakAdminToken};
+
+    #[tokio::test]
+    async fn provisioning() {
+        let server = KeycloakMockServer::start().await.unwrap();
+
+        let mut stream = TcpStream::connect(server.url().trim_start_matches("http://"))
+            .await
+            .unwrap();
+        stream
+            .write_all(b"GET /admin/realms HTTP/1.1\r\nhost: keycloak\r\nconnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 401 Unauthorized\r\n"));
+
+        let token = KeycloakAdminToken::acquire("http://keycloak.mock", "admin", "admin", &server)
+            .await
+            .unwrap();
+        let admin = KeycloakAdmin::new("http://keycloak.mock", token, server);
+        admin
+            .post(RealmRepresentation {
+                realm: Some("test".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let realm = admin.realm("test");
+
+        let parent = admin
+            .realm_groups_post(
+                "test",
+                GroupRepresentation {
+                    name: Some("parent".into()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        let parent = parent.to_id().unwrap();
+        admin
+            .realm_groups_with_group_id_children_post(
+                "test",
+                parent,
+                GroupRepresentation {
+                    name: Some("child".into()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        let child: Value = realm
+            .request(
+                Method::GET,
+                "/admin/realms/{realm}/group-by-path/parent/child",
+            )
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(child["path"], "/parent/child");
+
+        let user = UserRepresentation {
+            username: Some("Alice".into()),
+            groups: Some(vec!["/parent/child".into()].into()),
+            ..Default::default()
+        };
+        let user_id = admin.realm_users_post("test", user.clone()).await.unwrap();
+        let user_id = user_id.to_id().unwrap();
+        let error = admin.realm_users_post("test", user).await.err().unwrap();
+        assert!(error.is_conflict());
+        assert_eq!(
+            error.endpoint().unwrap().to_string(),
+            "POST /admin/realms/{realm}/users"
+        );
+
+        admin
+            .realm_roles_post(
+                "test",
+                RoleRepresentation {
+                    name: Some("reader".into()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        realm
+            .request(
+                Method::POST,
+                "/admin/realms/{realm}/users/{id}/role-mappings/realm",
+            )
+            .path_param("id", user_id)
+            .json(&json!([{ "name": "reader" }]))
+            .send_default()
+            .await
+            .unwrap();
+        let mappings: Value = realm
+            .request(
+                Method::GET,
+                "/admin/realms/{realm}/users/{id}/role-mappings",
+            )
+            .path_param("id", user_id)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(mappings["realmMappings"][0]["name"], "reader");
+
+        let groups: Value = realm
+            .request(Method::GET, "/admin/realms/{realm}/users/{id}/groups")
+            .path_param("id", user_id)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(groups[0]["id"], child["id"]);
+
+        realm
+            .request(Method::DELETE, "/admin/realms/{realm}/groups/{id}")
+            .path_param("id", parent)
+            .send_default()
+            .await
+            .unwrap();
+        let groups: Value = realm
+            .request(Method::GET, "/admin/realms/{realm}/users/{id}/groups")
+            .path_param("id", user_id)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(groups, json!([]));
+    }
+
+    #[tokio::test]
+    async fn invalid_admin() {
+        let error = KeycloakMockServer::start_with_admin("", "admin")
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn chunked_body() {
+        let server = KeycloakMockServer::start().await.unwrap();
+
+        let mut stream = TcpStream::connect(server.url().trim_start_matches("http://"))
+            .await
+            .unwrap();
+        stream
+            .write_all(
+                b"POST /realms/master/protocol/openid-connect/token HTTP/1.1\r\n\
+                host: keycloak\r\n\
+                content-type: application/x-www-form-urlencoded\r\n\
+                transfer-encoding: chunked\r\n\
+                connection: close\r\n\r\n\
+                13;name=value\r\ngrant_type=password\r\n\
+                2d\r\n&client_id=admin-cli&username=admin&password=\r\n\
+                5\r\nadmin\r\n\
+                0\r\ntrailer: ignored\r\n\r\n",
+            )
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+        assert!(response.contains("access_token"));
+    }
+
+    #[tokio::test]
+    async fn drop_closes_connections() {
+        let server = KeycloakMockServer::start().await.unwrap();
+
+        let mut stream = TcpStream::connect(server.url().trim_start_matches("http://"))
+            .await
+            .unwrap();
+        stream
+            .write_all(b"GET /admin/realms HTTP/1.1\r\nhost: keycloak\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = [0; 1024];
+        assert!(stream.read(&mut response).await.unwrap() > 0);
+
+        drop(server);
+        let mut rest = Vec::new();
+        tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            stream.read_to_end(&mut rest),
+        )
+        .await
+        .expect("connection is closed")
+        .unwrap();
+    }
+}
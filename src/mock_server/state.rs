@@ -0,0 +1,1317 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use http::{
+    header::{AUTHORIZATION, CONTENT_TYPE, LOCATION},
+    HeaderMap, HeaderValue, StatusCode,
+};
+use percent_encoding::percent_decode_str;
+use serde_json::{json, Map, Value};
+
+use crate::{rest::url_enc::encode_url_param as p, KeycloakResponse};
+
+type Object = Map<String, Value>;
+
+/// Lifetime of issued access tokens in seconds.
+const EXPIRES_IN: u64 = 300;
+
+/// Request received by the mock server.
+pub(super) struct MockRequest<'a> {
+    pub method: &'a str,
+    /// Percent-encoded path of the URL.
+    pub path: &'a str,
+    pub query: &'a [(String, String)],
+    pub headers: &'a HeaderMap,
+    pub body: &'a [u8],
+}
+
+enum Reply {
+    Json(Value),
+    /// `201 Created` with the URL of the created resource.
+    Created(String),
+    NoContent,
+}
+
+/// Error response.
+struct Failure {
+    status: StatusCode,
+    body: Value,
+}
+
+impl Failure {
+    fn not_found(what: &str) -> Self {
+        Self {
+            status: StatusCode::NOT_FOUND,
+            body: json!({ "error": format!("{what} not found") }),
+        }
+    }
+
+    fn no_route() -> Self {
+        Self {
+            status: StatusCode::NOT_FOUND,
+            body: json!({ "error": "Unable to find matching target resource method" }),
+        }
+    }
+
+    fn conflict(message: String) -> Self {
+        Self {
+            status: StatusCode::CONFLICT,
+            body: json!({ "errorMessage": message }),
+        }
+    }
+
+    fn bad_request(message: &str) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            body: json!({ "errorMessage": message }),
+        }
+    }
+
+    fn oauth(status: StatusCode, error: &str, description: &str) -> Self {
+        Self {
+            status,
+            body: json!({ "error": error, "error_description": description }),
+        }
+    }
+}
+
+/// Generates ids looking like the UUIDs of Keycloak.
+#[derive(Default)]
+struct Ids(u64);
+
+impl Ids {
+    fn next(&mut self) -> String {
+        self.0 += 1;
+        format!("00000000-0000-4000-8000-{:012x}", self.0)
+    }
+}
+
+/// State of the mock server: realms, their resources and issued tokens.
+pub(super) struct MockState {
+    admin_username: String,
+    admin_password: String,
+    ids: Ids,
+    access_tokens: HashSet<String>,
+    refresh_tokens: HashSet<String>,
+    realms: Vec<Realm>,
+}
+
+impl MockState {
+    /// Creates the `master` realm with the admin user, fails if the username is invalid.
+    pub(super) fn new(admin_username: &str, admin_password: &str) -> Result<Self, String> {
+        let mut ids = Ids::default();
+        let mut master = Realm::new(ids.next(), Object::new());
+        master.representation.extend([
+            ("realm".into(), "master".into()),
+            ("enabled".into(), true.into()),
+        ]);
+        let mut admin = Object::new();
+        admin.insert("username".into(), admin_username.into());
+        admin.insert("enabled".into(), true.into());
+        master.create_user(&mut ids, admin).map_err(|failure| {
+            format!(
+                "can not create admin user {admin_username}: {}",
+                failure.body
+            )
+        })?;
+        Ok(Self {
+            admin_username: admin_username.into(),
+            admin_password: admin_password.into(),
+            ids,
+            access_tokens: HashSet::new(),
+            refresh_tokens: HashSet::new(),
+            realms: vec![master],
+        })
+    }
+
+    /// Handles `request`, `base_url` is the URL of the server used in `Location` headers.
+    pub(super) fn handle(&mut self, base_url: &str, request: MockRequest) -> KeycloakResponse {
+        let segments: Vec<String> = request
+            .path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| percent_decode_str(segment).decode_utf8_lossy().into_owned())
+            .collect();
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+        let mut headers = HeaderMap::new();
+        let (status, body) = match self.route(base_url, &request, &segments) {
+            Ok(Reply::Json(body)) => (StatusCode::OK, Some(body)),
+            Ok(Reply::Created(location)) => {
+                if let Ok(location) = HeaderValue::try_from(location) {
+                    headers.insert(LOCATION, location);
+                }
+                (StatusCode::CREATED, None)
+            }
+            Ok(Reply::NoContent) => (StatusCode::NO_CONTENT, None),
+            Err(failure) => (failure.status, Some(failure.body)),
+        };
+        let body = match body {
+            Some(body) => {
+                headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                body.to_string().into_bytes()
+            }
+            None => Vec::new(),
+        };
+        KeycloakResponse::new(status, headers, body)
+    }
+
+    fn route(
+        &mut self,
+        base_url: &str,
+        request: &MockRequest,
+        segments: &[&str],
+    ) -> Result<Reply, Failure> {
+        match (request.method, segments) {
+            ("POST", ["realms", realm, "protocol", "openid-connect", "token"]) => {
+                self.token(realm, request)
+            }
+            (_, ["admin", "realms", path @ ..]) => {
+                self.authorize(request.headers)?;
+                self.admin(base_url, request, path)
+            }
+            _ => Err(Failure::no_route()),
+        }
+    }
+
+    fn authorize(&self, headers: &HeaderMap) -> Result<(), Failure> {
+        let token = headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        match token {
+            Some(token) if self.access_tokens.contains(token) => Ok(()),
+            _ => Err(Failure {
+                status: StatusCode::UNAUTHORIZED,
+                body: json!({ "error": "HTTP 401 Unauthorized" }),
+            }),
+        }
+    }
+
+    fn token(&mut self, realm: &str, request: &MockRequest) -> Result<Reply, Failure> {
+        let realm = self
+            .realms
+            .iter()
+            .find(|candidate| candidate.name() == realm)
+            .ok_or_else(|| {
+                Failure::oauth(
+                    StatusCode::NOT_FOUND,
+                    "Realm does not exist",
+                    "Realm does not exist",
+                )
+            })?;
+        let form: Vec<(String, String)> =
+            form_urlencoded::parse(request.body).into_owned().collect();
+        let param = |name: &str| {
+            form.iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+        };
+        let basic = request
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Basic "))
+            .and_then(|value| STANDARD.decode(value).ok())
+            .and_then(|value| String::from_utf8(value).ok());
+        let (client_id, client_secret) =
+            match basic.as_deref().and_then(|basic| basic.split_once(':')) {
+                Some((client_id, client_secret)) => {
+                    (Some(client_id.to_string()), Some(client_secret.to_string()))
+                }
+                None => (param("client_id"), param("client_secret")),
+            };
+        let refresh = match param("grant_type").as_deref() {
+            Some("password") => {
+                if realm.name() != "master"
+                    || param("username").as_deref() != Some(&self.admin_username)
+                    || param("password").as_deref() != Some(&self.admin_password)
+                {
+                    return Err(Failure::oauth(
+                        StatusCode::UNAUTHORIZED,
+                        "invalid_grant",
+                        "Invalid user credentials",
+                    ));
+                }
+                true
+            }
+            Some("client_credentials") => {
+                let authorized = realm.clients.iter().any(|client| {
+                    client_id.is_some()
+                        && str_field(client, "clientId") == client_id.as_deref()
+                        && str_field(client, "secret") == client_secret.as_deref()
+                        && client.get("serviceAccountsEnabled") == Some(&Value::Bool(true))
+                });
+                if !authorized {
+                    return Err(Failure::oauth(
+                        StatusCode::UNAUTHORIZED,
+                        "unauthorized_client",
+                        "Invalid client or Invalid client credentials",
+                    ));
+                }
+                false
+            }
+            Some("refresh_token") => {
+                let refresh_token = param("refresh_token").unwrap_or_default();
+                if !self.refresh_tokens.contains(&refresh_token) {
+                    return Err(Failure::oauth(
+                        StatusCode::BAD_REQUEST,
+                        "invalid_grant",
+                        "Invalid refresh token",
+                    ));
+                }
+                true
+            }
+            _ => {
+                return Err(Failure::oauth(
+                    StatusCode::BAD_REQUEST,
+                    "unsupported_grant_type",
+                    "Unsupported grant_type",
+                ))
+            }
+        };
+        let access_token = format!("mock-access-token-{}", self.ids.next());
+        self.access_tokens.insert(access_token.clone());
+        let mut token = json!({
+            "access_token": access_token,
+            "expires_in": EXPIRES_IN,
+            "token_type": "Bearer",
+            "not-before-policy": 0,
+            "scope": "profile email",
+        });
+        if refresh {
+            let refresh_token = format!("mock-refresh-token-{}", self.ids.next());
+            self.refresh_tokens.insert(refresh_token.clone());
+            token["refresh_token"] = refresh_token.into();
+            token["refresh_expires_in"] = 1800.into();
+            token["session_state"] = self.ids.next().into();
+        }
+        Ok(Reply::Json(token))
+    }
+
+    fn admin(
+        &mut self,
+        base_url: &str,
+        request: &MockRequest,
+        path: &[&str],
+    ) -> Result<Reply, Failure> {
+        let query = Query(request.query);
+        match (request.method, path) {
+            ("GET", []) => Ok(Reply::Json(
+                self.realms
+                    .iter()
+                    .map(|realm| Value::Object(realm.representation.clone()))
+                    .collect(),
+            )),
+            ("POST", []) => {
+                let mut representation = object_body(request.body)?;
+                let name = str_field(&representation, "realm")
+                    .filter(|name| !name.is_empty())
+                    .ok_or_else(|| Failure::bad_request("Realm name cannot be empty"))?
+                    .to_string();
+                if self.realms.iter().any(|realm| realm.name() == name) {
+                    return Err(Failure::conflict(
+                        "Conflict detected. See logs for details".into(),
+                    ));
+                }
+                for nested in ["users", "groups", "roles", "clients", "clientScopes"] {
+                    representation.remove(nested);
+                }
+                let id = match str_field(&representation, "id") {
+                    Some(id) => id.to_string(),
+                    None => self.ids.next(),
+                };
+                self.realms.push(Realm::new(id, representation));
+                Ok(Reply::Created(format!(
+                    "{base_url}/admin/realms/{}",
+                    p(&name)
+                )))
+            }
+            (method, [name, path @ ..]) => {
+                let index = self
+                    .realms
+                    .iter()
+                    .position(|realm| realm.name() == *name)
+                    .ok_or_else(|| Failure::not_found("Realm"))?;
+                match (method, path) {
+                    ("GET", []) => Ok(Reply::Json(Value::Object(
+                        self.realms[index].representation.clone(),
+                    ))),
+                    ("PUT", []) => {
+                        let update = object_body(request.body)?;
+                        if let Some(rename) = str_field(&update, "realm") {
+                            if rename != *name
+                                && self.realms.iter().any(|realm| realm.name() == rename)
+                            {
+                                return Err(Failure::conflict(format!(
+                                    "Realm {rename} already exists"
+                                )));
+                            }
+                        }
+                        merge(&mut self.realms[index].representation, update);
+                        Ok(Reply::NoContent)
+                    }
+                    ("DELETE", []) => {
+                        self.realms.remove(index);
+                        Ok(Reply::NoContent)
+                    }
+                    _ => {
+                        let location = format!("{base_url}/admin/realms/{}", p(name));
+                        let MockState { ids, realms, .. } = self;
+                        realms[index].route(
+                            ids,
+                            &location,
+                            request.method,
+                            path,
+                            &query,
+                            request.body,
+                        )
+                    }
+                }
+            }
+            _ => Err(Failure::no_route()),
+        }
+    }
+}
+
+/// Resources of a realm. Groups, roles and clients are stored flat with
+/// references by id, relations between resources are stored as pairs of ids.
+struct Realm {
+    representation: Object,
+    users: Vec<Object>,
+    /// Groups with `parentId` of subgroups.
+    groups: Vec<Object>,
+    /// Realm and client roles with `containerId` of the realm or client.
+    roles: Vec<Object>,
+    clients: Vec<Object>,
+    client_scopes: Vec<Object>,
+    /// Pairs of user id and group id.
+    memberships: BTreeSet<(String, String)>,
+    /// Pairs of user or group id and role id.
+    role_mappings: BTreeSet<(String, String)>,
+    /// Pairs of composite role id and role id.
+    composites: BTreeSet<(String, String)>,
+    /// Pairs of client id and client scope id.
+    default_client_scopes: BTreeSet<(String, String)>,
+    optional_client_scopes: BTreeSet<(String, String)>,
+}
+
+impl Realm {
+    fn new(id: String, mut representation: Object) -> Self {
+        representation.insert("id".into(), id.into());
+        Self {
+            representation,
+            users: Vec::new(),
+            groups: Vec::new(),
+            roles: Vec::new(),
+            clients: Vec::new(),
+            client_scopes: Vec::new(),
+            memberships: BTreeSet::new(),
+            role_mappings: BTreeSet::new(),
+            composites: BTreeSet::new(),
+            default_client_scopes: BTreeSet::new(),
+            optional_client_scopes: BTreeSet::new(),
+        }
+    }
+
+    fn name(&self) -> &str {
+        str_field(&self.representation, "realm").unwrap_or_default()
+    }
+
+    fn id(&self) -> String {
+        str_field(&self.representation, "id")
+            .unwrap_or_default()
+            .into()
+    }
+
+    fn route(
+        &mut self,
+        ids: &mut Ids,
+        location: &str,
+        method: &str,
+        path: &[&str],
+        query: &Query,
+        body: &[u8],
+    ) -> Result<Reply, Failure> {
+        match (method, path) {
+            ("GET", ["users"]) => Ok(Reply::Json(query.page(self.find_users(query)).collect())),
+            ("POST", ["users"]) => {
+                let id = self.create_user(ids, object_body(body)?)?;
+                Ok(Reply::Created(format!("{location}/users/{id}")))
+            }
+            ("GET", ["users", "count"]) => Ok(Reply::Json(self.find_users(query).count().into())),
+            ("GET", ["users", id]) => Ok(Reply::Json(Value::Object(self.user(id)?.clone()))),
+            ("PUT", ["users", id]) => self.update_user(id, object_body(body)?),
+            ("DELETE", ["users", id]) => {
+                self.user(id)?;
+                self.users.retain(|user| str_field(user, "id") != Some(id));
+                self.memberships.retain(|(user, _)| user != id);
+                self.role_mappings.retain(|(holder, _)| holder != id);
+                Ok(Reply::NoContent)
+            }
+            ("GET", ["users", id, "groups"]) => {
+                self.user(id)?;
+                let groups = self
+                    .memberships
+                    .iter()
+                    .filter(|(user, _)| user == id)
+                    .filter_map(|(_, group)| self.group(group).ok())
+                    .map(|group| self.group_json(group));
+                Ok(Reply::Json(query.page(groups).collect()))
+            }
+            ("PUT" | "DELETE", ["users", id, "groups", group]) => {
+                self.user(id)?;
+                self.group(group)?;
+                let membership = (id.to_string(), group.to_string());
+                if method == "PUT" {
+                    self.memberships.insert(membership);
+                } else {
+                    self.memberships.remove(&membership);
+                }
+                Ok(Reply::NoContent)
+            }
+            (_, ["users", id, "role-mappings", path @ ..]) => {
+                self.user(id)?;
+                self.role_mappings(id, method, path, body)
+            }
+            ("GET", ["groups"]) => {
+                let search = query.get("search").map(str::to_lowercase);
+                let groups = self
+                    .groups
+                    .iter()
+                    .filter(|group| !group.contains_key("parentId"))
+                    .filter(|group| match &search {
+                        Some(search) => contains(str_field(group, "name"), search),
+                        None => true,
+                    })
+                    .map(|group| self.group_json(group));
+                Ok(Reply::Json(query.page(groups).collect()))
+            }
+            ("POST", ["groups"]) => self.create_group(ids, location, None, object_body(body)?),
+            ("GET", ["groups", "count"]) => {
+                let count = self
+                    .groups
+                    .iter()
+                    .filter(|group| !group.contains_key("parentId"))
+                    .count();
+                Ok(Reply::Json(json!({ "count": count })))
+            }
+            ("GET", ["groups", id]) => Ok(Reply::Json(self.group_json(self.group(id)?))),
+            ("PUT", ["groups", id]) => {
+                let update = object_body(body)?;
+                let group = self.group(id)?;
+                if let Some(name) = str_field(&update, "name") {
+                    self.check_group_name(id, group.get("parentId").and_then(Value::as_str), name)?;
+                }
+                let index =
+                    position(&self.groups, id).ok_or_else(|| Failure::not_found("Group"))?;
+                merge(&mut self.groups[index], update);
+                Ok(Reply::NoContent)
+            }
+            ("DELETE", ["groups", id]) => {
+                self.group(id)?;
+                let removed = self.subtree(id);
+                self.groups
+                    .retain(|group| !removed.iter().any(|id| str_field(group, "id") == Some(id)));
+                self.memberships
+                    .retain(|(_, group)| !removed.contains(group));
+                self.role_mappings
+                    .retain(|(holder, _)| !removed.contains(holder));
+                Ok(Reply::NoContent)
+            }
+            ("GET", ["groups", id, "children"]) => {
+                self.group(id)?;
+                let children = self
+                    .groups
+                    .iter()
+                    .filter(|group| group.get("parentId").and_then(Value::as_str) == Some(id))
+                    .map(|group| self.group_json(group));
+                Ok(Reply::Json(query.page(children).collect()))
+            }
+            ("POST", ["groups", id, "children"]) => {
+                self.group(id)?;
+                self.create_group(ids, location, Some(id), object_body(body)?)
+            }
+            ("GET", ["groups", id, "members"]) => {
+                self.group(id)?;
+                let members = self
+                    .memberships
+                    .iter()
+                    .filter(|(_, group)| group == id)
+                    .filter_map(|(user, _)| self.user(user).ok())
+                    .map(|user| Value::Object(user.clone()));
+                Ok(Reply::Json(query.page(members).collect()))
+            }
+            (_, ["groups", id, "role-mappings", path @ ..]) => {
+                self.group(id)?;
+                self.role_mappings(id, method, path, body)
+            }
+            ("GET", ["group-by-path", path @ ..]) => {
+                let path = format!("/{}", path.join("/").trim_start_matches('/'));
+                let group = self
+                    .groups
+                    .iter()
+                    .find(|group| self.group_path(group) == path)
+                    .ok_or_else(|| Failure::not_found("Group path does not exist"))?;
+                Ok(Reply::Json(self.group_json(group)))
+            }
+            (_, ["roles", path @ ..]) => {
+                let realm_id = self.id();
+                self.container_roles(
+                    ids,
+                    &format!("{location}/roles"),
+                    &realm_id,
+                    method,
+                    path,
+                    query,
+                    body,
+                )
+            }
+            (_, ["roles-by-id", id, path @ ..]) => {
+                self.role(id)?;
+                self.role_by_id(id, method, path, body)
+            }
+            ("GET", ["clients"]) => {
+                let client_id = query.get("clientId");
+                let search = query.flag("search");
+                let clients = self
+                    .clients
+                    .iter()
+                    .filter(|client| match client_id {
+                        Some(client_id) if search => {
+                            contains(str_field(client, "clientId"), &client_id.to_lowercase())
+                        }
+                        Some(client_id) => str_field(client, "clientId") == Some(client_id),
+                        None => true,
+                    })
+                    .map(|client| Value::Object(client.clone()));
+                Ok(Reply::Json(query.page(clients).collect()))
+            }
+            ("POST", ["clients"]) => {
+                let id = self.create_client(ids, object_body(body)?)?;
+                Ok(Reply::Created(format!("{location}/clients/{id}")))
+            }
+            ("GET", ["clients", id]) => Ok(Reply::Json(Value::Object(self.client(id)?.clone()))),
+            ("PUT", ["clients", id]) => {
+                let update = object_body(body)?;
+                self.client(id)?;
+                if let Some(client_id) = str_field(&update, "clientId") {
+                    let taken = self.clients.iter().any(|client| {
+                        str_field(client, "clientId") == Some(client_id)
+                            && str_field(client, "id") != Some(id)
+                    });
+                    if taken {
+                        return Err(Failure::conflict(format!(
+                            "Client {client_id} already exists"
+                        )));
+                    }
+                }
+                let index =
+                    position(&self.clients, id).ok_or_else(|| Failure::not_found("Client"))?;
+                merge(&mut self.clients[index], update);
+                Ok(Reply::NoContent)
+            }
+            ("DELETE", ["clients", id]) => {
+                self.client(id)?;
+                self.clients
+                    .retain(|client| str_field(client, "id") != Some(id));
+                let roles: Vec<String> = self
+                    .roles
+                    .iter()
+                    .filter(|role| str_field(role, "containerId") == Some(id))
+                    .filter_map(|role| str_field(role, "id").map(String::from))
+                    .collect();
+                for role in roles {
+                    self.delete_role(&role);
+                }
+                self.default_client_scopes
+                    .retain(|(client, _)| client != id);
+                self.optional_client_scopes
+                    .retain(|(client, _)| client != id);
+                Ok(Reply::NoContent)
+            }
+            (_, ["clients", id, "roles", path @ ..]) => {
+                self.client(id)?;
+                let location = format!("{location}/clients/{id}/roles");
+                self.container_roles(ids, &location, id, method, path, query, body)
+            }
+            (
+                _,
+                ["clients", id, kind @ ("default-client-scopes" | "optional-client-scopes"), path @ ..],
+            ) => {
+                self.client(id)?;
+                let links = if *kind == "default-client-scopes" {
+                    &mut self.default_client_scopes
+                } else {
+                    &mut self.optional_client_scopes
+                };
+                match (method, path) {
+                    ("GET", []) => {
+                        let scopes = links
+                            .iter()
+                            .filter(|(client, _)| client == id)
+                            .filter_map(|(_, scope)| find(&self.client_scopes, scope))
+                            .map(
+                                |scope| json!({ "id": scope.get("id"), "name": scope.get("name") }),
+                            )
+                            .collect();
+                        Ok(Reply::Json(scopes))
+                    }
+                    ("PUT" | "DELETE", [scope]) => {
+                        find(&self.client_scopes, scope)
+                            .ok_or_else(|| Failure::not_found("Client scope"))?;
+                        let link = (id.to_string(), scope.to_string());
+                        if method == "PUT" {
+                            links.insert(link);
+                        } else {
+                            links.remove(&link);
+                        }
+                        Ok(Reply::NoContent)
+                    }
+                    _ => Err(Failure::no_route()),
+                }
+            }
+            ("GET", ["client-scopes"]) => Ok(Reply::Json(
+                self.client_scopes
+                    .iter()
+                    .cloned()
+                    .map(Value::Object)
+                    .collect(),
+            )),
+            ("POST", ["client-scopes"]) => {
+                let mut scope = object_body(body)?;
+                let name = str_field(&scope, "name")
+                    .filter(|name| !name.is_empty())
+                    .ok_or_else(|| Failure::bad_request("Client Scope name is missing"))?
+                    .to_string();
+                if self
+                    .client_scopes
+                    .iter()
+                    .any(|scope| str_field(scope, "name") == Some(&name))
+                {
+                    return Err(Failure::conflict(format!(
+                        "Client Scope {name} already exists"
+                    )));
+                }
+                let id = ids.next();
+                scope.insert("id".into(), id.clone().into());
+                scope
+                    .entry("protocol")
+                    .or_insert_with(|| "openid-connect".into());
+                self.client_scopes.push(scope);
+                Ok(Reply::Created(format!("{location}/client-scopes/{id}")))
+            }
+            ("GET", ["client-scopes", id]) => Ok(Reply::Json(Value::Object(
+                find(&self.client_scopes, id)
+                    .ok_or_else(|| Failure::not_found("Client scope"))?
+                    .clone(),
+            ))),
+            ("PUT", ["client-scopes", id]) => {
+                let update = object_body(body)?;
+                let index = position(&self.client_scopes, id)
+                    .ok_or_else(|| Failure::not_found("Client scope"))?;
+                if let Some(name) = str_field(&update, "name") {
+                    let taken = self.client_scopes.iter().any(|scope| {
+                        str_field(scope, "name") == Some(name) && str_field(scope, "id") != Some(id)
+                    });
+                    if taken {
+                        return Err(Failure::conflict(format!(
+                            "Client Scope {name} already exists"
+                        )));
+                    }
+                }
+                merge(&mut self.client_scopes[index], update);
+                Ok(Reply::NoContent)
+            }
+            ("DELETE", ["client-scopes", id]) => {
+                let index = position(&self.client_scopes, id)
+                    .ok_or_else(|| Failure::not_found("Client scope"))?;
+                self.client_scopes.remove(index);
+                self.default_client_scopes.retain(|(_, scope)| scope != id);
+                self.optional_client_scopes.retain(|(_, scope)| scope != id);
+                Ok(Reply::NoContent)
+            }
+            _ => Err(Failure::no_route()),
+        }
+    }
+
+    fn user(&self, id: &str) -> Result<&Object, Failure> {
+        find(&self.users, id).ok_or_else(|| Failure::not_found("User"))
+    }
+
+    /// Users matching the query parameters, ordered by username.
+    fn find_users<'a>(&'a self, query: &'a Query) -> impl Iterator<Item = Value> + 'a {
+        let exact = query.flag("exact");
+        let search = query
+            .get("search")
+            .filter(|search| *search != "*")
+            .map(|search| search.trim_matches('*').to_lowercase());
+        let mut users: Vec<&Object> = self
+            .users
+            .iter()
+            .filter(move |user| match &search {
+                Some(search) => ["username", "email", "firstName", "lastName"]
+                    .iter()
+                    .any(|field| contains(str_field(user, field), search)),
+                None => true,
+            })
+            .filter(move |user| {
+                ["username", "email", "firstName", "lastName"]
+                    .iter()
+                    .all(|field| match query.get(field).map(str::to_lowercase) {
+                        Some(value) if exact => {
+                            str_field(user, field).map(str::to_lowercase) == Some(value)
+                        }
+                        Some(value) => contains(str_field(user, field), &value),
+                        None => true,
+                    })
+            })
+            .collect();
+        users.sort_by_key(|user| str_field(user, "username"));
+        users.into_iter().map(|user| Value::Object(user.clone()))
+    }
+
+    fn create_user(&mut self, ids: &mut Ids, mut user: Object) -> Result<String, Failure> {
+        let username = str_field(&user, "username")
+            .filter(|username| !username.is_empty())
+            .ok_or_else(|| Failure::bad_request("User name is missing"))?
+            .to_lowercase();
+        user.insert("username".into(), username.clone().into());
+        self.check_user_unique(None, &mut user)?;
+        let groups: Vec<String> = match user.remove("groups") {
+            Some(Value::Array(paths)) => paths
+                .iter()
+                .map(|path| {
+                    let path = path.as_str().unwrap_or_default();
+                    self.groups
+                        .iter()
+                        .find(|group| self.group_path(group) == path)
+                        .and_then(|group| str_field(group, "id").map(String::from))
+                        .ok_or_else(|| Failure::bad_request(&format!("Group {path} not found")))
+                })
+                .collect::<Result<_, _>>()?,
+            _ => Vec::new(),
+        };
+        let id = ids.next();
+        user.remove("credentials");
+        user.insert("id".into(), id.clone().into());
+        for flag in ["enabled", "emailVerified"] {
+            user.entry(flag).or_insert(false.into());
+        }
+        let created = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        user.insert("createdTimestamp".into(), created.into());
+        self.users.push(user);
+        for group in groups {
+            self.memberships.insert((id.clone(), group));
+        }
+        Ok(id)
+    }
+
+    fn update_user(&mut self, id: &str, mut update: Object) -> Result<Reply, Failure> {
+        let index = position(&self.users, id).ok_or_else(|| Failure::not_found("User"))?;
+        if let Some(username) = str_field(&update, "username").map(str::to_lowercase) {
+            update.insert("username".into(), username.into());
+        }
+        self.check_user_unique(Some(id), &mut update)?;
+        update.remove("credentials");
+        update.remove("groups");
+        merge(&mut self.users[index], update);
+        Ok(Reply::NoContent)
+    }
+
+    /// Fails if another user has the username or email of `user`.
+    fn check_user_unique(&self, id: Option<&str>, user: &mut Object) -> Result<(), Failure> {
+        if let Some(email) = str_field(user, "email").map(str::to_lowercase) {
+            user.insert("email".into(), email.into());
+        }
+        for (field, message) in [
+            ("username", "User exists with same username"),
+            ("email", "User exists with same email"),
+        ] {
+            let Some(value) = str_field(user, field).filter(|value| !value.is_empty()) else {
+                continue;
+            };
+            let taken = self.users.iter().any(|other| {
+                str_field(other, field) == Some(value) && str_field(other, "id") != id
+            });
+            if taken {
+                return Err(Failure::conflict(message.into()));
+            }
+        }
+        Ok(())
+    }
+
+    fn group(&self, id: &str) -> Result<&Object, Failure> {
+        find(&self.groups, id).ok_or_else(|| Failure::not_found("Group"))
+    }
+
+    fn group_path(&self, group: &Object) -> String {
+        let mut path = format!("/{}", str_field(group, "name").unwrap_or_default());
+        let mut parent = group.get("parentId").and_then(Value::as_str);
+        while let Some(group) = parent.and_then(|parent| find(&self.groups, parent)) {
+            path = format!("/{}{path}", str_field(group, "name").unwrap_or_default());
+            parent = group.get("parentId").and_then(Value::as_str);
+        }
+        path
+    }
+
+    fn group_json(&self, group: &Object) -> Value {
+        let mut json = group.clone();
+        let id = str_field(group, "id");
+        let sub_group_count = self
+            .groups
+            .iter()
+            .filter(|child| child.get("parentId").and_then(Value::as_str) == id)
+            .count();
+        json.insert("path".into(), self.group_path(group).into());
+        json.insert("subGroupCount".into(), sub_group_count.into());
+        json.insert("subGroups".into(), Value::Array(Vec::new()));
+        Value::Object(json)
+    }
+
+    /// Ids of group `id` and all of its subgroups.
+    fn subtree(&self, id: &str) -> Vec<String> {
+        let mut ids = vec![id.to_string()];
+        let mut index = 0;
+        while let Some(parent) = ids.get(index).cloned() {
+            ids.extend(
+                self.groups
+                    .iter()
+                    .filter(|group| group.get("parentId").and_then(Value::as_str) == Some(&parent))
+                    .filter_map(|group| str_field(group, "id").map(String::from)),
+            );
+            index += 1;
+        }
+        ids
+    }
+
+    /// Fails if a sibling of group `id` below `parent` is named `name`.
+    fn check_group_name(&self, id: &str, parent: Option<&str>, name: &str) -> Result<(), Failure> {
+        let taken = self.groups.iter().any(|group| {
+            group.get("parentId").and_then(Value::as_str) == parent
+                && str_field(group, "name") == Some(name)
+                && str_field(group, "id") != Some(id)
+        });
+        match (taken, parent) {
+            (false, _) => Ok(()),
+            (true, None) => Err(Failure::conflict(format!(
+                "Top level group named '{name}' already exists."
+            ))),
+            (true, Some(_)) => Err(Failure::conflict(format!(
+                "Sibling group named '{name}' already exists."
+            ))),
+        }
+    }
+
+    /// Creates a group below `parent`, or moves the group if `group` has the id of an existing one.
+    fn create_group(
+        &mut self,
+        ids: &mut Ids,
+        location: &str,
+        parent: Option<&str>,
+        mut group: Object,
+    ) -> Result<Reply, Failure> {
+        if let Some(id) = str_field(&group, "id").map(String::from) {
+            let index = position(&self.groups, &id).ok_or_else(|| Failure::not_found("Group"))?;
+            if parent.is_some_and(|parent| self.subtree(&id).iter().any(|id| id == parent)) {
+                return Err(Failure::bad_request(
+                    "Cannot move group into one of its subgroups",
+                ));
+            }
+            let name = str_field(&self.groups[index], "name")
+                .unwrap_or_default()
+                .to_string();
+            self.check_group_name(&id, parent, &name)?;
+            match parent {
+                Some(parent) => self.groups[index].insert("parentId".into(), parent.into()),
+                None => self.groups[index].remove("parentId"),
+            };
+            return Ok(Reply::NoContent);
+        }
+        let name = str_field(&group, "name")
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| Failure::bad_request("Group name is missing"))?
+            .to_string();
+        self.check_group_name("", parent, &name)?;
+        let id = ids.next();
+        group.insert("id".into(), id.clone().into());
+        group.remove("subGroups");
+        group.remove("path");
+        match parent {
+            Some(parent) => group.insert("parentId".into(), parent.into()),
+            None => group.remove("parentId"),
+        };
+        self.groups.push(group);
+        Ok(Reply::Created(format!("{location}/groups/{id}")))
+    }
+
+    fn client(&self, id: &str) -> Result<&Object, Failure> {
+        find(&self.clients, id).ok_or_else(|| Failure::not_found("Client"))
+    }
+
+    fn create_client(&mut self, ids: &mut Ids, mut client: Object) -> Result<String, Failure> {
+        let client_id = str_field(&client, "clientId")
+            .filter(|client_id| !client_id.is_empty())
+            .ok_or_else(|| Failure::bad_request("Client id is missing"))?
+            .to_string();
+        if self
+            .clients
+            .iter()
+            .any(|client| str_field(client, "clientId") == Some(&client_id))
+        {
+            return Err(Failure::conflict(format!(
+                "Client {client_id} already exists"
+            )));
+        }
+        let id = match str_field(&client, "id") {
+            Some(id) if find(&self.clients, id).is_some() => {
+                return Err(Failure::conflict(format!("Client {id} already exists")))
+            }
+            Some(id) => id.to_string(),
+            None => ids.next(),
+        };
+        client.insert("id".into(), id.clone().into());
+        client.entry("enabled").or_insert(true.into());
+        client
+            .entry("protocol")
+            .or_insert_with(|| "openid-connect".into());
+        client.entry("publicClient").or_insert(false.into());
+        client.entry("bearerOnly").or_insert(false.into());
+        if client.get("publicClient") != Some(&Value::Bool(true)) && !client.contains_key("secret")
+        {
+            client.insert("secret".into(), ids.next().into());
+        }
+        self.clients.push(client);
+        Ok(id)
+    }
+
+    fn role(&self, id: &str) -> Result<&Object, Failure> {
+        find(&self.roles, id).ok_or_else(|| Failure::not_found("Role"))
+    }
+
+    fn role_json(&self, role: &Object) -> Value {
+        let mut json = role.clone();
+        let id = str_field(role, "id").unwrap_or_default();
+        let composite = self.composites.iter().any(|(parent, _)| parent == id);
+        json.insert("composite".into(), composite.into());
+        Value::Object(json)
+    }
+
+    fn role_by_name(&self, container: &str, name: &str) -> Result<String, Failure> {
+        self.roles
+            .iter()
+            .find(|role| {
+                str_field(role, "containerId") == Some(container)
+                    && str_field(role, "name") == Some(name)
+            })
+            .and_then(|role| str_field(role, "id").map(String::from))
+            .ok_or_else(|| Failure::not_found("Role"))
+    }
+
+    /// Resolves the role of a representation in the request body by id, or by name in `container`.
+    fn resolve_role(&self, container: &str, role: &Value) -> Result<String, Failure> {
+        if let Some(id) = role.get("id").and_then(Value::as_str) {
+            let found = self.role(id)?;
+            if str_field(found, "containerId") == Some(container) {
+                return Ok(id.into());
+            }
+        }
+        match role.get("name").and_then(Value::as_str) {
+            Some(name) => self.role_by_name(container, name),
+            None => Err(Failure::not_found("Role")),
+        }
+    }
+
+    /// Routes of realm roles (`container` is the realm id) and client roles
+    /// (`container` is the client id), addressed by name.
+    #[allow(clippy::too_many_arguments)]
+    fn container_roles(
+        &mut self,
+        ids: &mut Ids,
+        location: &str,
+        container: &str,
+        method: &str,
+        path: &[&str],
+        query: &Query,
+        body: &[u8],
+    ) -> Result<Reply, Failure> {
+        match (method, path) {
+            ("GET", []) => {
+                let search = query.get("search").map(str::to_lowercase);
+                let roles = self
+                    .roles
+                    .iter()
+                    .filter(|role| str_field(role, "containerId") == Some(container))
+                    .filter(|role| match &search {
+                        Some(search) => contains(str_field(role, "name"), search),
+                        None => true,
+                    })
+                    .map(|role| self.role_json(role));
+                Ok(Reply::Json(query.page(roles).collect()))
+            }
+            ("POST", []) => {
+                let mut role = object_body(body)?;
+                let name = str_field(&role, "name")
+                    .filter(|name| !name.is_empty())
+                    .ok_or_else(|| Failure::bad_request("Role name is missing"))?
+                    .to_string();
+                if self.role_by_name(container, &name).is_ok() {
+                    return Err(Failure::conflict(format!(
+                        "Role with name {name} already exists"
+                    )));
+                }
+                role.insert("id".into(), ids.next().into());
+                role.insert("containerId".into(), container.into());
+                role.insert("clientRole".into(), (container != self.id()).into());
+                role.remove("composite");
+                role.remove("composites");
+                self.roles.push(role);
+                Ok(Reply::Created(format!("{location}/{}", p(&name))))
+            }
+            (method, [name, path @ ..]) => {
+                let id = self.role_by_name(container, name)?;
+                self.role_by_id(&id, method, path, body)
+            }
+            _ => Err(Failure::no_route()),
+        }
+    }
+
+    fn role_by_id(
+        &mut self,
+        id: &str,
+        method: &str,
+        path: &[&str],
+        body: &[u8],
+    ) -> Result<Reply, Failure> {
+        match (method, path) {
+            ("GET", []) => Ok(Reply::Json(self.role_json(self.role(id)?))),
+            ("PUT", []) => {
+                let mut update = object_body(body)?;
+                let container = str_field(self.role(id)?, "containerId")
+                    .unwrap_or_default()
+                    .to_string();
+                if let Some(name) = str_field(&update, "name") {
+                    if self
+                        .role_by_name(&container, name)
+                        .is_ok_and(|other| other != id)
+                    {
+                        return Err(Failure::conflict(format!(
+                            "Role with name {name} already exists"
+                        )));
+                    }
+                }
+                for derived in ["containerId", "clientRole", "composite", "composites"] {
+                    update.remove(derived);
+                }
+                let index = position(&self.roles, id).ok_or_else(|| Failure::not_found("Role"))?;
+                // Keycloak replaces the description, clearing it if missing.
+                if !update.contains_key("description") {
+                    self.roles[index].remove("description");
+                }
+                merge(&mut self.roles[index], update);
+                Ok(Reply::NoContent)
+            }
+            ("DELETE", []) => {
+                self.role(id)?;
+                self.delete_role(id);
+                Ok(Reply::NoContent)
+            }
+            ("GET", ["composites"]) => {
+                let roles = self
+                    .composites
+                    .iter()
+                    .filter(|(parent, _)| parent == id)
+                    .filter_map(|(_, child)| self.role(child).ok())
+                    .map(|role| self.role_json(role))
+                    .collect();
+                Ok(Reply::Json(roles))
+            }
+            ("POST" | "DELETE", ["composites"]) => {
+                let realm_id = self.id();
+                let children = array_body(body)?
+                    .iter()
+                    .map(|role| match role.get("id").and_then(Value::as_str) {
+                        Some(child) => self.role(child).map(|_| child.to_string()),
+                        None => self.resolve_role(&realm_id, role),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                for child in children {
+                    let composite = (id.to_string(), child);
+                    if method == "POST" {
+                        self.composites.insert(composite);
+                    } else {
+                        self.composites.remove(&composite);
+                    }
+                }
+                Ok(Reply::NoContent)
+            }
+            _ => Err(Failure::no_route()),
+        }
+    }
+
+    fn delete_role(&mut self, id: &str) {
+        self.roles.retain(|role| str_field(role, "id") != Some(id));
+        self.role_mappings.retain(|(_, role)| role != id);
+        self.composites
+            .retain(|(parent, child)| parent != id && child != id);
+    }
+
+    fn mapped_roles(&self, holder: &str, container: &str) -> Vec<Value> {
+        self.role_mappings
+            .iter()
+            .filter(|(mapped, _)| mapped == holder)
+            .filter_map(|(_, role)| self.role(role).ok())
+            .filter(|role| str_field(role, "containerId") == Some(container))
+            .map(|role| self.role_json(role))
+            .collect()
+    }
+
+    /// Role mapping routes of user or group `holder`.
+    fn role_mappings(
+        &mut self,
+        holder: &str,
+        method: &str,
+        path: &[&str],
+        body: &[u8],
+    ) -> Result<Reply, Failure> {
+        let realm_id = self.id();
+        let container = match path {
+            [] if method == "GET" => {
+                let mut mappings = Object::new();
+                let realm_mappings = self.mapped_roles(holder, &realm_id);
+                if !realm_mappings.is_empty() {
+                    mappings.insert("realmMappings".into(), realm_mappings.into());
+                }
+                let client_mappings: Object = self
+                    .clients
+                    .iter()
+                    .filter_map(|client| {
+                        let id = str_field(client, "id")?;
+                        let client_id = str_field(client, "clientId")?;
+                        let roles = self.mapped_roles(holder, id);
+                        (!roles.is_empty()).then(|| {
+                            let mapping =
+                                json!({ "id": id, "client": client_id, "mappings": roles });
+                            (client_id.to_string(), mapping)
+                        })
+                    })
+                    .collect();
+                if !client_mappings.is_empty() {
+                    mappings.insert("clientMappings".into(), client_mappings.into());
+                }
+                return Ok(Reply::Json(Value::Object(mappings)));
+            }
+            ["realm"] => realm_id,
+            ["clients", client] => {
+                self.client(client)?;
+                client.to_string()
+            }
+            ["realm" | "clients", .., "available"] if method == "GET" => {
+                let container = match path {
+                    ["clients", client, ..] => {
+                        self.client(client)?;
+                        client.to_string()
+                    }
+                    _ => realm_id,
+                };
+                let available = self
+                    .roles
+                    .iter()
+                    .filter(|role| str_field(role, "containerId") == Some(&container))
+                    .filter(|role| {
+                        let role = str_field(role, "id").unwrap_or_default().to_string();
+                        !self.role_mappings.contains(&(holder.to_string(), role))
+                    })
+                    .map(|role| self.role_json(role))
+                    .collect();
+                return Ok(Reply::Json(available));
+            }
+            _ => return Err(Failure::no_route()),
+        };
+        match method {
+            "GET" => Ok(Reply::Json(self.mapped_roles(holder, &container).into())),
+            "POST" | "DELETE" => {
+                let roles = array_body(body)?
+                    .iter()
+                    .map(|role| self.resolve_role(&container, role))
+                    .collect::<Result<Vec<_>, _>>()?;
+                for role in roles {
+                    let mapping = (holder.to_string(), role);
+                    if method == "POST" {
+                        self.role_mappings.insert(mapping);
+                    } else {
+                        self.role_mappings.remove(&mapping);
+                    }
+                }
+                Ok(Reply::NoContent)
+            }
+            _ => Err(Failure::no_route()),
+        }
+    }
+}
+
+/// Query parameters of a request.
+struct Query<'a>(&'a [(String, String)]);
+
+impl Query<'_> {
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.get(name) == Some("true")
+    }
+
+    /// Applies the `first` and `max` parameters to `items`.
+    fn page<T>(&self, items: impl Iterator<Item = T>) -> impl Iterator<Item = T> {
+        let number = |name| self.get(name).and_then(|value| value.parse::<i64>().ok());
+        let first = number("first").unwrap_or_default().max(0) as usize;
+        let max = number("max")
+            .filter(|max| *max >= 0)
+            .map_or(usize::MAX, |max| max as usize);
+        items.skip(first).take(max)
+    }
+}
+
+fn object_body(body: &[u8]) -> Result<Object, Failure> {
+    serde_json::from_slice(body).map_err(|_| Failure::bad_request("Invalid JSON body"))
+}
+
+fn array_body(body: &[u8]) -> Result<Vec<Value>, Failure> {
+    serde_json::from_slice(body).map_err(|_| Failure::bad_request("Invalid JSON body"))
+}
+
+fn str_field<'a>(object: &'a Object, name: &str) -> Option<&'a str> {
+    object.get(name).and_then(Value::as_str)
+}
+
+/// Returns `true` if `value` contains the lowercase `search` ignoring case.
+fn contains(value: Option<&str>, search: &str) -> bool {
+    value.is_some_and(|value| value.to_lowercase().contains(search))
+}
+
+fn find<'a>(objects: &'a [Object], id: &str) -> Option<&'a Object> {
+    objects
+        .iter()
+        .find(|object| str_field(object, "id") == Some(id))
+}
+
+fn position(objects: &[Object], id: &str) -> Option<usize> {
+    objects
+        .iter()
+        .position(|object| str_field(object, "id") == Some(id))
+}
+
+/// Replaces the fields of `object` present in `update`, except the id.
+fn merge(object: &mut Object, mut update: Object) {
+    update.remove("id");
+    object.extend(update);
+}
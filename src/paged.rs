@@ -0,0 +1,190 @@
+use std::future::Future;
+
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
+
+use crate::{types::TypeVec, KeycloakError, KeycloakRealmAdminMethod};
+
+/// Realm admin method returning a list which supports `first` / `max` pagination.
+///
+/// Pages are fetched lazily: the next request is sent only when the previous page
+/// was consumed. Iteration stops after a page shorter than the page size.
+pub trait KeycloakRealmAdminPagedMethod:
+    KeycloakRealmAdminMethod<Output = TypeVec<Self::Item>, Args: Clone + Send> + Clone + Send + Sized
+{
+    type Item: Clone + Send;
+
+    /// Returns `args` with the pagination offset and page size replaced.
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args;
+
+    /// Stream of pages, starting at offset 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - method arguments, `first` and `max` are ignored
+    /// * `page_size` - number of items requested per page, at least 1
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use futures_util::TryStreamExt;
+    /// use keycloak::{KeycloakAdmin, KeycloakRealmAdminPagedMethod};
+    ///
+    /// async fn list(admin: &KeycloakAdmin) -> Result<(), keycloak::KeycloakError> {
+    ///     let realm = admin.realm("test");
+    ///     let mut pages = std::pin::pin!(realm.users_get().pages(Default::default(), 100));
+    ///     while let Some(page) = pages.try_next().await? {
+    ///         println!("{} users", page.len());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    fn pages(
+        self,
+        args: Self::Args,
+        page_size: i32,
+    ) -> impl Stream<Item = Result<Vec<Self::Item>, KeycloakError>> + Send {
+        let page_size = page_size.max(1);
+        stream::unfold(Some((self, args, 0)), move |state| async move {
+            let (method, args, first) = state?;
+            let page = match method
+                .clone()
+                .opts(Self::with_page(args.clone(), first, page_size))
+                .await
+            {
+                Ok(page) => page,
+                Err(error) => return Some((Err(error), None)),
+            };
+            if page.is_empty() {
+                return None;
+            }
+            let next = first
+                .checked_add(page_size)
+                .filter(|_| page.len() >= page_size as usize)
+                .map(|first| (method, args, first));
+            Some((Ok(into_vec(page)), next))
+        })
+    }
+
+    /// Stream of items across all pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - method arguments, `first` and `max` are ignored
+    /// * `page_size` - number of items requested per page, at least 1
+    fn stream(
+        self,
+        args: Self::Args,
+        page_size: i32,
+    ) -> impl Stream<Item = Result<Self::Item, KeycloakError>> + Send {
+        self.pages(args, page_size)
+            .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    /// Collects at most `limit` items across all pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - method arguments, `first` and `max` are ignored
+    /// * `page_size` - number of items requested per page, at least 1
+    /// * `limit` - upper bound of collected items
+    fn collect_all(
+        self,
+        args: Self::Args,
+        page_size: i32,
+        limit: usize,
+    ) -> impl Future<Output = Result<Vec<Self::Item>, KeycloakError>> + Send {
+        let page_size = i32::try_from(limit).map_or(page_size, |limit| page_size.min(limit));
+        self.stream(args, page_size).take(limit).try_collect()
+    }
+}
+
+#[cfg(not(feature = "rc-vec"))]
+fn into_vec<I: Clone>(items: TypeVec<I>) -> Vec<I> {
+    items
+}
+
+#[cfg(feature = "rc-vec")]
+fn into_vec<I: Clone>(items: TypeVec<I>) -> Vec<I> {
+    items.to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use futures_util::TryStreamExt;
+    use http::{HeaderMap, StatusCode};
+    use serde_json::json;
+
+    use super::KeycloakRealmAdminPagedMethod;
+    use crate::{
+        test_util::StaticToken, KeycloakAdmin, KeycloakError, KeycloakRequest, KeycloakResponse,
+        KeycloakTransport,
+    };
+
+    #[derive(Clone, Default)]
+    struct Roles(Arc<Mutex<Vec<(usize, usize)>>>);
+
+    #[async_trait]
+    impl KeycloakTransport for Roles {
+        async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+            let param = |name: &str| {
+                request
+                    .query_pairs()
+                    .iter()
+                    .find(|(key, _)| key == name)
+                    .and_then(|(_, value)| value.parse::<usize>().ok())
+                    .unwrap()
+            };
+            let (first, max) = (param("first"), param("max"));
+            self.0.lock().unwrap().push((first, max));
+            let roles = (first..5.min(first + max))
+                .map(|i| json!({"name": format!("role-{i}")}))
+                .collect::<Vec<_>>();
+            Ok(KeycloakResponse::new(
+                StatusCode::OK,
+                HeaderMap::new(),
+                serde_json::to_vec(&roles).unwrap(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn pagination() {
+        let transport = Roles::default();
+        let admin = KeycloakAdmin::new("http://keycloak", StaticToken, transport.clone());
+        let realm = admin.realm("test");
+        let requests = || std::mem::take(&mut *transport.0.lock().unwrap());
+
+        let roles = realm
+            .roles_get()
+            .collect_all(Default::default(), 2, 100)
+            .await
+            .unwrap();
+        let names = roles
+            .iter()
+            .map(|role| role.name.as_deref().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["role-0", "role-1", "role-2", "role-3", "role-4"]);
+        assert_eq!(requests(), [(0, 2), (2, 2), (4, 2)]);
+
+        let roles = realm
+            .roles_get()
+            .collect_all(Default::default(), 10, 3)
+            .await
+            .unwrap();
+        assert_eq!(roles.len(), 3);
+        assert_eq!(requests(), [(0, 3)]);
+
+        let pages = realm
+            .roles_get()
+            .pages(Default::default(), 5)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(pages.len(), 1);
+        assert_eq!(requests(), [(0, 5), (5, 5)]);
+    }
+}
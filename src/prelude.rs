@@ -4,3 +4,8 @@ pub use reqwest12 as reqwest;
 pub use reqwest13 as reqwest;
 #[cfg(all(feature = "reqwest13", not(feature = "reqwest12")))]
 pub use reqwest13 as reqwest;
+
+pub use http;
+
+#[cfg(feature = "jwt")]
+pub use jsonwebtoken;
@@ -0,0 +1,832 @@
+//! Declarative reconciliation of realm configuration.
+//!
+//! [`KeycloakReconciler`] compares a desired state, given as a subset of
+//! [`RealmRepresentation`], with the live state of a realm read through the
+//! Admin API, and computes a [`KeycloakReconcilePlan`] of create, update and
+//! delete operations. The plan can be shown as dry run, or applied in
+//! dependency order, e.g. client scopes before the clients using them and
+//! roles before their composites.
+//!
+//! Reconciled parts of the desired state:
+//! - `clientScopes`, with their `protocolMappers`,
+//! - `roles.realm` and `roles.client`, with their `composites`,
+//! - `clients`, with their `protocolMappers`,
+//! - `groups`, with their `subGroups`,
+//! - `identityProviders` and `identityProviderMappers`,
+//! - `authenticationFlows`, without executions: planning fails with
+//!   [`KeycloakError::InvalidRequest`] if a desired flow has executions,
+//! - `requiredActions`.
+//!
+//! A part is reconciled only if it is set, e.g. clients are left alone if
+//! `clients` is `None`. Resources missing from a reconciled part are deleted,
+//! unless deletion is disabled for their kind with
+//! [`KeycloakReconciler::with_deletion`]. Built-in resources, e.g. the
+//! `account` client, the `offline_access` role or the `UPDATE_PASSWORD`
+//! required action, are never deleted.
+//!
+//! Like with the `ensure_*` methods of [`KeycloakRealmAdmin`], only the fields
+//! set in the desired representations are compared with the live ones.
+//! Identity providers and required actions, which Keycloak replaces as a
+//! whole, are updated with these fields applied to the live representation.
+
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{types::*, KeycloakError, KeycloakRealmAdmin, KeycloakTokenSupplier};
+
+mod plan;
+
+/// Kind of a resource managed by [`KeycloakReconciler`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeycloakResourceKind {
+    ClientScope,
+    /// Protocol mapper of a client or of a client scope.
+    ProtocolMapper,
+    RealmRole,
+    Client,
+    ClientRole,
+    /// Membership of a role in a composite role.
+    CompositeRole,
+    Group,
+    IdentityProvider,
+    IdentityProviderMapper,
+    AuthenticationFlow,
+    RequiredAction,
+}
+
+impl Display for KeycloakResourceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            KeycloakResourceKind::ClientScope => "client scope",
+            KeycloakResourceKind::ProtocolMapper => "protocol mapper",
+            KeycloakResourceKind::RealmRole => "realm role",
+            KeycloakResourceKind::Client => "client",
+            KeycloakResourceKind::ClientRole => "client role",
+            KeycloakResourceKind::CompositeRole => "composite role",
+            KeycloakResourceKind::Group => "group",
+            KeycloakResourceKind::IdentityProvider => "identity provider",
+            KeycloakResourceKind::IdentityProviderMapper => "identity provider mapper",
+            KeycloakResourceKind::AuthenticationFlow => "authentication flow",
+            KeycloakResourceKind::RequiredAction => "required action",
+        })
+    }
+}
+
+/// Change made by a [`KeycloakReconcileOperation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeycloakReconcileAction {
+    Create,
+    Update,
+    Delete,
+}
+
+impl Display for KeycloakReconcileAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            KeycloakReconcileAction::Create => "create",
+            KeycloakReconcileAction::Update => "update",
+            KeycloakReconcileAction::Delete => "delete",
+        })
+    }
+}
+
+/// Operation of a [`KeycloakReconcilePlan`].
+///
+/// Displayed as e.g. `create client my-app`.
+#[derive(Clone, Debug)]
+pub struct KeycloakReconcileOperation {
+    pub action: KeycloakReconcileAction,
+    pub kind: KeycloakResourceKind,
+    /// Human readable identifier of the resource, e.g. the `clientId` of a
+    /// client, `{clientId}/{name}` of a client role or the path of a group.
+    pub name: String,
+    resource: Resource,
+}
+
+impl Display for KeycloakReconcileOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.action, self.kind, self.name)
+    }
+}
+
+/// Operations to bring a realm to its desired state, in the order they are applied.
+///
+/// Displayed as one operation per line.
+#[derive(Clone, Debug, Default)]
+pub struct KeycloakReconcilePlan {
+    operations: Vec<KeycloakReconcileOperation>,
+}
+
+impl KeycloakReconcilePlan {
+    pub fn operations(&self) -> &[KeycloakReconcileOperation] {
+        &self.operations
+    }
+
+    /// Returns `true` if the realm is in its desired state.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+}
+
+impl Display for KeycloakReconcilePlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for operation in &self.operations {
+            writeln!(f, "{operation}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Reconciles a realm with a desired state, see the [module documentation](self).
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn doc(admin: keycloak::KeycloakAdmin) -> Result<(), keycloak::KeycloakError> {
+/// use keycloak::{
+///     reconcile::{KeycloakReconciler, KeycloakResourceKind},
+///     types::RealmRepresentation,
+/// };
+///
+/// let desired: RealmRepresentation =
+///     serde_json::from_str(&std::fs::read_to_string("realm.json").unwrap()).unwrap();
+/// let realm = admin.realm("test");
+/// let reconciler =
+///     KeycloakReconciler::new(&realm).with_deletion(KeycloakResourceKind::Group, false);
+///
+/// let plan = reconciler.plan(&desired).await?;
+/// print!("{plan}");
+/// reconciler.apply(&plan).await?;
+/// # Ok(()) }
+/// ```
+pub struct KeycloakReconciler<'a, TS: KeycloakTokenSupplier> {
+    realm: &'a KeycloakRealmAdmin<'a, TS>,
+    kept: HashSet<KeycloakResourceKind>,
+}
+
+impl<'a, TS: KeycloakTokenSupplier + Send + Sync> KeycloakReconciler<'a, TS> {
+    /// Creates a reconciler of `realm`, deleting resources of all kinds.
+    pub fn new(realm: &'a KeycloakRealmAdmin<'a, TS>) -> Self {
+        Self {
+            realm,
+            kept: HashSet::new(),
+        }
+    }
+
+    /// Enables or disables deletion of resources of `kind` missing from the desired state.
+    pub fn with_deletion(mut self, kind: KeycloakResourceKind, enabled: bool) -> Self {
+        if enabled {
+            self.kept.remove(&kind);
+        } else {
+            self.kept.insert(kind);
+        }
+        self
+    }
+
+    /// Computes and applies the plan to reach the `desired` state.
+    ///
+    /// Returns the applied plan.
+    pub async fn reconcile(
+        &self,
+        desired: &RealmRepresentation,
+    ) -> Result<KeycloakReconcilePlan, KeycloakError> {
+        let plan = self.plan(desired).await?;
+        self.apply(&plan).await?;
+        Ok(plan)
+    }
+
+    /// Applies the operations of `plan` in order, stopping at the first failure.
+    pub async fn apply(&self, plan: &KeycloakReconcilePlan) -> Result<(), KeycloakError> {
+        for operation in &plan.operations {
+            self.execute(operation).await?;
+        }
+        Ok(())
+    }
+
+    async fn execute(&self, operation: &KeycloakReconcileOperation) -> Result<(), KeycloakError> {
+        use KeycloakReconcileAction::*;
+
+        let admin = self.realm.admin;
+        let realm = self.realm.realm;
+        match (&operation.resource, operation.action) {
+            (Resource::ClientScope(scope), Delete) => {
+                admin
+                    .realm_client_scopes_with_client_scope_id_delete(
+                        realm,
+                        required(&scope.id, "client scope id")?,
+                    )
+                    .await?;
+            }
+            (Resource::ClientScope(scope), _) => {
+                self.realm.ensure_client_scope(scope.clone()).await?;
+            }
+            (Resource::RealmRole(role), Delete) => {
+                admin
+                    .realm_roles_with_role_name_delete(realm, required(&role.name, "role name")?)
+                    .await?;
+                self.realm.clear_lookup_cache();
+            }
+            (Resource::RealmRole(role), _) => {
+                self.realm.ensure_realm_role(role.clone()).await?;
+            }
+            (Resource::Client(client), Delete) => {
+                admin
+                    .realm_clients_with_client_uuid_delete(
+                        realm,
+                        required(&client.id, "client id")?,
+                    )
+                    .await?;
+                self.realm.clear_lookup_cache();
+            }
+            (Resource::Client(client), _) => {
+                self.realm.ensure_client(client.as_ref().clone()).await?;
+            }
+            (Resource::ClientRole(client_id, role), Delete) => {
+                admin
+                    .realm_clients_with_client_uuid_roles_with_role_name_delete(
+                        realm,
+                        &self.client_uuid(client_id).await?,
+                        required(&role.name, "role name")?,
+                    )
+                    .await?;
+                self.realm.clear_lookup_cache();
+            }
+            (Resource::ClientRole(client_id, role), _) => {
+                self.realm
+                    .ensure_client_role(client_id, role.clone())
+                    .await?;
+            }
+            (Resource::Composite(parent, child), action) => {
+                let parent_id = self.role(parent).await?.id;
+                let child = vec![self.role(child).await?];
+                if action == Delete {
+                    admin
+                        .realm_roles_by_id_with_role_id_composites_delete(
+                            realm,
+                            required(&parent_id, "role id")?,
+                            child,
+                        )
+                        .await?;
+                } else {
+                    admin
+                        .realm_roles_by_id_with_role_id_composites_post(
+                            realm,
+                            required(&parent_id, "role id")?,
+                            child,
+                        )
+                        .await?;
+                }
+            }
+            (Resource::Group(group), Delete) => {
+                admin
+                    .realm_groups_with_group_id_delete(realm, required(&group.id, "group id")?)
+                    .await?;
+                self.realm.clear_lookup_cache();
+            }
+            (Resource::Group(group), _) => {
+                self.realm.ensure_group(group.clone()).await?;
+            }
+            (Resource::ProtocolMapper(owner, mapper), action) => {
+                self.execute_protocol_mapper(owner, mapper, action).await?;
+            }
+            (Resource::IdentityProvider(provider), Create) => {
+                admin
+                    .realm_identity_provider_instances_post(realm, provider.clone())
+                    .await?;
+            }
+            (Resource::IdentityProvider(provider), Update) => {
+                admin
+                    .realm_identity_provider_instances_with_alias_put(
+                        realm,
+                        required(&provider.alias, "identity provider alias")?,
+                        provider.clone(),
+                    )
+                    .await?;
+            }
+            (Resource::IdentityProvider(provider), Delete) => {
+                admin
+                    .realm_identity_provider_instances_with_alias_delete(
+                        realm,
+                        required(&provider.alias, "identity provider alias")?,
+                    )
+                    .await?;
+            }
+            (Resource::IdentityProviderMapper(mapper), action) => {
+                let alias = required(&mapper.identity_provider_alias, "identity provider alias")?;
+                match action {
+                    Create => {
+                        admin
+                            .realm_identity_provider_instances_with_alias_mappers_post(
+                                realm,
+                                alias,
+                                mapper.clone(),
+                            )
+                            .await?;
+                    }
+                    Update => {
+                        admin
+                            .realm_identity_provider_instances_with_alias_mappers_with_id_put(
+                                realm,
+                                alias,
+                                required(&mapper.id, "mapper id")?,
+                                mapper.clone(),
+                            )
+                            .await?;
+                    }
+                    Delete => {
+                        admin
+                            .realm_identity_provider_instances_with_alias_mappers_with_id_delete(
+                                realm,
+                                alias,
+                                required(&mapper.id, "mapper id")?,
+                            )
+                            .await?;
+                    }
+                }
+            }
+            (Resource::AuthenticationFlow(flow), Create) => {
+                admin
+                    .realm_authentication_flows_post(realm, flow.clone())
+                    .await?;
+            }
+            (Resource::AuthenticationFlow(flow), Update) => {
+                admin
+                    .realm_authentication_flows_with_id_put(
+                        realm,
+                        required(&flow.id, "flow id")?,
+                        flow.clone(),
+                    )
+                    .await?;
+            }
+            (Resource::AuthenticationFlow(flow), Delete) => {
+                admin
+                    .realm_authentication_flows_with_id_delete(
+                        realm,
+                        required(&flow.id, "flow id")?,
+                    )
+                    .await?;
+            }
+            (Resource::RequiredAction(required_action), action) => {
+                let alias = required(&required_action.alias, "required action alias")?;
+                if action == Delete {
+                    admin
+                        .realm_authentication_required_actions_with_alias_delete(realm, alias)
+                        .await?;
+                    return Ok(());
+                }
+                if action == Create {
+                    admin
+                        .realm_authentication_register_required_action_post(
+                            realm,
+                            RequiredActionProviderRepresentation {
+                                name: required_action
+                                    .name
+                                    .clone()
+                                    .or_else(|| required_action.alias.clone()),
+                                provider_id: required_action
+                                    .provider_id
+                                    .clone()
+                                    .or_else(|| required_action.alias.clone()),
+                                ..Default::default()
+                            },
+                        )
+                        .await?;
+                }
+                admin
+                    .realm_authentication_required_actions_with_alias_put(
+                        realm,
+                        alias,
+                        required_action.clone(),
+                    )
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn execute_protocol_mapper(
+        &self,
+        owner: &MapperOwner,
+        mapper: &ProtocolMapperRepresentation,
+        action: KeycloakReconcileAction,
+    ) -> Result<(), KeycloakError> {
+        let admin = self.realm.admin;
+        let realm = self.realm.realm;
+        match (owner, action) {
+            (MapperOwner::Client(client_id), KeycloakReconcileAction::Create) => {
+                admin
+                    .realm_clients_with_client_uuid_protocol_mappers_models_post(
+                        realm,
+                        &self.client_uuid(client_id).await?,
+                        mapper.clone(),
+                    )
+                    .await?;
+            }
+            (MapperOwner::Client(client_id), KeycloakReconcileAction::Update) => {
+                admin
+                    .realm_clients_with_client_uuid_protocol_mappers_models_with_id_put(
+                        realm,
+                        &self.client_uuid(client_id).await?,
+                        required(&mapper.id, "mapper id")?,
+                        mapper.clone(),
+                    )
+                    .await?;
+            }
+            (MapperOwner::Client(client_id), KeycloakReconcileAction::Delete) => {
+                admin
+                    .realm_clients_with_client_uuid_protocol_mappers_models_with_id_delete(
+                        realm,
+                        &self.client_uuid(client_id).await?,
+                        required(&mapper.id, "mapper id")?,
+                    )
+                    .await?;
+            }
+            (MapperOwner::ClientScope(name), KeycloakReconcileAction::Create) => {
+                admin
+                    .realm_client_scopes_with_client_scope_id_protocol_mappers_models_post(
+                        realm,
+                        &self.client_scope_id(name).await?,
+                        mapper.clone(),
+                    )
+                    .await?;
+            }
+            (MapperOwner::ClientScope(name), KeycloakReconcileAction::Update) => {
+                admin
+                    .realm_client_scopes_with_client_scope_id_protocol_mappers_models_with_id_put(
+                        realm,
+                        &self.client_scope_id(name).await?,
+                        required(&mapper.id, "mapper id")?,
+                        mapper.clone(),
+                    )
+                    .await?;
+            }
+            (MapperOwner::ClientScope(name), KeycloakReconcileAction::Delete) => {
+                admin
+                    .realm_client_scopes_with_client_scope_id_protocol_mappers_models_with_id_delete(
+                        realm,
+                        &self.client_scope_id(name).await?,
+                        required(&mapper.id, "mapper id")?,
+                    )
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn client_uuid(&self, client_id: &str) -> Result<String, KeycloakError> {
+        let client = self.realm.client_by_client_id(client_id).await?;
+        client
+            .and_then(|client| client.id)
+            .map(|id| id.to_string())
+            .ok_or_else(|| missing(format!("client {client_id}")))
+    }
+
+    async fn client_scope_id(&self, name: &str) -> Result<String, KeycloakError> {
+        let scopes = self
+            .realm
+            .admin
+            .realm_client_scopes_get(self.realm.realm)
+            .await?;
+        scopes
+            .iter()
+            .find(|scope| scope.name.as_deref() == Some(name))
+            .and_then(|scope| scope.id.as_deref())
+            .map(str::to_owned)
+            .ok_or_else(|| missing(format!("client scope {name}")))
+    }
+
+    async fn role(&self, role: &RoleRef) -> Result<RoleRepresentation, KeycloakError> {
+        let found = match role {
+            RoleRef::Realm(name) => self.realm.realm_role_by_name(name).await?,
+            RoleRef::Client(client_id, name) => {
+                self.realm.client_role_by_name(client_id, name).await?
+            }
+        };
+        found.ok_or_else(|| missing(format!("role {role}")))
+    }
+}
+
+/// Resource changed by an operation, the live representation for deletions.
+#[derive(Clone, Debug)]
+enum Resource {
+    ClientScope(ClientScopeRepresentation),
+    RealmRole(RoleRepresentation),
+    Client(Box<ClientRepresentation>),
+    ClientRole(String, RoleRepresentation),
+    /// Composite role and its member.
+    Composite(RoleRef, RoleRef),
+    Group(GroupRepresentation),
+    ProtocolMapper(MapperOwner, ProtocolMapperRepresentation),
+    IdentityProvider(IdentityProviderRepresentation),
+    IdentityProviderMapper(IdentityProviderMapperRepresentation),
+    AuthenticationFlow(AuthenticationFlowRepresentation),
+    RequiredAction(RequiredActionProviderRepresentation),
+}
+
+/// Role by name, client roles by `clientId` of the client.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum RoleRef {
+    Realm(String),
+    Client(String, String),
+}
+
+impl Display for RoleRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoleRef::Realm(name) => f.write_str(name),
+            RoleRef::Client(client_id, name) => write!(f, "{client_id}/{name}"),
+        }
+    }
+}
+
+/// Client by `clientId` or client scope by name.
+#[derive(Clone, Debug)]
+enum MapperOwner {
+    Client(String),
+    ClientScope(String),
+}
+
+impl Display for MapperOwner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MapperOwner::Client(client_id) => write!(f, "client {client_id}"),
+            MapperOwner::ClientScope(name) => write!(f, "client scope {name}"),
+        }
+    }
+}
+
+fn required<'v>(value: &'v Option<TypeString>, name: &str) -> Result<&'v str, KeycloakError> {
+    value
+        .as_deref()
+        .ok_or_else(|| KeycloakError::InvalidRequest(format!("{name} is not set")))
+}
+
+fn missing(resource: String) -> KeycloakError {
+    KeycloakError::InvalidRequest(format!("{resource} does not exist"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use http::{header::LOCATION, HeaderMap, HeaderValue, Method, StatusCode};
+    use serde_json::{json, Value};
+
+    use super::KeycloakReconciler;
+    use crate::{
+        test_util::StaticToken, types::*, KeycloakAdmin, KeycloakError, KeycloakRequest,
+        KeycloakRequestBody, KeycloakResponse, KeycloakTransport,
+    };
+
+    /// Realm with identity providers, authentication flows and required
+    /// actions, logging the changes made to it.
+    #[derive(Clone, Default)]
+    struct Realm(Arc<Mutex<Vec<(String, Option<Value>)>>>);
+
+    #[async_trait]
+    impl KeycloakTransport for Realm {
+        async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+            let path = request
+                .url()
+                .trim_start_matches("http://keycloak/admin/realms/test");
+            if request.method() != Method::GET {
+                let body = match request.body() {
+                    KeycloakRequestBody::Json(json) => serde_json::from_slice(json).ok(),
+                    _ => None,
+                };
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((format!("{} {path}", request.method()), body));
+                let mut headers = HeaderMap::new();
+                headers.insert(LOCATION, HeaderValue::from_static("http://keycloak/1"));
+                return Ok(KeycloakResponse::new(
+                    StatusCode::CREATED,
+                    headers,
+                    Vec::new(),
+                ));
+            }
+            let first = request
+                .query_pairs()
+                .iter()
+                .any(|(key, value)| key == "first" && value != "0");
+            let body = match path {
+                _ if first => json!([]),
+                "/clients" => json!([
+                    {"id": "c1", "clientId": "app", "protocolMappers": [
+                        {"id": "m1", "name": "legacy", "protocol": "openid-connect",
+                         "protocolMapper": "oidc-audience-mapper"},
+                        {"id": "m2", "name": "email", "protocol": "openid-connect",
+                         "protocolMapper": "oidc-usermodel-property-mapper",
+                         "config": {"user.attribute": "email"}}
+                    ]},
+                    {"id": "c2", "clientId": "account"}
+                ]),
+                "/identity-provider/instances" => json!([
+                    {"alias": "github", "providerId": "github", "enabled": true,
+                     "config": {"clientId": "old", "clientSecret": "**********"}},
+                    {"alias": "legacy", "providerId": "oidc"}
+                ]),
+                "/identity-provider/instances/github/mappers" => json!([
+                    {"id": "im1", "name": "legacy", "identityProviderAlias": "github",
+                     "identityProviderMapper": "hardcoded-role-idp-mapper"}
+                ]),
+                "/authentication/flows" => json!([
+                    {"id": "f1", "alias": "browser", "builtIn": true, "topLevel": true},
+                    {"id": "f2", "alias": "login", "builtIn": false, "topLevel": true,
+                     "providerId": "basic-flow", "description": "Login"},
+                    {"id": "f3", "alias": "legacy", "builtIn": false, "topLevel": true}
+                ]),
+                "/authentication/required-actions" => json!([
+                    {"alias": "UPDATE_PASSWORD", "name": "Update Password", "enabled": true},
+                    {"alias": "VERIFY_EMAIL", "name": "Verify Email", "enabled": true},
+                    {"alias": "accept-terms", "name": "Accept terms", "enabled": true,
+                     "defaultAction": false, "priority": 100},
+                    {"alias": "legacy", "name": "Legacy", "enabled": false}
+                ]),
+                _ => json!([]),
+            };
+            Ok(KeycloakResponse::new(
+                StatusCode::OK,
+                HeaderMap::new(),
+                serde_json::to_vec(&body).unwrap(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn reconcile_realm_settings() {
+        let transport = Realm::default();
+        let admin = KeycloakAdmin::new("http://keycloak", StaticToken, transport.clone());
+        let realm = admin.realm("test");
+        let desired: RealmRepresentation = serde_json::from_value(json!({
+            "clients": [{"clientId": "app", "protocolMappers": [
+                {"name": "email", "protocol": "openid-connect",
+                 "protocolMapper": "oidc-usermodel-property-mapper",
+                 "config": {"user.attribute": "mail"}},
+                {"name": "audience", "protocol": "openid-connect",
+                 "protocolMapper": "oidc-audience-mapper"}
+            ]}],
+            "identityProviders": [
+                {"alias": "github", "providerId": "github", "config": {"clientId": "app"}},
+                {"alias": "gitlab", "providerId": "gitlab"}
+            ],
+            "identityProviderMappers": [
+                {"name": "staff", "identityProviderAlias": "github",
+                 "identityProviderMapper": "hardcoded-group-idp-mapper"}
+            ],
+            "authenticationFlows": [
+                {"alias": "login", "providerId": "basic-flow", "description": "Log in"}
+            ],
+            "requiredActions": [{"alias": "accept-terms", "priority": 10}]
+        }))
+        .unwrap();
+
+        let plan = KeycloakReconciler::new(&realm)
+            .reconcile(&desired)
+            .await
+            .unwrap();
+        assert_eq!(
+            plan.to_string(),
+            "update protocol mapper email of client app\n\
+             create protocol mapper audience of client app\n\
+             update identity provider github\n\
+             create identity provider gitlab\n\
+             create identity provider mapper staff of github\n\
+             update authentication flow login\n\
+             update required action accept-terms\n\
+             delete required action legacy\n\
+             delete authentication flow legacy\n\
+             delete identity provider mapper legacy of github\n\
+             delete identity provider legacy\n\
+             delete protocol mapper legacy of client app\n"
+        );
+
+        let changes = std::mem::take(&mut *transport.0.lock().unwrap());
+        let requests = changes
+            .iter()
+            .map(|(request, _)| request.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            requests,
+            [
+                "PUT /clients/c1/protocol-mappers/models/m2",
+                "POST /clients/c1/protocol-mappers/models",
+                "PUT /identity-provider/instances/github",
+                "POST /identity-provider/instances",
+                "POST /identity-provider/instances/github/mappers",
+                "PUT /authentication/flows/f2",
+                "PUT /authentication/required-actions/accept-terms",
+                "DELETE /authentication/required-actions/legacy",
+                "DELETE /authentication/flows/f3",
+                "DELETE /identity-provider/instances/github/mappers/im1",
+                "DELETE /identity-provider/instances/legacy",
+                "DELETE /clients/c1/protocol-mappers/models/m1",
+            ]
+        );
+        assert_eq!(
+            changes[2].1,
+            Some(json!({
+                "alias": "github", "providerId": "github", "enabled": true,
+                "config": {"clientId": "app", "clientSecret": "**********"}
+            }))
+        );
+        assert_eq!(
+            changes[6].1,
+            Some(json!({
+                "alias": "accept-terms", "name": "Accept terms", "enabled": true,
+                "defaultAction": false, "priority": 10
+            }))
+        );
+
+        let desired: RealmRepresentation = serde_json::from_value(json!({
+            "authenticationFlows": [
+                {"alias": "login", "providerId": "basic-flow", "authenticationExecutions": [
+                    {"authenticator": "auth-cookie", "requirement": "ALTERNATIVE"}
+                ]}
+            ]
+        }))
+        .unwrap();
+        assert!(matches!(
+            KeycloakReconciler::new(&realm).plan(&desired).await,
+            Err(KeycloakError::InvalidRequest(_))
+        ));
+    }
+
+    #[cfg(feature = "mock-server")]
+    #[tokio::test]
+    async fn reconcile() {
+        use super::{KeycloakReconcileAction::*, KeycloakResourceKind};
+        use crate::mock_server::KeycloakMockServer;
+
+        let admin = KeycloakMockServer::admin_with_realm("test").await;
+        admin
+            .realm_roles_post(
+                "test",
+                RoleRepresentation {
+                    name: Some("legacy".into()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        let realm = admin.realm("test");
+
+        let desired = |description: &str| -> RealmRepresentation {
+            serde_json::from_value(json!({
+                "clientScopes": [{"name": "audience", "protocol": "openid-connect"}],
+                "roles": {
+                    "realm": [
+                        {"name": "reader", "description": description},
+                        {"name": "editor", "composites": {
+                            "realm": ["reader"],
+                            "client": {"app": ["admin"]}
+                        }}
+                    ],
+                    "client": {"app": [{"name": "admin"}]}
+                },
+                "clients": [{"clientId": "app", "publicClient": true}],
+                "groups": [{"name": "staff", "subGroups": [{"name": "team"}]}]
+            }))
+            .unwrap()
+        };
+        let reconciler =
+            KeycloakReconciler::new(&realm).with_deletion(KeycloakResourceKind::RealmRole, false);
+
+        let plan = reconciler.plan(&desired("read")).await.unwrap();
+        assert_eq!(
+            plan.to_string(),
+            "create client scope audience\n\
+             create realm role reader\n\
+             create realm role editor\n\
+             create client app\n\
+             create client role app/admin\n\
+             create composite role reader in editor\n\
+             create composite role app/admin in editor\n\
+             create group /staff\n\
+             create group /staff/team\n"
+        );
+        reconciler.apply(&plan).await.unwrap();
+        assert!(reconciler.plan(&desired("read")).await.unwrap().is_empty());
+        let editor = realm.realm_role_by_name("editor").await.unwrap().unwrap();
+        assert_eq!(editor.composite, Some(true));
+
+        let plan = reconciler.reconcile(&desired("read all")).await.unwrap();
+        let operations = plan
+            .operations()
+            .iter()
+            .map(|operation| (operation.action, operation.name.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(operations, [(Update, "reader")]);
+
+        let reconciler = reconciler.with_deletion(KeycloakResourceKind::RealmRole, true);
+        let plan = reconciler.reconcile(&desired("read all")).await.unwrap();
+        assert_eq!(plan.to_string(), "delete realm role legacy\n");
+        assert!(realm.realm_role_by_name("legacy").await.unwrap().is_none());
+        assert!(reconciler
+            .plan(&desired("read all"))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,907 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use super::{
+    required, KeycloakReconcileAction, KeycloakReconcileOperation, KeycloakReconcilePlan,
+    KeycloakReconciler, KeycloakResourceKind, MapperOwner, Resource, RoleRef,
+};
+use crate::{
+    resource::{
+        groups::{RealmGroupsGetArgs, RealmGroupsWithGroupIdChildrenGetArgs},
+        identity_providers::RealmIdentityProviderInstancesGetArgs,
+    },
+    rest::ensure::{differs, merged},
+    types::*,
+    KeycloakError, KeycloakRealmAdminPagedMethod, KeycloakTokenSupplier,
+};
+
+const BUILT_IN_CLIENTS: &[&str] = &[
+    "account",
+    "account-console",
+    "admin-cli",
+    "broker",
+    "realm-management",
+    "security-admin-console",
+];
+
+const BUILT_IN_CLIENT_SCOPES: &[&str] = &[
+    "acr",
+    "address",
+    "basic",
+    "email",
+    "microprofile-jwt",
+    "offline_access",
+    "organization",
+    "phone",
+    "profile",
+    "role_list",
+    "roles",
+    "saml_organization",
+    "service_account",
+    "web-origins",
+];
+
+const BUILT_IN_REALM_ROLES: &[&str] = &["offline_access", "uma_authorization"];
+
+const BUILT_IN_MASTER_ROLES: &[&str] = &["admin", "create-realm"];
+
+const BUILT_IN_REQUIRED_ACTIONS: &[&str] = &[
+    "CONFIGURE_RECOVERY_AUTHN_CODES",
+    "CONFIGURE_TOTP",
+    "TERMS_AND_CONDITIONS",
+    "UPDATE_EMAIL",
+    "UPDATE_PASSWORD",
+    "UPDATE_PROFILE",
+    "VERIFY_EMAIL",
+    "VERIFY_PROFILE",
+    "delete_account",
+    "delete_credential",
+    "idp_link",
+    "update_user_locale",
+    "webauthn-register",
+    "webauthn-register-passwordless",
+];
+
+const PAGE_SIZE: i32 = 100;
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakReconciler<'_, TS> {
+    /// Computes the operations to reach the `desired` state, without changing the realm.
+    ///
+    /// # Arguments
+    ///
+    /// * `desired` - Desired state, only the reconciled parts which are set
+    ///   are compared with the realm.
+    pub async fn plan(
+        &self,
+        desired: &RealmRepresentation,
+    ) -> Result<KeycloakReconcilePlan, KeycloakError> {
+        let admin = self.realm.admin;
+        let realm = self.realm.realm;
+        let mut planner = Planner {
+            kept: &self.kept,
+            upserts: Vec::new(),
+            deletions: Vec::new(),
+        };
+
+        if let Some(scopes) = &desired.client_scopes {
+            let live = admin.realm_client_scopes_get(realm).await?;
+            plan_client_scopes(&mut planner, scopes, &live)?;
+        }
+
+        let clients = if desired.clients.is_some() || desired.roles.is_some() {
+            admin
+                .realm_clients_get(realm, None, None, None, None, None, None)
+                .await?
+                .to_vec()
+        } else {
+            Vec::new()
+        };
+
+        let realm_roles = desired
+            .roles
+            .as_ref()
+            .and_then(|roles| roles.realm.as_ref());
+        let live_realm_roles = match realm_roles {
+            Some(roles) => {
+                let live = admin
+                    .realm_roles_get(realm, Some(false), None, None, None)
+                    .await?
+                    .to_vec();
+                plan_realm_roles(&mut planner, realm, roles, &live)?;
+                live
+            }
+            None => Vec::new(),
+        };
+
+        if let Some(desired_clients) = &desired.clients {
+            plan_clients(&mut planner, realm, desired_clients, &clients)?;
+        }
+
+        let mut client_roles = desired
+            .roles
+            .as_ref()
+            .and_then(|roles| roles.client.as_ref())
+            .map(|roles| roles.iter().collect::<Vec<_>>())
+            .unwrap_or_default();
+        client_roles.sort_by_key(|(client_id, _)| *client_id);
+        let mut live_client_roles = Vec::new();
+        if !client_roles.is_empty() {
+            planner.part();
+        }
+        for (client_id, roles) in &client_roles {
+            let client_uuid = clients
+                .iter()
+                .find(|client| client.client_id.as_deref() == Some(client_id.as_str()))
+                .and_then(|client| client.id.as_deref());
+            let live = match client_uuid {
+                Some(client_uuid) => admin
+                    .realm_clients_with_client_uuid_roles_get(
+                        realm,
+                        client_uuid,
+                        Some(false),
+                        None,
+                        None,
+                        None,
+                    )
+                    .await?
+                    .to_vec(),
+                None => Vec::new(),
+            };
+            plan_client_roles(&mut planner, client_id, roles, &live)?;
+            live_client_roles.push(live);
+        }
+
+        let mut composites = Vec::new();
+        for role in realm_roles.into_iter().flat_map(|roles| roles.iter()) {
+            let name = required(&role.name, "role name")?;
+            let live = live_realm_roles
+                .iter()
+                .find(|live| live.name.as_deref() == Some(name));
+            composites.push((RoleRef::Realm(name.to_owned()), role, live));
+        }
+        for ((client_id, roles), live_roles) in client_roles.iter().zip(&live_client_roles) {
+            for role in roles.iter() {
+                let name = required(&role.name, "role name")?;
+                let live = live_roles
+                    .iter()
+                    .find(|live| live.name.as_deref() == Some(name));
+                composites.push((
+                    RoleRef::Client(client_id.to_string(), name.to_owned()),
+                    role,
+                    live,
+                ));
+            }
+        }
+        planner.part();
+        for (parent, role, live) in composites {
+            let Some(wanted) = &role.composites else {
+                continue;
+            };
+            let current = match live.and_then(|live| live.id.as_deref()) {
+                Some(id) => admin
+                    .realm_roles_by_id_with_role_id_composites_get(realm, id, None, None, None)
+                    .await?
+                    .iter()
+                    .filter_map(|child| role_ref(child, &clients))
+                    .collect(),
+                None => Vec::new(),
+            };
+            plan_composites(&mut planner, &parent, wanted, &current);
+        }
+
+        if let Some(groups) = &desired.groups {
+            let mut wanted = Vec::new();
+            flatten_groups(groups, "", &mut wanted)?;
+            let live = self.live_groups().await?;
+            plan_groups(&mut planner, &wanted, &live);
+        }
+
+        let live_providers = if desired.identity_providers.is_some()
+            || desired.identity_provider_mappers.is_some()
+        {
+            self.realm
+                .identity_provider_instances_get()
+                .collect_all(
+                    RealmIdentityProviderInstancesGetArgs {
+                        brief_representation: Some(false),
+                        ..Default::default()
+                    },
+                    PAGE_SIZE,
+                    usize::MAX,
+                )
+                .await?
+        } else {
+            Vec::new()
+        };
+        if let Some(providers) = &desired.identity_providers {
+            plan_identity_providers(&mut planner, providers, &live_providers)?;
+        }
+        if let Some(mappers) = &desired.identity_provider_mappers {
+            let mut aliases = mappers
+                .iter()
+                .map(|mapper| required(&mapper.identity_provider_alias, "identity provider alias"))
+                .chain(
+                    desired
+                        .identity_providers
+                        .iter()
+                        .flat_map(|providers| providers.iter())
+                        .map(|provider| required(&provider.alias, "identity provider alias")),
+                )
+                .collect::<Result<Vec<_>, _>>()?;
+            aliases.sort_unstable();
+            aliases.dedup();
+            planner.part();
+            for alias in aliases {
+                let exists = live_providers
+                    .iter()
+                    .any(|provider| provider.alias.as_deref() == Some(alias));
+                let live = if exists {
+                    admin
+                        .realm_identity_provider_instances_with_alias_mappers_get(realm, alias)
+                        .await?
+                        .to_vec()
+                } else {
+                    Vec::new()
+                };
+                let wanted = mappers
+                    .iter()
+                    .filter(|mapper| mapper.identity_provider_alias.as_deref() == Some(alias));
+                plan_identity_provider_mappers(&mut planner, alias, wanted, &live)?;
+            }
+        }
+
+        if let Some(flows) = &desired.authentication_flows {
+            let live = admin.realm_authentication_flows_get(realm).await?;
+            plan_authentication_flows(&mut planner, flows, &live)?;
+        }
+
+        if let Some(actions) = &desired.required_actions {
+            let live = admin
+                .realm_authentication_required_actions_get(realm)
+                .await?;
+            plan_required_actions(&mut planner, actions, &live)?;
+        }
+
+        Ok(planner.finish())
+    }
+
+    /// Groups of the realm with their paths, parents before their subgroups.
+    async fn live_groups(&self) -> Result<Vec<(String, GroupRepresentation)>, KeycloakError> {
+        let top = self
+            .realm
+            .groups_get()
+            .collect_all(
+                RealmGroupsGetArgs {
+                    brief_representation: Some(false),
+                    ..Default::default()
+                },
+                PAGE_SIZE,
+                usize::MAX,
+            )
+            .await?;
+        let mut pending = top
+            .into_iter()
+            .rev()
+            .map(|group| (String::new(), group))
+            .collect::<Vec<_>>();
+        let mut groups = Vec::new();
+        while let Some((parent, group)) = pending.pop() {
+            let path = format!("{parent}/{}", group.name.as_deref().unwrap_or_default());
+            if let Some(id) = group
+                .id
+                .as_deref()
+                .filter(|_| group.sub_group_count != Some(0))
+            {
+                let children = self
+                    .realm
+                    .groups_with_group_id_children_get(id)
+                    .collect_all(
+                        RealmGroupsWithGroupIdChildrenGetArgs {
+                            brief_representation: Some(false),
+                            ..Default::default()
+                        },
+                        PAGE_SIZE,
+                        usize::MAX,
+                    )
+                    .await?;
+                pending.extend(
+                    children
+                        .into_iter()
+                        .rev()
+                        .map(|child| (path.clone(), child)),
+                );
+            }
+            groups.push((path, group));
+        }
+        Ok(groups)
+    }
+}
+
+/// Operations collected while planning.
+struct Planner<'k> {
+    kept: &'k HashSet<KeycloakResourceKind>,
+    upserts: Vec<KeycloakReconcileOperation>,
+    /// Deletions of each part of the desired state, the parts are deleted in
+    /// reverse order.
+    deletions: Vec<Vec<KeycloakReconcileOperation>>,
+}
+
+impl Planner<'_> {
+    /// Starts the deletions of the next part.
+    fn part(&mut self) {
+        self.deletions.push(Vec::new());
+    }
+
+    fn create(&mut self, kind: KeycloakResourceKind, name: String, resource: Resource) {
+        self.upserts.push(KeycloakReconcileOperation {
+            action: KeycloakReconcileAction::Create,
+            kind,
+            name,
+            resource,
+        });
+    }
+
+    /// Creates `desired` if there is no `current` resource, or updates it if they differ.
+    fn upsert<T: Serialize>(
+        &mut self,
+        kind: KeycloakResourceKind,
+        name: String,
+        current: Option<&T>,
+        desired: &T,
+        resource: Resource,
+    ) {
+        match current {
+            None => self.create(kind, name, resource),
+            Some(current) if differs(current, desired, &[]) => {
+                self.upserts.push(KeycloakReconcileOperation {
+                    action: KeycloakReconcileAction::Update,
+                    kind,
+                    name,
+                    resource,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+
+    fn delete(&mut self, kind: KeycloakResourceKind, name: String, resource: Resource) {
+        if self.kept.contains(&kind) {
+            return;
+        }
+        if let Some(part) = self.deletions.last_mut() {
+            part.push(KeycloakReconcileOperation {
+                action: KeycloakReconcileAction::Delete,
+                kind,
+                name,
+                resource,
+            });
+        }
+    }
+
+    fn finish(self) -> KeycloakReconcilePlan {
+        let mut operations = self.upserts;
+        operations.extend(self.deletions.into_iter().rev().flatten());
+        KeycloakReconcilePlan { operations }
+    }
+}
+
+fn plan_client_scopes(
+    planner: &mut Planner,
+    desired: &[ClientScopeRepresentation],
+    live: &[ClientScopeRepresentation],
+) -> Result<(), KeycloakError> {
+    planner.part();
+    for scope in desired {
+        let name = required(&scope.name, "client scope name")?;
+        let body = ClientScopeRepresentation {
+            protocol_mappers: None,
+            ..scope.clone()
+        };
+        let current = live.iter().find(|live| live.name.as_deref() == Some(name));
+        planner.upsert(
+            KeycloakResourceKind::ClientScope,
+            name.to_owned(),
+            current,
+            &body,
+            Resource::ClientScope(body.clone()),
+        );
+        if let Some(mappers) = &scope.protocol_mappers {
+            plan_protocol_mappers(
+                planner,
+                MapperOwner::ClientScope(name.to_owned()),
+                mappers,
+                current
+                    .and_then(|current| current.protocol_mappers.as_deref())
+                    .unwrap_or_default(),
+            )?;
+        }
+    }
+    for scope in live {
+        let name = scope.name.as_deref().unwrap_or_default();
+        if BUILT_IN_CLIENT_SCOPES.contains(&name)
+            || desired
+                .iter()
+                .any(|scope| scope.name.as_deref() == Some(name))
+        {
+            continue;
+        }
+        planner.delete(
+            KeycloakResourceKind::ClientScope,
+            name.to_owned(),
+            Resource::ClientScope(scope.clone()),
+        );
+    }
+    Ok(())
+}
+
+fn plan_realm_roles(
+    planner: &mut Planner,
+    realm: &str,
+    desired: &[RoleRepresentation],
+    live: &[RoleRepresentation],
+) -> Result<(), KeycloakError> {
+    planner.part();
+    for role in desired {
+        let name = required(&role.name, "role name")?;
+        let body = RoleRepresentation {
+            composite: None,
+            composites: None,
+            ..role.clone()
+        };
+        let current = live.iter().find(|live| live.name.as_deref() == Some(name));
+        planner.upsert(
+            KeycloakResourceKind::RealmRole,
+            name.to_owned(),
+            current,
+            &body,
+            Resource::RealmRole(body.clone()),
+        );
+    }
+    let default_roles = format!("default-roles-{}", realm.to_lowercase());
+    for role in live {
+        let name = role.name.as_deref().unwrap_or_default();
+        if name == default_roles
+            || BUILT_IN_REALM_ROLES.contains(&name)
+            || (realm == "master" && BUILT_IN_MASTER_ROLES.contains(&name))
+            || desired
+                .iter()
+                .any(|role| role.name.as_deref() == Some(name))
+        {
+            continue;
+        }
+        planner.delete(
+            KeycloakResourceKind::RealmRole,
+            name.to_owned(),
+            Resource::RealmRole(role.clone()),
+        );
+    }
+    Ok(())
+}
+
+fn plan_clients(
+    planner: &mut Planner,
+    realm: &str,
+    desired: &[ClientRepresentation],
+    live: &[ClientRepresentation],
+) -> Result<(), KeycloakError> {
+    planner.part();
+    for client in desired {
+        let client_id = required(&client.client_id, "clientId")?;
+        let body = ClientRepresentation {
+            protocol_mappers: None,
+            ..client.clone()
+        };
+        let current = live
+            .iter()
+            .find(|live| live.client_id.as_deref() == Some(client_id));
+        planner.upsert(
+            KeycloakResourceKind::Client,
+            client_id.to_owned(),
+            current,
+            &body,
+            Resource::Client(Box::new(body.clone())),
+        );
+        if let Some(mappers) = &client.protocol_mappers {
+            plan_protocol_mappers(
+                planner,
+                MapperOwner::Client(client_id.to_owned()),
+                mappers,
+                current
+                    .and_then(|current| current.protocol_mappers.as_deref())
+                    .unwrap_or_default(),
+            )?;
+        }
+    }
+    for client in live {
+        let client_id = client.client_id.as_deref().unwrap_or_default();
+        if BUILT_IN_CLIENTS.contains(&client_id)
+            || (realm == "master" && client_id.ends_with("-realm"))
+            || desired
+                .iter()
+                .any(|client| client.client_id.as_deref() == Some(client_id))
+        {
+            continue;
+        }
+        planner.delete(
+            KeycloakResourceKind::Client,
+            client_id.to_owned(),
+            Resource::Client(Box::new(client.clone())),
+        );
+    }
+    Ok(())
+}
+
+fn plan_protocol_mappers(
+    planner: &mut Planner,
+    owner: MapperOwner,
+    desired: &[ProtocolMapperRepresentation],
+    live: &[ProtocolMapperRepresentation],
+) -> Result<(), KeycloakError> {
+    for mapper in desired {
+        let name = required(&mapper.name, "protocol mapper name")?;
+        let current = live.iter().find(|live| live.name.as_deref() == Some(name));
+        let body = ProtocolMapperRepresentation {
+            id: current.and_then(|current| current.id.clone()),
+            ..mapper.clone()
+        };
+        planner.upsert(
+            KeycloakResourceKind::ProtocolMapper,
+            format!("{name} of {owner}"),
+            current,
+            &body,
+            Resource::ProtocolMapper(owner.clone(), body.clone()),
+        );
+    }
+    for mapper in live {
+        let name = mapper.name.as_deref().unwrap_or_default();
+        if desired
+            .iter()
+            .any(|mapper| mapper.name.as_deref() == Some(name))
+        {
+            continue;
+        }
+        planner.delete(
+            KeycloakResourceKind::ProtocolMapper,
+            format!("{name} of {owner}"),
+            Resource::ProtocolMapper(owner.clone(), mapper.clone()),
+        );
+    }
+    Ok(())
+}
+
+fn plan_client_roles(
+    planner: &mut Planner,
+    client_id: &str,
+    desired: &[RoleRepresentation],
+    live: &[RoleRepresentation],
+) -> Result<(), KeycloakError> {
+    for role in desired {
+        let name = required(&role.name, "role name")?;
+        let body = RoleRepresentation {
+            composite: None,
+            composites: None,
+            ..role.clone()
+        };
+        let current = live.iter().find(|live| live.name.as_deref() == Some(name));
+        planner.upsert(
+            KeycloakResourceKind::ClientRole,
+            format!("{client_id}/{name}"),
+            current,
+            &body,
+            Resource::ClientRole(client_id.to_owned(), body.clone()),
+        );
+    }
+    for role in live {
+        let name = role.name.as_deref().unwrap_or_default();
+        if desired
+            .iter()
+            .any(|role| role.name.as_deref() == Some(name))
+        {
+            continue;
+        }
+        planner.delete(
+            KeycloakResourceKind::ClientRole,
+            format!("{client_id}/{name}"),
+            Resource::ClientRole(client_id.to_owned(), role.clone()),
+        );
+    }
+    Ok(())
+}
+
+fn plan_composites(
+    planner: &mut Planner,
+    parent: &RoleRef,
+    desired: &Composites,
+    live: &[RoleRef],
+) {
+    let mut client = desired
+        .client
+        .iter()
+        .flat_map(|client| client.iter())
+        .collect::<Vec<_>>();
+    client.sort_by_key(|(client_id, _)| *client_id);
+    let wanted = desired
+        .realm
+        .iter()
+        .flat_map(|roles| roles.iter())
+        .map(|name| RoleRef::Realm(name.clone()))
+        .chain(client.into_iter().flat_map(|(client_id, roles)| {
+            roles
+                .iter()
+                .map(|name| RoleRef::Client(client_id.clone(), name.clone()))
+        }))
+        .collect::<Vec<_>>();
+    for child in &wanted {
+        if !live.contains(child) {
+            planner.create(
+                KeycloakResourceKind::CompositeRole,
+                format!("{child} in {parent}"),
+                Resource::Composite(parent.clone(), child.clone()),
+            );
+        }
+    }
+    for child in live {
+        if !wanted.contains(child) {
+            planner.delete(
+                KeycloakResourceKind::CompositeRole,
+                format!("{child} in {parent}"),
+                Resource::Composite(parent.clone(), child.clone()),
+            );
+        }
+    }
+}
+
+/// Reference to a live member of a composite role.
+fn role_ref(role: &RoleRepresentation, clients: &[ClientRepresentation]) -> Option<RoleRef> {
+    let name = role.name.as_deref()?.to_owned();
+    if role.client_role != Some(true) {
+        return Some(RoleRef::Realm(name));
+    }
+    let client_id = clients
+        .iter()
+        .find(|client| client.id.is_some() && client.id == role.container_id)?
+        .client_id
+        .as_deref()?;
+    Some(RoleRef::Client(client_id.to_owned(), name))
+}
+
+/// Collects `groups` and their subgroups with their paths, parents before their subgroups.
+fn flatten_groups(
+    groups: &[GroupRepresentation],
+    parent: &str,
+    groups_by_path: &mut Vec<(String, GroupRepresentation)>,
+) -> Result<(), KeycloakError> {
+    for group in groups {
+        let path = format!("{parent}/{}", required(&group.name, "group name")?);
+        groups_by_path.push((
+            path.clone(),
+            GroupRepresentation {
+                client_roles: None,
+                id: None,
+                parent_id: None,
+                path: Some(path.as_str().into()),
+                realm_roles: None,
+                sub_group_count: None,
+                sub_groups: None,
+                ..group.clone()
+            },
+        ));
+        if let Some(sub_groups) = &group.sub_groups {
+            flatten_groups(sub_groups, &path, groups_by_path)?;
+        }
+    }
+    Ok(())
+}
+
+fn plan_groups(
+    planner: &mut Planner,
+    desired: &[(String, GroupRepresentation)],
+    live: &[(String, GroupRepresentation)],
+) {
+    planner.part();
+    for (path, group) in desired {
+        let current = live
+            .iter()
+            .find(|(live, _)| live == path)
+            .map(|(_, current)| current);
+        planner.upsert(
+            KeycloakResourceKind::Group,
+            path.clone(),
+            current,
+            group,
+            Resource::Group(group.clone()),
+        );
+    }
+    for (path, group) in live {
+        let parent = path.rsplit_once('/').map(|(parent, _)| parent);
+        let managed = |path: &str| desired.iter().any(|(desired, _)| desired == path);
+        // Subgroups of deleted groups are deleted with their parent.
+        if managed(path) || parent.is_some_and(|parent| !parent.is_empty() && !managed(parent)) {
+            continue;
+        }
+        planner.delete(
+            KeycloakResourceKind::Group,
+            path.clone(),
+            Resource::Group(group.clone()),
+        );
+    }
+}
+
+fn plan_identity_providers(
+    planner: &mut Planner,
+    desired: &[IdentityProviderRepresentation],
+    live: &[IdentityProviderRepresentation],
+) -> Result<(), KeycloakError> {
+    planner.part();
+    for provider in desired {
+        let alias = required(&provider.alias, "identity provider alias")?;
+        let current = live
+            .iter()
+            .find(|live| live.alias.as_deref() == Some(alias));
+        // Keycloak replaces identity providers as a whole, e.g. unset config
+        // entries like `clientSecret` would be removed.
+        let body = match current {
+            Some(current) => merged(current, provider)?,
+            None => provider.clone(),
+        };
+        planner.upsert(
+            KeycloakResourceKind::IdentityProvider,
+            alias.to_owned(),
+            current,
+            provider,
+            Resource::IdentityProvider(body),
+        );
+    }
+    for provider in live {
+        let alias = provider.alias.as_deref().unwrap_or_default();
+        if desired
+            .iter()
+            .any(|provider| provider.alias.as_deref() == Some(alias))
+        {
+            continue;
+        }
+        planner.delete(
+            KeycloakResourceKind::IdentityProvider,
+            alias.to_owned(),
+            Resource::IdentityProvider(provider.clone()),
+        );
+    }
+    Ok(())
+}
+
+fn plan_identity_provider_mappers<'d>(
+    planner: &mut Planner,
+    alias: &str,
+    desired: impl Iterator<Item = &'d IdentityProviderMapperRepresentation> + Clone,
+    live: &[IdentityProviderMapperRepresentation],
+) -> Result<(), KeycloakError> {
+    for mapper in desired.clone() {
+        let name = required(&mapper.name, "identity provider mapper name")?;
+        let current = live.iter().find(|live| live.name.as_deref() == Some(name));
+        let body = IdentityProviderMapperRepresentation {
+            id: current.and_then(|current| current.id.clone()),
+            ..mapper.clone()
+        };
+        planner.upsert(
+            KeycloakResourceKind::IdentityProviderMapper,
+            format!("{name} of {alias}"),
+            current,
+            &body,
+            Resource::IdentityProviderMapper(body.clone()),
+        );
+    }
+    for mapper in live {
+        let name = mapper.name.as_deref().unwrap_or_default();
+        if desired
+            .clone()
+            .any(|mapper| mapper.name.as_deref() == Some(name))
+        {
+            continue;
+        }
+        planner.delete(
+            KeycloakResourceKind::IdentityProviderMapper,
+            format!("{name} of {alias}"),
+            Resource::IdentityProviderMapper(IdentityProviderMapperRepresentation {
+                identity_provider_alias: Some(alias.into()),
+                ..mapper.clone()
+            }),
+        );
+    }
+    Ok(())
+}
+
+fn plan_authentication_flows(
+    planner: &mut Planner,
+    desired: &[AuthenticationFlowRepresentation],
+    live: &[AuthenticationFlowRepresentation],
+) -> Result<(), KeycloakError> {
+    planner.part();
+    for flow in desired {
+        let alias = required(&flow.alias, "authentication flow alias")?;
+        // executions are managed with their own endpoints, not with the flow
+        if flow
+            .authentication_executions
+            .as_ref()
+            .is_some_and(|executions| !executions.is_empty())
+        {
+            return Err(KeycloakError::InvalidRequest(format!(
+                "executions of authentication flow {alias} can not be reconciled"
+            )));
+        }
+        let current = live
+            .iter()
+            .find(|live| live.alias.as_deref() == Some(alias));
+        let body = AuthenticationFlowRepresentation {
+            built_in: None,
+            id: current.and_then(|current| current.id.clone()),
+            ..flow.clone()
+        };
+        planner.upsert(
+            KeycloakResourceKind::AuthenticationFlow,
+            alias.to_owned(),
+            current,
+            &body,
+            Resource::AuthenticationFlow(body.clone()),
+        );
+    }
+    for flow in live {
+        let alias = flow.alias.as_deref().unwrap_or_default();
+        if flow.built_in == Some(true)
+            || desired
+                .iter()
+                .any(|flow| flow.alias.as_deref() == Some(alias))
+        {
+            continue;
+        }
+        planner.delete(
+            KeycloakResourceKind::AuthenticationFlow,
+            alias.to_owned(),
+            Resource::AuthenticationFlow(flow.clone()),
+        );
+    }
+    Ok(())
+}
+
+fn plan_required_actions(
+    planner: &mut Planner,
+    desired: &[RequiredActionProviderRepresentation],
+    live: &[RequiredActionProviderRepresentation],
+) -> Result<(), KeycloakError> {
+    planner.part();
+    for action in desired {
+        let alias = required(&action.alias, "required action alias")?;
+        let current = live
+            .iter()
+            .find(|live| live.alias.as_deref() == Some(alias));
+        // Keycloak replaces required actions as a whole, e.g. an unset
+        // `enabled` would disable the action.
+        let body = match current {
+            Some(current) => merged(current, action)?,
+            None => action.clone(),
+        };
+        planner.upsert(
+            KeycloakResourceKind::RequiredAction,
+            alias.to_owned(),
+            current,
+            action,
+            Resource::RequiredAction(body),
+        );
+    }
+    for action in live {
+        let alias = action.alias.as_deref().unwrap_or_default();
+        if BUILT_IN_REQUIRED_ACTIONS.contains(&alias)
+            || desired
+                .iter()
+                .any(|action| action.alias.as_deref() == Some(alias))
+        {
+            continue;
+        }
+        planner.delete(
+            KeycloakResourceKind::RequiredAction,
+            alias.to_owned(),
+            Resource::RequiredAction(action.clone()),
+        );
+    }
+    Ok(())
+}
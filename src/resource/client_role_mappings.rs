@@ -299,7 +299,7 @@ pub struct RealmGroupsWithGroupIdRoleMappingsClientsWithClientIdCompositeGet<
     pub client_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmGroupsWithGroupIdRoleMappingsClientsWithClientIdCompositeGetArgs {
     /// if false, return roles with their attributes
     pub brief_representation: Option<bool>,
@@ -351,7 +351,7 @@ pub struct RealmUsersWithUserIdRoleMappingsClientsWithClientIdCompositeGet<
     pub client_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmUsersWithUserIdRoleMappingsClientsWithClientIdCompositeGetArgs {
     /// if false, return roles with their attributes
     pub brief_representation: Option<bool>,
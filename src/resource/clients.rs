@@ -850,7 +850,7 @@ pub struct RealmClientsGet<'a, TS: KeycloakTokenSupplier> {
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsGetArgs {
     /// filter by clientId
     pub client_id: Option<String>,
@@ -905,6 +905,28 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmClientsGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmClientsGet<'_, TS>
+{
+    type Item = ClientRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmClientsWithClientUuidEvaluateScopesGenerateExampleAccessTokenGet<
     'a,
     TS: KeycloakTokenSupplier,
@@ -915,7 +937,7 @@ pub struct RealmClientsWithClientUuidEvaluateScopesGenerateExampleAccessTokenGet
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidEvaluateScopesGenerateExampleAccessTokenGetArgs {
     pub audience: Option<String>,
     pub scope: Option<String>,
@@ -970,7 +992,7 @@ pub struct RealmClientsWithClientUuidEvaluateScopesGenerateExampleIdTokenGet<
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidEvaluateScopesGenerateExampleIdTokenGetArgs {
     pub audience: Option<String>,
     pub scope: Option<String>,
@@ -1025,7 +1047,7 @@ pub struct RealmClientsWithClientUuidEvaluateScopesGenerateExampleUserinfoGet<
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidEvaluateScopesGenerateExampleUserinfoGetArgs {
     pub scope: Option<String>,
     pub user_id: Option<String>,
@@ -1072,7 +1094,7 @@ pub struct RealmClientsWithClientUuidEvaluateScopesProtocolMappersGet<'a, TS: Ke
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidEvaluateScopesProtocolMappersGetArgs {
     pub scope: Option<String>,
 }
@@ -1120,7 +1142,7 @@ pub struct RealmClientsWithClientUuidEvaluateScopesScopeMappingsWithRoleContaine
     pub role_container_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidEvaluateScopesScopeMappingsWithRoleContainerIdGrantedGetArgs {
     pub scope: Option<String>,
 }
@@ -1171,7 +1193,7 @@ pub struct RealmClientsWithClientUuidEvaluateScopesScopeMappingsWithRoleContaine
     pub role_container_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidEvaluateScopesScopeMappingsWithRoleContainerIdNotGrantedGetArgs
 {
     pub scope: Option<String>,
@@ -1224,7 +1246,7 @@ pub struct RealmClientsWithClientUuidOfflineSessionsGet<'a, TS: KeycloakTokenSup
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidOfflineSessionsGetArgs {
     /// Paging offset
     pub first: Option<i32>,
@@ -1264,6 +1286,28 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmClientsWithClientUuidOfflineSessionsGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            client_uuid: self.client_uuid,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmClientsWithClientUuidOfflineSessionsGet<'_, TS>
+{
+    type Item = UserSessionRepresentation;
+
+    fn with_page(_args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+        }
+    }
+}
+
 pub struct RealmClientsWithClientUuidUserSessionsGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
@@ -1271,7 +1315,7 @@ pub struct RealmClientsWithClientUuidUserSessionsGet<'a, TS: KeycloakTokenSuppli
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidUserSessionsGetArgs {
     /// Paging offset
     pub first: Option<i32>,
@@ -1311,6 +1355,28 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmClientsWithClientUuidUserSessionsGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            client_uuid: self.client_uuid,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmClientsWithClientUuidUserSessionsGet<'_, TS>
+{
+    type Item = UserSessionRepresentation;
+
+    fn with_page(_args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+        }
+    }
+}
+
 #[cfg(feature = "builder")]
 mod builder {
     use crate::builder::Builder;
@@ -126,7 +126,7 @@ pub struct RealmComponentsGet<'a, TS: KeycloakTokenSupplier> {
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmComponentsGetArgs {
     pub name: Option<String>,
     pub parent: Option<String>,
@@ -176,7 +176,7 @@ pub struct RealmComponentsWithIdSubComponentTypesGet<'a, TS: KeycloakTokenSuppli
     pub id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmComponentsWithIdSubComponentTypesGetArgs {
     pub type_: Option<String>,
 }
@@ -269,7 +269,7 @@ pub struct RealmGroupsGet<'a, TS: KeycloakTokenSupplier> {
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmGroupsGetArgs {
     pub brief_representation: Option<bool>,
     pub exact: Option<bool>,
@@ -326,12 +326,34 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmGroupsGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmGroupsGet<'_, TS>
+{
+    type Item = GroupRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmGroupsCountGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmGroupsCountGetArgs {
     pub search: Option<String>,
     pub top: Option<bool>,
@@ -370,7 +392,7 @@ pub struct RealmGroupsWithGroupIdChildrenGet<'a, TS: KeycloakTokenSupplier> {
     pub group_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmGroupsWithGroupIdChildrenGetArgs {
     /// Boolean which defines whether brief groups representations are returned or not (default: false)
     pub brief_representation: Option<bool>,
@@ -429,13 +451,36 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmGroupsWithGroupIdChildrenGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            group_id: self.group_id,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmGroupsWithGroupIdChildrenGet<'_, TS>
+{
+    type Item = GroupRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmGroupsWithGroupIdMembersGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
     pub group_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmGroupsWithGroupIdMembersGetArgs {
     /// Only return basic information (only guaranteed to return id, username, created, first and last name, email, enabled state, email verification state, federation link, and access. Note that it means that namely user attributes, required actions, and not before are not returned.)
     pub brief_representation: Option<bool>,
@@ -482,6 +527,29 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmGroupsWithGroupIdMembersGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            group_id: self.group_id,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmGroupsWithGroupIdMembersGet<'_, TS>
+{
+    type Item = UserRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 #[cfg(feature = "builder")]
 mod builder {
     use crate::builder::Builder;
@@ -399,7 +399,7 @@ pub struct RealmIdentityProviderInstancesGet<'a, TS: KeycloakTokenSupplier> {
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmIdentityProviderInstancesGetArgs {
     /// Boolean which defines whether brief representations are returned (default: false)
     pub brief_representation: Option<bool>,
@@ -461,13 +461,35 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmIdentityProviderInstancesGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmIdentityProviderInstancesGet<'_, TS>
+{
+    type Item = IdentityProviderRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmIdentityProviderInstancesWithAliasExportGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
     pub alias: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmIdentityProviderInstancesWithAliasExportGetArgs {
     /// Format to use
     pub format: Option<String>,
@@ -7,7 +7,7 @@ use serde_json::Value;
 
 use crate::{
     types::*, DefaultResponse, KeycloakError, KeycloakRealmAdmin, KeycloakRealmAdminMethod,
-    KeycloakTokenSupplier,
+    KeycloakRealmAdminPagedMethod, KeycloakTokenSupplier,
 };
 
 /// Attack Detection
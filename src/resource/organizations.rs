@@ -980,7 +980,7 @@ pub struct RealmOrganizationsGet<'a, TS: KeycloakTokenSupplier> {
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmOrganizationsGetArgs {
     /// if false, return the full representation. Otherwise, only the basic fields are returned.
     pub brief_representation: Option<bool>,
@@ -1036,12 +1036,34 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmOrganizationsGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmOrganizationsGet<'_, TS>
+{
+    type Item = OrganizationRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmOrganizationsCountGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmOrganizationsCountGetArgs {
     /// Boolean which defines whether the param 'search' must match exactly or not
     pub exact: Option<bool>,
@@ -1087,7 +1109,7 @@ pub struct RealmOrganizationsMembersWithMemberIdOrganizationsGet<'a, TS: Keycloa
     pub member_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmOrganizationsMembersWithMemberIdOrganizationsGetArgs {
     /// if false, return the full representation. Otherwise, only the basic fields are returned.
     pub brief_representation: Option<bool>,
@@ -1132,7 +1154,7 @@ pub struct RealmOrganizationsWithOrgIdGroupsGet<'a, TS: KeycloakTokenSupplier> {
     pub org_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmOrganizationsWithOrgIdGroupsGetArgs {
     pub brief_representation: Option<bool>,
     pub exact: Option<bool>,
@@ -1191,6 +1213,29 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmOrganizationsWithOrgIdGroupsGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            org_id: self.org_id,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmOrganizationsWithOrgIdGroupsGet<'_, TS>
+{
+    type Item = GroupRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmOrganizationsWithOrgIdGroupsGroupByPathWithPathGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
@@ -1198,7 +1243,7 @@ pub struct RealmOrganizationsWithOrgIdGroupsGroupByPathWithPathGet<'a, TS: Keycl
     pub path: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmOrganizationsWithOrgIdGroupsGroupByPathWithPathGetArgs {
     /// Whether to return the count of subgroups (default: false)
     pub sub_groups_count: Option<bool>,
@@ -1243,7 +1288,7 @@ pub struct RealmOrganizationsWithOrgIdGroupsWithGroupIdGet<'a, TS: KeycloakToken
     pub group_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmOrganizationsWithOrgIdGroupsWithGroupIdGetArgs {
     /// Whether to return the count of subgroups (default: false)
     pub sub_groups_count: Option<bool>,
@@ -1288,7 +1333,7 @@ pub struct RealmOrganizationsWithOrgIdGroupsWithGroupIdChildrenGet<'a, TS: Keycl
     pub group_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmOrganizationsWithOrgIdGroupsWithGroupIdChildrenGetArgs {
     /// Boolean which defines whether the params "search" must match exactly or not
     pub exact: Option<bool>,
@@ -1344,6 +1389,32 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone
+    for RealmOrganizationsWithOrgIdGroupsWithGroupIdChildrenGet<'_, TS>
+{
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            org_id: self.org_id,
+            group_id: self.group_id,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmOrganizationsWithOrgIdGroupsWithGroupIdChildrenGet<'_, TS>
+{
+    type Item = GroupRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmOrganizationsWithOrgIdGroupsWithGroupIdMembersGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
@@ -1351,7 +1422,7 @@ pub struct RealmOrganizationsWithOrgIdGroupsWithGroupIdMembersGet<'a, TS: Keyclo
     pub group_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmOrganizationsWithOrgIdGroupsWithGroupIdMembersGetArgs {
     /// Only return basic information (only guaranteed to return id, username, created, first and last name, email, enabled state, email verification state, federation link, and access. Note that it means that namely user attributes, required actions, and not before are not returned.)
     pub brief_representation: Option<bool>,
@@ -1399,6 +1470,32 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone
+    for RealmOrganizationsWithOrgIdGroupsWithGroupIdMembersGet<'_, TS>
+{
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            org_id: self.org_id,
+            group_id: self.group_id,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmOrganizationsWithOrgIdGroupsWithGroupIdMembersGet<'_, TS>
+{
+    type Item = MemberRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmOrganizationsWithOrgIdIdentityProvidersWithAliasGroupsGet<
     'a,
     TS: KeycloakTokenSupplier,
@@ -1410,7 +1507,7 @@ pub struct RealmOrganizationsWithOrgIdIdentityProvidersWithAliasGroupsGet<
     pub alias: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmOrganizationsWithOrgIdIdentityProvidersWithAliasGroupsGetArgs {
     /// If true, return brief representation; otherwise return full representation
     pub brief_representation: Option<bool>,
@@ -1474,13 +1571,39 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone
+    for RealmOrganizationsWithOrgIdIdentityProvidersWithAliasGroupsGet<'_, TS>
+{
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            org_id: self.org_id,
+            alias: self.alias,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmOrganizationsWithOrgIdIdentityProvidersWithAliasGroupsGet<'_, TS>
+{
+    type Item = GroupRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmOrganizationsWithOrgIdInvitationsGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
     pub org_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmOrganizationsWithOrgIdInvitationsGetArgs {
     pub email: Option<String>,
     pub first: Option<i32>,
@@ -1536,13 +1659,36 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmOrganizationsWithOrgIdInvitationsGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            org_id: self.org_id,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmOrganizationsWithOrgIdInvitationsGet<'_, TS>
+{
+    type Item = OrganizationInvitationRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmOrganizationsWithOrgIdMembersGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
     pub org_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmOrganizationsWithOrgIdMembersGetArgs {
     /// Boolean which defines whether the param 'search' must match exactly or not
     pub exact: Option<bool>,
@@ -1597,6 +1743,29 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmOrganizationsWithOrgIdMembersGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            org_id: self.org_id,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmOrganizationsWithOrgIdMembersGet<'_, TS>
+{
+    type Item = MemberRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmOrganizationsWithOrgIdMembersWithMemberIdGroupsGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
@@ -1604,7 +1773,7 @@ pub struct RealmOrganizationsWithOrgIdMembersWithMemberIdGroupsGet<'a, TS: Keycl
     pub member_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmOrganizationsWithOrgIdMembersWithMemberIdGroupsGetArgs {
     pub brief_representation: Option<bool>,
     pub first: Option<i32>,
@@ -1652,6 +1821,32 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone
+    for RealmOrganizationsWithOrgIdMembersWithMemberIdGroupsGet<'_, TS>
+{
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            org_id: self.org_id,
+            member_id: self.member_id,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmOrganizationsWithOrgIdMembersWithMemberIdGroupsGet<'_, TS>
+{
+    type Item = GroupRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmOrganizationsWithOrgIdMembersWithMemberIdOrganizationsGet<
     'a,
     TS: KeycloakTokenSupplier,
@@ -1662,7 +1857,7 @@ pub struct RealmOrganizationsWithOrgIdMembersWithMemberIdOrganizationsGet<
     pub member_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmOrganizationsWithOrgIdMembersWithMemberIdOrganizationsGetArgs {
     /// if false, return the full representation. Otherwise, only the basic fields are returned.
     pub brief_representation: Option<bool>,
@@ -862,7 +862,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerPermissionGet<'a, TS: Ke
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerPermissionGetArgs {
     pub fields: Option<String>,
     pub first: Option<i32>,
@@ -930,6 +930,31 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone
+    for RealmClientsWithClientUuidAuthzResourceServerPermissionGet<'_, TS>
+{
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            client_uuid: self.client_uuid,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmClientsWithClientUuidAuthzResourceServerPermissionGet<'_, TS>
+{
+    type Item = AbstractPolicyRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmClientsWithClientUuidAuthzResourceServerPermissionSearchGet<
     'a,
     TS: KeycloakTokenSupplier,
@@ -940,7 +965,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerPermissionSearchGet<
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerPermissionSearchGetArgs {
     pub fields: Option<String>,
     pub name: Option<String>,
@@ -985,7 +1010,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerPolicyGet<'a, TS: Keyclo
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerPolicyGetArgs {
     pub fields: Option<String>,
     pub first: Option<i32>,
@@ -1053,6 +1078,31 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone
+    for RealmClientsWithClientUuidAuthzResourceServerPolicyGet<'_, TS>
+{
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            client_uuid: self.client_uuid,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmClientsWithClientUuidAuthzResourceServerPolicyGet<'_, TS>
+{
+    type Item = AbstractPolicyRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmClientsWithClientUuidAuthzResourceServerPolicySearchGet<
     'a,
     TS: KeycloakTokenSupplier,
@@ -1063,7 +1113,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerPolicySearchGet<
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerPolicySearchGetArgs {
     pub fields: Option<String>,
     pub name: Option<String>,
@@ -1108,7 +1158,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerResourceGet<'a, TS: Keyc
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerResourceGetArgs {
     pub id: Option<String>,
     pub deep: Option<bool>,
@@ -1176,6 +1226,31 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone
+    for RealmClientsWithClientUuidAuthzResourceServerResourceGet<'_, TS>
+{
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            client_uuid: self.client_uuid,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmClientsWithClientUuidAuthzResourceServerResourceGet<'_, TS>
+{
+    type Item = ResourceRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmClientsWithClientUuidAuthzResourceServerResourcePost<'a, TS: KeycloakTokenSupplier>
 {
     /// Realm admin client
@@ -1185,7 +1260,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerResourcePost<'a, TS: Key
     pub body: ResourceRepresentation,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerResourcePostArgs {
     pub id: Option<String>,
     pub deep: Option<bool>,
@@ -1264,7 +1339,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerResourceSearchGet<
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerResourceSearchGetArgs {
     pub id: Option<String>,
     pub deep: Option<bool>,
@@ -1343,7 +1418,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdGe
     pub resource_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdGetArgs {
     pub id: Option<String>,
     pub deep: Option<bool>,
@@ -1425,7 +1500,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdPu
     pub body: ResourceRepresentation,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdPutArgs {
     pub id: Option<String>,
     pub deep: Option<bool>,
@@ -1507,7 +1582,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdDe
     pub resource_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdDeleteArgs {
     pub id: Option<String>,
     pub deep: Option<bool>,
@@ -1588,7 +1663,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdAt
     pub resource_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdAttributesGetArgs {
     pub id: Option<String>,
     pub deep: Option<bool>,
@@ -1670,7 +1745,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdPe
     pub resource_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdPermissionsGetArgs {
     pub id: Option<String>,
     pub deep: Option<bool>,
@@ -1741,6 +1816,32 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone
+    for RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdPermissionsGet<'_, TS>
+{
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            client_uuid: self.client_uuid,
+            resource_id: self.resource_id,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdPermissionsGet<'_, TS>
+{
+    type Item = PolicyRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdScopesGet<
     'a,
     TS: KeycloakTokenSupplier,
@@ -1752,7 +1853,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdSc
     pub resource_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdScopesGetArgs {
     pub id: Option<String>,
     pub deep: Option<bool>,
@@ -1822,6 +1923,32 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone
+    for RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdScopesGet<'_, TS>
+{
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            client_uuid: self.client_uuid,
+            resource_id: self.resource_id,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmClientsWithClientUuidAuthzResourceServerResourceWithResourceIdScopesGet<'_, TS>
+{
+    type Item = ScopeRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmClientsWithClientUuidAuthzResourceServerScopeGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
@@ -1829,7 +1956,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerScopeGet<'a, TS: Keycloa
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerScopeGetArgs {
     pub first: Option<i32>,
     pub max: Option<i32>,
@@ -1876,6 +2003,31 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone
+    for RealmClientsWithClientUuidAuthzResourceServerScopeGet<'_, TS>
+{
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            client_uuid: self.client_uuid,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmClientsWithClientUuidAuthzResourceServerScopeGet<'_, TS>
+{
+    type Item = ScopeRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmClientsWithClientUuidAuthzResourceServerScopeSearchGet<
     'a,
     TS: KeycloakTokenSupplier,
@@ -1886,7 +2038,7 @@ pub struct RealmClientsWithClientUuidAuthzResourceServerScopeSearchGet<
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidAuthzResourceServerScopeSearchGetArgs {
     pub name: Option<String>,
 }
@@ -868,7 +868,7 @@ pub struct RealmAdminEventsGet<'a, TS: KeycloakTokenSupplier> {
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmAdminEventsGetArgs {
     pub auth_client: Option<String>,
     pub auth_ip_address: Option<String>,
@@ -941,12 +941,34 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmAdminEventsGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmAdminEventsGet<'_, TS>
+{
+    type Item = AdminEventRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmClientPoliciesPoliciesGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientPoliciesPoliciesGetArgs {
     pub include_global_policies: Option<bool>,
 }
@@ -985,7 +1007,7 @@ pub struct RealmClientPoliciesProfilesGet<'a, TS: KeycloakTokenSupplier> {
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientPoliciesProfilesGetArgs {
     pub include_global_profiles: Option<bool>,
 }
@@ -1024,7 +1046,7 @@ pub struct RealmEventsGet<'a, TS: KeycloakTokenSupplier> {
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmEventsGetArgs {
     /// App or oauth client name
     pub client: Option<String>,
@@ -1092,13 +1114,35 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmEventsGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmEventsGet<'_, TS>
+{
+    type Item = EventRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmLocalizationWithLocaleGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
     pub locale: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmLocalizationWithLocaleGetArgs {
     pub use_realm_default_locale_fallback: Option<bool>,
 }
@@ -1139,7 +1183,7 @@ pub struct RealmPartialExportPost<'a, TS: KeycloakTokenSupplier> {
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmPartialExportPostArgs {
     pub export_clients: Option<bool>,
     pub export_groups_and_roles: Option<bool>,
@@ -1183,7 +1227,7 @@ pub struct RealmSessionsWithSessionDelete<'a, TS: KeycloakTokenSupplier> {
     pub session: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmSessionsWithSessionDeleteArgs {
     pub is_offline: Option<bool>,
 }
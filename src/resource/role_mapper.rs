@@ -300,7 +300,7 @@ pub struct RealmGroupsWithGroupIdRoleMappingsRealmCompositeGet<'a, TS: KeycloakT
     pub group_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmGroupsWithGroupIdRoleMappingsRealmCompositeGetArgs {
     /// if false, return roles with their attributes
     pub brief_representation: Option<bool>,
@@ -345,7 +345,7 @@ pub struct RealmUsersWithUserIdRoleMappingsRealmCompositeGet<'a, TS: KeycloakTok
     pub user_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmUsersWithUserIdRoleMappingsRealmCompositeGetArgs {
     /// if false, return roles with their attributes
     pub brief_representation: Option<bool>,
@@ -774,7 +774,7 @@ pub struct RealmClientsWithClientUuidRolesGet<'a, TS: KeycloakTokenSupplier> {
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidRolesGetArgs {
     pub brief_representation: Option<bool>,
     pub first: Option<i32>,
@@ -821,6 +821,29 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmClientsWithClientUuidRolesGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            client_uuid: self.client_uuid,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmClientsWithClientUuidRolesGet<'_, TS>
+{
+    type Item = RoleRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmClientsWithClientUuidRolesWithRoleNameGroupsGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
@@ -830,7 +853,7 @@ pub struct RealmClientsWithClientUuidRolesWithRoleNameGroupsGet<'a, TS: Keycloak
     pub role_name: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidRolesWithRoleNameGroupsGetArgs {
     /// if false, return a full representation of the {@code GroupRepresentation} objects.
     pub brief_representation: Option<bool>,
@@ -878,6 +901,32 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone
+    for RealmClientsWithClientUuidRolesWithRoleNameGroupsGet<'_, TS>
+{
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            client_uuid: self.client_uuid,
+            role_name: self.role_name,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmClientsWithClientUuidRolesWithRoleNameGroupsGet<'_, TS>
+{
+    type Item = UserRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmClientsWithClientUuidRolesWithRoleNameUsersGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
@@ -887,7 +936,7 @@ pub struct RealmClientsWithClientUuidRolesWithRoleNameUsersGet<'a, TS: KeycloakT
     pub role_name: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidRolesWithRoleNameUsersGetArgs {
     /// Boolean which defines whether brief representations are returned (default: false)
     pub brief_representation: Option<bool>,
@@ -935,12 +984,38 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone
+    for RealmClientsWithClientUuidRolesWithRoleNameUsersGet<'_, TS>
+{
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            client_uuid: self.client_uuid,
+            role_name: self.role_name,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmClientsWithClientUuidRolesWithRoleNameUsersGet<'_, TS>
+{
+    type Item = UserRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmRolesGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmRolesGetArgs {
     pub brief_representation: Option<bool>,
     pub first: Option<i32>,
@@ -984,6 +1059,28 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmRolesGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmRolesGet<'_, TS>
+{
+    type Item = RoleRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmRolesWithRoleNameGroupsGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
@@ -991,7 +1088,7 @@ pub struct RealmRolesWithRoleNameGroupsGet<'a, TS: KeycloakTokenSupplier> {
     pub role_name: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmRolesWithRoleNameGroupsGetArgs {
     /// if false, return a full representation of the {@code GroupRepresentation} objects.
     pub brief_representation: Option<bool>,
@@ -1038,6 +1135,29 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmRolesWithRoleNameGroupsGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            role_name: self.role_name,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmRolesWithRoleNameGroupsGet<'_, TS>
+{
+    type Item = UserRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmRolesWithRoleNameUsersGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
@@ -1045,7 +1165,7 @@ pub struct RealmRolesWithRoleNameUsersGet<'a, TS: KeycloakTokenSupplier> {
     pub role_name: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmRolesWithRoleNameUsersGetArgs {
     /// Boolean which defines whether brief representations are returned (default: false)
     pub brief_representation: Option<bool>,
@@ -1090,6 +1210,29 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmRolesWithRoleNameUsersGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            role_name: self.role_name,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmRolesWithRoleNameUsersGet<'_, TS>
+{
+    type Item = UserRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 #[cfg(feature = "builder")]
 mod builder {
     use crate::builder::Builder;
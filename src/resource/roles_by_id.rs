@@ -261,7 +261,7 @@ pub struct RealmRolesByIdWithRoleIdCompositesGet<'a, TS: KeycloakTokenSupplier>
     pub role_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmRolesByIdWithRoleIdCompositesGetArgs {
     pub first: Option<i32>,
     pub max: Option<i32>,
@@ -301,6 +301,29 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmRolesByIdWithRoleIdCompositesGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            role_id: self.role_id,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmRolesByIdWithRoleIdCompositesGet<'_, TS>
+{
+    type Item = RoleRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 #[cfg(feature = "builder")]
 mod builder {
     use crate::builder::Builder;
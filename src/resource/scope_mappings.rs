@@ -950,7 +950,7 @@ pub struct RealmClientScopesWithClientScopeIdScopeMappingsClientsWithClientCompo
     pub client: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientScopesWithClientScopeIdScopeMappingsClientsWithClientCompositeGetArgs {
     /// if false, return roles with their attributes
     pub brief_representation: Option<bool>,
@@ -1000,7 +1000,7 @@ pub struct RealmClientScopesWithClientScopeIdScopeMappingsRealmCompositeGet<
     pub client_scope_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientScopesWithClientScopeIdScopeMappingsRealmCompositeGetArgs {
     /// if false, return roles with their attributes
     pub brief_representation: Option<bool>,
@@ -1049,7 +1049,7 @@ pub struct RealmClientTemplatesWithClientScopeIdScopeMappingsClientsWithClientCo
     pub client: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientTemplatesWithClientScopeIdScopeMappingsClientsWithClientCompositeGetArgs {
     /// if false, return roles with their attributes
     pub brief_representation: Option<bool>,
@@ -1099,7 +1099,7 @@ pub struct RealmClientTemplatesWithClientScopeIdScopeMappingsRealmCompositeGet<
     pub client_scope_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientTemplatesWithClientScopeIdScopeMappingsRealmCompositeGetArgs {
     /// if false, return roles with their attributes
     pub brief_representation: Option<bool>,
@@ -1150,7 +1150,7 @@ pub struct RealmClientsWithClientUuidScopeMappingsClientsWithClientCompositeGet<
     pub client: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidScopeMappingsClientsWithClientCompositeGetArgs {
     /// if false, return roles with their attributes
     pub brief_representation: Option<bool>,
@@ -1198,7 +1198,7 @@ pub struct RealmClientsWithClientUuidScopeMappingsRealmCompositeGet<'a, TS: Keyc
     pub client_uuid: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmClientsWithClientUuidScopeMappingsRealmCompositeGetArgs {
     /// if false, return roles with their attributes
     pub brief_representation: Option<bool>,
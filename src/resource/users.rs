@@ -867,7 +867,7 @@ pub struct RealmUsersGet<'a, TS: KeycloakTokenSupplier> {
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmUsersGetArgs {
     /// Boolean which defines whether brief representations are returned (default: false)
     pub brief_representation: Option<bool>,
@@ -963,12 +963,34 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmUsersGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmUsersGet<'_, TS>
+{
+    type Item = UserRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmUsersCountGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmUsersCountGetArgs {
     /// Only return users created after (inclusive) the given date, in ISO-8601 format (yyyy-MM-dd) or epoch milliseconds
     pub created_after: Option<String>,
@@ -1058,7 +1080,7 @@ pub struct RealmUsersWithUserIdGet<'a, TS: KeycloakTokenSupplier> {
     pub user_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmUsersWithUserIdGetArgs {
     /// Indicates if the user profile metadata should be added to the response
     pub user_profile_metadata: Option<bool>,
@@ -1102,7 +1124,7 @@ pub struct RealmUsersWithUserIdExecuteActionsEmailPut<'a, TS: KeycloakTokenSuppl
     pub body: Vec<String>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmUsersWithUserIdExecuteActionsEmailPutArgs {
     /// Client id
     pub client_id: Option<String>,
@@ -1156,7 +1178,7 @@ pub struct RealmUsersWithUserIdGroupsGet<'a, TS: KeycloakTokenSupplier> {
     pub user_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmUsersWithUserIdGroupsGetArgs {
     pub brief_representation: Option<bool>,
     pub first: Option<i32>,
@@ -1201,13 +1223,36 @@ where
     }
 }
 
+impl<TS: KeycloakTokenSupplier> Clone for RealmUsersWithUserIdGroupsGet<'_, TS> {
+    fn clone(&self) -> Self {
+        Self {
+            realm_admin: self.realm_admin,
+            user_id: self.user_id,
+        }
+    }
+}
+
+impl<TS: KeycloakTokenSupplier + Send + Sync> KeycloakRealmAdminPagedMethod
+    for RealmUsersWithUserIdGroupsGet<'_, TS>
+{
+    type Item = GroupRepresentation;
+
+    fn with_page(args: Self::Args, first: i32, max: i32) -> Self::Args {
+        Self::Args {
+            first: Some(first),
+            max: Some(max),
+            ..args
+        }
+    }
+}
+
 pub struct RealmUsersWithUserIdGroupsCountGet<'a, TS: KeycloakTokenSupplier> {
     /// Realm admin client
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
     pub user_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmUsersWithUserIdGroupsCountGetArgs {
     pub search: Option<String>,
 }
@@ -1245,7 +1290,7 @@ pub struct RealmUsersWithUserIdResetPasswordEmailPut<'a, TS: KeycloakTokenSuppli
     pub user_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmUsersWithUserIdResetPasswordEmailPutArgs {
     /// client id
     pub client_id: Option<String>,
@@ -1294,7 +1339,7 @@ pub struct RealmUsersWithUserIdSendVerifyEmailPut<'a, TS: KeycloakTokenSupplier>
     pub user_id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmUsersWithUserIdSendVerifyEmailPutArgs {
     /// Client id
     pub client_id: Option<String>,
@@ -220,7 +220,7 @@ pub struct RealmWorkflowsGet<'a, TS: KeycloakTokenSupplier> {
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmWorkflowsGetArgs {
     /// Boolean which defines whether the param 'search' must match exactly or not
     pub exact: Option<bool>,
@@ -273,7 +273,7 @@ pub struct RealmWorkflowsMigratePost<'a, TS: KeycloakTokenSupplier> {
     pub realm_admin: &'a KeycloakRealmAdmin<'a, TS>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmWorkflowsMigratePostArgs {
     /// A String representing the id of the step to migrate from
     pub from: Option<String>,
@@ -315,7 +315,7 @@ pub struct RealmWorkflowsWithIdGet<'a, TS: KeycloakTokenSupplier> {
     pub id: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmWorkflowsWithIdGetArgs {
     /// Indicates whether the workflow and step ids should be included in the representation or not - defaults to true
     pub include_id: Option<bool>,
@@ -361,7 +361,7 @@ pub struct RealmWorkflowsWithIdActivateWithTypeWithResourceIdPost<'a, TS: Keyclo
     pub type_: &'a str,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RealmWorkflowsWithIdActivateWithTypeWithResourceIdPostArgs {
     /// Optional value representing the time to schedule the first workflow step. The value is either an integer representing the seconds from now, an integer followed by 'ms' representing milliseconds from now, or an ISO-8601 date string.
     pub not_before: Option<String>,
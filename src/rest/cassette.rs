@@ -0,0 +1,472 @@
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use http::{
+    header::{AUTHORIZATION, COOKIE, SET_COOKIE},
+    HeaderMap, HeaderName, HeaderValue, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{KeycloakRequest, KeycloakRequestBody, KeycloakResponse, KeycloakTransport};
+use crate::KeycloakError;
+
+/// Placeholder of redacted tokens and credentials.
+const REDACTED: &str = "[redacted]";
+
+/// JSON fields and form parameters holding tokens or credentials, compared
+/// ignoring case.
+const SECRET_FIELDS: &[&str] = &[
+    "access_token",
+    "refresh_token",
+    "id_token",
+    "client_secret",
+    "client_assertion",
+    "subject_token",
+    "device_code",
+    "token",
+    "password",
+    // `value` of `CredentialRepresentation`, e.g. passwords and client secrets
+    "value",
+    "secretData",
+    // `secret` of `ClientRepresentation`
+    "secret",
+    // `config.clientSecret` of `IdentityProviderRepresentation`
+    "clientSecret",
+];
+
+/// Recorded requests and responses, stored as JSON file.
+///
+/// Recorded with [`KeycloakRecorder`] and served by [`KeycloakReplay`].
+/// `Authorization` and cookie headers are never recorded, and token and
+/// credential fields of JSON and form bodies are redacted. Requests are
+/// redacted the same way before they are matched, so replayed requests may
+/// carry the real credentials.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct KeycloakCassette {
+    pub interactions: Vec<KeycloakInteraction>,
+}
+
+/// Request and response of a [`KeycloakCassette`].
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct KeycloakInteraction {
+    pub request: KeycloakRecordedRequest,
+    pub response: KeycloakRecordedResponse,
+}
+
+/// Request of a [`KeycloakInteraction`], matched by all of its fields.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct KeycloakRecordedRequest {
+    pub method: String,
+    /// Path of the URL, without scheme and host.
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub query: Vec<(String, String)>,
+    /// JSON body, or text of form and plain text bodies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+/// Response of a [`KeycloakInteraction`].
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct KeycloakRecordedResponse {
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<(String, String)>,
+    /// Body if it is valid UTF-8.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub body: String,
+    /// Base64 encoded body if it is not valid UTF-8.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_base64: Option<String>,
+}
+
+impl KeycloakCassette {
+    /// Reads a cassette from the JSON file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let json = fs::read(path)?;
+        serde_json::from_slice(&json).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+    }
+
+    /// Writes the cassette as JSON file to `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let json = serde_json::to_vec_pretty(self)?;
+        fs::write(path, json)
+    }
+}
+
+impl KeycloakRecordedRequest {
+    fn new(request: &KeycloakRequest) -> Self {
+        let body = match request.body() {
+            KeycloakRequestBody::Json(json) => serde_json::from_slice(json).ok().map(redact),
+            KeycloakRequestBody::Form(form) => Some(Value::String(redact_form(form))),
+            KeycloakRequestBody::Text(text) => Some(Value::String(text.clone())),
+            KeycloakRequestBody::Empty | KeycloakRequestBody::Multipart(_) => None,
+        };
+        Self {
+            method: request.method().to_string(),
+            path: url_path(request.url()).into(),
+            query: request.query_pairs().to_vec(),
+            body,
+        }
+    }
+}
+
+impl KeycloakRecordedResponse {
+    fn new(response: &KeycloakResponse) -> Self {
+        let headers = response
+            .headers()
+            .iter()
+            .filter(|(name, _)| !is_secret_header(name))
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.into())))
+            .collect();
+        let (body, body_base64) = match std::str::from_utf8(response.body()) {
+            Ok(text) => match serde_json::from_str(text) {
+                Ok(json) => (redact(json).to_string(), None),
+                Err(_) => (text.into(), None),
+            },
+            Err(_) => (String::new(), Some(STANDARD.encode(response.body()))),
+        };
+        Self {
+            status: response.status().as_u16(),
+            headers,
+            body,
+            body_base64,
+        }
+    }
+
+    fn to_response(&self) -> Result<KeycloakResponse, KeycloakError> {
+        let invalid = |e: &dyn std::fmt::Display| {
+            KeycloakError::TransportFailure(format!("invalid recorded response: {e}").into())
+        };
+        let status = StatusCode::from_u16(self.status).map_err(|e| invalid(&e))?;
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            headers.append(
+                HeaderName::try_from(name).map_err(|e| invalid(&e))?,
+                HeaderValue::try_from(value).map_err(|e| invalid(&e))?,
+            );
+        }
+        let body = match &self.body_base64 {
+            Some(body) => STANDARD.decode(body).map_err(|e| invalid(&e))?,
+            None => self.body.clone().into_bytes(),
+        };
+        Ok(KeycloakResponse::new(status, headers, body))
+    }
+}
+
+/// [`KeycloakTransport`] recording the requests sent with another transport.
+///
+/// Clones share the recorded interactions.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+/// use keycloak::{prelude::reqwest, KeycloakAdmin, KeycloakAdminToken, KeycloakRecorder};
+///
+/// let client = reqwest::Client::new();
+/// let url = "http://localhost:8080";
+/// let admin_token = KeycloakAdminToken::acquire(url, "admin", "password", &client).await?;
+///
+/// let recorder = KeycloakRecorder::new(client);
+/// let admin = KeycloakAdmin::new(url, admin_token, recorder.clone());
+/// admin.realm("test").get().await?;
+///
+/// recorder.cassette().save("tests/cassettes/users.json")?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone)]
+pub struct KeycloakRecorder<T> {
+    transport: T,
+    cassette: Arc<Mutex<KeycloakCassette>>,
+}
+
+impl<T: KeycloakTransport> KeycloakRecorder<T> {
+    /// Creates a recorder sending the requests with `transport`.
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            cassette: Default::default(),
+        }
+    }
+
+    /// Returns the interactions recorded so far.
+    pub fn cassette(&self) -> KeycloakCassette {
+        self.cassette
+            .lock()
+            .expect("cassette lock poisoned")
+            .clone()
+    }
+}
+
+#[async_trait]
+impl<T: KeycloakTransport> KeycloakTransport for KeycloakRecorder<T> {
+    async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+        let recorded_request = KeycloakRecordedRequest::new(&request);
+        let response = self.transport.send(request).await?;
+        self.cassette
+            .lock()
+            .expect("cassette lock poisoned")
+            .interactions
+            .push(KeycloakInteraction {
+                request: recorded_request,
+                response: KeycloakRecordedResponse::new(&response),
+            });
+        Ok(response)
+    }
+}
+
+/// [`KeycloakTransport`] answering requests from a [`KeycloakCassette`].
+///
+/// A request is answered with the response of the first unused interaction
+/// with the same method, path, query and body. Requests without such
+/// interaction fail with [`KeycloakError::TransportFailure`].
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+/// use keycloak::{KeycloakAdmin, KeycloakCassette, KeycloakError, KeycloakReplay, KeycloakTokenSupplier};
+///
+/// /// Tokens are not recorded, any token does.
+/// struct ReplayToken;
+///
+/// #[async_trait::async_trait]
+/// impl KeycloakTokenSupplier for ReplayToken {
+///     async fn get(&self, _url: &str) -> Result<String, KeycloakError> {
+///         Ok("replay".into())
+///     }
+/// }
+///
+/// let replay = KeycloakReplay::new(KeycloakCassette::load("tests/cassettes/users.json")?);
+/// let admin = KeycloakAdmin::new("http://localhost:8080", ReplayToken, replay.clone());
+/// admin.realm("test").get().await?;
+///
+/// assert_eq!(replay.unused(), 0);
+/// # Ok(()) }
+/// ```
+#[derive(Clone)]
+pub struct KeycloakReplay {
+    interactions: Arc<Mutex<Vec<Option<KeycloakInteraction>>>>,
+}
+
+impl KeycloakReplay {
+    pub fn new(cassette: KeycloakCassette) -> Self {
+        Self {
+            interactions: Arc::new(Mutex::new(
+                cassette.interactions.into_iter().map(Some).collect(),
+            )),
+        }
+    }
+
+    /// Returns the number of interactions which were not replayed yet.
+    pub fn unused(&self) -> usize {
+        self.interactions
+            .lock()
+            .expect("cassette lock poisoned")
+            .iter()
+            .flatten()
+            .count()
+    }
+}
+
+#[async_trait]
+impl KeycloakTransport for KeycloakReplay {
+    async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+        let recorded_request = KeycloakRecordedRequest::new(&request);
+        let interaction = self
+            .interactions
+            .lock()
+            .expect("cassette lock poisoned")
+            .iter_mut()
+            .find(|interaction| {
+                matches!(interaction, Some(interaction) if interaction.request == recorded_request)
+            })
+            .and_then(Option::take)
+            .ok_or_else(|| {
+                KeycloakError::TransportFailure(
+                    format!(
+                        "no recorded interaction for {} {}",
+                        request.method(),
+                        request.url_with_query()
+                    )
+                    .into(),
+                )
+            })?;
+        interaction.response.to_response()
+    }
+}
+
+/// Returns the path of `url`, e.g. `/admin/realms` for `http://localhost:8080/admin/realms`.
+fn url_path(url: &str) -> &str {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    without_scheme
+        .find('/')
+        .map_or("/", |start| &without_scheme[start..])
+}
+
+fn is_secret_header(name: &HeaderName) -> bool {
+    [AUTHORIZATION, COOKIE, SET_COOKIE].contains(name)
+}
+
+fn is_secret_field(name: &str) -> bool {
+    SECRET_FIELDS
+        .iter()
+        .any(|field| field.eq_ignore_ascii_case(name))
+}
+
+/// Replaces values of token and credential parameters of URL encoded `form`.
+fn redact_form(form: &str) -> String {
+    let pairs = form_urlencoded::parse(form.as_bytes()).map(|(name, value)| {
+        let value = if is_secret_field(&name) {
+            REDACTED.into()
+        } else {
+            value
+        };
+        (name, value)
+    });
+    form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs)
+        .finish()
+}
+
+/// Replaces values of token and credential fields in `value`.
+fn redact(mut value: Value) -> Value {
+    match &mut value {
+        Value::Object(object) => {
+            for (name, field) in object.iter_mut() {
+                *field = if is_secret_field(name) {
+                    Value::String(REDACTED.into())
+                } else {
+                    redact(field.take())
+                };
+            }
+        }
+        Value::Array(array) => {
+            for element in array.iter_mut() {
+                *element = redact(element.take());
+            }
+        }
+        _ => {}
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use http::{HeaderMap, StatusCode};
+    use serde_json::json;
+
+    use super::{KeycloakRecorder, KeycloakReplay};
+    use crate::{
+        types::*, KeycloakAdminToken, KeycloakError, KeycloakRequest, KeycloakResponse,
+        KeycloakTransport,
+    };
+
+    const SECRET: &str = "s3cr3t";
+
+    struct Keycloak;
+
+    #[async_trait]
+    impl KeycloakTransport for Keycloak {
+        async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+            let body = match request.url() {
+                url if url.ends_with("/token") => json!({
+                    "access_token": SECRET,
+                    "refresh_token": SECRET,
+                    "expires_in": 60,
+                    "token_type": "Bearer"
+                }),
+                url if url.ends_with("/client-secret") => json!(CredentialRepresentation {
+                    type_: Some("secret".into()),
+                    value: Some(SECRET.into()),
+                    ..Default::default()
+                }),
+                url if url.ends_with("/clients") => json!([ClientRepresentation {
+                    client_id: Some("app".into()),
+                    secret: Some(SECRET.into()),
+                    ..Default::default()
+                }]),
+                _ => json!(null),
+            };
+            Ok(KeycloakResponse::new(
+                StatusCode::OK,
+                HeaderMap::new(),
+                serde_json::to_vec(&body).unwrap(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn record_replay() {
+        let url = "http://keycloak/admin/realms/test";
+        let user = UserRepresentation {
+            username: Some("user".into()),
+            credentials: Some(
+                vec![CredentialRepresentation {
+                    type_: Some("password".into()),
+                    value: Some(SECRET.into()),
+                    temporary: Some(false),
+                    ..Default::default()
+                }]
+                .into(),
+            ),
+            ..Default::default()
+        };
+        let provider: IdentityProviderRepresentation = serde_json::from_value(json!({
+            "alias": "github",
+            "config": {"clientId": "app", "clientSecret": SECRET}
+        }))
+        .unwrap();
+        let requests = || {
+            [
+                KeycloakRequest::post(format!("{url}/users"))
+                    .json(&user)
+                    .unwrap(),
+                KeycloakRequest::put(format!("{url}/identity-provider/instances/github"))
+                    .json(&provider)
+                    .unwrap(),
+                KeycloakRequest::get(format!("{url}/clients/1/client-secret")),
+                KeycloakRequest::get(format!("{url}/clients")),
+            ]
+        };
+
+        let recorder = KeycloakRecorder::new(Keycloak);
+        let token = KeycloakAdminToken::acquire("http://keycloak", "admin", SECRET, &recorder)
+            .await
+            .unwrap();
+        assert_eq!(token.access_token(), SECRET);
+        let mut recorded = Vec::new();
+        for request in requests() {
+            recorded.push(recorder.send(request).await.unwrap());
+        }
+        let cassette = recorder.cassette();
+        let json = serde_json::to_string(&cassette).unwrap();
+        assert!(!json.contains(SECRET), "{json}");
+        assert_eq!(cassette.interactions.len(), 5);
+
+        let replay = KeycloakReplay::new(cassette);
+        assert!(replay
+            .send(KeycloakRequest::get(format!("{url}/users")))
+            .await
+            .is_err());
+        let token = KeycloakAdminToken::acquire("http://localhost", "admin", SECRET, &replay)
+            .await
+            .unwrap();
+        assert_eq!(token.access_token(), "[redacted]");
+        for (request, recorded) in requests().into_iter().zip(&recorded) {
+            let replayed = replay.send(request).await.unwrap();
+            assert_eq!(replayed.status(), recorded.status());
+        }
+        assert_eq!(replay.unused(), 0);
+        assert!(replay.send(requests()[0].clone()).await.is_err());
+    }
+}
@@ -1,7 +1,9 @@
-use crate::prelude::reqwest::{self, Response};
+use http::header::LOCATION;
 
-/// Default adapter around [Response].
-pub struct DefaultResponse(Response);
+use super::KeycloakResponse;
+
+/// Default adapter around [KeycloakResponse].
+pub struct DefaultResponse(KeycloakResponse);
 
 impl DefaultResponse {
     /// Id of resource created.
@@ -10,30 +12,30 @@ impl DefaultResponse {
     pub fn to_id(&self) -> Option<&'_ str> {
         self.0
             .headers()
-            .get(reqwest::header::LOCATION)
+            .get(LOCATION)
             .and_then(|v| v.to_str().ok())
             .and_then(|v| v.split('/').next_back())
     }
 
-    pub fn into_response(self) -> Response {
+    pub fn into_response(self) -> KeycloakResponse {
         self.0
     }
 }
 
-impl From<Response> for DefaultResponse {
-    fn from(value: Response) -> Self {
+impl From<KeycloakResponse> for DefaultResponse {
+    fn from(value: KeycloakResponse) -> Self {
         Self(value)
     }
 }
 
-impl AsRef<Response> for DefaultResponse {
-    fn as_ref(&self) -> &Response {
+impl AsRef<KeycloakResponse> for DefaultResponse {
+    fn as_ref(&self) -> &KeycloakResponse {
         &self.0
     }
 }
 
-impl AsMut<Response> for DefaultResponse {
-    fn as_mut(&mut self) -> &mut Response {
+impl AsMut<KeycloakResponse> for DefaultResponse {
+    fn as_mut(&mut self) -> &mut KeycloakResponse {
         &mut self.0
     }
 }
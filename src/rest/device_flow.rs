@@ -0,0 +1,372 @@
+use std::{sync::Arc, time::Duration};
+
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+
+use super::{error_check, transmit, KeycloakRequest, KeycloakTransport};
+use crate::{
+    KeycloakAdminToken, KeycloakClientAuth, KeycloakError, KeycloakServerLocation,
+    KeycloakTokenError,
+};
+
+/// Value of `grant_type` for the device authorization grant.
+const DEVICE_CODE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// Polling interval used if Keycloak does not provide one.
+const DEFAULT_POLLING_INTERVAL: u64 = 5;
+
+/// Increase of the polling interval requested by `slow_down` error.
+const SLOW_DOWN_INCREMENT: u64 = 5;
+
+/// Login with [OAuth 2.0 Device Authorization Grant] for interactive tools.
+///
+/// Instead of asking the operator for a password, the tool shows a verification
+/// URI and a user code. The operator opens the URI in a browser, logs in there
+/// and confirms the code, while the tool polls the token endpoint.
+///
+/// The client must have `OAuth 2.0 Device Authorization Grant` enabled.
+///
+/// [OAuth 2.0 Device Authorization Grant]: https://www.rfc-editor.org/rfc/rfc8628
+#[derive(Clone)]
+pub struct KeycloakDeviceFlow {
+    client_id: String,
+    client_auth: KeycloakClientAuth,
+    realm: String,
+    scope: Option<String>,
+    client: Arc<dyn KeycloakTransport>,
+    location: Option<KeycloakServerLocation>,
+}
+
+/// Device authorization issued by Keycloak, to be shown to the operator.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct KeycloakDeviceAuthorization {
+    device_code: String,
+    user_code: String,
+    verification_uri: String,
+    verification_uri_complete: Option<String>,
+    expires_in: u64,
+    interval: Option<u64>,
+}
+
+impl KeycloakDeviceAuthorization {
+    /// Returns the code the operator has to confirm.
+    pub fn user_code(&self) -> &str {
+        &self.user_code
+    }
+
+    /// Returns the URI the operator has to open.
+    pub fn verification_uri(&self) -> &str {
+        &self.verification_uri
+    }
+
+    /// Returns the URI with the user code already included, if provided by Keycloak.
+    pub fn verification_uri_complete(&self) -> Option<&str> {
+        self.verification_uri_complete.as_deref()
+    }
+
+    /// Returns the lifetime in seconds of the device code.
+    pub fn expires_in(&self) -> u64 {
+        self.expires_in
+    }
+
+    /// Returns the minimal polling interval in seconds, if provided by Keycloak.
+    pub fn interval(&self) -> Option<u64> {
+        self.interval
+    }
+}
+
+impl KeycloakDeviceFlow {
+    /// Creates a device flow login for a public client in the `master` realm.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - The client id of a public client with `OAuth 2.0 Device Authorization Grant` enabled.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the login calls.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn doc() -> Result<(), keycloak::KeycloakError> {
+    /// use keycloak::{prelude::reqwest, KeycloakAdmin, KeycloakDeviceFlow};
+    ///
+    /// let client = reqwest::Client::new();
+    /// let url = "https://keycloak.example.com";
+    ///
+    /// let admin_token = KeycloakDeviceFlow::create("admin-tool", client.clone())
+    ///     .acquire(url, |authorization| {
+    ///         eprintln!(
+    ///             "Open {} and confirm code {}",
+    ///             authorization.verification_uri(),
+    ///             authorization.user_code()
+    ///         );
+    ///     })
+    ///     .await?;
+    ///
+    /// let admin = KeycloakAdmin::new(url, admin_token, client);
+    /// // ... use `admin` to call the Admin REST API.
+    /// # let _ = admin;
+    /// # Ok(()) }
+    /// ```
+    pub fn create(client_id: &str, client: impl KeycloakTransport + 'static) -> Self {
+        Self::create_with_custom_realm(client_id, KeycloakClientAuth::Public, "master", client)
+    }
+
+    /// Creates a device flow login for a client in a caller-specified realm.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - The client id of a client with `OAuth 2.0 Device Authorization Grant` enabled.
+    /// * `client_auth` - How the client authenticates, [`KeycloakClientAuth::Public`] for public clients.
+    /// * `realm` - The Keycloak realm the `client_id` lives in.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the login calls.
+    pub fn create_with_custom_realm(
+        client_id: &str,
+        client_auth: KeycloakClientAuth,
+        realm: &str,
+        client: impl KeycloakTransport + 'static,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_auth,
+            realm: realm.into(),
+            scope: None,
+            client: Arc::new(client),
+            location: None,
+        }
+    }
+
+    /// Sets the scope requested with the device authorization.
+    pub fn with_scope(mut self, scope: &str) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    /// Sets the server location to take the device authorization and token
+    /// endpoints from, instead of deriving them from the `url` passed to
+    /// [`KeycloakDeviceFlow::acquire`].
+    pub fn with_location(mut self, location: KeycloakServerLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Requests a device authorization and waits until the operator confirms it.
+    ///
+    /// `on_authorization` is called once with the verification URI and the
+    /// user code to show to the operator. The token endpoint is then polled
+    /// with the interval requested by Keycloak, until the operator confirms or
+    /// denies the login or the device code expires. Polling stops with
+    /// [`KeycloakTokenError::Expired`] once the device code expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    /// * `on_authorization` - Callback presenting the authorization to the operator.
+    pub async fn acquire<F>(
+        &self,
+        url: &str,
+        on_authorization: F,
+    ) -> Result<KeycloakAdminToken, KeycloakError>
+    where
+        F: FnOnce(&KeycloakDeviceAuthorization),
+    {
+        let authorization = self.authorize(url).await?;
+        let expiry = Instant::now() + Duration::from_secs(authorization.expires_in);
+
+        on_authorization(&authorization);
+
+        let mut interval = authorization.interval.unwrap_or(DEFAULT_POLLING_INTERVAL);
+        loop {
+            let next_poll = Instant::now() + Duration::from_secs(interval);
+            if next_poll >= expiry {
+                return Err(KeycloakError::TokenFailure(KeycloakTokenError::Expired));
+            }
+            tokio::time::sleep_until(next_poll).await;
+            match self.poll(url, &authorization.device_code).await {
+                Err(KeycloakError::HttpFailure {
+                    body: Some(ref body),
+                    ..
+                }) if body.error.as_deref() == Some("authorization_pending") => {}
+                Err(KeycloakError::HttpFailure {
+                    body: Some(ref body),
+                    ..
+                }) if body.error.as_deref() == Some("slow_down") => {
+                    interval += SLOW_DOWN_INCREMENT;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "authorize_device",
+        skip_all,
+        fields(
+            client_id = %self.client_id,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
+    async fn authorize(&self, url: &str) -> Result<KeycloakDeviceAuthorization, KeycloakError> {
+        let location = KeycloakServerLocation::resolve(self.location.as_ref(), url);
+        let token_endpoint = location.token_endpoint(&self.realm);
+        let params = self
+            .scope
+            .as_deref()
+            .map(|scope| vec![("scope", scope)])
+            .unwrap_or_default();
+        let request = self.client_auth.authenticate(
+            KeycloakRequest::post(location.device_authorization_endpoint(&self.realm)),
+            &token_endpoint,
+            &self.client_id,
+            &params,
+        )?;
+        let response = transmit(self.client.as_ref(), request).await?;
+        error_check(response)?.json()
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "acquire_token",
+        skip_all,
+        fields(
+            grant_type = DEVICE_CODE_GRANT_TYPE,
+            client_id = %self.client_id,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
+    async fn poll(
+        &self,
+        url: &str,
+        device_code: &str,
+    ) -> Result<KeycloakAdminToken, KeycloakError> {
+        let token_endpoint = KeycloakServerLocation::resolve(self.location.as_ref(), url)
+            .token_endpoint(&self.realm);
+        let request = self.client_auth.authenticate(
+            KeycloakRequest::post(&token_endpoint),
+            &token_endpoint,
+            &self.client_id,
+            &[
+                ("grant_type", DEVICE_CODE_GRANT_TYPE),
+                ("device_code", device_code),
+            ],
+        )?;
+        let response = transmit(self.client.as_ref(), request).await?;
+        error_check(response)?.json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+    use http::{HeaderMap, StatusCode};
+    use serde_json::json;
+    use tokio::time::Instant;
+
+    use super::KeycloakDeviceFlow;
+    use crate::{
+        KeycloakError, KeycloakRequest, KeycloakResponse, KeycloakTokenError, KeycloakTransport,
+    };
+
+    /// Keycloak answering the polls with the queued errors, then with a
+    /// token, and logging when they were received.
+    #[derive(Clone)]
+    struct Keycloak {
+        expires_in: u64,
+        errors: Arc<Mutex<Vec<&'static str>>>,
+        polls: Arc<Mutex<Vec<Instant>>>,
+    }
+
+    impl Keycloak {
+        fn new(expires_in: u64, errors: &[&'static str]) -> Self {
+            Self {
+                expires_in,
+                errors: Arc::new(Mutex::new(errors.iter().rev().copied().collect())),
+                polls: Default::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl KeycloakTransport for Keycloak {
+        async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+            let (status, body) = if request.url().ends_with("/auth/device") {
+                let authorization = json!({
+                    "device_code": "device",
+                    "user_code": "ABCD-EFGH",
+                    "verification_uri": "http://keycloak/realms/master/device",
+                    "expires_in": self.expires_in,
+                    "interval": 1
+                });
+                (StatusCode::OK, authorization)
+            } else {
+                self.polls.lock().unwrap().push(Instant::now());
+                match self.errors.lock().unwrap().pop() {
+                    Some(error) => (StatusCode::BAD_REQUEST, json!({ "error": error })),
+                    None => {
+                        let token = json!({
+                            "access_token": "access",
+                            "expires_in": 60,
+                            "token_type": "Bearer"
+                        });
+                        (StatusCode::OK, token)
+                    }
+                }
+            };
+            Ok(KeycloakResponse::new(
+                status,
+                HeaderMap::new(),
+                serde_json::to_vec(&body).unwrap(),
+            ))
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn polling() {
+        let keycloak = Keycloak::new(60, &["authorization_pending", "slow_down"]);
+        let start = Instant::now();
+        let mut user_code = None;
+        let token = KeycloakDeviceFlow::create("cli", keycloak.clone())
+            .acquire("http://keycloak", |authorization| {
+                user_code = Some(authorization.user_code().to_owned());
+            })
+            .await
+            .unwrap();
+        assert_eq!(token.access_token(), "access");
+        assert_eq!(user_code.as_deref(), Some("ABCD-EFGH"));
+        let polls = keycloak
+            .polls
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|poll| poll.duration_since(start))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            polls,
+            [1, 2, 8].map(Duration::from_secs),
+            "slow_down increases the interval by 5 seconds"
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn expiry() {
+        let keycloak = Keycloak::new(10, &["authorization_pending"; 20]);
+        let start = Instant::now();
+        let error = KeycloakDeviceFlow::create("cli", keycloak.clone())
+            .acquire("http://keycloak", |_| {})
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            KeycloakError::TokenFailure(KeycloakTokenError::Expired)
+        ));
+        assert_eq!(keycloak.polls.lock().unwrap().len(), 9);
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+}
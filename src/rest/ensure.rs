@@ -0,0 +1,567 @@
+#[cfg(feature = "tag-roles")]
+use serde::de::DeserializeOwned;
+#[cfg(any(
+    feature = "tag-client-scopes",
+    feature = "tag-clients",
+    all(feature = "tag-groups", feature = "tag-realms-admin"),
+    feature = "tag-roles",
+    feature = "tag-users"
+))]
+use {
+    super::{KeycloakRealmAdmin, KeycloakTokenSupplier},
+    crate::{types::*, KeycloakError},
+    serde::Serialize,
+    serde_json::Value,
+};
+
+/// Change made by an `ensure_*` method of [`KeycloakRealmAdmin`](crate::KeycloakRealmAdmin).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeycloakEnsureAction {
+    /// Resource was missing and has been created.
+    Created,
+    /// Resource differed from the desired representation and has been updated.
+    Updated,
+    /// Resource already matched the desired representation.
+    Unchanged,
+}
+
+/// Outcome of an `ensure_*` method of [`KeycloakRealmAdmin`](crate::KeycloakRealmAdmin).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeycloakEnsured {
+    /// Change made to the resource.
+    pub action: KeycloakEnsureAction,
+    /// Id of the created or existing resource.
+    pub id: String,
+}
+
+/// Idempotent create-or-update operations.
+///
+/// The current representation is compared with the fields set in the desired
+/// one, fields left `None` are not compared. The resource is updated only if
+/// a set field differs, the `id` of the desired representation is ignored.
+/// Resources replaced as a whole by Keycloak, like roles, are updated with the
+/// set fields applied to the current representation, so fields left `None`
+/// keep their values.
+#[cfg(any(
+    feature = "tag-client-scopes",
+    feature = "tag-clients",
+    all(feature = "tag-groups", feature = "tag-realms-admin"),
+    feature = "tag-roles",
+    feature = "tag-users"
+))]
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    /// Creates or updates the realm role with the name of `role`.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - Desired role, `name` is required.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn doc(admin: keycloak::KeycloakAdmin) -> Result<(), keycloak::KeycloakError> {
+    /// use keycloak::{types::RoleRepresentation, KeycloakEnsureAction};
+    ///
+    /// let ensured = admin
+    ///     .realm("test")
+    ///     .ensure_realm_role(RoleRepresentation {
+    ///         name: Some("reader".into()),
+    ///         description: Some("Read only access".into()),
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// if ensured.action != KeycloakEnsureAction::Unchanged {
+    ///     println!("role {} changed", ensured.id);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "tag-roles")]
+    pub async fn ensure_realm_role(
+        &self,
+        role: RoleRepresentation,
+    ) -> Result<KeycloakEnsured, KeycloakError> {
+        let name = Self::required(role.name.as_deref(), "role name")?.to_owned();
+        match self.realm_role_by_name(&name).await? {
+            Some(current) if !differs(&current, &role, &[]) => {
+                Self::ensured(KeycloakEnsureAction::Unchanged, current.id.as_deref())
+            }
+            Some(current) => {
+                self.admin
+                    .realm_roles_with_role_name_put(self.realm, &name, merged(&current, &role)?)
+                    .await?;
+                self.clear_lookup_cache();
+                Self::ensured(KeycloakEnsureAction::Updated, current.id.as_deref())
+            }
+            None => {
+                self.admin.realm_roles_post(self.realm, role).await?;
+                self.clear_lookup_cache();
+                let created = self.realm_role_by_name(&name).await?;
+                Self::ensured(
+                    KeycloakEnsureAction::Created,
+                    created.and_then(|role| role.id).as_deref(),
+                )
+            }
+        }
+    }
+
+    /// Creates or updates the role with the name of `role` of the client with `clientId`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - `clientId` of an existing client, not its internal id.
+    /// * `role` - Desired role, `name` is required.
+    #[cfg(all(feature = "tag-clients", feature = "tag-roles"))]
+    pub async fn ensure_client_role(
+        &self,
+        client_id: &str,
+        role: RoleRepresentation,
+    ) -> Result<KeycloakEnsured, KeycloakError> {
+        let name = Self::required(role.name.as_deref(), "role name")?.to_owned();
+        let client_uuid = self
+            .client_by_client_id(client_id)
+            .await?
+            .and_then(|client| client.id)
+            .ok_or_else(|| {
+                KeycloakError::InvalidRequest(format!("client {client_id} does not exist"))
+            })?;
+        match self.client_role_by_name(client_id, &name).await? {
+            Some(current) if !differs(&current, &role, &[]) => {
+                Self::ensured(KeycloakEnsureAction::Unchanged, current.id.as_deref())
+            }
+            Some(current) => {
+                self.admin
+                    .realm_clients_with_client_uuid_roles_with_role_name_put(
+                        self.realm,
+                        &client_uuid,
+                        &name,
+                        merged(&current, &role)?,
+                    )
+                    .await?;
+                self.clear_lookup_cache();
+                Self::ensured(KeycloakEnsureAction::Updated, current.id.as_deref())
+            }
+            None => {
+                self.admin
+                    .realm_clients_with_client_uuid_roles_post(self.realm, &client_uuid, role)
+                    .await?;
+                self.clear_lookup_cache();
+                let created = self.client_role_by_name(client_id, &name).await?;
+                Self::ensured(
+                    KeycloakEnsureAction::Created,
+                    created.and_then(|role| role.id).as_deref(),
+                )
+            }
+        }
+    }
+
+    /// Creates or updates the client with the `clientId` of `client`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Desired client, `client_id` is required.
+    #[cfg(feature = "tag-clients")]
+    pub async fn ensure_client(
+        &self,
+        client: ClientRepresentation,
+    ) -> Result<KeycloakEnsured, KeycloakError> {
+        let client_id = Self::required(client.client_id.as_deref(), "clientId")?.to_owned();
+        match self.client_by_client_id(&client_id).await? {
+            Some(current) if !differs(&current, &client, &[]) => {
+                Self::ensured(KeycloakEnsureAction::Unchanged, current.id.as_deref())
+            }
+            Some(current) => {
+                let id = Self::required(current.id.as_deref(), "client id")?;
+                self.admin
+                    .realm_clients_with_client_uuid_put(self.realm, id, client)
+                    .await?;
+                self.clear_lookup_cache();
+                Self::ensured(KeycloakEnsureAction::Updated, Some(id))
+            }
+            None => {
+                let created = self.admin.realm_clients_post(self.realm, client).await?;
+                self.clear_lookup_cache();
+                match created.to_id() {
+                    Some(id) => Self::ensured(KeycloakEnsureAction::Created, Some(id)),
+                    None => Self::ensured(
+                        KeycloakEnsureAction::Created,
+                        self.client_by_client_id(&client_id)
+                            .await?
+                            .and_then(|client| client.id)
+                            .as_deref(),
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Creates or updates the client scope with the name of `client_scope`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_scope` - Desired client scope, `name` is required.
+    #[cfg(feature = "tag-client-scopes")]
+    pub async fn ensure_client_scope(
+        &self,
+        client_scope: ClientScopeRepresentation,
+    ) -> Result<KeycloakEnsured, KeycloakError> {
+        let name = Self::required(client_scope.name.as_deref(), "client scope name")?;
+        let current = self
+            .admin
+            .realm_client_scopes_get(self.realm)
+            .await?
+            .iter()
+            .find(|scope| scope.name.as_deref() == Some(name))
+            .cloned();
+        match current {
+            Some(current) if !differs(&current, &client_scope, &[]) => {
+                Self::ensured(KeycloakEnsureAction::Unchanged, current.id.as_deref())
+            }
+            Some(current) => {
+                let id = Self::required(current.id.as_deref(), "client scope id")?;
+                self.admin
+                    .realm_client_scopes_with_client_scope_id_put(self.realm, id, client_scope)
+                    .await?;
+                self.clear_lookup_cache();
+                Self::ensured(KeycloakEnsureAction::Updated, Some(id))
+            }
+            None => {
+                let created = self
+                    .admin
+                    .realm_client_scopes_post(self.realm, client_scope)
+                    .await?;
+                self.clear_lookup_cache();
+                Self::ensured(KeycloakEnsureAction::Created, created.to_id())
+            }
+        }
+    }
+
+    /// Creates or updates the group at the path of `group`.
+    ///
+    /// The path is taken from `path`, or is `/{name}` if `path` is not set.
+    /// The parent group of a subgroup must exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - Desired group, `path` or `name` is required.
+    #[cfg(all(feature = "tag-groups", feature = "tag-realms-admin"))]
+    pub async fn ensure_group(
+        &self,
+        mut group: GroupRepresentation,
+    ) -> Result<KeycloakEnsured, KeycloakError> {
+        let path = match (group.path.as_deref(), group.name.as_deref()) {
+            (Some(path), _) => format!("/{}", path.trim_matches('/')),
+            (None, Some(name)) => format!("/{name}"),
+            (None, None) => {
+                return Err(KeycloakError::InvalidRequest(
+                    "group name or path is not set".into(),
+                ))
+            }
+        };
+        let (parent, name) = path.rsplit_once('/').unwrap_or_default();
+        if name.is_empty() {
+            return Err(KeycloakError::InvalidRequest(format!(
+                "group path {path} has no name"
+            )));
+        }
+        if group
+            .name
+            .as_deref()
+            .is_some_and(|group_name| group_name != name)
+        {
+            return Err(KeycloakError::InvalidRequest(format!(
+                "group name does not match path {path}"
+            )));
+        }
+        group.name = Some(name.into());
+        match self.group_by_path(&path).await? {
+            Some(current) if !differs(&current, &group, &["path"]) => {
+                Self::ensured(KeycloakEnsureAction::Unchanged, current.id.as_deref())
+            }
+            Some(current) => {
+                let id = Self::required(current.id.as_deref(), "group id")?;
+                self.admin
+                    .realm_groups_with_group_id_put(self.realm, id, group)
+                    .await?;
+                self.clear_lookup_cache();
+                Self::ensured(KeycloakEnsureAction::Updated, Some(id))
+            }
+            None => {
+                let created = if parent.is_empty() {
+                    self.admin.realm_groups_post(self.realm, group).await?
+                } else {
+                    let parent_id = self
+                        .group_by_path(parent)
+                        .await?
+                        .and_then(|parent| parent.id)
+                        .ok_or_else(|| {
+                            KeycloakError::InvalidRequest(format!(
+                                "parent group {parent} does not exist"
+                            ))
+                        })?;
+                    self.admin
+                        .realm_groups_with_group_id_children_post(self.realm, &parent_id, group)
+                        .await?
+                };
+                self.clear_lookup_cache();
+                Self::ensured(KeycloakEnsureAction::Created, created.to_id())
+            }
+        }
+    }
+
+    /// Creates or updates the user with the username of `user`.
+    ///
+    /// `credentials` and `groups` are only used when the user is created.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - Desired user, `username` is required.
+    #[cfg(feature = "tag-users")]
+    pub async fn ensure_user(
+        &self,
+        mut user: UserRepresentation,
+    ) -> Result<KeycloakEnsured, KeycloakError> {
+        let username = Self::required(user.username.as_deref(), "username")?.to_owned();
+        match self.user_by_username(&username).await? {
+            Some(current) if !differs(&current, &user, &["username", "credentials", "groups"]) => {
+                Self::ensured(KeycloakEnsureAction::Unchanged, current.id.as_deref())
+            }
+            Some(current) => {
+                let id = Self::required(current.id.as_deref(), "user id")?;
+                user.credentials = None;
+                user.groups = None;
+                self.admin
+                    .realm_users_with_user_id_put(self.realm, id, user)
+                    .await?;
+                self.clear_lookup_cache();
+                Self::ensured(KeycloakEnsureAction::Updated, Some(id))
+            }
+            None => {
+                let created = self.admin.realm_users_post(self.realm, user).await?;
+                self.clear_lookup_cache();
+                match created.to_id() {
+                    Some(id) => Self::ensured(KeycloakEnsureAction::Created, Some(id)),
+                    None => Self::ensured(
+                        KeycloakEnsureAction::Created,
+                        self.user_by_username(&username)
+                            .await?
+                            .and_then(|user| user.id)
+                            .as_deref(),
+                    ),
+                }
+            }
+        }
+    }
+
+    fn ensured(
+        action: KeycloakEnsureAction,
+        id: Option<&str>,
+    ) -> Result<KeycloakEnsured, KeycloakError> {
+        let id = Self::required(id, "resource id")?.to_owned();
+        Ok(KeycloakEnsured { action, id })
+    }
+
+    fn required<'v>(value: Option<&'v str>, name: &str) -> Result<&'v str, KeycloakError> {
+        value.ok_or_else(|| KeycloakError::InvalidRequest(format!("{name} is not set")))
+    }
+}
+
+/// Returns `true` if a field set in `desired` differs from `current`.
+#[cfg(any(
+    feature = "tag-client-scopes",
+    feature = "tag-clients",
+    all(feature = "tag-groups", feature = "tag-realms-admin"),
+    feature = "tag-roles",
+    feature = "tag-users"
+))]
+pub(crate) fn differs<T: Serialize>(current: &T, desired: &T, ignored: &[&str]) -> bool {
+    let mut desired = serde_json::to_value(desired).unwrap_or_default();
+    if let Value::Object(desired) = &mut desired {
+        for key in ignored.iter().chain(&["id"]) {
+            desired.remove(*key);
+        }
+    }
+    let current = serde_json::to_value(current).unwrap_or_default();
+    !contains(&current, &desired)
+}
+
+/// Returns `current` with the fields set in `desired` applied, objects are
+/// merged and other values replaced.
+#[cfg(feature = "tag-roles")]
+pub(crate) fn merged<T: Serialize + DeserializeOwned>(
+    current: &T,
+    desired: &T,
+) -> Result<T, KeycloakError> {
+    let mut desired = serde_json::to_value(desired)?;
+    if let Value::Object(desired) = &mut desired {
+        desired.remove("id");
+    }
+    let mut current = serde_json::to_value(current)?;
+    merge(&mut current, desired);
+    Ok(serde_json::from_value(current)?)
+}
+
+#[cfg(feature = "tag-roles")]
+fn merge(current: &mut Value, desired: Value) {
+    match (current, desired) {
+        (_, Value::Null) => {}
+        (Value::Object(current), Value::Object(desired)) => {
+            for (key, value) in desired {
+                merge(current.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (current, desired) => *current = desired,
+    }
+}
+
+/// Returns `true` if `current` has all values of `desired`, arrays match
+/// regardless of the order of their elements.
+#[cfg(any(
+    feature = "tag-client-scopes",
+    feature = "tag-clients",
+    all(feature = "tag-groups", feature = "tag-realms-admin"),
+    feature = "tag-roles",
+    feature = "tag-users"
+))]
+fn contains(current: &Value, desired: &Value) -> bool {
+    match (current, desired) {
+        (_, Value::Null) => true,
+        (Value::Object(current), Value::Object(desired)) => desired
+            .iter()
+            .all(|(key, value)| contains(current.get(key).unwrap_or(&Value::Null), value)),
+        (Value::Array(current), Value::Array(desired)) => {
+            current.len() == desired.len()
+                && desired
+                    .iter()
+                    .all(|value| current.iter().any(|item| contains(item, value)))
+        }
+        _ => current == desired,
+    }
+}
+
+#[cfg(all(test, feature = "mock-server", feature = "tags-all"))]
+mod tests {
+    use super::KeycloakEnsureAction::{Created, Unchanged, Updated};
+    use crate::{mock_server::KeycloakMockServer, types::*};
+
+    #[tokio::test]
+    async fn ensure() {
+        let admin = KeycloakMockServer::admin_with_realm("test").await;
+        let realm = admin.realm("test").with_lookup_cache();
+
+        let role = |description: &str| RoleRepresentation {
+            name: Some("reader".into()),
+            description: Some(description.into()),
+            ..Default::default()
+        };
+        let created = realm.ensure_realm_role(role("read")).await.unwrap();
+        assert_eq!(created.action, Created);
+        let unchanged = realm.ensure_realm_role(role("read")).await.unwrap();
+        assert_eq!(unchanged.action, Unchanged);
+        assert_eq!(unchanged.id, created.id);
+        let updated = realm.ensure_realm_role(role("read all")).await.unwrap();
+        assert_eq!(updated.action, Updated);
+        assert_eq!(updated.id, created.id);
+        let composite = RoleRepresentation {
+            name: Some("reader".into()),
+            composite: Some(false),
+            attributes: Some(
+                [("level".to_owned(), vec!["1".into()].into())]
+                    .into_iter()
+                    .collect::<std::collections::HashMap<_, _>>()
+                    .into(),
+            ),
+            ..Default::default()
+        };
+        assert_eq!(
+            realm.ensure_realm_role(composite).await.unwrap().action,
+            Updated
+        );
+        let found = realm.realm_role_by_name("reader").await.unwrap().unwrap();
+        assert_eq!(found.description.as_deref(), Some("read all"));
+
+        let client = ClientRepresentation {
+            client_id: Some("app".into()),
+            redirect_uris: Some(vec!["https://app/*".into()].into()),
+            ..Default::default()
+        };
+        let created = realm.ensure_client(client.clone()).await.unwrap();
+        assert_eq!(created.action, Created);
+        assert_eq!(realm.ensure_client(client).await.unwrap().action, Unchanged);
+        let admin_role = RoleRepresentation {
+            name: Some("admin".into()),
+            ..Default::default()
+        };
+        let role = realm
+            .ensure_client_role("app", admin_role.clone())
+            .await
+            .unwrap();
+        assert_eq!(role.action, Created);
+        assert_eq!(
+            realm.ensure_client_role("app", admin_role).await.unwrap(),
+            super::KeycloakEnsured {
+                action: Unchanged,
+                id: role.id
+            }
+        );
+
+        let scope = ClientScopeRepresentation {
+            name: Some("profile".into()),
+            protocol: Some("openid-connect".into()),
+            ..Default::default()
+        };
+        let created = realm.ensure_client_scope(scope.clone()).await.unwrap();
+        assert_eq!(created.action, Created);
+        assert_eq!(
+            realm.ensure_client_scope(scope).await.unwrap().id,
+            created.id
+        );
+
+        let group = |path: &str| GroupRepresentation {
+            path: Some(path.into()),
+            ..Default::default()
+        };
+        assert!(realm.ensure_group(group("/parent/child")).await.is_err());
+        assert!(realm.ensure_group(group("/")).await.is_err());
+        let parent = realm.ensure_group(group("/parent")).await.unwrap();
+        assert_eq!(parent.action, Created);
+        let child = realm.ensure_group(group("/parent/child")).await.unwrap();
+        assert_eq!(child.action, Created);
+        let found = realm.group_by_path("/parent/child").await.unwrap().unwrap();
+        assert_eq!(found.id.as_deref(), Some(child.id.as_str()));
+        assert_eq!(
+            realm
+                .ensure_group(group("parent/child"))
+                .await
+                .unwrap()
+                .action,
+            Unchanged
+        );
+
+        let user = UserRepresentation {
+            username: Some("Alice".into()),
+            enabled: Some(true),
+            credentials: Some(
+                vec![CredentialRepresentation {
+                    type_: Some("password".into()),
+                    value: Some("secret".into()),
+                    ..Default::default()
+                }]
+                .into(),
+            ),
+            ..Default::default()
+        };
+        let created = realm.ensure_user(user.clone()).await.unwrap();
+        assert_eq!(created.action, Created);
+        assert_eq!(
+            realm.ensure_user(user.clone()).await.unwrap().action,
+            Unchanged
+        );
+        let disabled = UserRepresentation {
+            enabled: Some(false),
+            ..user
+        };
+        assert_eq!(realm.ensure_user(disabled).await.unwrap().action, Updated);
+        let found = realm.user_by_username("alice").await.unwrap().unwrap();
+        assert_eq!(found.enabled, Some(false));
+    }
+}
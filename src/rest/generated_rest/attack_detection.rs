@@ -16,20 +16,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/attack-detection/brute-force/users`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmattack_detectionbrute_forceusers>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/attack-detection/brute-force/users",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_attack_detection_brute_force_users_delete(
         &self,
         realm: &str,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/attack-detection/brute-force/users",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/attack-detection/brute-force/users",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/attack-detection/brute-force/users",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get status of a username in brute force detection
@@ -46,6 +59,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmattack_detectionbrute_forceusersuserid>
     ///
     /// REST method: `GET /admin/realms/{realm}/attack-detection/brute-force/users/{userId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/attack-detection/brute-force/users/{userId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_attack_detection_brute_force_users_with_user_id_get(
         &self,
         realm: &str,
@@ -53,15 +77,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeMap<String, Value>, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/attack-detection/brute-force/users/{user_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/attack-detection/brute-force/users/{user_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/attack-detection/brute-force/users/{userId}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Clear any user login failures for the user This can release temporary disabled user
@@ -80,6 +106,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmattack_detectionbrute_forceusersuserid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/attack-detection/brute-force/users/{userId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/attack-detection/brute-force/users/{userId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_attack_detection_brute_force_users_with_user_id_delete(
         &self,
         realm: &str,
@@ -87,15 +124,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/attack-detection/brute-force/users/{user_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/attack-detection/brute-force/users/{user_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/attack-detection/brute-force/users/{userId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 }
 // not all paths processed
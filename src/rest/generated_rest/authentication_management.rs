@@ -14,20 +14,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/authentication/authenticator-providers`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationauthenticator_providers>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/authenticator-providers",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_authenticator_providers_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<TypeMap<String, Value>>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/authenticator-providers",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/authenticator-providers",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/authenticator-providers",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get client authenticator providers Returns a stream of client authenticator providers.
@@ -41,20 +54,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/authentication/client-authenticator-providers`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationclient_authenticator_providers>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/client-authenticator-providers",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_client_authenticator_providers_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<TypeMap<String, Value>>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/client-authenticator-providers",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/client-authenticator-providers",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/client-authenticator-providers",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Create new authenticator configuration
@@ -72,22 +98,32 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmauthenticationconfig>
     #[deprecated]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/authentication/config",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_config_post(
         &self,
         realm: &str,
         body: AuthenticatorConfigRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/authentication/config",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/authentication/config",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/authentication/config", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get authenticator provider's configuration description
@@ -104,6 +140,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationconfig_descriptionproviderid>
     ///
     /// REST method: `GET /admin/realms/{realm}/authentication/config-description/{providerId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/config-description/{providerId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_config_description_with_provider_id_get(
         &self,
         realm: &str,
@@ -111,15 +158,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<AuthenticatorConfigInfoRepresentation, KeycloakError> {
         let realm = p(realm);
         let provider_id = p(provider_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/config-description/{provider_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/config-description/{provider_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/config-description/{providerId}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get authenticator configuration
@@ -134,6 +183,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/authentication/config/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationconfigid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/config/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_config_with_id_get(
         &self,
         realm: &str,
@@ -141,15 +201,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<AuthenticatorConfigRepresentation, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/config/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/config/{id}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/authentication/config/{id}", builder)
+            .await?;
+        response.json()
     }
 
     /// Update authenticator configuration
@@ -167,6 +226,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/authentication/config/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmauthenticationconfigid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/authentication/config/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_config_with_id_put(
         &self,
         realm: &str,
@@ -175,16 +245,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/authentication/config/{id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/authentication/config/{id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/authentication/config/{id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete authenticator configuration
@@ -201,6 +270,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/authentication/config/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmauthenticationconfigid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/authentication/config/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_config_with_id_delete(
         &self,
         realm: &str,
@@ -208,15 +288,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/authentication/config/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/authentication/config/{id}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/authentication/config/{id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Add new authentication execution
@@ -233,22 +312,32 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/authentication/executions`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmauthenticationexecutions>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/authentication/executions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_executions_post(
         &self,
         realm: &str,
         body: AuthenticationExecutionRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/authentication/executions",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/authentication/executions",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/authentication/executions", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get Single Execution
@@ -265,6 +354,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationexecutionsexecutionid>
     ///
     /// REST method: `GET /admin/realms/{realm}/authentication/executions/{executionId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/executions/{executionId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_executions_with_execution_id_get(
         &self,
         realm: &str,
@@ -272,15 +372,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<AuthenticationExecutionRepresentation, KeycloakError> {
         let realm = p(realm);
         let execution_id = p(execution_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/executions/{execution_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/executions/{execution_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/executions/{executionId}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Delete execution
@@ -299,6 +401,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmauthenticationexecutionsexecutionid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/authentication/executions/{executionId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/authentication/executions/{executionId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_executions_with_execution_id_delete(
         &self,
         realm: &str,
@@ -306,15 +419,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let execution_id = p(execution_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/authentication/executions/{execution_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/authentication/executions/{execution_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/executions/{executionId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Update execution with new configuration
@@ -334,6 +449,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmauthenticationexecutionsexecutionidconfig>
     ///
     /// REST method: `POST /admin/realms/{realm}/authentication/executions/{executionId}/config`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/authentication/executions/{executionId}/config",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_executions_with_execution_id_config_post(
         &self,
         realm: &str,
@@ -342,16 +468,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let execution_id = p(execution_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/authentication/executions/{execution_id}/config",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/authentication/executions/{execution_id}/config",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/executions/{executionId}/config",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get execution's configuration
@@ -370,6 +498,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/authentication/executions/{executionId}/config/{id}`
     #[deprecated]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/executions/{executionId}/config/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_executions_with_execution_id_config_with_id_get(
         &self,
         realm: &str,
@@ -379,15 +518,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let execution_id = p(execution_id);
         let id = p(id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/executions/{execution_id}/config/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/executions/{execution_id}/config/{id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/executions/{executionId}/config/{id}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Lower execution's priority
@@ -406,6 +547,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmauthenticationexecutionsexecutionidlower_priority>
     ///
     /// REST method: `POST /admin/realms/{realm}/authentication/executions/{executionId}/lower-priority`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/authentication/executions/{executionId}/lower-priority",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_executions_with_execution_id_lower_priority_post(
         &self,
         realm: &str,
@@ -413,15 +565,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let execution_id = p(execution_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/authentication/executions/{execution_id}/lower-priority",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/authentication/executions/{execution_id}/lower-priority",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/executions/{executionId}/lower-priority",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Raise execution's priority
@@ -440,6 +594,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmauthenticationexecutionsexecutionidraise_priority>
     ///
     /// REST method: `POST /admin/realms/{realm}/authentication/executions/{executionId}/raise-priority`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/authentication/executions/{executionId}/raise-priority",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_executions_with_execution_id_raise_priority_post(
         &self,
         realm: &str,
@@ -447,15 +612,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let execution_id = p(execution_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/authentication/executions/{execution_id}/raise-priority",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/authentication/executions/{execution_id}/raise-priority",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/executions/{executionId}/raise-priority",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get authentication flows Returns a stream of authentication flows.
@@ -469,20 +636,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/authentication/flows`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationflows>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/flows",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_flows_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<AuthenticationFlowRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/flows",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/flows",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/authentication/flows", builder)
+            .await?;
+        response.json()
     }
 
     /// Create a new authentication flow
@@ -499,22 +676,32 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/authentication/flows`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmauthenticationflows>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/authentication/flows",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_flows_post(
         &self,
         realm: &str,
         body: AuthenticationFlowRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/authentication/flows",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/authentication/flows",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/authentication/flows", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Copy existing authentication flow under a new name The new name is given as 'newName' attribute of the passed JSON object
@@ -534,6 +721,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmauthenticationflowsflowaliascopy>
     ///
     /// REST method: `POST /admin/realms/{realm}/authentication/flows/{flowAlias}/copy`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/authentication/flows/{flowAlias}/copy",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_flows_with_flow_alias_copy_post(
         &self,
         realm: &str,
@@ -542,16 +740,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let flow_alias = p(flow_alias);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/authentication/flows/{flow_alias}/copy",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/authentication/flows/{flow_alias}/copy",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/flows/{flowAlias}/copy",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get authentication executions for a flow
@@ -568,6 +768,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationflowsflowaliasexecutions>
     ///
     /// REST method: `GET /admin/realms/{realm}/authentication/flows/{flowAlias}/executions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/flows/{flowAlias}/executions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_flows_with_flow_alias_executions_get(
         &self,
         realm: &str,
@@ -575,15 +786,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<AuthenticationExecutionInfoRepresentation>, KeycloakError> {
         let realm = p(realm);
         let flow_alias = p(flow_alias);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/flows/{flow_alias}/executions",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/flows/{flow_alias}/executions",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/flows/{flowAlias}/executions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Update authentication executions of a Flow
@@ -603,6 +816,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmauthenticationflowsflowaliasexecutions>
     ///
     /// REST method: `PUT /admin/realms/{realm}/authentication/flows/{flowAlias}/executions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/authentication/flows/{flowAlias}/executions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_flows_with_flow_alias_executions_put(
         &self,
         realm: &str,
@@ -611,16 +835,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let flow_alias = p(flow_alias);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/authentication/flows/{flow_alias}/executions",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/authentication/flows/{flow_alias}/executions",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/flows/{flowAlias}/executions",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Add new authentication execution to a flow
@@ -640,6 +866,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmauthenticationflowsflowaliasexecutionsexecution>
     ///
     /// REST method: `POST /admin/realms/{realm}/authentication/flows/{flowAlias}/executions/execution`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/authentication/flows/{flowAlias}/executions/execution",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_flows_with_flow_alias_executions_execution_post(
         &self,
         realm: &str,
@@ -648,16 +885,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let flow_alias = p(flow_alias);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/authentication/flows/{flow_alias}/executions/execution",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/authentication/flows/{flow_alias}/executions/execution",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/flows/{flowAlias}/executions/execution",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Add new flow with new execution to existing flow
@@ -677,6 +916,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmauthenticationflowsflowaliasexecutionsflow>
     ///
     /// REST method: `POST /admin/realms/{realm}/authentication/flows/{flowAlias}/executions/flow`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/authentication/flows/{flowAlias}/executions/flow",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_flows_with_flow_alias_executions_flow_post(
         &self,
         realm: &str,
@@ -685,16 +935,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let flow_alias = p(flow_alias);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/authentication/flows/{flow_alias}/executions/flow",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/authentication/flows/{flow_alias}/executions/flow",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/flows/{flowAlias}/executions/flow",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get authentication flow for id
@@ -709,6 +961,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/authentication/flows/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationflowsid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/flows/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_flows_with_id_get(
         &self,
         realm: &str,
@@ -716,15 +979,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<AuthenticationFlowRepresentation, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/flows/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/flows/{id}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/authentication/flows/{id}", builder)
+            .await?;
+        response.json()
     }
 
     /// Update an authentication flow
@@ -742,6 +1004,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/authentication/flows/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmauthenticationflowsid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/authentication/flows/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_flows_with_id_put(
         &self,
         realm: &str,
@@ -750,16 +1023,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/authentication/flows/{id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/authentication/flows/{id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/authentication/flows/{id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete an authentication flow
@@ -776,6 +1048,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/authentication/flows/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmauthenticationflowsid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/authentication/flows/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_flows_with_id_delete(
         &self,
         realm: &str,
@@ -783,15 +1066,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/authentication/flows/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/authentication/flows/{id}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/authentication/flows/{id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get form action providers Returns a stream of form action providers.
@@ -805,20 +1087,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/authentication/form-action-providers`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationform_action_providers>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/form-action-providers",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_form_action_providers_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<TypeMap<String, Value>>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/form-action-providers",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/form-action-providers",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/form-action-providers",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get form providers Returns a stream of form providers.
@@ -832,20 +1127,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/authentication/form-providers`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationform_providers>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/form-providers",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_form_providers_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<TypeMap<String, Value>>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/form-providers",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/form-providers",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/form-providers",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get configuration descriptions for all clients
@@ -859,20 +1167,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/authentication/per-client-config-description`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationper_client_config_description>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/per-client-config-description",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_per_client_config_description_get(
         &self,
         realm: &str,
     ) -> Result<TypeMap<String, TypeVec<ConfigPropertyRepresentation>>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/per-client-config-description",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/per-client-config-description",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/per-client-config-description",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Register a new required actions
@@ -889,22 +1210,35 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/authentication/register-required-action`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmauthenticationregister_required_action>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/authentication/register-required-action",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_register_required_action_post(
         &self,
         realm: &str,
         body: RequiredActionProviderRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/authentication/register-required-action",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/authentication/register-required-action",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/register-required-action",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get required actions Returns a stream of required actions.
@@ -918,20 +1252,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/authentication/required-actions`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationrequired_actions>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/required-actions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_required_actions_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<RequiredActionProviderRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/required-actions",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/required-actions",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/required-actions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get required action for alias
@@ -946,6 +1293,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/authentication/required-actions/{alias}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationrequired_actionsalias>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/required-actions/{alias}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_required_actions_with_alias_get(
         &self,
         realm: &str,
@@ -953,15 +1311,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<RequiredActionProviderRepresentation, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/required-actions/{alias}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/required-actions/{alias}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/required-actions/{alias}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Update required action
@@ -979,6 +1339,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/authentication/required-actions/{alias}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmauthenticationrequired_actionsalias>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/authentication/required-actions/{alias}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_required_actions_with_alias_put(
         &self,
         realm: &str,
@@ -987,16 +1358,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/authentication/required-actions/{alias}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/authentication/required-actions/{alias}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/required-actions/{alias}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete required action
@@ -1013,6 +1386,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/authentication/required-actions/{alias}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmauthenticationrequired_actionsalias>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/authentication/required-actions/{alias}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_required_actions_with_alias_delete(
         &self,
         realm: &str,
@@ -1020,15 +1404,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/authentication/required-actions/{alias}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/authentication/required-actions/{alias}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/required-actions/{alias}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get RequiredAction configuration
@@ -1043,6 +1429,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/authentication/required-actions/{alias}/config`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationrequired_actionsaliasconfig>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/required-actions/{alias}/config",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_required_actions_with_alias_config_get(
         &self,
         realm: &str,
@@ -1050,15 +1447,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<RequiredActionConfigRepresentation, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/required-actions/{alias}/config",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/required-actions/{alias}/config",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/required-actions/{alias}/config",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Update RequiredAction configuration
@@ -1076,6 +1475,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/authentication/required-actions/{alias}/config`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmauthenticationrequired_actionsaliasconfig>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/authentication/required-actions/{alias}/config",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_required_actions_with_alias_config_put(
         &self,
         realm: &str,
@@ -1084,16 +1494,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/authentication/required-actions/{alias}/config",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/authentication/required-actions/{alias}/config",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/required-actions/{alias}/config",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete RequiredAction configuration
@@ -1110,6 +1522,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/authentication/required-actions/{alias}/config`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmauthenticationrequired_actionsaliasconfig>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/authentication/required-actions/{alias}/config",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_required_actions_with_alias_config_delete(
         &self,
         realm: &str,
@@ -1117,15 +1540,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/authentication/required-actions/{alias}/config",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/authentication/required-actions/{alias}/config",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/required-actions/{alias}/config",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get RequiredAction provider configuration description
@@ -1140,6 +1565,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/authentication/required-actions/{alias}/config-description`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationrequired_actionsaliasconfig_description>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/required-actions/{alias}/config-description",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_required_actions_with_alias_config_description_get(
         &self,
         realm: &str,
@@ -1147,15 +1583,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<RequiredActionConfigInfoRepresentation, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/required-actions/{alias}/config-description",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/required-actions/{alias}/config-description",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/required-actions/{alias}/config-description",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Lower required action's priority
@@ -1172,6 +1610,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/authentication/required-actions/{alias}/lower-priority`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmauthenticationrequired_actionsaliaslower_priority>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/authentication/required-actions/{alias}/lower-priority",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_required_actions_with_alias_lower_priority_post(
         &self,
         realm: &str,
@@ -1179,15 +1628,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/authentication/required-actions/{alias}/lower-priority",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/authentication/required-actions/{alias}/lower-priority",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/required-actions/{alias}/lower-priority",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Raise required action's priority
@@ -1204,6 +1655,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/authentication/required-actions/{alias}/raise-priority`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmauthenticationrequired_actionsaliasraise_priority>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/authentication/required-actions/{alias}/raise-priority",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_required_actions_with_alias_raise_priority_post(
         &self,
         realm: &str,
@@ -1211,15 +1673,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/authentication/required-actions/{alias}/raise-priority",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/authentication/required-actions/{alias}/raise-priority",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/required-actions/{alias}/raise-priority",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get unregistered required actions Returns a stream of unregistered required actions.
@@ -1233,20 +1697,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/authentication/unregistered-required-actions`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmauthenticationunregistered_required_actions>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/authentication/unregistered-required-actions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_authentication_unregistered_required_actions_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<RequiredActionProviderRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/authentication/unregistered-required-actions",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/authentication/unregistered-required-actions",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/authentication/unregistered-required-actions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
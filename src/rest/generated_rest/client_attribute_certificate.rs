@@ -18,6 +18,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidcertificatesattr>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_certificates_with_attr_get(
         &self,
         realm: &str,
@@ -27,15 +38,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let attr = p(attr);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get a keystore file for the client, containing private key and public certificate
@@ -54,6 +67,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrdownload>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/download`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/download",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_certificates_with_attr_download_post(
         &self,
         realm: &str,
@@ -64,16 +88,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let attr = p(attr);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/download",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.text().await.map(From::from)?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/download",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/download",
+                builder,
+            )
+            .await?;
+        Ok(response.text()?.into())
     }
 
     /// Generate a new certificate with new key pair
@@ -91,6 +117,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrgenerate>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/generate`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/generate",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_certificates_with_attr_generate_post(
         &self,
         realm: &str,
@@ -100,15 +137,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let attr = p(attr);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/generate",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/generate",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/generate",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Generate a new keypair and certificate, and get the private key file
@@ -130,6 +169,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrgenerate_and_download>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/generate-and-download`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/generate-and-download",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_certificates_with_attr_generate_and_download_post(
         &self,
         realm: &str,
@@ -140,16 +190,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let attr = p(attr);
-        let builder = self
-            .client
-            .post(format!(
+        let builder = KeycloakRequest::post(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/generate-and-download",
                 self.url
             ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.text().await.map(From::from)?)
+            .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/generate-and-download", builder).await?;
+        Ok(response.text()?.into())
     }
 
     /// Upload certificate and eventually private key
@@ -167,6 +214,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrupload>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/upload`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/upload",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_certificates_with_attr_upload_post(
         &self,
         realm: &str,
@@ -176,15 +234,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let attr = p(attr);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/upload",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/upload",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/upload",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Upload only certificate, not private key
@@ -202,6 +262,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidcertificatesattrupload_certificate>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/upload-certificate`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/upload-certificate",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_certificates_with_attr_upload_certificate_post(
         &self,
         realm: &str,
@@ -211,15 +282,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let attr = p(attr);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/upload-certificate",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/certificates/{attr}/upload-certificate",
+            self.url
+        ));
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/certificates/{attr}/upload-certificate", builder).await?;
+        response.json()
     }
 
     /// Uploads a certificate, prepares the jwks or public key associated, and returns the certificate representation.
@@ -233,20 +301,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/identity-provider/upload-certificate`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerupload_certificate>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/identity-provider/upload-certificate",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_upload_certificate_post(
         &self,
         realm: &str,
     ) -> Result<CertificateRepresentation, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/identity-provider/upload-certificate",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/identity-provider/upload-certificate",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/upload-certificate",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
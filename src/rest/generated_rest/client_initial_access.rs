@@ -12,20 +12,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/clients-initial-access`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclients_initial_access>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients-initial-access",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_initial_access_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<ClientInitialAccessPresentation>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients-initial-access",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients-initial-access",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/clients-initial-access", builder)
+            .await?;
+        response.json()
     }
 
     /// Create a new initial access token.
@@ -40,22 +50,32 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/clients-initial-access`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclients_initial_access>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients-initial-access",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_initial_access_post(
         &self,
         realm: &str,
         body: ClientInitialAccessCreatePresentation,
     ) -> Result<ClientInitialAccessCreatePresentation, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients-initial-access",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients-initial-access",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/clients-initial-access", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -70,6 +90,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/clients-initial-access/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclients_initial_accessid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/clients-initial-access/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_initial_access_with_id_delete(
         &self,
         realm: &str,
@@ -77,15 +108,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/clients-initial-access/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/clients-initial-access/{id}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/clients-initial-access/{id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 }
 // not all paths processed
@@ -14,20 +14,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/client-registration-policy/providers`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_registration_policyproviders>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-registration-policy/providers",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_registration_policy_providers_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<ComponentTypeRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-registration-policy/providers",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-registration-policy/providers",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-registration-policy/providers",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
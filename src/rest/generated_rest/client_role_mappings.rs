@@ -18,6 +18,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_id>
     ///
     /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_role_mappings_clients_with_client_id_get(
         &self,
         realm: &str,
@@ -27,15 +38,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let group_id = p(group_id);
         let client_id = p(client_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Add client-level roles to the user or group role mapping
@@ -56,6 +69,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_id>
     ///
     /// REST method: `POST /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_role_mappings_clients_with_client_id_post(
         &self,
         realm: &str,
@@ -66,16 +90,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let group_id = p(group_id);
         let client_id = p(client_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete client-level roles from user or group role mapping
@@ -96,6 +122,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_id>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_role_mappings_clients_with_client_id_delete(
         &self,
         realm: &str,
@@ -106,16 +143,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let group_id = p(group_id);
         let client_id = p(client_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get available client-level roles that can be mapped to the user or group
@@ -133,6 +172,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_idavailable>
     ///
     /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}/available`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}/available",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_role_mappings_clients_with_client_id_available_get(
         &self,
         realm: &str,
@@ -142,15 +192,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let group_id = p(group_id);
         let client_id = p(client_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}/available",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}/available",
+            self.url
+        ));
+        let response = self.call("/admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}/available", builder).await?;
+        response.json()
     }
 
     /// Get effective client-level role mappings This recurses any composite roles
@@ -169,6 +216,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsclientsclient_idcomposite>
     ///
     /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}/composite`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}/composite",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_role_mappings_clients_with_client_id_composite_get(
         &self,
         realm: &str,
@@ -179,18 +237,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let group_id = p(group_id);
         let client_id = p(client_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}/composite",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/clients/{client_id}/composite",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/groups/{group-id}/role-mappings/clients/{client-id}/composite", builder).await?;
+        response.json()
     }
 
     /// Get client-level role mappings for the user or group, and the app
@@ -208,6 +263,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsclientsclient_id>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_role_mappings_clients_with_client_id_get(
         &self,
         realm: &str,
@@ -217,15 +283,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let client_id = p(client_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Add client-level roles to the user or group role mapping
@@ -246,6 +314,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmusersuser_idrole_mappingsclientsclient_id>
     ///
     /// REST method: `POST /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_role_mappings_clients_with_client_id_post(
         &self,
         realm: &str,
@@ -256,16 +335,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let client_id = p(client_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete client-level roles from user or group role mapping
@@ -286,6 +367,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmusersuser_idrole_mappingsclientsclient_id>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_role_mappings_clients_with_client_id_delete(
         &self,
         realm: &str,
@@ -296,16 +388,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let client_id = p(client_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get available client-level roles that can be mapped to the user or group
@@ -323,6 +417,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsclientsclient_idavailable>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}/available`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}/available",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_role_mappings_clients_with_client_id_available_get(
         &self,
         realm: &str,
@@ -332,15 +437,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let client_id = p(client_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}/available",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}/available",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}/available",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get effective client-level role mappings This recurses any composite roles
@@ -359,6 +466,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsclientsclient_idcomposite>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}/composite`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}/composite",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_role_mappings_clients_with_client_id_composite_get(
         &self,
         realm: &str,
@@ -369,18 +487,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let client_id = p(client_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}/composite",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/role-mappings/clients/{client_id}/composite",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/role-mappings/clients/{client-id}/composite",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
@@ -14,17 +14,28 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/client-scopes`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopes>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-scopes",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/client-scopes", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/client-scopes", self.url));
+        let response = self
+            .call("/admin/realms/{realm}/client-scopes", builder)
+            .await?;
+        response.json()
     }
 
     /// Create a new client scope Client Scope’s name must be unique!
@@ -41,19 +52,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/client-scopes`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_scopes>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/client-scopes",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_post(
         &self,
         realm: &str,
         body: ClientScopeRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!("{}/admin/realms/{realm}/client-scopes", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder =
+            KeycloakRequest::post(format!("{}/admin/realms/{realm}/client-scopes", self.url))
+                .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/client-scopes", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get representation of the client scope
@@ -70,6 +92,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_id>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_get(
         &self,
         realm: &str,
@@ -77,15 +110,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ClientScopeRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-scopes/{client_scope_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-scopes/{client_scope_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-scopes/{client-scope-id}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Update the client scope
@@ -105,6 +140,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclient_scopesclient_scope_id>
     ///
     /// REST method: `PUT /admin/realms/{realm}/client-scopes/{client-scope-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_put(
         &self,
         realm: &str,
@@ -113,16 +159,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/client-scopes/{client_scope_id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/client-scopes/{client_scope_id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-scopes/{client-scope-id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete the client scope
@@ -141,6 +189,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_scopesclient_scope_id>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/client-scopes/{client-scope-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_delete(
         &self,
         realm: &str,
@@ -148,15 +207,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/client-scopes/{client_scope_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/client-scopes/{client_scope_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-scopes/{client-scope-id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get client scopes belonging to the realm Returns a list of client scopes belonging to the realm
@@ -170,20 +231,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/client-templates`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templates>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-templates",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-templates",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-templates",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/client-templates", builder)
+            .await?;
+        response.json()
     }
 
     /// Create a new client scope Client Scope’s name must be unique!
@@ -200,22 +271,32 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/client-templates`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_templates>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/client-templates",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_post(
         &self,
         realm: &str,
         body: ClientScopeRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/client-templates",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/client-templates",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/client-templates", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get representation of the client scope
@@ -232,6 +313,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_id>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_get(
         &self,
         realm: &str,
@@ -239,15 +331,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ClientScopeRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-templates/{client_scope_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-templates/{client_scope_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-templates/{client-scope-id}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Update the client scope
@@ -267,6 +361,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclient_templatesclient_scope_id>
     ///
     /// REST method: `PUT /admin/realms/{realm}/client-templates/{client-scope-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_put(
         &self,
         realm: &str,
@@ -275,16 +380,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/client-templates/{client_scope_id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/client-templates/{client_scope_id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-templates/{client-scope-id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete the client scope
@@ -303,6 +410,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_templatesclient_scope_id>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/client-templates/{client-scope-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_delete(
         &self,
         realm: &str,
@@ -310,15 +428,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/client-templates/{client_scope_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/client-templates/{client_scope_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-templates/{client-scope-id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 }
 // not all paths processed
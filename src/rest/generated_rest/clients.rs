@@ -21,6 +21,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclients>
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_get(
         &self,
         realm: &str,
@@ -32,30 +43,28 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         viewable_only: Option<bool>,
     ) -> Result<TypeVec<ClientRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/clients", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/clients", self.url));
         if let Some(v) = client_id {
-            builder = builder.query(&[("clientId", v)]);
+            builder = builder.query(&[("clientId", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = q {
-            builder = builder.query(&[("q", v)]);
+            builder = builder.query(&[("q", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
         if let Some(v) = viewable_only {
-            builder = builder.query(&[("viewableOnly", v)]);
+            builder = builder.query(&[("viewableOnly", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/clients", builder).await?;
+        response.json()
     }
 
     /// Create a new client Client’s client_id must be unique!
@@ -72,19 +81,27 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/clients`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclients>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_post(
         &self,
         realm: &str,
         body: ClientRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!("{}/admin/realms/{realm}/clients", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!("{}/admin/realms/{realm}/clients", self.url))
+            .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/clients", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Get representation of the client
@@ -101,6 +118,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuid>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_get(
         &self,
         realm: &str,
@@ -108,15 +136,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ClientRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/clients/{client-uuid}", builder)
+            .await?;
+        response.json()
     }
 
     /// Update the client
@@ -136,6 +163,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuid>
     ///
     /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_put(
         &self,
         realm: &str,
@@ -144,16 +182,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/clients/{client-uuid}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete the client
@@ -172,6 +209,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_delete(
         &self,
         realm: &str,
@@ -179,15 +227,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/clients/{client-uuid}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get the client secret
@@ -204,6 +251,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidclient_secret>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/client-secret`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/client-secret",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_client_secret_get(
         &self,
         realm: &str,
@@ -211,15 +269,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<CredentialRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/client-secret",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/client-secret",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/client-secret",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Generate a new secret for the client
@@ -236,6 +296,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidclient_secret>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/client-secret`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/client-secret",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_client_secret_post(
         &self,
         realm: &str,
@@ -243,15 +314,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<CredentialRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/client-secret",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/client-secret",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/client-secret",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get the rotated client secret
@@ -268,6 +341,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidclient_secretrotated>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/client-secret/rotated`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/client-secret/rotated",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_client_secret_rotated_get(
         &self,
         realm: &str,
@@ -275,15 +359,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<CredentialRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/client-secret/rotated",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/client-secret/rotated",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/client-secret/rotated",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Invalidate the rotated secret for the client
@@ -302,6 +388,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidclient_secretrotated>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/client-secret/rotated`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/client-secret/rotated",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_client_secret_rotated_delete(
         &self,
         realm: &str,
@@ -309,15 +406,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/client-secret/rotated",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/client-secret/rotated",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/client-secret/rotated",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get default client scopes.  Only name and ids are returned.
@@ -334,6 +433,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuiddefault_client_scopes>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/default-client-scopes`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/default-client-scopes",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_default_client_scopes_get(
         &self,
         realm: &str,
@@ -341,15 +451,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/default-client-scopes",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/default-client-scopes",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/default-client-scopes",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -367,6 +479,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuiddefault_client_scopesclientscopeid>
     ///
     /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/default-client-scopes/{clientScopeId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/default-client-scopes/{clientScopeId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_default_client_scopes_with_client_scope_id_put(
         &self,
         realm: &str,
@@ -376,16 +499,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/default-client-scopes/{client_scope_id}",
-                self.url
-            ))
-            .header(CONTENT_LENGTH, "0")
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/default-client-scopes/{client_scope_id}",
+            self.url
+        ))
+        .header(CONTENT_LENGTH, HeaderValue::from_static("0"));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/default-client-scopes/{clientScopeId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -403,6 +528,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuiddefault_client_scopesclientscopeid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/default-client-scopes/{clientScopeId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/default-client-scopes/{clientScopeId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_default_client_scopes_with_client_scope_id_delete(
         &self,
         realm: &str,
@@ -412,15 +548,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/default-client-scopes/{client_scope_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/default-client-scopes/{client_scope_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/default-client-scopes/{clientScopeId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Create JSON with payload of example access token
@@ -440,6 +578,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesgenerate_example_access_token>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-access-token`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-access-token",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_evaluate_scopes_generate_example_access_token_get(
         &self,
         realm: &str,
@@ -450,24 +599,21 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<AccessToken, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/generate-example-access-token",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = audience {
-            builder = builder.query(&[("audience", v)]);
+            builder = builder.query(&[("audience", v)])?;
         }
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = user_id {
-            builder = builder.query(&[("userId", v)]);
+            builder = builder.query(&[("userId", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-access-token", builder).await?;
+        response.json()
     }
 
     /// Create JSON with payload of example id token
@@ -487,6 +633,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesgenerate_example_id_token>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-id-token`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-id-token",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_evaluate_scopes_generate_example_id_token_get(
         &self,
         realm: &str,
@@ -497,24 +654,21 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<IDToken, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/generate-example-id-token",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = audience {
-            builder = builder.query(&[("audience", v)]);
+            builder = builder.query(&[("audience", v)])?;
         }
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = user_id {
-            builder = builder.query(&[("userId", v)]);
+            builder = builder.query(&[("userId", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-id-token", builder).await?;
+        response.json()
     }
 
     /// Create JSON with payload of example user info
@@ -533,6 +687,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesgenerate_example_userinfo>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-userinfo`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-userinfo",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_evaluate_scopes_generate_example_userinfo_get(
         &self,
         realm: &str,
@@ -542,21 +707,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<Value, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/generate-example-userinfo",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = user_id {
-            builder = builder.query(&[("userId", v)]);
+            builder = builder.query(&[("userId", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/generate-example-userinfo", builder).await?;
+        response.json()
     }
 
     /// Return list of all protocol mappers, which will be used when generating tokens issued for particular client.
@@ -574,6 +736,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesprotocol_mappers>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/protocol-mappers`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/protocol-mappers",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_evaluate_scopes_protocol_mappers_get(
         &self,
         realm: &str,
@@ -582,18 +755,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<ProtocolMapperEvaluationRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/protocol-mappers",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/protocol-mappers",
+            self.url
+        ));
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/protocol-mappers",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get effective scope mapping of all roles of particular role container, which this client is defacto allowed to have in the accessToken issued for him.
@@ -612,6 +787,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesscope_mappingsrolecontaineridgranted>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/scope-mappings/{roleContainerId}/granted`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/scope-mappings/{roleContainerId}/granted",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_evaluate_scopes_scope_mappings_with_role_container_id_granted_get(
         &self,
         realm: &str,
@@ -622,18 +808,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_container_id = p(role_container_id);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/scope-mappings/{role_container_id}/granted",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/scope-mappings/{roleContainerId}/granted", builder).await?;
+        response.json()
     }
 
     /// Get roles, which this client doesn't have scope for and can't have them in the accessToken issued for him.
@@ -652,6 +835,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidevaluate_scopesscope_mappingsrolecontaineridnot_granted>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/scope-mappings/{roleContainerId}/not-granted`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/scope-mappings/{roleContainerId}/not-granted",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_evaluate_scopes_scope_mappings_with_role_container_id_not_granted_get(
         &self,
         realm: &str,
@@ -662,18 +856,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_container_id = p(role_container_id);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/evaluate-scopes/scope-mappings/{role_container_id}/not-granted",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/evaluate-scopes/scope-mappings/{roleContainerId}/not-granted", builder).await?;
+        response.json()
     }
 
     /// Parameters:
@@ -691,6 +882,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidinstallationprovidersproviderid>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/installation/providers/{providerId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/installation/providers/{providerId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_installation_providers_with_provider_id_get(
         &self,
         realm: &str,
@@ -700,15 +902,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let provider_id = p(provider_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/installation/providers/{provider_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/installation/providers/{provider_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/installation/providers/{providerId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Return object stating whether client Authorization permissions have been initialized or not and a reference
@@ -725,6 +929,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidmanagementpermissions>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/management/permissions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/management/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_management_permissions_get(
         &self,
         realm: &str,
@@ -732,15 +947,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ManagementPermissionReference, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/management/permissions",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/management/permissions",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/management/permissions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Return object stating whether client Authorization permissions have been initialized or not and a reference
@@ -758,6 +975,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidmanagementpermissions>
     ///
     /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/management/permissions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/management/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_management_permissions_put(
         &self,
         realm: &str,
@@ -766,16 +994,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ManagementPermissionReference, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/management/permissions",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/management/permissions",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/management/permissions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Register a cluster node with the client Manually register cluster node to this client - usually it’s not needed to call this directly as adapter should handle by sending registration request to Keycloak
@@ -795,6 +1025,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidnodes>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/nodes`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/nodes",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_nodes_post(
         &self,
         realm: &str,
@@ -803,16 +1044,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/nodes",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/nodes",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/clients/{client-uuid}/nodes", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Unregister a cluster node from the client
@@ -832,6 +1072,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidnodesnode>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/nodes/{node}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/nodes/{node}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_nodes_with_node_delete(
         &self,
         realm: &str,
@@ -841,15 +1092,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let node = p(node);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/nodes/{node}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/nodes/{node}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/nodes/{node}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get application offline session count Returns a number of offline user sessions associated with this client { "count": number }
@@ -866,6 +1119,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidoffline_session_count>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/offline-session-count`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/offline-session-count",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_offline_session_count_get(
         &self,
         realm: &str,
@@ -873,15 +1137,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeMap<String, i64>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/offline-session-count",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/offline-session-count",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/offline-session-count",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get offline sessions for client Returns a list of offline user sessions associated with this client
@@ -900,6 +1166,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidoffline_sessions>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/offline-sessions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/offline-sessions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_offline_sessions_get(
         &self,
         realm: &str,
@@ -909,21 +1186,23 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<UserSessionRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/offline-sessions",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/offline-sessions",
+            self.url
+        ));
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/offline-sessions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get optional client scopes.  Only name and ids are returned.
@@ -940,6 +1219,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidoptional_client_scopes>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_optional_client_scopes_get(
         &self,
         realm: &str,
@@ -947,15 +1237,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/optional-client-scopes",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/optional-client-scopes",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -973,6 +1265,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidoptional_client_scopesclientscopeid>
     ///
     /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes/{clientScopeId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes/{clientScopeId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_optional_client_scopes_with_client_scope_id_put(
         &self,
         realm: &str,
@@ -982,16 +1285,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .put(format!(
+        let builder = KeycloakRequest::put(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/optional-client-scopes/{client_scope_id}",
                 self.url
             ))
-            .header(CONTENT_LENGTH, "0")
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+            .header(CONTENT_LENGTH, HeaderValue::from_static("0"));
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes/{clientScopeId}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -1009,6 +1309,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidoptional_client_scopesclientscopeid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes/{clientScopeId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes/{clientScopeId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_optional_client_scopes_with_client_scope_id_delete(
         &self,
         realm: &str,
@@ -1018,15 +1329,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .delete(format!(
+        let builder = KeycloakRequest::delete(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/optional-client-scopes/{client_scope_id}",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+            ));
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/optional-client-scopes/{clientScopeId}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Push the client's revocation policy to its admin URL If the client has an admin URL, push revocation policy to it.
@@ -1043,6 +1351,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidpush_revocation>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/push-revocation`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/push-revocation",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_push_revocation_post(
         &self,
         realm: &str,
@@ -1050,15 +1369,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<GlobalRequestResult, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/push-revocation",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/push-revocation",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/push-revocation",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Generate a new registration access token for the client
@@ -1075,6 +1396,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidregistration_access_token>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/registration-access-token`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/registration-access-token",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_registration_access_token_post(
         &self,
         realm: &str,
@@ -1082,15 +1414,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ClientRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/registration-access-token",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/registration-access-token",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/registration-access-token",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get a user dedicated to the service account
@@ -1107,6 +1441,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidservice_account_user>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/service-account-user`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/service-account-user",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_service_account_user_get(
         &self,
         realm: &str,
@@ -1114,15 +1459,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<UserRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/service-account-user",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/service-account-user",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/service-account-user",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get application session count Returns a number of user sessions associated with this client { "count": number }
@@ -1139,6 +1486,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidsession_count>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/session-count`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/session-count",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_session_count_get(
         &self,
         realm: &str,
@@ -1146,15 +1504,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeMap<String, i64>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/session-count",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/session-count",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/session-count",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Test if registered cluster nodes are available Tests availability by sending 'ping' request to all cluster nodes.
@@ -1171,6 +1531,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidtest_nodes_available>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/test-nodes-available`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/test-nodes-available",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_test_nodes_available_get(
         &self,
         realm: &str,
@@ -1178,15 +1549,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<GlobalRequestResult, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/test-nodes-available",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/test-nodes-available",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/test-nodes-available",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get user sessions for client Returns a list of user sessions associated with this client
@@ -1205,6 +1578,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuiduser_sessions>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/user-sessions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/user-sessions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_user_sessions_get(
         &self,
         realm: &str,
@@ -1214,21 +1598,23 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<UserSessionRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/user-sessions",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/user-sessions",
+            self.url
+        ));
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/user-sessions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
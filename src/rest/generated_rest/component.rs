@@ -16,6 +16,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/components`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmcomponents>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/components",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_components_get(
         &self,
         realm: &str,
@@ -25,24 +36,24 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         type_: Option<String>,
     ) -> Result<TypeVec<ComponentRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/components", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/components", self.url));
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
         if let Some(v) = parent {
-            builder = builder.query(&[("parent", v)]);
+            builder = builder.query(&[("parent", v)])?;
         }
         if let Some(v) = provider_id {
-            builder = builder.query(&[("providerId", v)]);
+            builder = builder.query(&[("providerId", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
+            builder = builder.query(&[("type", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/components", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -57,19 +68,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/components`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmcomponents>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/components",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_components_post(
         &self,
         realm: &str,
         body: ComponentRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!("{}/admin/realms/{realm}/components", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder =
+            KeycloakRequest::post(format!("{}/admin/realms/{realm}/components", self.url))
+                .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/components", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -82,6 +104,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/components/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmcomponentsid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/components/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_components_with_id_get(
         &self,
         realm: &str,
@@ -89,12 +122,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ComponentRepresentation, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/components/{id}", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/components/{id}", self.url));
+        let response = self
+            .call("/admin/realms/{realm}/components/{id}", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -110,6 +143,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/components/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmcomponentsid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/components/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_components_with_id_put(
         &self,
         realm: &str,
@@ -118,13 +162,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let builder = self
-            .client
-            .put(format!("{}/admin/realms/{realm}/components/{id}", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder =
+            KeycloakRequest::put(format!("{}/admin/realms/{realm}/components/{id}", self.url))
+                .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/components/{id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -139,6 +183,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/components/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmcomponentsid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/components/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_components_with_id_delete(
         &self,
         realm: &str,
@@ -146,12 +201,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let builder = self
-            .client
-            .delete(format!("{}/admin/realms/{realm}/components/{id}", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder =
+            KeycloakRequest::delete(format!("{}/admin/realms/{realm}/components/{id}", self.url));
+        let response = self
+            .call("/admin/realms/{realm}/components/{id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// List of subcomponent types that are available to configure for a particular parent component.
@@ -167,6 +222,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/components/{id}/sub-component-types`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmcomponentsidsub_component_types>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/components/{id}/sub-component-types",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_components_with_id_sub_component_types_get(
         &self,
         realm: &str,
@@ -175,18 +241,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<ComponentTypeRepresentation>, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/components/{id}/sub-component-types",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/components/{id}/sub-component-types",
+            self.url
+        ));
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
+            builder = builder.query(&[("type", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/components/{id}/sub-component-types",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
@@ -23,6 +23,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroups>
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/groups",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_get(
         &self,
         realm: &str,
@@ -36,36 +47,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         sub_groups_count: Option<bool>,
     ) -> Result<TypeVec<GroupRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/groups", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!("{}/admin/realms/{realm}/groups", self.url));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = exact {
-            builder = builder.query(&[("exact", v)]);
+            builder = builder.query(&[("exact", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = populate_hierarchy {
-            builder = builder.query(&[("populateHierarchy", v)]);
+            builder = builder.query(&[("populateHierarchy", v)])?;
         }
         if let Some(v) = q {
-            builder = builder.query(&[("q", v)]);
+            builder = builder.query(&[("q", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
         if let Some(v) = sub_groups_count {
-            builder = builder.query(&[("subGroupsCount", v)]);
+            builder = builder.query(&[("subGroupsCount", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/groups", builder).await?;
+        response.json()
     }
 
     /// create or add a top level realm groupSet or create child.
@@ -82,19 +90,27 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/groups`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmgroups>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/groups",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_post(
         &self,
         realm: &str,
         body: GroupRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!("{}/admin/realms/{realm}/groups", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!("{}/admin/realms/{realm}/groups", self.url))
+            .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/groups", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Returns the groups counts.
@@ -110,6 +126,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/groups/count`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupscount>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/groups/count",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_count_get(
         &self,
         realm: &str,
@@ -117,18 +144,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         top: Option<bool>,
     ) -> Result<TypeMap<String, i64>, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/groups/count", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/groups/count", self.url));
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
         if let Some(v) = top {
-            builder = builder.query(&[("top", v)]);
+            builder = builder.query(&[("top", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/groups/count", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -143,6 +170,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_id>
     ///
     /// REST method: `GET /admin/realms/{realm}/groups/{group-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/groups/{group-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_get(
         &self,
         realm: &str,
@@ -150,15 +188,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<GroupRepresentation, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/groups/{group-id}", builder)
+            .await?;
+        response.json()
     }
 
     /// Update group, ignores subgroups.
@@ -178,6 +215,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmgroupsgroup_id>
     ///
     /// REST method: `PUT /admin/realms/{realm}/groups/{group-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/groups/{group-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_put(
         &self,
         realm: &str,
@@ -186,16 +234,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/groups/{group-id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -212,6 +259,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmgroupsgroup_id>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/groups/{group-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/groups/{group-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_delete(
         &self,
         realm: &str,
@@ -219,15 +277,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/groups/{group-id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Return a paginated list of subgroups that have a parent group corresponding to the group on the URL
@@ -251,6 +308,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/children`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/children",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_children_get(
         &self,
         realm: &str,
@@ -264,33 +332,32 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<GroupRepresentation>, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/children",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/children",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = exact {
-            builder = builder.query(&[("exact", v)]);
+            builder = builder.query(&[("exact", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
         if let Some(v) = sub_groups_count {
-            builder = builder.query(&[("subGroupsCount", v)]);
+            builder = builder.query(&[("subGroupsCount", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/groups/{group-id}/children", builder)
+            .await?;
+        response.json()
     }
 
     /// Set or create child.
@@ -310,6 +377,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmgroupsgroup_idchildren>
     ///
     /// REST method: `POST /admin/realms/{realm}/groups/{group-id}/children`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/children",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_children_post(
         &self,
         realm: &str,
@@ -318,16 +396,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/children",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/children",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/groups/{group-id}/children", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Return object stating whether client Authorization permissions have been initialized or not and a reference
@@ -344,6 +421,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idmanagementpermissions>
     ///
     /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/management/permissions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/management/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_management_permissions_get(
         &self,
         realm: &str,
@@ -351,15 +439,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ManagementPermissionReference, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/management/permissions",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/management/permissions",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/groups/{group-id}/management/permissions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Return object stating whether client Authorization permissions have been initialized or not and a reference
@@ -377,6 +467,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmgroupsgroup_idmanagementpermissions>
     ///
     /// REST method: `PUT /admin/realms/{realm}/groups/{group-id}/management/permissions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/management/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_management_permissions_put(
         &self,
         realm: &str,
@@ -385,16 +486,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ManagementPermissionReference, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/management/permissions",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/management/permissions",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/groups/{group-id}/management/permissions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get users Returns a stream of users, filtered according to query parameters
@@ -414,6 +517,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idmembers>
     ///
     /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/members`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/members",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_members_get(
         &self,
         realm: &str,
@@ -424,24 +538,23 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/members",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/members",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/groups/{group-id}/members", builder)
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
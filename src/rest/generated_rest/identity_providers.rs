@@ -15,22 +15,35 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/identity-provider/import-config`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerimport_config>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/identity-provider/import-config",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_import_config_post(
         &self,
         realm: &str,
         body: TypeMap<String, Value>,
     ) -> Result<TypeMap<String, TypeString>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/identity-provider/import-config",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/identity-provider/import-config",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/import-config",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// List identity providers
@@ -52,6 +65,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstances>
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/identity-provider/instances",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_get(
         &self,
         realm: &str,
@@ -64,36 +88,35 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         type_: Option<String>,
     ) -> Result<TypeVec<IdentityProviderRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = capability {
-            builder = builder.query(&[("capability", v)]);
+            builder = builder.query(&[("capability", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = realm_only {
-            builder = builder.query(&[("realmOnly", v)]);
+            builder = builder.query(&[("realmOnly", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
+            builder = builder.query(&[("type", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/identity-provider/instances", builder)
+            .await?;
+        response.json()
     }
 
     /// Create a new identity provider
@@ -110,22 +133,32 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/identity-provider/instances`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerinstances>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/identity-provider/instances",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_post(
         &self,
         realm: &str,
         body: IdentityProviderRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/identity-provider/instances", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get the identity provider
@@ -140,6 +173,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesalias>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/identity-provider/instances/{alias}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_with_alias_get(
         &self,
         realm: &str,
@@ -147,15 +191,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<IdentityProviderRepresentation, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances/{alias}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances/{alias}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/instances/{alias}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Update the identity provider
@@ -173,6 +219,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/identity-provider/instances/{alias}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmidentity_providerinstancesalias>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/identity-provider/instances/{alias}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_with_alias_put(
         &self,
         realm: &str,
@@ -181,16 +238,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances/{alias}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances/{alias}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/instances/{alias}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete the identity provider
@@ -207,6 +266,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/identity-provider/instances/{alias}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmidentity_providerinstancesalias>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/identity-provider/instances/{alias}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_with_alias_delete(
         &self,
         realm: &str,
@@ -214,15 +284,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances/{alias}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances/{alias}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/instances/{alias}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Export public broker configuration for identity provider
@@ -240,6 +312,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/export`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasexport>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/identity-provider/instances/{alias}/export",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_with_alias_export_get(
         &self,
         realm: &str,
@@ -248,18 +331,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances/{alias}/export",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances/{alias}/export",
+            self.url
+        ));
         if let Some(v) = format {
-            builder = builder.query(&[("format", v)]);
+            builder = builder.query(&[("format", v)])?;
         }
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/instances/{alias}/export",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Return object stating whether client Authorization permissions have been initialized or not and a reference
@@ -274,6 +359,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasmanagementpermissions>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_with_alias_management_permissions_get(
         &self,
         realm: &str,
@@ -281,15 +377,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ManagementPermissionReference, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Return object stating whether client Authorization permissions have been initialized or not and a reference
@@ -305,6 +403,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmidentity_providerinstancesaliasmanagementpermissions>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_with_alias_management_permissions_put(
         &self,
         realm: &str,
@@ -313,16 +422,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ManagementPermissionReference, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/instances/{alias}/management/permissions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get mapper types for identity provider
@@ -337,6 +448,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/mapper-types`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasmapper_types>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/identity-provider/instances/{alias}/mapper-types",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_with_alias_mapper_types_get(
         &self,
         realm: &str,
@@ -344,15 +466,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeMap<String, IdentityProviderMapperTypeRepresentation>, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances/{alias}/mapper-types",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances/{alias}/mapper-types",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/instances/{alias}/mapper-types",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get mappers for identity provider
@@ -367,6 +491,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/mappers`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasmappers>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/identity-provider/instances/{alias}/mappers",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_with_alias_mappers_get(
         &self,
         realm: &str,
@@ -374,15 +509,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<IdentityProviderMapperRepresentation>, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances/{alias}/mappers",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances/{alias}/mappers",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/instances/{alias}/mappers",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Add a mapper to identity provider
@@ -400,6 +537,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/identity-provider/instances/{alias}/mappers`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerinstancesaliasmappers>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/identity-provider/instances/{alias}/mappers",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_with_alias_mappers_post(
         &self,
         realm: &str,
@@ -408,16 +556,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances/{alias}/mappers",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances/{alias}/mappers",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/instances/{alias}/mappers",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get mapper by id for the identity provider
@@ -433,6 +583,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasmappersid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_with_alias_mappers_with_id_get(
         &self,
         realm: &str,
@@ -442,15 +603,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let alias = p(alias);
         let id = p(id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Update a mapper for the identity provider
@@ -469,6 +632,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmidentity_providerinstancesaliasmappersid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_with_alias_mappers_with_id_put(
         &self,
         realm: &str,
@@ -479,16 +653,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let alias = p(alias);
         let id = p(id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete a mapper for the identity provider
@@ -506,6 +682,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmidentity_providerinstancesaliasmappersid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_with_alias_mappers_with_id_delete(
         &self,
         realm: &str,
@@ -515,15 +702,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let alias = p(alias);
         let id = p(id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/instances/{alias}/mappers/{id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Reaload keys for the identity provider if the provider supports it, "true" is returned if reload was performed, "false" if not.
@@ -538,6 +727,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/identity-provider/instances/{alias}/reload-keys`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerinstancesaliasreload_keys>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/identity-provider/instances/{alias}/reload-keys",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_instances_with_alias_reload_keys_get(
         &self,
         realm: &str,
@@ -545,15 +745,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<bool, KeycloakError> {
         let realm = p(realm);
         let alias = p(alias);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/identity-provider/instances/{alias}/reload-keys",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/identity-provider/instances/{alias}/reload-keys",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/instances/{alias}/reload-keys",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get the identity provider factory for that provider id
@@ -568,6 +770,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/identity-provider/providers/{provider_id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmidentity_providerprovidersprovider_id>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/identity-provider/providers/{provider_id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_providers_with_provider_id_get(
         &self,
         realm: &str,
@@ -575,15 +788,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<IdentityProviderRepresentation, KeycloakError> {
         let realm = p(realm);
         let provider_id = p(provider_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/identity-provider/providers/{provider_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/identity-provider/providers/{provider_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/providers/{provider_id}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
@@ -12,17 +12,25 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/keys`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmkeys>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/keys",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_keys_get(
         &self,
         realm: &str,
     ) -> Result<KeysMetadataRepresentation, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/keys", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!("{}/admin/realms/{realm}/keys", self.url));
+        let response = self.call("/admin/realms/{realm}/keys", builder).await?;
+        response.json()
     }
 }
 // not all paths processed
@@ -1,4 +1,4 @@
-use reqwest::header::CONTENT_LENGTH;
+use http::header::{HeaderValue, CONTENT_LENGTH};
 use serde_json::Value;
 
 use super::{url_enc::encode_url_param as p, *};
@@ -21,6 +21,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmorganizations>
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_get(
         &self,
         realm: &str,
@@ -32,30 +43,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         search: Option<String>,
     ) -> Result<TypeVec<OrganizationRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/organizations", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/organizations", self.url));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = exact {
-            builder = builder.query(&[("exact", v)]);
+            builder = builder.query(&[("exact", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = q {
-            builder = builder.query(&[("q", v)]);
+            builder = builder.query(&[("q", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/organizations", builder)
+            .await?;
+        response.json()
     }
 
     /// Creates a new organization
@@ -72,19 +83,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/organizations`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmorganizations>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/organizations",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_post(
         &self,
         realm: &str,
         body: OrganizationRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!("{}/admin/realms/{realm}/organizations", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder =
+            KeycloakRequest::post(format!("{}/admin/realms/{realm}/organizations", self.url))
+                .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/organizations", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Returns the organizations counts.
@@ -101,6 +123,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/organizations/count`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmorganizationscount>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/count",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_count_get(
         &self,
         realm: &str,
@@ -109,24 +142,23 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         search: Option<String>,
     ) -> Result<i64, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/count",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/count",
+            self.url
+        ));
         if let Some(v) = exact {
-            builder = builder.query(&[("exact", v)]);
+            builder = builder.query(&[("exact", v)])?;
         }
         if let Some(v) = q {
-            builder = builder.query(&[("q", v)]);
+            builder = builder.query(&[("q", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/organizations/count", builder)
+            .await?;
+        response.json()
     }
 
     /// Returns the organizations associated with the user that has the specified id
@@ -144,6 +176,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmorganizationsmembersmember_idorganizations>
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/members/{member-id}/organizations`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/members/{member-id}/organizations",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_members_with_member_id_organizations_get(
         &self,
         realm: &str,
@@ -152,18 +195,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<OrganizationRepresentation>, KeycloakError> {
         let realm = p(realm);
         let member_id = p(member_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/members/{member_id}/organizations",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/members/{member_id}/organizations",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/members/{member-id}/organizations",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Returns the organization representation
@@ -180,6 +225,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmorganizationsorg_id>
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_get(
         &self,
         realm: &str,
@@ -187,15 +243,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<OrganizationRepresentation, KeycloakError> {
         let realm = p(realm);
         let org_id = p(org_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/organizations/{org-id}", builder)
+            .await?;
+        response.json()
     }
 
     /// Updates the organization
@@ -215,6 +270,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmorganizationsorg_id>
     ///
     /// REST method: `PUT /admin/realms/{realm}/organizations/{org-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_put(
         &self,
         realm: &str,
@@ -223,16 +289,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let org_id = p(org_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/organizations/{org-id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Deletes the organization
@@ -251,6 +316,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmorganizationsorg_id>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/organizations/{org-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_delete(
         &self,
         realm: &str,
@@ -258,15 +334,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let org_id = p(org_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/organizations/{org-id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get organization groups
@@ -292,6 +367,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/groups`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/groups",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_groups_get(
         &self,
         realm: &str,
@@ -307,39 +393,41 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<GroupRepresentation>, KeycloakError> {
         let realm = p(realm);
         let org_id = p(org_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/groups",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/groups",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = exact {
-            builder = builder.query(&[("exact", v)]);
+            builder = builder.query(&[("exact", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = populate_hierarchy {
-            builder = builder.query(&[("populateHierarchy", v)]);
+            builder = builder.query(&[("populateHierarchy", v)])?;
         }
         if let Some(v) = q {
-            builder = builder.query(&[("q", v)]);
+            builder = builder.query(&[("q", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
         if let Some(v) = sub_groups_count {
-            builder = builder.query(&[("subGroupsCount", v)]);
+            builder = builder.query(&[("subGroupsCount", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/groups",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Creates a new top-level group or moves an existing group to top-level
@@ -359,6 +447,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmorganizationsorg_idgroups>
     ///
     /// REST method: `POST /admin/realms/{realm}/organizations/{org-id}/groups`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/groups",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_groups_post(
         &self,
         realm: &str,
@@ -367,16 +466,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let org_id = p(org_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/groups",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/groups",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/groups",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get organization group by path
@@ -395,6 +496,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmorganizationsorg_idgroupsgroup_by_pathpath>
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/groups/group-by-path/{path}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/groups/group-by-path/{path}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_groups_group_by_path_with_path_get(
         &self,
         realm: &str,
@@ -405,18 +517,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let path = p(path);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/groups/group-by-path/{path}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/groups/group-by-path/{path}",
+            self.url
+        ));
         if let Some(v) = sub_groups_count {
-            builder = builder.query(&[("subGroupsCount", v)]);
+            builder = builder.query(&[("subGroupsCount", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/groups/group-by-path/{path}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get organization group representation
@@ -435,6 +549,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmorganizationsorg_idgroupsgroup_id>
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/groups/{group-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_groups_with_group_id_get(
         &self,
         realm: &str,
@@ -445,18 +570,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let group_id = p(group_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}",
+            self.url
+        ));
         if let Some(v) = sub_groups_count {
-            builder = builder.query(&[("subGroupsCount", v)]);
+            builder = builder.query(&[("subGroupsCount", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Update organization group
@@ -477,6 +604,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmorganizationsorg_idgroupsgroup_id>
     ///
     /// REST method: `PUT /admin/realms/{realm}/organizations/{org-id}/groups/{group-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_groups_with_group_id_put(
         &self,
         realm: &str,
@@ -487,16 +625,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete the organization group
@@ -516,6 +656,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmorganizationsorg_idgroupsgroup_id>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/organizations/{org-id}/groups/{group-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_groups_with_group_id_delete(
         &self,
         realm: &str,
@@ -525,15 +676,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get subgroups of this organization group
@@ -557,6 +710,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/children`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/children",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_groups_with_group_id_children_get(
         &self,
         realm: &str,
@@ -571,30 +735,32 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let group_id = p(group_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}/children",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}/children",
+            self.url
+        ));
         if let Some(v) = exact {
-            builder = builder.query(&[("exact", v)]);
+            builder = builder.query(&[("exact", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
         if let Some(v) = sub_groups_count {
-            builder = builder.query(&[("subGroupsCount", v)]);
+            builder = builder.query(&[("subGroupsCount", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/children",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Create or move a subgroup
@@ -615,6 +781,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmorganizationsorg_idgroupsgroup_idchildren>
     ///
     /// REST method: `POST /admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/children`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/children",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_groups_with_group_id_children_post(
         &self,
         realm: &str,
@@ -625,16 +802,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}/children",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}/children",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/children",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get members of this organization group
@@ -655,6 +834,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmorganizationsorg_idgroupsgroup_idmembers>
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/members`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/members",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_groups_with_group_id_members_get(
         &self,
         realm: &str,
@@ -667,24 +857,26 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let group_id = p(group_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}/members",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}/members",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/members",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Add a user to this organization group
@@ -705,6 +897,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmorganizationsorg_idgroupsgroup_idmembersuserid>
     ///
     /// REST method: `PUT /admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/members/{userId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/members/{userId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_groups_with_group_id_members_with_user_id_put(
         &self,
         realm: &str,
@@ -716,16 +919,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let org_id = p(org_id);
         let group_id = p(group_id);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}/members/{user_id}",
-                self.url
-            ))
-            .header(CONTENT_LENGTH, "0")
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}/members/{user_id}",
+            self.url
+        ))
+        .header(CONTENT_LENGTH, HeaderValue::from_static("0"));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/members/{userId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Remove a user from this organization group
@@ -746,6 +951,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmorganizationsorg_idgroupsgroup_idmembersuserid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/members/{userId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/members/{userId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_groups_with_group_id_members_with_user_id_delete(
         &self,
         realm: &str,
@@ -757,15 +973,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let org_id = p(org_id);
         let group_id = p(group_id);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}/members/{user_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/groups/{group_id}/members/{user_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/groups/{group-id}/members/{userId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Returns all identity providers associated with the organization
@@ -782,6 +1000,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmorganizationsorg_ididentity_providers>
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/identity-providers`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/identity-providers",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_identity_providers_get(
         &self,
         realm: &str,
@@ -789,15 +1018,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<IdentityProviderRepresentation>, KeycloakError> {
         let realm = p(realm);
         let org_id = p(org_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/identity-providers",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/identity-providers",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/identity-providers",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Adds the identity provider with the specified id to the organization
@@ -817,6 +1048,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmorganizationsorg_ididentity_providers>
     ///
     /// REST method: `POST /admin/realms/{realm}/organizations/{org-id}/identity-providers`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/identity-providers",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_identity_providers_post(
         &self,
         realm: &str,
@@ -825,16 +1067,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let org_id = p(org_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/identity-providers",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/identity-providers",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/identity-providers",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Returns the identity provider associated with the organization that has the specified alias
@@ -852,6 +1096,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmorganizationsorg_ididentity_providersalias>
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/identity-providers/{alias}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/identity-providers/{alias}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_identity_providers_with_alias_get(
         &self,
         realm: &str,
@@ -861,15 +1116,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let alias = p(alias);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/identity-providers/{alias}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/identity-providers/{alias}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/identity-providers/{alias}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Removes the identity provider with the specified alias from the organization
@@ -889,6 +1146,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmorganizationsorg_ididentity_providersalias>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/organizations/{org-id}/identity-providers/{alias}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/identity-providers/{alias}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_identity_providers_with_alias_delete(
         &self,
         realm: &str,
@@ -898,15 +1166,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let alias = p(alias);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/identity-providers/{alias}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/identity-providers/{alias}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/identity-providers/{alias}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Returns organization groups for the identity provider
@@ -932,6 +1202,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/identity-providers/{alias}/groups`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/identity-providers/{alias}/groups",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_identity_providers_with_alias_groups_get(
         &self,
         realm: &str,
@@ -948,36 +1229,38 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let alias = p(alias);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/identity-providers/{alias}/groups",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/identity-providers/{alias}/groups",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = exact {
-            builder = builder.query(&[("exact", v)]);
+            builder = builder.query(&[("exact", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = q {
-            builder = builder.query(&[("q", v)]);
+            builder = builder.query(&[("q", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
         if let Some(v) = sub_groups_count {
-            builder = builder.query(&[("subGroupsCount", v)]);
+            builder = builder.query(&[("subGroupsCount", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/identity-providers/{alias}/groups",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get invitations for the organization
@@ -1002,6 +1285,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/invitations`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/invitations",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_invitations_get(
         &self,
         realm: &str,
@@ -1016,36 +1310,38 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<OrganizationInvitationRepresentation>, KeycloakError> {
         let realm = p(realm);
         let org_id = p(org_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/invitations",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/invitations",
+            self.url
+        ));
         if let Some(v) = email {
-            builder = builder.query(&[("email", v)]);
+            builder = builder.query(&[("email", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = first_name {
-            builder = builder.query(&[("firstName", v)]);
+            builder = builder.query(&[("firstName", v)])?;
         }
         if let Some(v) = last_name {
-            builder = builder.query(&[("lastName", v)]);
+            builder = builder.query(&[("lastName", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
         if let Some(v) = status {
-            builder = builder.query(&[("status", v)]);
+            builder = builder.query(&[("status", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/invitations",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get invitation by ID
@@ -1063,6 +1359,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmorganizationsorg_idinvitationsid>
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/invitations/{id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/invitations/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_invitations_with_id_get(
         &self,
         realm: &str,
@@ -1072,15 +1379,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let id = p(id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/invitations/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/invitations/{id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/invitations/{id}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Delete an invitation
@@ -1100,6 +1409,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmorganizationsorg_idinvitationsid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/organizations/{org-id}/invitations/{id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/invitations/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_invitations_with_id_delete(
         &self,
         realm: &str,
@@ -1109,15 +1429,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let id = p(id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/invitations/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/invitations/{id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/invitations/{id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Resend an invitation
@@ -1137,6 +1459,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmorganizationsorg_idinvitationsidresend>
     ///
     /// REST method: `POST /admin/realms/{realm}/organizations/{org-id}/invitations/{id}/resend`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/invitations/{id}/resend",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_invitations_with_id_resend_post(
         &self,
         realm: &str,
@@ -1146,15 +1479,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let id = p(id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/invitations/{id}/resend",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/invitations/{id}/resend",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/invitations/{id}/resend",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Returns a paginated list of organization members filtered according to the specified parameters
@@ -1177,6 +1512,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/members`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/members",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_members_get(
         &self,
         realm: &str,
@@ -1189,30 +1535,32 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<MemberRepresentation>, KeycloakError> {
         let realm = p(realm);
         let org_id = p(org_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/members",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/members",
+            self.url
+        ));
         if let Some(v) = exact {
-            builder = builder.query(&[("exact", v)]);
+            builder = builder.query(&[("exact", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = membership_type {
-            builder = builder.query(&[("membershipType", v)]);
+            builder = builder.query(&[("membershipType", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/members",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Adds the user with the specified id as a member of the organization
@@ -1232,6 +1580,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmorganizationsorg_idmembers>
     ///
     /// REST method: `POST /admin/realms/{realm}/organizations/{org-id}/members`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/members",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_members_post(
         &self,
         realm: &str,
@@ -1240,16 +1599,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let org_id = p(org_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/members",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/members",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/members",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Returns number of members in the organization.
@@ -1266,6 +1627,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmorganizationsorg_idmemberscount>
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/members/count`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/members/count",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_members_count_get(
         &self,
         realm: &str,
@@ -1273,15 +1645,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<i64, KeycloakError> {
         let realm = p(realm);
         let org_id = p(org_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/members/count",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/members/count",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/members/count",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Invites an existing user to the organization, using the specified user id
@@ -1301,6 +1675,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmorganizationsorg_idmembersinvite_existing_user>
     ///
     /// REST method: `POST /admin/realms/{realm}/organizations/{org-id}/members/invite-existing-user`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/members/invite-existing-user",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_members_invite_existing_user_post(
         &self,
         realm: &str,
@@ -1309,16 +1694,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let org_id = p(org_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/members/invite-existing-user",
-                self.url
-            ))
-            .form(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/members/invite-existing-user",
+            self.url
+        ))
+        .form(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/members/invite-existing-user",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Invites an existing user or sends a registration link to a new user, based on the provided e-mail address.
@@ -1338,6 +1725,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmorganizationsorg_idmembersinvite_user>
     ///
     /// REST method: `POST /admin/realms/{realm}/organizations/{org-id}/members/invite-user`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/members/invite-user",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_members_invite_user_post(
         &self,
         realm: &str,
@@ -1346,16 +1744,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let org_id = p(org_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/members/invite-user",
-                self.url
-            ))
-            .form(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/members/invite-user",
+            self.url
+        ))
+        .form(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/members/invite-user",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Returns the member of the organization with the specified id
@@ -1373,6 +1773,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmorganizationsorg_idmembersmember_id>
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/members/{member-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/members/{member-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_members_with_member_id_get(
         &self,
         realm: &str,
@@ -1382,15 +1793,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let member_id = p(member_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/members/{member_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/members/{member_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/members/{member-id}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Removes the user with the specified id from the organization
@@ -1410,6 +1823,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmorganizationsorg_idmembersmember_id>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/organizations/{org-id}/members/{member-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/members/{member-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_members_with_member_id_delete(
         &self,
         realm: &str,
@@ -1419,15 +1843,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let member_id = p(member_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/members/{member_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/members/{member_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/members/{member-id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Returns the organization group memberships for a member with the specified id
@@ -1450,6 +1876,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/members/{member-id}/groups`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/members/{member-id}/groups",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_members_with_member_id_groups_get(
         &self,
         realm: &str,
@@ -1463,27 +1900,29 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let member_id = p(member_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/members/{member_id}/groups",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/members/{member_id}/groups",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/members/{member-id}/groups",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Returns the organizations associated with the user that has the specified id
@@ -1502,6 +1941,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmorganizationsorg_idmembersmember_idorganizations>
     ///
     /// REST method: `GET /admin/realms/{realm}/organizations/{org-id}/members/{member-id}/organizations`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/organizations/{org-id}/members/{member-id}/organizations",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_organizations_with_org_id_members_with_member_id_organizations_get(
         &self,
         realm: &str,
@@ -1512,18 +1962,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let org_id = p(org_id);
         let member_id = p(member_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/organizations/{org_id}/members/{member_id}/organizations",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/organizations/{org_id}/members/{member_id}/organizations",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/organizations/{org-id}/members/{member-id}/organizations",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
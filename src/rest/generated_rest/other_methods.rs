@@ -13,6 +13,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_server>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_get(
         &self,
         realm: &str,
@@ -20,15 +31,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ResourceServerRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -44,6 +57,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidauthzresource_server>
     ///
     /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_put(
         &self,
         realm: &str,
@@ -52,16 +76,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -77,6 +103,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverimport>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/import`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/import",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_import_post(
         &self,
         realm: &str,
@@ -85,16 +122,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/import",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/import",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/import",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -119,6 +158,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_permission_get(
         &self,
         realm: &str,
@@ -137,48 +187,50 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<AbstractPolicyRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission",
+            self.url
+        ));
         if let Some(v) = fields {
-            builder = builder.query(&[("fields", v)]);
+            builder = builder.query(&[("fields", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
         if let Some(v) = owner {
-            builder = builder.query(&[("owner", v)]);
+            builder = builder.query(&[("owner", v)])?;
         }
         if let Some(v) = permission {
-            builder = builder.query(&[("permission", v)]);
+            builder = builder.query(&[("permission", v)])?;
         }
         if let Some(v) = policy_id {
-            builder = builder.query(&[("policyId", v)]);
+            builder = builder.query(&[("policyId", v)])?;
         }
         if let Some(v) = resource {
-            builder = builder.query(&[("resource", v)]);
+            builder = builder.query(&[("resource", v)])?;
         }
         if let Some(v) = resource_type {
-            builder = builder.query(&[("resourceType", v)]);
+            builder = builder.query(&[("resourceType", v)])?;
         }
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
-        }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            builder = builder.query(&[("type", v)])?;
+        }
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -194,6 +246,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverpermission>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_permission_post(
         &self,
         realm: &str,
@@ -202,16 +265,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -225,6 +290,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverpermissionevaluate>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/evaluate`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/evaluate",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_permission_evaluate_post(
         &self,
         realm: &str,
@@ -233,16 +309,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<PolicyEvaluationResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
+        let builder = KeycloakRequest::post(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission/evaluate",
                 self.url
             ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/evaluate", builder).await?;
+        response.json()
     }
 
     /// Parameters:
@@ -255,6 +328,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpermissionproviders>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/providers`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/providers",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_permission_providers_get(
         &self,
         realm: &str,
@@ -262,15 +346,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<PolicyProviderRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission/providers",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/providers", builder).await?;
+        response.json()
     }
 
     /// Parameters:
@@ -285,6 +366,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpermissionsearch>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/search`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/search",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_permission_search_get(
         &self,
         realm: &str,
@@ -294,21 +386,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<AbstractPolicyRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission/search",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/permission/search",
+            self.url
+        ));
         if let Some(v) = fields {
-            builder = builder.query(&[("fields", v)]);
+            builder = builder.query(&[("fields", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/permission/search", builder).await?;
+        response.json()
     }
 
     /// Parameters:
@@ -333,6 +422,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_policy_get(
         &self,
         realm: &str,
@@ -351,48 +451,50 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<AbstractPolicyRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy",
+            self.url
+        ));
         if let Some(v) = fields {
-            builder = builder.query(&[("fields", v)]);
+            builder = builder.query(&[("fields", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
         if let Some(v) = owner {
-            builder = builder.query(&[("owner", v)]);
+            builder = builder.query(&[("owner", v)])?;
         }
         if let Some(v) = permission {
-            builder = builder.query(&[("permission", v)]);
+            builder = builder.query(&[("permission", v)])?;
         }
         if let Some(v) = policy_id {
-            builder = builder.query(&[("policyId", v)]);
+            builder = builder.query(&[("policyId", v)])?;
         }
         if let Some(v) = resource {
-            builder = builder.query(&[("resource", v)]);
+            builder = builder.query(&[("resource", v)])?;
         }
         if let Some(v) = resource_type {
-            builder = builder.query(&[("resourceType", v)]);
+            builder = builder.query(&[("resourceType", v)])?;
         }
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
-        }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            builder = builder.query(&[("type", v)])?;
+        }
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -408,6 +510,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicy>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_policy_post(
         &self,
         realm: &str,
@@ -416,16 +529,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -439,6 +554,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicyevaluate>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/evaluate`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/evaluate",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_policy_evaluate_post(
         &self,
         realm: &str,
@@ -447,16 +573,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<PolicyEvaluationResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy/evaluate",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy/evaluate",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/evaluate",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -469,6 +597,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicyproviders>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/providers`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/providers",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_policy_providers_get(
         &self,
         realm: &str,
@@ -476,15 +615,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<PolicyProviderRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy/providers",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy/providers",
+            self.url
+        ));
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/providers", builder).await?;
+        response.json()
     }
 
     /// Parameters:
@@ -499,6 +635,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverpolicysearch>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/search`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/search",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_policy_search_get(
         &self,
         realm: &str,
@@ -508,21 +655,23 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<AbstractPolicyRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy/search",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/policy/search",
+            self.url
+        ));
         if let Some(v) = fields {
-            builder = builder.query(&[("fields", v)]);
+            builder = builder.query(&[("fields", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
-        }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            builder = builder.query(&[("name", v)])?;
+        }
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/policy/search",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -547,6 +696,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_resource_get(
         &self,
         realm: &str,
@@ -565,48 +725,50 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<ResourceRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource",
+            self.url
+        ));
         if let Some(v) = id {
-            builder = builder.query(&[("_id", v)]);
+            builder = builder.query(&[("_id", v)])?;
         }
         if let Some(v) = deep {
-            builder = builder.query(&[("deep", v)]);
+            builder = builder.query(&[("deep", v)])?;
         }
         if let Some(v) = exact_name {
-            builder = builder.query(&[("exactName", v)]);
+            builder = builder.query(&[("exactName", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = matching_uri {
-            builder = builder.query(&[("matchingUri", v)]);
+            builder = builder.query(&[("matchingUri", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
         if let Some(v) = owner {
-            builder = builder.query(&[("owner", v)]);
+            builder = builder.query(&[("owner", v)])?;
         }
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
+            builder = builder.query(&[("type", v)])?;
         }
         if let Some(v) = uri {
-            builder = builder.query(&[("uri", v)]);
-        }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            builder = builder.query(&[("uri", v)])?;
+        }
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -632,6 +794,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_resource_post(
         &self,
         realm: &str,
@@ -651,49 +824,51 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ResourceRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource",
+            self.url
+        ))
+        .json(&body)?;
         if let Some(v) = id {
-            builder = builder.query(&[("_id", v)]);
+            builder = builder.query(&[("_id", v)])?;
         }
         if let Some(v) = deep {
-            builder = builder.query(&[("deep", v)]);
+            builder = builder.query(&[("deep", v)])?;
         }
         if let Some(v) = exact_name {
-            builder = builder.query(&[("exactName", v)]);
+            builder = builder.query(&[("exactName", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = matching_uri {
-            builder = builder.query(&[("matchingUri", v)]);
+            builder = builder.query(&[("matchingUri", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
         if let Some(v) = owner {
-            builder = builder.query(&[("owner", v)]);
+            builder = builder.query(&[("owner", v)])?;
         }
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
+            builder = builder.query(&[("type", v)])?;
         }
         if let Some(v) = uri {
-            builder = builder.query(&[("uri", v)]);
-        }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            builder = builder.query(&[("uri", v)])?;
+        }
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -718,6 +893,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/search`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/search",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_resource_search_get(
         &self,
         realm: &str,
@@ -736,48 +922,50 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ResourceRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/search",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/search",
+            self.url
+        ));
         if let Some(v) = id {
-            builder = builder.query(&[("_id", v)]);
+            builder = builder.query(&[("_id", v)])?;
         }
         if let Some(v) = deep {
-            builder = builder.query(&[("deep", v)]);
+            builder = builder.query(&[("deep", v)])?;
         }
         if let Some(v) = exact_name {
-            builder = builder.query(&[("exactName", v)]);
+            builder = builder.query(&[("exactName", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = matching_uri {
-            builder = builder.query(&[("matchingUri", v)]);
+            builder = builder.query(&[("matchingUri", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
         if let Some(v) = owner {
-            builder = builder.query(&[("owner", v)]);
+            builder = builder.query(&[("owner", v)])?;
         }
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
+            builder = builder.query(&[("type", v)])?;
         }
         if let Some(v) = uri {
-            builder = builder.query(&[("uri", v)]);
-        }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            builder = builder.query(&[("uri", v)])?;
+        }
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/search",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -803,6 +991,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_get(
         &self,
         realm: &str,
@@ -823,48 +1022,45 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let resource_id = p(resource_id);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = id {
-            builder = builder.query(&[("_id", v)]);
+            builder = builder.query(&[("_id", v)])?;
         }
         if let Some(v) = deep {
-            builder = builder.query(&[("deep", v)]);
+            builder = builder.query(&[("deep", v)])?;
         }
         if let Some(v) = exact_name {
-            builder = builder.query(&[("exactName", v)]);
+            builder = builder.query(&[("exactName", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = matching_uri {
-            builder = builder.query(&[("matchingUri", v)]);
+            builder = builder.query(&[("matchingUri", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
         if let Some(v) = owner {
-            builder = builder.query(&[("owner", v)]);
+            builder = builder.query(&[("owner", v)])?;
         }
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
+            builder = builder.query(&[("type", v)])?;
         }
         if let Some(v) = uri {
-            builder = builder.query(&[("uri", v)]);
+            builder = builder.query(&[("uri", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}", builder).await?;
+        response.json()
     }
 
     /// Parameters:
@@ -893,6 +1089,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_put(
         &self,
         realm: &str,
@@ -914,49 +1121,46 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let resource_id = p(resource_id);
-        let mut builder = self
-            .client
-            .put(format!(
+        let mut builder = KeycloakRequest::put(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}",
                 self.url
             ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            .json(&body)?;
         if let Some(v) = id {
-            builder = builder.query(&[("_id", v)]);
+            builder = builder.query(&[("_id", v)])?;
         }
         if let Some(v) = deep {
-            builder = builder.query(&[("deep", v)]);
+            builder = builder.query(&[("deep", v)])?;
         }
         if let Some(v) = exact_name {
-            builder = builder.query(&[("exactName", v)]);
+            builder = builder.query(&[("exactName", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = matching_uri {
-            builder = builder.query(&[("matchingUri", v)]);
+            builder = builder.query(&[("matchingUri", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
         if let Some(v) = owner {
-            builder = builder.query(&[("owner", v)]);
+            builder = builder.query(&[("owner", v)])?;
         }
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
+            builder = builder.query(&[("type", v)])?;
         }
         if let Some(v) = uri {
-            builder = builder.query(&[("uri", v)]);
+            builder = builder.query(&[("uri", v)])?;
         }
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -984,6 +1188,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_delete(
         &self,
         realm: &str,
@@ -1004,48 +1219,45 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let resource_id = p(resource_id);
-        let mut builder = self
-            .client
-            .delete(format!(
+        let mut builder = KeycloakRequest::delete(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = id {
-            builder = builder.query(&[("_id", v)]);
+            builder = builder.query(&[("_id", v)])?;
         }
         if let Some(v) = deep {
-            builder = builder.query(&[("deep", v)]);
+            builder = builder.query(&[("deep", v)])?;
         }
         if let Some(v) = exact_name {
-            builder = builder.query(&[("exactName", v)]);
+            builder = builder.query(&[("exactName", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = matching_uri {
-            builder = builder.query(&[("matchingUri", v)]);
+            builder = builder.query(&[("matchingUri", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
         if let Some(v) = owner {
-            builder = builder.query(&[("owner", v)]);
+            builder = builder.query(&[("owner", v)])?;
         }
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
+            builder = builder.query(&[("type", v)])?;
         }
         if let Some(v) = uri {
-            builder = builder.query(&[("uri", v)]);
+            builder = builder.query(&[("uri", v)])?;
         }
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -1073,6 +1285,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/attributes`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/attributes",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_attributes_get(
         &self,
         realm: &str,
@@ -1093,48 +1316,45 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let resource_id = p(resource_id);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}/attributes",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = id {
-            builder = builder.query(&[("_id", v)]);
+            builder = builder.query(&[("_id", v)])?;
         }
         if let Some(v) = deep {
-            builder = builder.query(&[("deep", v)]);
+            builder = builder.query(&[("deep", v)])?;
         }
         if let Some(v) = exact_name {
-            builder = builder.query(&[("exactName", v)]);
+            builder = builder.query(&[("exactName", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = matching_uri {
-            builder = builder.query(&[("matchingUri", v)]);
+            builder = builder.query(&[("matchingUri", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
         if let Some(v) = owner {
-            builder = builder.query(&[("owner", v)]);
+            builder = builder.query(&[("owner", v)])?;
         }
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
+            builder = builder.query(&[("type", v)])?;
         }
         if let Some(v) = uri {
-            builder = builder.query(&[("uri", v)]);
+            builder = builder.query(&[("uri", v)])?;
         }
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/attributes", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -1160,6 +1380,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/permissions`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_permissions_get(
         &self,
         realm: &str,
@@ -1180,48 +1411,45 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let resource_id = p(resource_id);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}/permissions",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = id {
-            builder = builder.query(&[("_id", v)]);
+            builder = builder.query(&[("_id", v)])?;
         }
         if let Some(v) = deep {
-            builder = builder.query(&[("deep", v)]);
+            builder = builder.query(&[("deep", v)])?;
         }
         if let Some(v) = exact_name {
-            builder = builder.query(&[("exactName", v)]);
+            builder = builder.query(&[("exactName", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = matching_uri {
-            builder = builder.query(&[("matchingUri", v)]);
+            builder = builder.query(&[("matchingUri", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
         if let Some(v) = owner {
-            builder = builder.query(&[("owner", v)]);
+            builder = builder.query(&[("owner", v)])?;
         }
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
+            builder = builder.query(&[("type", v)])?;
         }
         if let Some(v) = uri {
-            builder = builder.query(&[("uri", v)]);
+            builder = builder.query(&[("uri", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/permissions", builder).await?;
+        response.json()
     }
 
     /// Parameters:
@@ -1247,6 +1475,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/scopes`
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/scopes",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_resource_with_resource_id_scopes_get(
         &self,
         realm: &str,
@@ -1267,48 +1506,45 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let resource_id = p(resource_id);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/resource/{resource_id}/scopes",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = id {
-            builder = builder.query(&[("_id", v)]);
+            builder = builder.query(&[("_id", v)])?;
         }
         if let Some(v) = deep {
-            builder = builder.query(&[("deep", v)]);
+            builder = builder.query(&[("deep", v)])?;
         }
         if let Some(v) = exact_name {
-            builder = builder.query(&[("exactName", v)]);
+            builder = builder.query(&[("exactName", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = matching_uri {
-            builder = builder.query(&[("matchingUri", v)]);
+            builder = builder.query(&[("matchingUri", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
         if let Some(v) = owner {
-            builder = builder.query(&[("owner", v)]);
+            builder = builder.query(&[("owner", v)])?;
         }
         if let Some(v) = scope {
-            builder = builder.query(&[("scope", v)]);
+            builder = builder.query(&[("scope", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&[("type", v)]);
+            builder = builder.query(&[("type", v)])?;
         }
         if let Some(v) = uri {
-            builder = builder.query(&[("uri", v)]);
+            builder = builder.query(&[("uri", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/resource/{resource-id}/scopes", builder).await?;
+        response.json()
     }
 
     /// Parameters:
@@ -1325,6 +1561,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscope>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_scope_get(
         &self,
         realm: &str,
@@ -1336,27 +1583,29 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<ScopeRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope",
+            self.url
+        ));
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
+            builder = builder.query(&[("name", v)])?;
         }
         if let Some(v) = scope_id {
-            builder = builder.query(&[("scopeId", v)]);
-        }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            builder = builder.query(&[("scopeId", v)])?;
+        }
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -1372,6 +1621,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidauthzresource_serverscope>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_scope_post(
         &self,
         realm: &str,
@@ -1380,16 +1640,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -1403,6 +1665,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscopesearch>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/search`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/search",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_scope_search_get(
         &self,
         realm: &str,
@@ -1411,18 +1684,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<ScopeRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/search",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/search",
+            self.url
+        ));
         if let Some(v) = name {
-            builder = builder.query(&[("name", v)]);
-        }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            builder = builder.query(&[("name", v)])?;
+        }
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/search",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -1436,6 +1711,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_id>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_get(
         &self,
         realm: &str,
@@ -1445,15 +1731,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let scope_id = p(scope_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}",
+            self.url
+        ));
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}", builder).await?;
+        response.json()
     }
 
     /// Parameters:
@@ -1470,6 +1753,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_id>
     ///
     /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_put(
         &self,
         realm: &str,
@@ -1480,16 +1774,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let scope_id = p(scope_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -1505,6 +1796,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_id>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_delete(
         &self,
         realm: &str,
@@ -1514,15 +1816,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let scope_id = p(scope_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}",
+            self.url
+        ));
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -1536,6 +1835,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_idpermissions>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}/permissions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_permissions_get(
         &self,
         realm: &str,
@@ -1545,15 +1855,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let scope_id = p(scope_id);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}/permissions",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}/permissions", builder).await?;
+        response.json()
     }
 
     /// Parameters:
@@ -1567,6 +1874,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serverscopescope_idresources>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}/resources`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}/resources",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_scope_with_scope_id_resources_get(
         &self,
         realm: &str,
@@ -1576,15 +1894,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let scope_id = p(scope_id);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/scope/{scope_id}/resources",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/scope/{scope-id}/resources", builder).await?;
+        response.json()
     }
 
     /// Parameters:
@@ -1597,6 +1912,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidauthzresource_serversettings>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/settings`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/settings",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_authz_resource_server_settings_get(
         &self,
         realm: &str,
@@ -1604,15 +1930,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ResourceServerRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/settings",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/authz/resource-server/settings",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/authz/resource-server/settings",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
@@ -20,6 +20,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_scopesclient_scope_idprotocol_mappersadd_models>
     ///
     /// REST method: `POST /admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/add-models`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/add-models",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_protocol_mappers_add_models_post(
         &self,
         realm: &str,
@@ -28,16 +39,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/protocol-mappers/add-models",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/protocol-mappers/add-models",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/add-models",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get mappers
@@ -54,6 +67,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idprotocol_mappersmodels>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_protocol_mappers_models_get(
         &self,
         realm: &str,
@@ -61,15 +85,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<ProtocolMapperRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/protocol-mappers/models",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/protocol-mappers/models",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Create a mapper
@@ -89,6 +115,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_scopesclient_scope_idprotocol_mappersmodels>
     ///
     /// REST method: `POST /admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_protocol_mappers_models_post(
         &self,
         realm: &str,
@@ -97,16 +134,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/protocol-mappers/models",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/protocol-mappers/models",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get mapper by id
@@ -124,6 +163,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idprotocol_mappersmodelsid>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models/{id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_protocol_mappers_models_with_id_get(
         &self,
         realm: &str,
@@ -133,15 +183,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let id = p(id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/protocol-mappers/models/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/protocol-mappers/models/{id}",
+            self.url
+        ));
+        let response = self.call("/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models/{id}", builder).await?;
+        response.json()
     }
 
     /// Update the mapper
@@ -162,6 +209,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclient_scopesclient_scope_idprotocol_mappersmodelsid>
     ///
     /// REST method: `PUT /admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models/{id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_protocol_mappers_models_with_id_put(
         &self,
         realm: &str,
@@ -172,16 +230,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let id = p(id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/protocol-mappers/models/{id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/protocol-mappers/models/{id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models/{id}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Delete the mapper
@@ -201,6 +256,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_scopesclient_scope_idprotocol_mappersmodelsid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models/{id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_protocol_mappers_models_with_id_delete(
         &self,
         realm: &str,
@@ -210,15 +276,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let id = p(id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/protocol-mappers/models/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/protocol-mappers/models/{id}",
+            self.url
+        ));
+        let response = self.call("/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/models/{id}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Get mappers by name for a specific protocol
@@ -236,6 +299,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idprotocol_mappersprotocolprotocol>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/protocol/{protocol}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/protocol/{protocol}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_protocol_mappers_protocol_with_protocol_get(
         &self,
         realm: &str,
@@ -245,15 +319,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let protocol = p(protocol);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/protocol-mappers/protocol/{protocol}",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/client-scopes/{client-scope-id}/protocol-mappers/protocol/{protocol}", builder).await?;
+        response.json()
     }
 
     /// Create multiple mappers
@@ -273,6 +344,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_templatesclient_scope_idprotocol_mappersadd_models>
     ///
     /// REST method: `POST /admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/add-models`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/add-models",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_protocol_mappers_add_models_post(
         &self,
         realm: &str,
@@ -281,16 +363,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .post(format!(
+        let builder = KeycloakRequest::post(format!(
                 "{}/admin/realms/{realm}/client-templates/{client_scope_id}/protocol-mappers/add-models",
                 self.url
             ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+            .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/add-models", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Get mappers
@@ -307,6 +386,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idprotocol_mappersmodels>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_protocol_mappers_models_get(
         &self,
         realm: &str,
@@ -314,15 +404,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<ProtocolMapperRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-templates/{client_scope_id}/protocol-mappers/models",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-templates/{client_scope_id}/protocol-mappers/models",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Create a mapper
@@ -342,6 +434,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_templatesclient_scope_idprotocol_mappersmodels>
     ///
     /// REST method: `POST /admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_protocol_mappers_models_post(
         &self,
         realm: &str,
@@ -350,16 +453,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/client-templates/{client_scope_id}/protocol-mappers/models",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/client-templates/{client_scope_id}/protocol-mappers/models",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get mapper by id
@@ -377,6 +482,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idprotocol_mappersmodelsid>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models/{id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_protocol_mappers_models_with_id_get(
         &self,
         realm: &str,
@@ -386,15 +502,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let id = p(id);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/client-templates/{client_scope_id}/protocol-mappers/models/{id}",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models/{id}", builder).await?;
+        response.json()
     }
 
     /// Update the mapper
@@ -415,6 +528,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclient_templatesclient_scope_idprotocol_mappersmodelsid>
     ///
     /// REST method: `PUT /admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models/{id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_protocol_mappers_models_with_id_put(
         &self,
         realm: &str,
@@ -425,16 +549,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let id = p(id);
-        let builder = self
-            .client
-            .put(format!(
+        let builder = KeycloakRequest::put(format!(
                 "{}/admin/realms/{realm}/client-templates/{client_scope_id}/protocol-mappers/models/{id}",
                 self.url
             ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+            .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models/{id}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Delete the mapper
@@ -454,6 +575,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_templatesclient_scope_idprotocol_mappersmodelsid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models/{id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_protocol_mappers_models_with_id_delete(
         &self,
         realm: &str,
@@ -463,15 +595,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let id = p(id);
-        let builder = self
-            .client
-            .delete(format!(
+        let builder = KeycloakRequest::delete(format!(
                 "{}/admin/realms/{realm}/client-templates/{client_scope_id}/protocol-mappers/models/{id}",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+            ));
+        let response = self.call("/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/models/{id}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Get mappers by name for a specific protocol
@@ -489,6 +618,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idprotocol_mappersprotocolprotocol>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/protocol/{protocol}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/protocol/{protocol}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_protocol_mappers_protocol_with_protocol_get(
         &self,
         realm: &str,
@@ -498,15 +638,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let protocol = p(protocol);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/client-templates/{client_scope_id}/protocol-mappers/protocol/{protocol}",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/client-templates/{client-scope-id}/protocol-mappers/protocol/{protocol}", builder).await?;
+        response.json()
     }
 
     /// Create multiple mappers
@@ -526,6 +663,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidprotocol_mappersadd_models>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/add-models`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/add-models",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_protocol_mappers_add_models_post(
         &self,
         realm: &str,
@@ -534,16 +682,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/add-models",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/add-models",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/add-models",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get mappers
@@ -560,6 +710,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidprotocol_mappersmodels>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_protocol_mappers_models_get(
         &self,
         realm: &str,
@@ -567,15 +728,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<ProtocolMapperRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/models",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/models",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Create a mapper
@@ -595,6 +758,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidprotocol_mappersmodels>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_protocol_mappers_models_post(
         &self,
         realm: &str,
@@ -603,16 +777,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/models",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/models",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get mapper by id
@@ -630,6 +806,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidprotocol_mappersmodelsid>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models/{id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_protocol_mappers_models_with_id_get(
         &self,
         realm: &str,
@@ -639,15 +826,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let id = p(id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/models/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/models/{id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models/{id}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Update the mapper
@@ -668,6 +857,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidprotocol_mappersmodelsid>
     ///
     /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models/{id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_protocol_mappers_models_with_id_put(
         &self,
         realm: &str,
@@ -678,16 +878,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let id = p(id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/models/{id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/models/{id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models/{id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete the mapper
@@ -707,6 +909,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidprotocol_mappersmodelsid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models/{id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_protocol_mappers_models_with_id_delete(
         &self,
         realm: &str,
@@ -716,15 +929,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let id = p(id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/models/{id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/models/{id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/models/{id}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get mappers by name for a specific protocol
@@ -742,6 +957,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidprotocol_mappersprotocolprotocol>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/protocol/{protocol}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/protocol/{protocol}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_protocol_mappers_protocol_with_protocol_get(
         &self,
         realm: &str,
@@ -751,15 +977,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let protocol = p(protocol);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/protocol/{protocol}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/protocol-mappers/protocol/{protocol}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/protocol-mappers/protocol/{protocol}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
@@ -14,19 +14,26 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealms>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms",
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn get(
         &self,
         brief_representation: Option<bool>,
     ) -> Result<TypeVec<RealmRepresentation>, KeycloakError> {
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!("{}/admin/realms", self.url));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms", builder).await?;
+        response.json()
     }
 
     /// Import a realm. Imports a realm from a full representation of that realm.
@@ -42,14 +49,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealms>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms",
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn post(&self, body: RealmRepresentation) -> Result<DefaultResponse, KeycloakError> {
-        let builder = self
-            .client
-            .post(format!("{}/admin/realms", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!("{}/admin/realms", self.url)).json(&body)?;
+        let response = self.call("/admin/realms", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Get the top-level representation of the realm It will not include nested information like User and Client representations.
@@ -63,14 +76,22 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealm>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_get(&self, realm: &str) -> Result<RealmRepresentation, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!("{}/admin/realms/{realm}", self.url));
+        let response = self.call("/admin/realms/{realm}", builder).await?;
+        response.json()
     }
 
     /// Update the top-level information of the realm Any user, roles or client information in the representation will be ignored.
@@ -87,19 +108,27 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealm>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_put(
         &self,
         realm: &str,
         body: RealmRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .put(format!("{}/admin/realms/{realm}", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder =
+            KeycloakRequest::put(format!("{}/admin/realms/{realm}", self.url)).json(&body)?;
+        let response = self.call("/admin/realms/{realm}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Delete the realm
@@ -115,14 +144,22 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealm>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_delete(&self, realm: &str) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .delete(format!("{}/admin/realms/{realm}", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!("{}/admin/realms/{realm}", self.url));
+        let response = self.call("/admin/realms/{realm}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Get admin events Returns all admin events, or filters events based on URL query parameters listed here
@@ -149,6 +186,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmadmin_events>
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/admin-events",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_admin_events_get(
         &self,
         realm: &str,
@@ -166,56 +214,56 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         resource_types: Option<Vec<String>>,
     ) -> Result<TypeVec<AdminEventRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/admin-events", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/admin-events", self.url));
         if let Some(v) = auth_client {
-            builder = builder.query(&[("authClient", v)]);
+            builder = builder.query(&[("authClient", v)])?;
         }
         if let Some(v) = auth_ip_address {
-            builder = builder.query(&[("authIpAddress", v)]);
+            builder = builder.query(&[("authIpAddress", v)])?;
         }
         if let Some(v) = auth_realm {
-            builder = builder.query(&[("authRealm", v)]);
+            builder = builder.query(&[("authRealm", v)])?;
         }
         if let Some(v) = auth_user {
-            builder = builder.query(&[("authUser", v)]);
+            builder = builder.query(&[("authUser", v)])?;
         }
         if let Some(v) = date_from {
-            builder = builder.query(&[("dateFrom", v)]);
+            builder = builder.query(&[("dateFrom", v)])?;
         }
         if let Some(v) = date_to {
-            builder = builder.query(&[("dateTo", v)]);
+            builder = builder.query(&[("dateTo", v)])?;
         }
         if let Some(v) = direction {
-            builder = builder.query(&[("direction", v)]);
+            builder = builder.query(&[("direction", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = operation_types {
             builder = builder.query(
                 &v.into_iter()
                     .map(|e| ("operationTypes", e))
                     .collect::<Vec<_>>(),
-            );
+            )?;
         }
         if let Some(v) = resource_path {
-            builder = builder.query(&[("resourcePath", v)]);
+            builder = builder.query(&[("resourcePath", v)])?;
         }
         if let Some(v) = resource_types {
             builder = builder.query(
                 &v.into_iter()
                     .map(|e| ("resourceTypes", e))
                     .collect::<Vec<_>>(),
-            );
+            )?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/admin-events", builder)
+            .await?;
+        response.json()
     }
 
     /// Delete all admin events
@@ -231,17 +279,28 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/admin-events`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmadmin_events>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/admin-events",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_admin_events_delete(
         &self,
         realm: &str,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .delete(format!("{}/admin/realms/{realm}/admin-events", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder =
+            KeycloakRequest::delete(format!("{}/admin/realms/{realm}/admin-events", self.url));
+        let response = self
+            .call("/admin/realms/{realm}/admin-events", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Base path for importing clients under this realm.
@@ -256,22 +315,35 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/client-description-converter`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_description_converter>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/client-description-converter",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_description_converter_post(
         &self,
         realm: &str,
         body: String,
     ) -> Result<ClientRepresentation, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/client-description-converter",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/client-description-converter",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-description-converter",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -284,24 +356,34 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/client-policies/policies`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_policiespolicies>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-policies/policies",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_policies_policies_get(
         &self,
         realm: &str,
         include_global_policies: Option<bool>,
     ) -> Result<ClientPoliciesRepresentation, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-policies/policies",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-policies/policies",
+            self.url
+        ));
         if let Some(v) = include_global_policies {
-            builder = builder.query(&[("include-global-policies", v)]);
+            builder = builder.query(&[("include-global-policies", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/client-policies/policies", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -316,22 +398,32 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/client-policies/policies`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclient_policiespolicies>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/client-policies/policies",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_policies_policies_put(
         &self,
         realm: &str,
         body: ClientPoliciesRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/client-policies/policies",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/client-policies/policies",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/client-policies/policies", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -344,24 +436,34 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/client-policies/profiles`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_policiesprofiles>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-policies/profiles",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_policies_profiles_get(
         &self,
         realm: &str,
         include_global_profiles: Option<bool>,
     ) -> Result<ClientProfilesRepresentation, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-policies/profiles",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-policies/profiles",
+            self.url
+        ));
         if let Some(v) = include_global_profiles {
-            builder = builder.query(&[("include-global-profiles", v)]);
+            builder = builder.query(&[("include-global-profiles", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/client-policies/profiles", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -376,22 +478,32 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/client-policies/profiles`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclient_policiesprofiles>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/client-policies/profiles",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_policies_profiles_put(
         &self,
         realm: &str,
         body: ClientProfilesRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/client-policies/profiles",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/client-policies/profiles",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/client-policies/profiles", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get client session stats Returns a JSON map.
@@ -405,20 +517,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/client-session-stats`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_session_stats>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-session-stats",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_session_stats_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<TypeMap<String, String>>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-session-stats",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-session-stats",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/client-session-stats", builder)
+            .await?;
+        response.json()
     }
 
     /// List all client types available in the current realm
@@ -432,17 +554,28 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/client-types`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_types>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-types",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_types_get(
         &self,
         realm: &str,
     ) -> Result<ClientTypesRepresentation, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/client-types", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/client-types", self.url));
+        let response = self
+            .call("/admin/realms/{realm}/client-types", builder)
+            .await?;
+        response.json()
     }
 
     /// Update a client type
@@ -459,19 +592,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/client-types`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclient_types>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/client-types",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_types_put(
         &self,
         realm: &str,
         body: ClientTypesRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .put(format!("{}/admin/realms/{realm}/client-types", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder =
+            KeycloakRequest::put(format!("{}/admin/realms/{realm}/client-types", self.url))
+                .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/client-types", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -483,20 +627,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/credential-registrators`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmcredential_registrators>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/credential-registrators",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_credential_registrators_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<String>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/credential-registrators",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/credential-registrators",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/credential-registrators", builder)
+            .await?;
+        response.json()
     }
 
     /// Get realm default client scopes. Only name and ids are returned.
@@ -510,20 +664,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/default-default-client-scopes`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmdefault_default_client_scopes>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/default-default-client-scopes",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_default_default_client_scopes_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/default-default-client-scopes",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/default-default-client-scopes",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/default-default-client-scopes",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -540,6 +707,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmdefault_default_client_scopesclientscopeid>
     ///
     /// REST method: `PUT /admin/realms/{realm}/default-default-client-scopes/{clientScopeId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/default-default-client-scopes/{clientScopeId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_default_default_client_scopes_with_client_scope_id_put(
         &self,
         realm: &str,
@@ -547,16 +725,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/default-default-client-scopes/{client_scope_id}",
-                self.url
-            ))
-            .header(CONTENT_LENGTH, "0")
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/default-default-client-scopes/{client_scope_id}",
+            self.url
+        ))
+        .header(CONTENT_LENGTH, HeaderValue::from_static("0"));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/default-default-client-scopes/{clientScopeId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -573,6 +753,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmdefault_default_client_scopesclientscopeid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/default-default-client-scopes/{clientScopeId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/default-default-client-scopes/{clientScopeId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_default_default_client_scopes_with_client_scope_id_delete(
         &self,
         realm: &str,
@@ -580,15 +771,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/default-default-client-scopes/{client_scope_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/default-default-client-scopes/{client_scope_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/default-default-client-scopes/{clientScopeId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get group hierarchy.  Only name and ids are returned.
@@ -602,17 +795,28 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/default-groups`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmdefault_groups>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/default-groups",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_default_groups_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<GroupRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/default-groups", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/default-groups", self.url));
+        let response = self
+            .call("/admin/realms/{realm}/default-groups", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -629,6 +833,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmdefault_groupsgroupid>
     ///
     /// REST method: `PUT /admin/realms/{realm}/default-groups/{groupId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/default-groups/{groupId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_default_groups_with_group_id_put(
         &self,
         realm: &str,
@@ -636,16 +851,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/default-groups/{group_id}",
-                self.url
-            ))
-            .header(CONTENT_LENGTH, "0")
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/default-groups/{group_id}",
+            self.url
+        ))
+        .header(CONTENT_LENGTH, HeaderValue::from_static("0"));
+        let response = self
+            .call("/admin/realms/{realm}/default-groups/{groupId}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -662,6 +876,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmdefault_groupsgroupid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/default-groups/{groupId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/default-groups/{groupId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_default_groups_with_group_id_delete(
         &self,
         realm: &str,
@@ -669,15 +894,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/default-groups/{group_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/default-groups/{group_id}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/default-groups/{groupId}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get realm optional client scopes. Only name and ids are returned.
@@ -691,20 +915,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/default-optional-client-scopes`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmdefault_optional_client_scopes>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/default-optional-client-scopes",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_default_optional_client_scopes_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<ClientScopeRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/default-optional-client-scopes",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/default-optional-client-scopes",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/default-optional-client-scopes",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -721,6 +958,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmdefault_optional_client_scopesclientscopeid>
     ///
     /// REST method: `PUT /admin/realms/{realm}/default-optional-client-scopes/{clientScopeId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/default-optional-client-scopes/{clientScopeId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_default_optional_client_scopes_with_client_scope_id_put(
         &self,
         realm: &str,
@@ -728,16 +976,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/default-optional-client-scopes/{client_scope_id}",
-                self.url
-            ))
-            .header(CONTENT_LENGTH, "0")
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/default-optional-client-scopes/{client_scope_id}",
+            self.url
+        ))
+        .header(CONTENT_LENGTH, HeaderValue::from_static("0"));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/default-optional-client-scopes/{clientScopeId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -754,6 +1004,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmdefault_optional_client_scopesclientscopeid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/default-optional-client-scopes/{clientScopeId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/default-optional-client-scopes/{clientScopeId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_default_optional_client_scopes_with_client_scope_id_delete(
         &self,
         realm: &str,
@@ -761,15 +1022,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/default-optional-client-scopes/{client_scope_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/default-optional-client-scopes/{client_scope_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/default-optional-client-scopes/{clientScopeId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get events Returns all events, or filters them based on URL query parameters listed here
@@ -793,6 +1056,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmevents>
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/events",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_events_get(
         &self,
         realm: &str,
@@ -807,39 +1081,36 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         user: Option<String>,
     ) -> Result<TypeVec<EventRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/events", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!("{}/admin/realms/{realm}/events", self.url));
         if let Some(v) = client {
-            builder = builder.query(&[("client", v)]);
+            builder = builder.query(&[("client", v)])?;
         }
         if let Some(v) = date_from {
-            builder = builder.query(&[("dateFrom", v)]);
+            builder = builder.query(&[("dateFrom", v)])?;
         }
         if let Some(v) = date_to {
-            builder = builder.query(&[("dateTo", v)]);
+            builder = builder.query(&[("dateTo", v)])?;
         }
         if let Some(v) = direction {
-            builder = builder.query(&[("direction", v)]);
+            builder = builder.query(&[("direction", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = ip_address {
-            builder = builder.query(&[("ipAddress", v)]);
+            builder = builder.query(&[("ipAddress", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = type_ {
-            builder = builder.query(&v.into_iter().map(|e| ("type", e)).collect::<Vec<_>>());
+            builder = builder.query(&v.into_iter().map(|e| ("type", e)).collect::<Vec<_>>())?;
         }
         if let Some(v) = user {
-            builder = builder.query(&[("user", v)]);
+            builder = builder.query(&[("user", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/events", builder).await?;
+        response.json()
     }
 
     /// Delete all events
@@ -855,14 +1126,22 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/events`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmevents>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/events",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_events_delete(&self, realm: &str) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .delete(format!("{}/admin/realms/{realm}/events", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!("{}/admin/realms/{realm}/events", self.url));
+        let response = self.call("/admin/realms/{realm}/events", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Get the events provider configuration Returns JSON object with events provider configuration
@@ -876,17 +1155,28 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/events/config`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmeventsconfig>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/events/config",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_events_config_get(
         &self,
         realm: &str,
     ) -> Result<RealmEventsConfigRepresentation, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/events/config", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/events/config", self.url));
+        let response = self
+            .call("/admin/realms/{realm}/events/config", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -901,19 +1191,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/events/config`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmeventsconfig>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/events/config",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_events_config_put(
         &self,
         realm: &str,
         body: RealmEventsConfigRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .put(format!("{}/admin/realms/{realm}/events/config", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder =
+            KeycloakRequest::put(format!("{}/admin/realms/{realm}/events/config", self.url))
+                .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/events/config", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -926,6 +1227,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/group-by-path/{path}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroup_by_pathpath>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/group-by-path/{path}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_group_by_path_with_path_get(
         &self,
         realm: &str,
@@ -933,15 +1245,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<GroupRepresentation, KeycloakError> {
         let realm = p(realm);
         let path = p(path);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/group-by-path/{path}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/group-by-path/{path}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/group-by-path/{path}", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -953,17 +1264,28 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/localization`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmlocalization>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/localization",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_localization_get(
         &self,
         realm: &str,
     ) -> Result<TypeVec<String>, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/localization", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/localization", self.url));
+        let response = self
+            .call("/admin/realms/{realm}/localization", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -977,6 +1299,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/localization/{locale}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmlocalizationlocale>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/localization/{locale}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_localization_with_locale_get(
         &self,
         realm: &str,
@@ -985,18 +1318,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeMap<String, TypeString>, KeycloakError> {
         let realm = p(realm);
         let locale = p(locale);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/localization/{locale}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/localization/{locale}",
+            self.url
+        ));
         if let Some(v) = use_realm_default_locale_fallback {
-            builder = builder.query(&[("useRealmDefaultLocaleFallback", v)]);
+            builder = builder.query(&[("useRealmDefaultLocaleFallback", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/localization/{locale}", builder)
+            .await?;
+        response.json()
     }
 
     /// Import localization from uploaded JSON file
@@ -1014,6 +1346,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/localization/{locale}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmlocalizationlocale>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/localization/{locale}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_localization_with_locale_post(
         &self,
         realm: &str,
@@ -1022,16 +1365,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let locale = p(locale);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/localization/{locale}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/localization/{locale}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/localization/{locale}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -1046,6 +1388,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/localization/{locale}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmlocalizationlocale>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/localization/{locale}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_localization_with_locale_delete(
         &self,
         realm: &str,
@@ -1053,15 +1406,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let locale = p(locale);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/localization/{locale}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/localization/{locale}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/localization/{locale}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -1075,6 +1427,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/localization/{locale}/{key}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmlocalizationlocalekey>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/localization/{locale}/{key}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_localization_with_locale_with_key_get(
         &self,
         realm: &str,
@@ -1084,15 +1447,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let key = p(key);
         let locale = p(locale);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/localization/{locale}/{key}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.text().await.map(From::from)?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/localization/{locale}/{key}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/localization/{locale}/{key}", builder)
+            .await?;
+        Ok(response.text()?.into())
     }
 
     /// Parameters:
@@ -1109,6 +1471,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/localization/{locale}/{key}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmlocalizationlocalekey>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/localization/{locale}/{key}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_localization_with_locale_with_key_put(
         &self,
         realm: &str,
@@ -1119,16 +1492,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let key = p(key);
         let locale = p(locale);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/localization/{locale}/{key}",
-                self.url
-            ))
-            .body(body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/localization/{locale}/{key}",
+            self.url
+        ))
+        .text(body);
+        let response = self
+            .call("/admin/realms/{realm}/localization/{locale}/{key}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -1144,6 +1516,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/localization/{locale}/{key}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmlocalizationlocalekey>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/localization/{locale}/{key}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_localization_with_locale_with_key_delete(
         &self,
         realm: &str,
@@ -1153,15 +1536,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let key = p(key);
         let locale = p(locale);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/localization/{locale}/{key}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/localization/{locale}/{key}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/localization/{locale}/{key}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Removes all user sessions.
@@ -1175,17 +1557,28 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/logout-all`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmlogout_all>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/logout-all",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_logout_all_post(
         &self,
         realm: &str,
     ) -> Result<GlobalRequestResult, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!("{}/admin/realms/{realm}/logout-all", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder =
+            KeycloakRequest::post(format!("{}/admin/realms/{realm}/logout-all", self.url));
+        let response = self
+            .call("/admin/realms/{realm}/logout-all", builder)
+            .await?;
+        response.json()
     }
 
     /// Partial export of existing realm into a JSON file.
@@ -1201,6 +1594,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/partial-export`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmpartial_export>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/partial-export",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_partial_export_post(
         &self,
         realm: &str,
@@ -1208,18 +1612,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         export_groups_and_roles: Option<bool>,
     ) -> Result<RealmRepresentation, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .post(format!("{}/admin/realms/{realm}/partial-export", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder =
+            KeycloakRequest::post(format!("{}/admin/realms/{realm}/partial-export", self.url));
         if let Some(v) = export_clients {
-            builder = builder.query(&[("exportClients", v)]);
+            builder = builder.query(&[("exportClients", v)])?;
         }
         if let Some(v) = export_groups_and_roles {
-            builder = builder.query(&[("exportGroupsAndRoles", v)]);
+            builder = builder.query(&[("exportGroupsAndRoles", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/partial-export", builder)
+            .await?;
+        response.json()
     }
 
     /// Partial import from a JSON file to an existing realm.
@@ -1234,19 +1638,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/partialImport`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmpartialimport>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/partialImport",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_partial_import_post(
         &self,
         realm: &str,
         body: RealmRepresentation,
     ) -> Result<Value, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!("{}/admin/realms/{realm}/partialImport", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder =
+            KeycloakRequest::post(format!("{}/admin/realms/{realm}/partialImport", self.url))
+                .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/partialImport", builder)
+            .await?;
+        response.json()
     }
 
     /// Push the realm's revocation policy to any client that has an admin url associated with it.
@@ -1260,17 +1675,28 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/push-revocation`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmpush_revocation>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/push-revocation",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_push_revocation_post(
         &self,
         realm: &str,
     ) -> Result<GlobalRequestResult, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!("{}/admin/realms/{realm}/push-revocation", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder =
+            KeycloakRequest::post(format!("{}/admin/realms/{realm}/push-revocation", self.url));
+        let response = self
+            .call("/admin/realms/{realm}/push-revocation", builder)
+            .await?;
+        response.json()
     }
 
     /// Remove a specific user session.
@@ -1288,6 +1714,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/sessions/{session}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmsessionssession>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/sessions/{session}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_sessions_with_session_delete(
         &self,
         realm: &str,
@@ -1296,18 +1733,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let session = p(session);
-        let mut builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/sessions/{session}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/sessions/{session}",
+            self.url
+        ));
         if let Some(v) = is_offline {
-            builder = builder.query(&[("isOffline", v)]);
+            builder = builder.query(&[("isOffline", v)])?;
         }
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let response = self
+            .call("/admin/realms/{realm}/sessions/{session}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Test SMTP connection with current logged in user
@@ -1325,22 +1761,32 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmtestsmtpconnection>
     #[deprecated]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/testSMTPConnection",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_test_smtp_connection_post(
         &self,
         realm: &str,
         body: TypeMap<String, String>,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/testSMTPConnection",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/testSMTPConnection",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/testSMTPConnection", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -1352,20 +1798,33 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/users-management-permissions`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusers_management_permissions>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users-management-permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_management_permissions_get(
         &self,
         realm: &str,
     ) -> Result<ManagementPermissionReference, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users-management-permissions",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users-management-permissions",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users-management-permissions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -1378,22 +1837,35 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/users-management-permissions`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmusers_management_permissions>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/users-management-permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_management_permissions_put(
         &self,
         realm: &str,
         body: ManagementPermissionReference,
     ) -> Result<ManagementPermissionReference, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/users-management-permissions",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/users-management-permissions",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users-management-permissions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
@@ -17,6 +17,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappings>
     ///
     /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/role-mappings",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_role_mappings_get(
         &self,
         realm: &str,
@@ -24,15 +35,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<MappingsRepresentation, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/role-mappings",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/role-mappings",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/groups/{group-id}/role-mappings",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get realm-level role mappings
@@ -49,6 +62,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsrealm>
     ///
     /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/role-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_role_mappings_realm_get(
         &self,
         realm: &str,
@@ -56,15 +80,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/realm",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/realm",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/groups/{group-id}/role-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Add realm-level role mappings to the user
@@ -84,6 +110,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmgroupsgroup_idrole_mappingsrealm>
     ///
     /// REST method: `POST /admin/realms/{realm}/groups/{group-id}/role-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/role-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_role_mappings_realm_post(
         &self,
         realm: &str,
@@ -92,16 +129,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/realm",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/realm",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/groups/{group-id}/role-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete realm-level role mappings
@@ -121,6 +160,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmgroupsgroup_idrole_mappingsrealm>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/groups/{group-id}/role-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/role-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_role_mappings_realm_delete(
         &self,
         realm: &str,
@@ -129,16 +179,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/realm",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/realm",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/groups/{group-id}/role-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get realm-level roles that can be mapped
@@ -155,6 +207,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsrealmavailable>
     ///
     /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/realm/available`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/role-mappings/realm/available",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_role_mappings_realm_available_get(
         &self,
         realm: &str,
@@ -162,15 +225,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/realm/available",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/realm/available",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/groups/{group-id}/role-mappings/realm/available",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get effective realm-level role mappings This will recurse all composite roles to get the result.
@@ -188,6 +253,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmgroupsgroup_idrole_mappingsrealmcomposite>
     ///
     /// REST method: `GET /admin/realms/{realm}/groups/{group-id}/role-mappings/realm/composite`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/groups/{group-id}/role-mappings/realm/composite",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_groups_with_group_id_role_mappings_realm_composite_get(
         &self,
         realm: &str,
@@ -196,18 +272,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let group_id = p(group_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/realm/composite",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/groups/{group_id}/role-mappings/realm/composite",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/groups/{group-id}/role-mappings/realm/composite",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get role mappings
@@ -224,6 +302,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappings>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/role-mappings",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_role_mappings_get(
         &self,
         realm: &str,
@@ -231,15 +320,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<MappingsRepresentation, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/role-mappings",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/role-mappings",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/role-mappings",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get realm-level role mappings
@@ -256,6 +347,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsrealm>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/role-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_role_mappings_realm_get(
         &self,
         realm: &str,
@@ -263,15 +365,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/role-mappings/realm",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/role-mappings/realm",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/role-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Add realm-level role mappings to the user
@@ -291,6 +395,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmusersuser_idrole_mappingsrealm>
     ///
     /// REST method: `POST /admin/realms/{realm}/users/{user-id}/role-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/users/{user-id}/role-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_role_mappings_realm_post(
         &self,
         realm: &str,
@@ -299,16 +414,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/role-mappings/realm",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/role-mappings/realm",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/role-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete realm-level role mappings
@@ -328,6 +445,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmusersuser_idrole_mappingsrealm>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/users/{user-id}/role-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/users/{user-id}/role-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_role_mappings_realm_delete(
         &self,
         realm: &str,
@@ -336,16 +464,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/role-mappings/realm",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/role-mappings/realm",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/role-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get realm-level roles that can be mapped
@@ -362,6 +492,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsrealmavailable>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/realm/available`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/role-mappings/realm/available",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_role_mappings_realm_available_get(
         &self,
         realm: &str,
@@ -369,15 +510,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/role-mappings/realm/available",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/role-mappings/realm/available",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/role-mappings/realm/available",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get effective realm-level role mappings This will recurse all composite roles to get the result.
@@ -395,6 +538,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idrole_mappingsrealmcomposite>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/role-mappings/realm/composite`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/role-mappings/realm/composite",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_role_mappings_realm_composite_get(
         &self,
         realm: &str,
@@ -403,18 +557,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/role-mappings/realm/composite",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/role-mappings/realm/composite",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/role-mappings/realm/composite",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
@@ -21,6 +21,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidroles>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_get(
         &self,
         realm: &str,
@@ -32,27 +43,26 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/roles",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/roles",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/clients/{client-uuid}/roles", builder)
+            .await?;
+        response.json()
     }
 
     /// Create a new role for the realm or client
@@ -72,6 +82,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidroles>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/roles`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_post(
         &self,
         realm: &str,
@@ -80,16 +101,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/roles",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/roles",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/clients/{client-uuid}/roles", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get a role by name
@@ -107,6 +127,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_name>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_with_role_name_get(
         &self,
         realm: &str,
@@ -116,15 +147,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Update a role by name
@@ -145,6 +178,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidrolesrole_name>
     ///
     /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_with_role_name_put(
         &self,
         realm: &str,
@@ -155,16 +199,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete a role by name
@@ -184,6 +230,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidrolesrole_name>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_with_role_name_delete(
         &self,
         realm: &str,
@@ -193,15 +250,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get composites of the role
@@ -219,6 +278,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namecomposites>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_with_role_name_composites_get(
         &self,
         realm: &str,
@@ -228,15 +298,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Add a composite to the role
@@ -257,6 +329,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidrolesrole_namecomposites>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_with_role_name_composites_post(
         &self,
         realm: &str,
@@ -267,16 +350,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Remove roles from the role's composite
@@ -297,6 +382,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidrolesrole_namecomposites>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_with_role_name_composites_delete(
         &self,
         realm: &str,
@@ -307,16 +403,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get client-level roles for the client that are in the role's composite
@@ -335,6 +433,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namecompositesclientstargetclientuuid>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites/clients/{targetClientUuid}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites/clients/{targetClientUuid}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_with_role_name_composites_clients_with_target_client_uuid_get(
         &self,
         realm: &str,
@@ -346,15 +455,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let client_uuid = p(client_uuid);
         let role_name = p(role_name);
         let target_client_uuid = p(target_client_uuid);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites/clients/{target_client_uuid}",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites/clients/{targetClientUuid}", builder).await?;
+        response.json()
     }
 
     /// Get realm-level roles of the role's composite
@@ -372,6 +478,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namecompositesrealm>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_with_role_name_composites_realm_get(
         &self,
         realm: &str,
@@ -381,15 +498,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites/realm",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/composites/realm",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/composites/realm",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Returns a stream of groups that have the specified role name
@@ -410,6 +529,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namegroups>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/groups`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/groups",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_with_role_name_groups_get(
         &self,
         realm: &str,
@@ -422,24 +552,26 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_name = p(role_name);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/groups",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/groups",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/groups",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Return object stating whether role Authorization permissions have been initialized or not and a reference
@@ -457,6 +589,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_namemanagementpermissions>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/management/permissions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/management/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_with_role_name_management_permissions_get(
         &self,
         realm: &str,
@@ -466,15 +609,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/management/permissions",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/management/permissions", builder).await?;
+        response.json()
     }
 
     /// Return object stating whether role Authorization permissions have been initialized or not and a reference
@@ -493,6 +633,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmclientsclient_uuidrolesrole_namemanagementpermissions>
     ///
     /// REST method: `PUT /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/management/permissions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/management/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_with_role_name_management_permissions_put(
         &self,
         realm: &str,
@@ -503,16 +654,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .put(format!(
+        let builder = KeycloakRequest::put(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/management/permissions",
                 self.url
             ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/management/permissions", builder).await?;
+        response.json()
     }
 
     /// Returns a stream of users that have the specified role name.
@@ -533,6 +681,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidrolesrole_nameusers>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/users`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/users",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_roles_with_role_name_users_get(
         &self,
         realm: &str,
@@ -545,24 +704,26 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_name = p(role_name);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/users",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/roles/{role_name}/users",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/roles/{role-name}/users",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get all roles for the realm or client
@@ -580,6 +741,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/roles`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/roles",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_get(
         &self,
         realm: &str,
@@ -589,24 +761,21 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         search: Option<String>,
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/roles", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!("{}/admin/realms/{realm}/roles", self.url));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/roles", builder).await?;
+        response.json()
     }
 
     /// Create a new role for the realm or client
@@ -623,19 +792,27 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/roles`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmroles>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/roles",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_post(
         &self,
         realm: &str,
         body: RoleRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!("{}/admin/realms/{realm}/roles", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!("{}/admin/realms/{realm}/roles", self.url))
+            .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/roles", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Get a role by name
@@ -652,6 +829,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_name>
     ///
     /// REST method: `GET /admin/realms/{realm}/roles/{role-name}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/roles/{role-name}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_with_role_name_get(
         &self,
         realm: &str,
@@ -659,15 +847,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<RoleRepresentation, KeycloakError> {
         let realm = p(realm);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/roles/{role_name}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/roles/{role_name}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/roles/{role-name}", builder)
+            .await?;
+        response.json()
     }
 
     /// Update a role by name
@@ -687,6 +874,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmrolesrole_name>
     ///
     /// REST method: `PUT /admin/realms/{realm}/roles/{role-name}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/roles/{role-name}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_with_role_name_put(
         &self,
         realm: &str,
@@ -695,16 +893,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/roles/{role_name}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/roles/{role_name}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/roles/{role-name}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete a role by name
@@ -723,6 +920,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmrolesrole_name>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/roles/{role-name}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/roles/{role-name}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_with_role_name_delete(
         &self,
         realm: &str,
@@ -730,15 +938,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/roles/{role_name}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/roles/{role_name}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/roles/{role-name}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get composites of the role
@@ -755,6 +962,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namecomposites>
     ///
     /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/composites`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/roles/{role-name}/composites",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_with_role_name_composites_get(
         &self,
         realm: &str,
@@ -762,15 +980,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/roles/{role_name}/composites",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/roles/{role_name}/composites",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles/{role-name}/composites",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Add a composite to the role
@@ -790,6 +1010,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmrolesrole_namecomposites>
     ///
     /// REST method: `POST /admin/realms/{realm}/roles/{role-name}/composites`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/roles/{role-name}/composites",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_with_role_name_composites_post(
         &self,
         realm: &str,
@@ -798,16 +1029,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/roles/{role_name}/composites",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/roles/{role_name}/composites",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles/{role-name}/composites",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Remove roles from the role's composite
@@ -827,6 +1060,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmrolesrole_namecomposites>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/roles/{role-name}/composites`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/roles/{role-name}/composites",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_with_role_name_composites_delete(
         &self,
         realm: &str,
@@ -835,16 +1079,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/roles/{role_name}/composites",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/roles/{role_name}/composites",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles/{role-name}/composites",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get client-level roles for the client that are in the role's composite
@@ -862,6 +1108,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namecompositesclientstargetclientuuid>
     ///
     /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/composites/clients/{targetClientUuid}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/roles/{role-name}/composites/clients/{targetClientUuid}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_with_role_name_composites_clients_with_target_client_uuid_get(
         &self,
         realm: &str,
@@ -871,15 +1128,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let role_name = p(role_name);
         let target_client_uuid = p(target_client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/roles/{role_name}/composites/clients/{target_client_uuid}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/roles/{role_name}/composites/clients/{target_client_uuid}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles/{role-name}/composites/clients/{targetClientUuid}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get realm-level roles of the role's composite
@@ -896,6 +1155,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namecompositesrealm>
     ///
     /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/composites/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/roles/{role-name}/composites/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_with_role_name_composites_realm_get(
         &self,
         realm: &str,
@@ -903,15 +1173,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/roles/{role_name}/composites/realm",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/roles/{role_name}/composites/realm",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles/{role-name}/composites/realm",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Returns a stream of groups that have the specified role name
@@ -931,6 +1203,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namegroups>
     ///
     /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/groups`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/roles/{role-name}/groups",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_with_role_name_groups_get(
         &self,
         realm: &str,
@@ -941,24 +1224,23 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
         let realm = p(realm);
         let role_name = p(role_name);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/roles/{role_name}/groups",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/roles/{role_name}/groups",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/roles/{role-name}/groups", builder)
+            .await?;
+        response.json()
     }
 
     /// Return object stating whether role Authorization permissions have been initialized or not and a reference
@@ -975,6 +1257,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_namemanagementpermissions>
     ///
     /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/management/permissions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/roles/{role-name}/management/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_with_role_name_management_permissions_get(
         &self,
         realm: &str,
@@ -982,15 +1275,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ManagementPermissionReference, KeycloakError> {
         let realm = p(realm);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/roles/{role_name}/management/permissions",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/roles/{role_name}/management/permissions",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles/{role-name}/management/permissions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Return object stating whether role Authorization permissions have been initialized or not and a reference
@@ -1008,6 +1303,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmrolesrole_namemanagementpermissions>
     ///
     /// REST method: `PUT /admin/realms/{realm}/roles/{role-name}/management/permissions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/roles/{role-name}/management/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_with_role_name_management_permissions_put(
         &self,
         realm: &str,
@@ -1016,16 +1322,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ManagementPermissionReference, KeycloakError> {
         let realm = p(realm);
         let role_name = p(role_name);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/roles/{role_name}/management/permissions",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/roles/{role_name}/management/permissions",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles/{role-name}/management/permissions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Returns a stream of users that have the specified role name.
@@ -1045,6 +1353,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmrolesrole_nameusers>
     ///
     /// REST method: `GET /admin/realms/{realm}/roles/{role-name}/users`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/roles/{role-name}/users",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_with_role_name_users_get(
         &self,
         realm: &str,
@@ -1055,24 +1374,23 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
         let realm = p(realm);
         let role_name = p(role_name);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/roles/{role_name}/users",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/roles/{role_name}/users",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/roles/{role-name}/users", builder)
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
@@ -17,6 +17,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_id>
     ///
     /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/roles-by-id/{role-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_by_id_with_role_id_get(
         &self,
         realm: &str,
@@ -24,15 +35,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<RoleRepresentation, KeycloakError> {
         let realm = p(realm);
         let role_id = p(role_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/roles-by-id/{role_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/roles-by-id/{role_id}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/roles-by-id/{role-id}", builder)
+            .await?;
+        response.json()
     }
 
     /// Update the role
@@ -52,6 +62,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmroles_by_idrole_id>
     ///
     /// REST method: `PUT /admin/realms/{realm}/roles-by-id/{role-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/roles-by-id/{role-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_by_id_with_role_id_put(
         &self,
         realm: &str,
@@ -60,16 +81,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let role_id = p(role_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/roles-by-id/{role_id}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/roles-by-id/{role_id}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/roles-by-id/{role-id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete the role
@@ -88,6 +108,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmroles_by_idrole_id>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/roles-by-id/{role-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/roles-by-id/{role-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_by_id_with_role_id_delete(
         &self,
         realm: &str,
@@ -95,15 +126,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let role_id = p(role_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/roles-by-id/{role_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/roles-by-id/{role_id}",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/roles-by-id/{role-id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get role's children Returns a set of role's children provided the role is a composite.
@@ -123,6 +153,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_idcomposites>
     ///
     /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}/composites`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/roles-by-id/{role-id}/composites",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_by_id_with_role_id_composites_get(
         &self,
         realm: &str,
@@ -133,24 +174,26 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let role_id = p(role_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/roles-by-id/{role_id}/composites",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/roles-by-id/{role_id}/composites",
+            self.url
+        ));
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles-by-id/{role-id}/composites",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Make the role a composite role by associating some child roles
@@ -170,6 +213,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmroles_by_idrole_idcomposites>
     ///
     /// REST method: `POST /admin/realms/{realm}/roles-by-id/{role-id}/composites`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/roles-by-id/{role-id}/composites",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_by_id_with_role_id_composites_post(
         &self,
         realm: &str,
@@ -178,16 +232,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let role_id = p(role_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/roles-by-id/{role_id}/composites",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/roles-by-id/{role_id}/composites",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles-by-id/{role-id}/composites",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Remove a set of roles from the role's composite
@@ -207,6 +263,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmroles_by_idrole_idcomposites>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/roles-by-id/{role-id}/composites`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/roles-by-id/{role-id}/composites",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_by_id_with_role_id_composites_delete(
         &self,
         realm: &str,
@@ -215,16 +282,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let role_id = p(role_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/roles-by-id/{role_id}/composites",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/roles-by-id/{role_id}/composites",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles-by-id/{role-id}/composites",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get client-level roles for the client that are in the role's composite
@@ -242,6 +311,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_idcompositesclientsclientuuid>
     ///
     /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}/composites/clients/{clientUuid}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/roles-by-id/{role-id}/composites/clients/{clientUuid}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_by_id_with_role_id_composites_clients_with_client_uuid_get(
         &self,
         realm: &str,
@@ -251,15 +331,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let role_id = p(role_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/roles-by-id/{role_id}/composites/clients/{client_uuid}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/roles-by-id/{role_id}/composites/clients/{client_uuid}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles-by-id/{role-id}/composites/clients/{clientUuid}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get realm-level roles that are in the role's composite
@@ -276,6 +358,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_idcompositesrealm>
     ///
     /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}/composites/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/roles-by-id/{role-id}/composites/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_by_id_with_role_id_composites_realm_get(
         &self,
         realm: &str,
@@ -283,15 +376,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let role_id = p(role_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/roles-by-id/{role_id}/composites/realm",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/roles-by-id/{role_id}/composites/realm",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles-by-id/{role-id}/composites/realm",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Return object stating whether role Authorization permissions have been initialized or not and a reference
@@ -308,6 +403,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmroles_by_idrole_idmanagementpermissions>
     ///
     /// REST method: `GET /admin/realms/{realm}/roles-by-id/{role-id}/management/permissions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/roles-by-id/{role-id}/management/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_by_id_with_role_id_management_permissions_get(
         &self,
         realm: &str,
@@ -315,15 +421,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ManagementPermissionReference, KeycloakError> {
         let realm = p(realm);
         let role_id = p(role_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/roles-by-id/{role_id}/management/permissions",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/roles-by-id/{role_id}/management/permissions",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles-by-id/{role-id}/management/permissions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Return object stating whether role Authorization permissions have been initialized or not and a reference
@@ -341,6 +449,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmroles_by_idrole_idmanagementpermissions>
     ///
     /// REST method: `PUT /admin/realms/{realm}/roles-by-id/{role-id}/management/permissions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/roles-by-id/{role-id}/management/permissions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_roles_by_id_with_role_id_management_permissions_put(
         &self,
         realm: &str,
@@ -349,16 +468,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<ManagementPermissionReference, KeycloakError> {
         let realm = p(realm);
         let role_id = p(role_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/roles-by-id/{role_id}/management/permissions",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/roles-by-id/{role_id}/management/permissions",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/roles-by-id/{role-id}/management/permissions",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
@@ -18,6 +18,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings`
     #[deprecated]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_scope_mappings_get(
         &self,
         realm: &str,
@@ -25,15 +36,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<MappingsRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get the roles associated with a client's scope Returns roles for the client.
@@ -51,6 +64,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclient>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_get(
         &self,
         realm: &str,
@@ -60,15 +84,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let client = p(client);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}", builder).await?;
+        response.json()
     }
 
     /// Add client-level roles to the client's scope
@@ -89,6 +110,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclient>
     ///
     /// REST method: `POST /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_post(
         &self,
         realm: &str,
@@ -99,16 +131,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let client = p(client);
-        let builder = self
-            .client
-            .post(format!(
+        let builder = KeycloakRequest::post(format!(
                 "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}",
                 self.url
             ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+            .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Remove client-level roles from the client's scope.
@@ -129,6 +158,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclient>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_delete(
         &self,
         realm: &str,
@@ -139,16 +179,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let client = p(client);
-        let builder = self
-            .client
-            .delete(format!(
+        let builder = KeycloakRequest::delete(format!(
                 "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}",
                 self.url
             ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+            .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// The available client-level roles Returns the roles for the client that can be associated with the client's scope
@@ -166,6 +203,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclientavailable>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}/available`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}/available",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_available_get(
         &self,
         realm: &str,
@@ -175,15 +223,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let client = p(client);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}/available",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}/available", builder).await?;
+        response.json()
     }
 
     /// Get effective client roles Returns the roles for the client that are associated with the client's scope.
@@ -202,6 +247,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsclientsclientcomposite>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}/composite`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}/composite",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_scope_mappings_clients_with_client_composite_get(
         &self,
         realm: &str,
@@ -212,18 +268,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let client = p(client);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/clients/{client}/composite",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/clients/{client}/composite", builder).await?;
+        response.json()
     }
 
     /// Get realm-level roles associated with the client's scope
@@ -240,6 +293,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealm>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_scope_mappings_realm_get(
         &self,
         realm: &str,
@@ -247,15 +311,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Add a set of realm-level roles to the client's scope
@@ -275,6 +341,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealm>
     ///
     /// REST method: `POST /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_scope_mappings_realm_post(
         &self,
         realm: &str,
@@ -283,16 +360,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Remove a set of realm-level roles from the client's scope
@@ -312,6 +391,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealm>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_scope_mappings_realm_delete(
         &self,
         realm: &str,
@@ -320,16 +410,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get realm-level roles that are available to attach to this client's scope
@@ -346,6 +438,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealmavailable>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm/available`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm/available",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_scope_mappings_realm_available_get(
         &self,
         realm: &str,
@@ -353,15 +456,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm/available",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm/available", builder).await?;
+        response.json()
     }
 
     /// Get effective realm-level roles associated with the client’s scope What this does is recurse any composite roles associated with the client’s scope and adds the roles to this lists.
@@ -379,6 +479,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_scopesclient_scope_idscope_mappingsrealmcomposite>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm/composite`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm/composite",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_scopes_with_client_scope_id_scope_mappings_realm_composite_get(
         &self,
         realm: &str,
@@ -387,18 +498,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/client-scopes/{client_scope_id}/scope-mappings/realm/composite",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/client-scopes/{client-scope-id}/scope-mappings/realm/composite", builder).await?;
+        response.json()
     }
 
     /// Get all scope mappings for the client
@@ -416,6 +524,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings`
     #[deprecated]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_scope_mappings_get(
         &self,
         realm: &str,
@@ -423,15 +542,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<MappingsRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get the roles associated with a client's scope Returns roles for the client.
@@ -449,6 +570,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclient>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_get(
         &self,
         realm: &str,
@@ -458,15 +590,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let client = p(client);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}", builder).await?;
+        response.json()
     }
 
     /// Add client-level roles to the client's scope
@@ -487,6 +616,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclient>
     ///
     /// REST method: `POST /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_post(
         &self,
         realm: &str,
@@ -497,16 +637,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let client = p(client);
-        let builder = self
-            .client
-            .post(format!(
+        let builder = KeycloakRequest::post(format!(
                 "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}",
                 self.url
             ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+            .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Remove client-level roles from the client's scope.
@@ -527,6 +664,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclient>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_delete(
         &self,
         realm: &str,
@@ -537,16 +685,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let client = p(client);
-        let builder = self
-            .client
-            .delete(format!(
+        let builder = KeycloakRequest::delete(format!(
                 "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}",
                 self.url
             ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+            .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// The available client-level roles Returns the roles for the client that can be associated with the client's scope
@@ -564,6 +709,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclientavailable>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}/available`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}/available",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_available_get(
         &self,
         realm: &str,
@@ -573,15 +729,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let client = p(client);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}/available",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}/available", builder).await?;
+        response.json()
     }
 
     /// Get effective client roles Returns the roles for the client that are associated with the client's scope.
@@ -600,6 +753,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsclientsclientcomposite>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}/composite`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}/composite",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_scope_mappings_clients_with_client_composite_get(
         &self,
         realm: &str,
@@ -610,18 +774,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
         let client = p(client);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/clients/{client}/composite",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/clients/{client}/composite", builder).await?;
+        response.json()
     }
 
     /// Get realm-level roles associated with the client's scope
@@ -638,6 +799,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealm>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_scope_mappings_realm_get(
         &self,
         realm: &str,
@@ -645,15 +817,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Add a set of realm-level roles to the client's scope
@@ -673,6 +847,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealm>
     ///
     /// REST method: `POST /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_scope_mappings_realm_post(
         &self,
         realm: &str,
@@ -681,16 +866,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Remove a set of realm-level roles from the client's scope
@@ -710,6 +897,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealm>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_scope_mappings_realm_delete(
         &self,
         realm: &str,
@@ -718,16 +916,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get realm-level roles that are available to attach to this client's scope
@@ -744,6 +944,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealmavailable>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm/available`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm/available",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_scope_mappings_realm_available_get(
         &self,
         realm: &str,
@@ -751,15 +962,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm/available",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm/available", builder).await?;
+        response.json()
     }
 
     /// Get effective realm-level roles associated with the client’s scope What this does is recurse any composite roles associated with the client’s scope and adds the roles to this lists.
@@ -777,6 +985,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclient_templatesclient_scope_idscope_mappingsrealmcomposite>
     ///
     /// REST method: `GET /admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm/composite`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm/composite",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_client_templates_with_client_scope_id_scope_mappings_realm_composite_get(
         &self,
         realm: &str,
@@ -785,18 +1004,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_scope_id = p(client_scope_id);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/client-templates/{client_scope_id}/scope-mappings/realm/composite",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/client-templates/{client-scope-id}/scope-mappings/realm/composite", builder).await?;
+        response.json()
     }
 
     /// Get all scope mappings for the client
@@ -814,6 +1030,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings`
     #[deprecated]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_scope_mappings_get(
         &self,
         realm: &str,
@@ -821,15 +1048,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<MappingsRepresentation, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get the roles associated with a client's scope Returns roles for the client.
@@ -847,6 +1076,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclient>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_scope_mappings_clients_with_client_get(
         &self,
         realm: &str,
@@ -856,15 +1096,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let client = p(client);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Add client-level roles to the client's scope
@@ -885,6 +1127,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclient>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_scope_mappings_clients_with_client_post(
         &self,
         realm: &str,
@@ -895,16 +1148,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let client = p(client);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Remove client-level roles from the client's scope.
@@ -925,6 +1180,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclient>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_scope_mappings_clients_with_client_delete(
         &self,
         realm: &str,
@@ -935,16 +1201,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let client = p(client);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// The available client-level roles Returns the roles for the client that can be associated with the client's scope
@@ -962,6 +1230,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclientavailable>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}/available`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}/available",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_scope_mappings_clients_with_client_available_get(
         &self,
         realm: &str,
@@ -971,15 +1250,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let client = p(client);
-        let builder = self
-            .client
-            .get(format!(
+        let builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}/available",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+            ));
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}/available", builder).await?;
+        response.json()
     }
 
     /// Get effective client roles Returns the roles for the client that are associated with the client's scope.
@@ -998,6 +1274,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsclientsclientcomposite>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}/composite`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}/composite",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_scope_mappings_clients_with_client_composite_get(
         &self,
         realm: &str,
@@ -1008,18 +1295,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
         let client = p(client);
-        let mut builder = self
-            .client
-            .get(format!(
+        let mut builder = KeycloakRequest::get(format!(
                 "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/clients/{client}/composite",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+            ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/clients/{client}/composite", builder).await?;
+        response.json()
     }
 
     /// Get realm-level roles associated with the client's scope
@@ -1036,6 +1320,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsrealm>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_scope_mappings_realm_get(
         &self,
         realm: &str,
@@ -1043,15 +1338,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Add a set of realm-level roles to the client's scope
@@ -1071,6 +1368,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmclientsclient_uuidscope_mappingsrealm>
     ///
     /// REST method: `POST /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_scope_mappings_realm_post(
         &self,
         realm: &str,
@@ -1079,16 +1387,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Remove a set of realm-level roles from the client's scope
@@ -1108,6 +1418,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmclientsclient_uuidscope_mappingsrealm>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_scope_mappings_realm_delete(
         &self,
         realm: &str,
@@ -1116,16 +1437,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get realm-level roles that are available to attach to this client's scope
@@ -1142,6 +1465,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsrealmavailable>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm/available`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm/available",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_scope_mappings_realm_available_get(
         &self,
         realm: &str,
@@ -1149,15 +1483,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm/available",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm/available",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm/available",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get effective realm-level roles associated with the client’s scope What this does is recurse any composite roles associated with the client’s scope and adds the roles to this lists.
@@ -1175,6 +1511,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmclientsclient_uuidscope_mappingsrealmcomposite>
     ///
     /// REST method: `GET /admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm/composite`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm/composite",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_clients_with_client_uuid_scope_mappings_realm_composite_get(
         &self,
         realm: &str,
@@ -1183,18 +1530,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<RoleRepresentation>, KeycloakError> {
         let realm = p(realm);
         let client_uuid = p(client_uuid);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm/composite",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/clients/{client_uuid}/scope-mappings/realm/composite",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/clients/{client-uuid}/scope-mappings/realm/composite",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
@@ -31,6 +31,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusers>
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_get(
         &self,
         realm: &str,
@@ -52,60 +63,57 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         username: Option<String>,
     ) -> Result<TypeVec<UserRepresentation>, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/users", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!("{}/admin/realms/{realm}/users", self.url));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = created_after {
-            builder = builder.query(&[("createdAfter", v)]);
+            builder = builder.query(&[("createdAfter", v)])?;
         }
         if let Some(v) = created_before {
-            builder = builder.query(&[("createdBefore", v)]);
+            builder = builder.query(&[("createdBefore", v)])?;
         }
         if let Some(v) = email {
-            builder = builder.query(&[("email", v)]);
+            builder = builder.query(&[("email", v)])?;
         }
         if let Some(v) = email_verified {
-            builder = builder.query(&[("emailVerified", v)]);
+            builder = builder.query(&[("emailVerified", v)])?;
         }
         if let Some(v) = enabled {
-            builder = builder.query(&[("enabled", v)]);
+            builder = builder.query(&[("enabled", v)])?;
         }
         if let Some(v) = exact {
-            builder = builder.query(&[("exact", v)]);
+            builder = builder.query(&[("exact", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = first_name {
-            builder = builder.query(&[("firstName", v)]);
+            builder = builder.query(&[("firstName", v)])?;
         }
         if let Some(v) = idp_alias {
-            builder = builder.query(&[("idpAlias", v)]);
+            builder = builder.query(&[("idpAlias", v)])?;
         }
         if let Some(v) = idp_user_id {
-            builder = builder.query(&[("idpUserId", v)]);
+            builder = builder.query(&[("idpUserId", v)])?;
         }
         if let Some(v) = last_name {
-            builder = builder.query(&[("lastName", v)]);
+            builder = builder.query(&[("lastName", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = q {
-            builder = builder.query(&[("q", v)]);
+            builder = builder.query(&[("q", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
         if let Some(v) = username {
-            builder = builder.query(&[("username", v)]);
+            builder = builder.query(&[("username", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self.call("/admin/realms/{realm}/users", builder).await?;
+        response.json()
     }
 
     /// Create a new user Username must be unique.
@@ -122,19 +130,27 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/users`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmusers>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/users",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_post(
         &self,
         realm: &str,
         body: UserRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!("{}/admin/realms/{realm}/users", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!("{}/admin/realms/{realm}/users", self.url))
+            .json(&body)?;
+        let response = self.call("/admin/realms/{realm}/users", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Returns the number of users that match the given criteria.
@@ -162,6 +178,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmuserscount>
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/count",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_count_get(
         &self,
         realm: &str,
@@ -180,51 +207,51 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         username: Option<String>,
     ) -> Result<i32, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/users/count", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/users/count", self.url));
         if let Some(v) = created_after {
-            builder = builder.query(&[("createdAfter", v)]);
+            builder = builder.query(&[("createdAfter", v)])?;
         }
         if let Some(v) = created_before {
-            builder = builder.query(&[("createdBefore", v)]);
+            builder = builder.query(&[("createdBefore", v)])?;
         }
         if let Some(v) = email {
-            builder = builder.query(&[("email", v)]);
+            builder = builder.query(&[("email", v)])?;
         }
         if let Some(v) = email_verified {
-            builder = builder.query(&[("emailVerified", v)]);
+            builder = builder.query(&[("emailVerified", v)])?;
         }
         if let Some(v) = enabled {
-            builder = builder.query(&[("enabled", v)]);
+            builder = builder.query(&[("enabled", v)])?;
         }
         if let Some(v) = exact {
-            builder = builder.query(&[("exact", v)]);
+            builder = builder.query(&[("exact", v)])?;
         }
         if let Some(v) = first_name {
-            builder = builder.query(&[("firstName", v)]);
+            builder = builder.query(&[("firstName", v)])?;
         }
         if let Some(v) = idp_alias {
-            builder = builder.query(&[("idpAlias", v)]);
+            builder = builder.query(&[("idpAlias", v)])?;
         }
         if let Some(v) = idp_user_id {
-            builder = builder.query(&[("idpUserId", v)]);
+            builder = builder.query(&[("idpUserId", v)])?;
         }
         if let Some(v) = last_name {
-            builder = builder.query(&[("lastName", v)]);
+            builder = builder.query(&[("lastName", v)])?;
         }
         if let Some(v) = q {
-            builder = builder.query(&[("q", v)]);
+            builder = builder.query(&[("q", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
         if let Some(v) = username {
-            builder = builder.query(&[("username", v)]);
+            builder = builder.query(&[("username", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/users/count", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -236,14 +263,25 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/users/profile`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersprofile>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/profile",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_profile_get(&self, realm: &str) -> Result<UPConfig, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/users/profile", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/users/profile", self.url));
+        let response = self
+            .call("/admin/realms/{realm}/users/profile", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -256,19 +294,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/users/profile`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmusersprofile>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/users/profile",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_profile_put(
         &self,
         realm: &str,
         body: UPConfig,
     ) -> Result<UPConfig, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .put(format!("{}/admin/realms/{realm}/users/profile", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder =
+            KeycloakRequest::put(format!("{}/admin/realms/{realm}/users/profile", self.url))
+                .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/users/profile", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -280,20 +329,30 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/users/profile/metadata`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersprofilemetadata>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/profile/metadata",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_profile_metadata_get(
         &self,
         realm: &str,
     ) -> Result<UserProfileMetadata, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/profile/metadata",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/profile/metadata",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/users/profile/metadata", builder)
+            .await?;
+        response.json()
     }
 
     /// Get representation of the user
@@ -311,6 +370,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_id>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_get(
         &self,
         realm: &str,
@@ -319,15 +389,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<UserRepresentation, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/users/{user_id}", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/users/{user_id}", self.url));
         if let Some(v) = user_profile_metadata {
-            builder = builder.query(&[("userProfileMetadata", v)]);
+            builder = builder.query(&[("userProfileMetadata", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/users/{user-id}", builder)
+            .await?;
+        response.json()
     }
 
     /// Update the user
@@ -347,6 +417,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmusersuser_id>
     ///
     /// REST method: `PUT /admin/realms/{realm}/users/{user-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/users/{user-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_put(
         &self,
         realm: &str,
@@ -355,13 +436,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .put(format!("{}/admin/realms/{realm}/users/{user_id}", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder =
+            KeycloakRequest::put(format!("{}/admin/realms/{realm}/users/{user_id}", self.url))
+                .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/users/{user-id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete the user
@@ -380,6 +461,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmusersuser_id>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/users/{user-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/users/{user-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_delete(
         &self,
         realm: &str,
@@ -387,12 +479,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .delete(format!("{}/admin/realms/{realm}/users/{user_id}", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder =
+            KeycloakRequest::delete(format!("{}/admin/realms/{realm}/users/{user_id}", self.url));
+        let response = self
+            .call("/admin/realms/{realm}/users/{user-id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Return credential types, which are provided by the user storage where user is stored.
@@ -409,6 +501,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idconfigured_user_storage_credential_types>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/configured-user-storage-credential-types`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/configured-user-storage-credential-types",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_configured_user_storage_credential_types_get(
         &self,
         realm: &str,
@@ -416,15 +519,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<String>, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/configured-user-storage-credential-types",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/configured-user-storage-credential-types",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/configured-user-storage-credential-types",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get consents granted by the user
@@ -441,6 +546,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idconsents>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/consents`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/consents",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_consents_get(
         &self,
         realm: &str,
@@ -448,15 +564,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<TypeMap<String, Value>>, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/consents",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/consents",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/users/{user-id}/consents", builder)
+            .await?;
+        response.json()
     }
 
     /// Revoke consent and offline tokens for particular client from user
@@ -476,6 +591,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmusersuser_idconsentsclient>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/users/{user-id}/consents/{client}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/users/{user-id}/consents/{client}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_consents_with_client_delete(
         &self,
         realm: &str,
@@ -485,15 +611,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let client = p(client);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/consents/{client}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/consents/{client}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/consents/{client}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -508,6 +636,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idcredentials>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/credentials`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/credentials",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_credentials_get(
         &self,
         realm: &str,
@@ -515,15 +654,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<CredentialRepresentation>, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/credentials",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/credentials",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/users/{user-id}/credentials", builder)
+            .await?;
+        response.json()
     }
 
     /// Remove a credential for a user
@@ -543,6 +681,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmusersuser_idcredentialscredentialid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/users/{user-id}/credentials/{credentialId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/users/{user-id}/credentials/{credentialId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_credentials_with_credential_id_delete(
         &self,
         realm: &str,
@@ -552,15 +701,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let credential_id = p(credential_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/credentials/{credential_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/credentials/{credential_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/credentials/{credentialId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Move a credential to a position behind another credential
@@ -581,6 +732,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmusersuser_idcredentialscredentialidmoveafternewpreviouscredentialid>
     ///
     /// REST method: `POST /admin/realms/{realm}/users/{user-id}/credentials/{credentialId}/moveAfter/{newPreviousCredentialId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/users/{user-id}/credentials/{credentialId}/moveAfter/{newPreviousCredentialId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_credentials_with_credential_id_move_after_with_new_previous_credential_id_post(
         &self,
         realm: &str,
@@ -592,15 +754,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let user_id = p(user_id);
         let credential_id = p(credential_id);
         let new_previous_credential_id = p(new_previous_credential_id);
-        let builder = self
-            .client
-            .post(format!(
+        let builder = KeycloakRequest::post(format!(
                 "{}/admin/realms/{realm}/users/{user_id}/credentials/{credential_id}/moveAfter/{new_previous_credential_id}",
                 self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+            ));
+        let response = self.call("/admin/realms/{realm}/users/{user-id}/credentials/{credentialId}/moveAfter/{newPreviousCredentialId}", builder).await?;
+        response.check().map(From::from)
     }
 
     /// Move a credential to a first position in the credentials list of the user
@@ -620,6 +779,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmusersuser_idcredentialscredentialidmovetofirst>
     ///
     /// REST method: `POST /admin/realms/{realm}/users/{user-id}/credentials/{credentialId}/moveToFirst`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/users/{user-id}/credentials/{credentialId}/moveToFirst",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_credentials_with_credential_id_move_to_first_post(
         &self,
         realm: &str,
@@ -629,15 +799,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let credential_id = p(credential_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/credentials/{credential_id}/moveToFirst",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/credentials/{credential_id}/moveToFirst",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/credentials/{credentialId}/moveToFirst",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Update a credential label for a user
@@ -658,6 +830,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmusersuser_idcredentialscredentialiduserlabel>
     ///
     /// REST method: `PUT /admin/realms/{realm}/users/{user-id}/credentials/{credentialId}/userLabel`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/users/{user-id}/credentials/{credentialId}/userLabel",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_credentials_with_credential_id_user_label_put(
         &self,
         realm: &str,
@@ -668,16 +851,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let credential_id = p(credential_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/credentials/{credential_id}/userLabel",
-                self.url
-            ))
-            .body(body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/credentials/{credential_id}/userLabel",
+            self.url
+        ))
+        .text(body);
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/credentials/{credentialId}/userLabel",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Disable all credentials for a user of a specific type
@@ -697,6 +882,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmusersuser_iddisable_credential_types>
     ///
     /// REST method: `PUT /admin/realms/{realm}/users/{user-id}/disable-credential-types`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/users/{user-id}/disable-credential-types",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_disable_credential_types_put(
         &self,
         realm: &str,
@@ -705,16 +901,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/disable-credential-types",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/disable-credential-types",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/disable-credential-types",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Send an email to the user with a link they can click to execute particular actions.
@@ -737,6 +935,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmusersuser_idexecute_actions_email>
     ///
     /// REST method: `PUT /admin/realms/{realm}/users/{user-id}/execute-actions-email`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/users/{user-id}/execute-actions-email",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_execute_actions_email_put(
         &self,
         realm: &str,
@@ -748,25 +957,27 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let mut builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/execute-actions-email",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/execute-actions-email",
+            self.url
+        ))
+        .json(&body)?;
         if let Some(v) = client_id {
-            builder = builder.query(&[("client_id", v)]);
+            builder = builder.query(&[("client_id", v)])?;
         }
         if let Some(v) = lifespan {
-            builder = builder.query(&[("lifespan", v)]);
+            builder = builder.query(&[("lifespan", v)])?;
         }
         if let Some(v) = redirect_uri {
-            builder = builder.query(&[("redirect_uri", v)]);
+            builder = builder.query(&[("redirect_uri", v)])?;
         }
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/execute-actions-email",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get social logins associated with the user
@@ -783,6 +994,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idfederated_identity>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/federated-identity`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/federated-identity",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_federated_identity_get(
         &self,
         realm: &str,
@@ -790,15 +1012,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<FederatedIdentityRepresentation>, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/federated-identity",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/federated-identity",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/federated-identity",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Add a social login provider to the user
@@ -819,6 +1043,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmusersuser_idfederated_identityprovider>
     ///
     /// REST method: `POST /admin/realms/{realm}/users/{user-id}/federated-identity/{provider}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/users/{user-id}/federated-identity/{provider}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_federated_identity_with_provider_post(
         &self,
         realm: &str,
@@ -829,16 +1064,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let provider = p(provider);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/federated-identity/{provider}",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/federated-identity/{provider}",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/federated-identity/{provider}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Remove a social login provider from user
@@ -858,6 +1095,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmusersuser_idfederated_identityprovider>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/users/{user-id}/federated-identity/{provider}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/users/{user-id}/federated-identity/{provider}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_federated_identity_with_provider_delete(
         &self,
         realm: &str,
@@ -867,15 +1115,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let provider = p(provider);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/federated-identity/{provider}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/federated-identity/{provider}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/federated-identity/{provider}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -894,6 +1144,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idgroups>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/groups`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/groups",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_groups_get(
         &self,
         realm: &str,
@@ -905,27 +1166,26 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<GroupRepresentation>, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/groups",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/groups",
+            self.url
+        ));
         if let Some(v) = brief_representation {
-            builder = builder.query(&[("briefRepresentation", v)]);
+            builder = builder.query(&[("briefRepresentation", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/users/{user-id}/groups", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -941,6 +1201,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idgroupscount>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/groups/count`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/groups/count",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_groups_count_get(
         &self,
         realm: &str,
@@ -949,18 +1220,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeMap<String, i64>, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let mut builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/groups/count",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/groups/count",
+            self.url
+        ));
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/groups/count",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -978,6 +1251,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmusersuser_idgroupsgroupid>
     ///
     /// REST method: `PUT /admin/realms/{realm}/users/{user-id}/groups/{groupId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/users/{user-id}/groups/{groupId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_groups_with_group_id_put(
         &self,
         realm: &str,
@@ -987,16 +1271,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/groups/{group_id}",
-                self.url
-            ))
-            .header(CONTENT_LENGTH, "0")
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/groups/{group_id}",
+            self.url
+        ))
+        .header(CONTENT_LENGTH, HeaderValue::from_static("0"));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/groups/{groupId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Parameters:
@@ -1014,6 +1300,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmusersuser_idgroupsgroupid>
     ///
     /// REST method: `DELETE /admin/realms/{realm}/users/{user-id}/groups/{groupId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/users/{user-id}/groups/{groupId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_groups_with_group_id_delete(
         &self,
         realm: &str,
@@ -1023,15 +1320,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let group_id = p(group_id);
-        let builder = self
-            .client
-            .delete(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/groups/{group_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::delete(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/groups/{group_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/groups/{groupId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Impersonate the user
@@ -1048,6 +1347,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmusersuser_idimpersonation>
     ///
     /// REST method: `POST /admin/realms/{realm}/users/{user-id}/impersonation`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/users/{user-id}/impersonation",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_impersonation_post(
         &self,
         realm: &str,
@@ -1055,15 +1365,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeMap<String, Value>, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/impersonation",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/impersonation",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/impersonation",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Remove all user sessions associated with the user Also send notification to all clients that have an admin URL to invalidate the sessions for the particular user.
@@ -1082,6 +1394,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmusersuser_idlogout>
     ///
     /// REST method: `POST /admin/realms/{realm}/users/{user-id}/logout`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/users/{user-id}/logout",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_logout_post(
         &self,
         realm: &str,
@@ -1089,15 +1412,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/logout",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/logout",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/users/{user-id}/logout", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get offline sessions associated with the user and client
@@ -1115,6 +1437,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idoffline_sessionsclientuuid>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/offline-sessions/{clientUuid}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/offline-sessions/{clientUuid}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_offline_sessions_with_client_uuid_get(
         &self,
         realm: &str,
@@ -1124,15 +1457,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let realm = p(realm);
         let user_id = p(user_id);
         let client_uuid = p(client_uuid);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/offline-sessions/{client_uuid}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/offline-sessions/{client_uuid}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/offline-sessions/{clientUuid}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Set up a new password for the user.
@@ -1152,6 +1487,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmusersuser_idreset_password>
     ///
     /// REST method: `PUT /admin/realms/{realm}/users/{user-id}/reset-password`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/users/{user-id}/reset-password",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_reset_password_put(
         &self,
         realm: &str,
@@ -1160,16 +1506,18 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/reset-password",
-                self.url
-            ))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/reset-password",
+            self.url
+        ))
+        .json(&body)?;
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/reset-password",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Send an email to the user with a link they can click to reset their password.
@@ -1191,6 +1539,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// REST method: `PUT /admin/realms/{realm}/users/{user-id}/reset-password-email`
     #[deprecated]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/users/{user-id}/reset-password-email",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_reset_password_email_put(
         &self,
         realm: &str,
@@ -1200,22 +1559,24 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let mut builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/reset-password-email",
-                self.url
-            ))
-            .header(CONTENT_LENGTH, "0")
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/reset-password-email",
+            self.url
+        ))
+        .header(CONTENT_LENGTH, HeaderValue::from_static("0"));
         if let Some(v) = client_id {
-            builder = builder.query(&[("client_id", v)]);
+            builder = builder.query(&[("client_id", v)])?;
         }
         if let Some(v) = redirect_uri {
-            builder = builder.query(&[("redirect_uri", v)]);
+            builder = builder.query(&[("redirect_uri", v)])?;
         }
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/reset-password-email",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Send an email-verification email to the user An email contains a link the user can click to verify their email address.
@@ -1237,6 +1598,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmusersuser_idsend_verify_email>
     ///
     /// REST method: `PUT /admin/realms/{realm}/users/{user-id}/send-verify-email`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/users/{user-id}/send-verify-email",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_send_verify_email_put(
         &self,
         realm: &str,
@@ -1247,25 +1619,27 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let mut builder = self
-            .client
-            .put(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/send-verify-email",
-                self.url
-            ))
-            .header(CONTENT_LENGTH, "0")
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::put(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/send-verify-email",
+            self.url
+        ))
+        .header(CONTENT_LENGTH, HeaderValue::from_static("0"));
         if let Some(v) = client_id {
-            builder = builder.query(&[("client_id", v)]);
+            builder = builder.query(&[("client_id", v)])?;
         }
         if let Some(v) = lifespan {
-            builder = builder.query(&[("lifespan", v)]);
+            builder = builder.query(&[("lifespan", v)])?;
         }
         if let Some(v) = redirect_uri {
-            builder = builder.query(&[("redirect_uri", v)]);
+            builder = builder.query(&[("redirect_uri", v)])?;
         }
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/send-verify-email",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Get sessions associated with the user
@@ -1282,6 +1656,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idsessions>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/sessions`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/sessions",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_sessions_get(
         &self,
         realm: &str,
@@ -1289,15 +1674,14 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeVec<UserSessionRepresentation>, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/sessions",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/sessions",
+            self.url
+        ));
+        let response = self
+            .call("/admin/realms/{realm}/users/{user-id}/sessions", builder)
+            .await?;
+        response.json()
     }
 
     /// Parameters:
@@ -1312,6 +1696,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmusersuser_idunmanagedattributes>
     ///
     /// REST method: `GET /admin/realms/{realm}/users/{user-id}/unmanagedAttributes`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/users/{user-id}/unmanagedAttributes",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_users_with_user_id_unmanaged_attributes_get(
         &self,
         realm: &str,
@@ -1319,15 +1714,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<TypeMap<String, TypeVec<String>>, KeycloakError> {
         let realm = p(realm);
         let user_id = p(user_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/users/{user_id}/unmanagedAttributes",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/users/{user_id}/unmanagedAttributes",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/users/{user-id}/unmanagedAttributes",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
 // not all paths processed
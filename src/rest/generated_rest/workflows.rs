@@ -18,6 +18,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/workflows`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmworkflows>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/workflows",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_workflows_get(
         &self,
         realm: &str,
@@ -27,24 +38,24 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         search: Option<String>,
     ) -> Result<WorkflowRepresentation, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/workflows", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/workflows", self.url));
         if let Some(v) = exact {
-            builder = builder.query(&[("exact", v)]);
+            builder = builder.query(&[("exact", v)])?;
         }
         if let Some(v) = first {
-            builder = builder.query(&[("first", v)]);
+            builder = builder.query(&[("first", v)])?;
         }
         if let Some(v) = max {
-            builder = builder.query(&[("max", v)]);
+            builder = builder.query(&[("max", v)])?;
         }
         if let Some(v) = search {
-            builder = builder.query(&[("search", v)]);
+            builder = builder.query(&[("search", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/workflows", builder)
+            .await?;
+        response.json()
     }
 
     /// Create workflow
@@ -61,19 +72,29 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/workflows`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmworkflows>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/workflows",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_workflows_post(
         &self,
         realm: &str,
         body: WorkflowRepresentation,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let builder = self
-            .client
-            .post(format!("{}/admin/realms/{realm}/workflows", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!("{}/admin/realms/{realm}/workflows", self.url))
+            .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/workflows", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Migrate scheduled resources from one step to another
@@ -91,6 +112,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `POST /admin/realms/{realm}/workflows/migrate`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmworkflowsmigrate>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/workflows/migrate",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_workflows_migrate_post(
         &self,
         realm: &str,
@@ -98,21 +130,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         to: Option<String>,
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
-        let mut builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/workflows/migrate",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/workflows/migrate",
+            self.url
+        ));
         if let Some(v) = from {
-            builder = builder.query(&[("from", v)]);
+            builder = builder.query(&[("from", v)])?;
         }
         if let Some(v) = to {
-            builder = builder.query(&[("to", v)]);
+            builder = builder.query(&[("to", v)])?;
         }
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let response = self
+            .call("/admin/realms/{realm}/workflows/migrate", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// List scheduled workflows for resource
@@ -129,6 +160,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmworkflowsscheduledresource_id>
     ///
     /// REST method: `GET /admin/realms/{realm}/workflows/scheduled/{resource-id}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/workflows/scheduled/{resource-id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_workflows_scheduled_with_resource_id_get(
         &self,
         realm: &str,
@@ -136,15 +178,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<WorkflowRepresentation, KeycloakError> {
         let realm = p(realm);
         let resource_id = p(resource_id);
-        let builder = self
-            .client
-            .get(format!(
-                "{}/admin/realms/{realm}/workflows/scheduled/{resource_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::get(format!(
+            "{}/admin/realms/{realm}/workflows/scheduled/{resource_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/workflows/scheduled/{resource-id}",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 
     /// Get workflow
@@ -160,6 +204,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `GET /admin/realms/{realm}/workflows/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_get_adminrealmsrealmworkflowsid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "GET",
+            http.route = "/admin/realms/{realm}/workflows/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_workflows_with_id_get(
         &self,
         realm: &str,
@@ -168,15 +223,15 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<WorkflowRepresentation, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let mut builder = self
-            .client
-            .get(format!("{}/admin/realms/{realm}/workflows/{id}", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder =
+            KeycloakRequest::get(format!("{}/admin/realms/{realm}/workflows/{id}", self.url));
         if let Some(v) = include_id {
-            builder = builder.query(&[("includeId", v)]);
+            builder = builder.query(&[("includeId", v)])?;
         }
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let response = self
+            .call("/admin/realms/{realm}/workflows/{id}", builder)
+            .await?;
+        response.json()
     }
 
     /// Update workflow
@@ -194,6 +249,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `PUT /admin/realms/{realm}/workflows/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_put_adminrealmsrealmworkflowsid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "PUT",
+            http.route = "/admin/realms/{realm}/workflows/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_workflows_with_id_put(
         &self,
         realm: &str,
@@ -202,13 +268,13 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let builder = self
-            .client
-            .put(format!("{}/admin/realms/{realm}/workflows/{id}", self.url))
-            .json(&body)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder =
+            KeycloakRequest::put(format!("{}/admin/realms/{realm}/workflows/{id}", self.url))
+                .json(&body)?;
+        let response = self
+            .call("/admin/realms/{realm}/workflows/{id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Delete workflow
@@ -225,6 +291,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// `DELETE /admin/realms/{realm}/workflows/{id}`
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_delete_adminrealmsrealmworkflowsid>
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "DELETE",
+            http.route = "/admin/realms/{realm}/workflows/{id}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_workflows_with_id_delete(
         &self,
         realm: &str,
@@ -232,12 +309,12 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ) -> Result<DefaultResponse, KeycloakError> {
         let realm = p(realm);
         let id = p(id);
-        let builder = self
-            .client
-            .delete(format!("{}/admin/realms/{realm}/workflows/{id}", self.url))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder =
+            KeycloakRequest::delete(format!("{}/admin/realms/{realm}/workflows/{id}", self.url));
+        let response = self
+            .call("/admin/realms/{realm}/workflows/{id}", builder)
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Activate workflow for resource
@@ -259,6 +336,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmworkflowsidactivatetyperesourceid>
     ///
     /// REST method: `POST /admin/realms/{realm}/workflows/{id}/activate/{type}/{resourceId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/workflows/{id}/activate/{type}/{resourceId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_workflows_with_id_activate_with_type_with_resource_id_post(
         &self,
         realm: &str,
@@ -271,18 +359,20 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let id = p(id);
         let resource_id = p(resource_id);
         let type_ = p(type_);
-        let mut builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/workflows/{id}/activate/{type_}/{resource_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
+        let mut builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/workflows/{id}/activate/{type_}/{resource_id}",
+            self.url
+        ));
         if let Some(v) = not_before {
-            builder = builder.query(&[("notBefore", v)]);
+            builder = builder.query(&[("notBefore", v)])?;
         }
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let response = self
+            .call(
+                "/admin/realms/{realm}/workflows/{id}/activate/{type}/{resourceId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 
     /// Deactivate workflow for resource
@@ -303,6 +393,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     /// Documentation: <https://www.keycloak.org/docs-api/26.6.0/rest-api/index.html#_post_adminrealmsrealmworkflowsiddeactivatetyperesourceid>
     ///
     /// REST method: `POST /admin/realms/{realm}/workflows/{id}/deactivate/{type}/{resourceId}`
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/workflows/{id}/deactivate/{type}/{resourceId}",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_workflows_with_id_deactivate_with_type_with_resource_id_post(
         &self,
         realm: &str,
@@ -314,15 +415,17 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
         let id = p(id);
         let resource_id = p(resource_id);
         let type_ = p(type_);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/workflows/{id}/deactivate/{type_}/{resource_id}",
-                self.url
-            ))
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        error_check(response).await.map(From::from)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/workflows/{id}/deactivate/{type_}/{resource_id}",
+            self.url
+        ));
+        let response = self
+            .call(
+                "/admin/realms/{realm}/workflows/{id}/deactivate/{type}/{resourceId}",
+                builder,
+            )
+            .await?;
+        response.check().map(From::from)
     }
 }
 // not all paths processed
@@ -0,0 +1,209 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// Client-side limit of the requests sent to Keycloak.
+///
+/// Limits the number of requests in flight and, optionally, the request rate
+/// with a token bucket. Clones share the same limits, so a limiter can be
+/// passed to several [`KeycloakAdmin`](crate::KeycloakAdmin)s to limit them
+/// together. All [`KeycloakRealmAdmin`](crate::KeycloakRealmAdmin)s of an
+/// admin client share its limiter.
+///
+/// Token requests of the token supplier are not limited.
+///
+/// The request rate is enforced with [`tokio::time::sleep`], so a limiter
+/// with [`KeycloakLimiter::with_requests_per_second`] requires a tokio runtime
+/// with the timer enabled, even with a
+/// [`KeycloakTransport`](crate::KeycloakTransport) not based on tokio. Limits
+/// of requests in flight only need an async executor.
+///
+/// # Example
+///
+/// ```no_run
+/// use keycloak::{prelude::reqwest, KeycloakAdmin, KeycloakAdminToken, KeycloakLimiter};
+///
+/// # fn doc(admin_token: KeycloakAdminToken) {
+/// let admin = KeycloakAdmin::new("https://keycloak.example.com", admin_token, reqwest::Client::new())
+///     .with_limiter(
+///         KeycloakLimiter::new()
+///             .with_max_in_flight(8)
+///             .with_requests_per_second(50),
+///     );
+/// # let _ = admin;
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct KeycloakLimiter {
+    in_flight: Option<Arc<Semaphore>>,
+    requests_per_second: Option<u32>,
+    burst: Option<u32>,
+    bucket: Option<Arc<Mutex<TokenBucket>>>,
+}
+
+impl KeycloakLimiter {
+    /// Creates a limiter without limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximal number of requests in flight, at least 1.
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.in_flight = Some(Arc::new(Semaphore::new(
+            max_in_flight.clamp(1, Semaphore::MAX_PERMITS),
+        )));
+        self
+    }
+
+    /// Sets the maximal number of requests per second, at least 1.
+    ///
+    /// Up to one second worth of requests may be sent at once, use
+    /// [`KeycloakLimiter::with_burst`] to change it.
+    pub fn with_requests_per_second(mut self, requests_per_second: u32) -> Self {
+        self.requests_per_second = Some(requests_per_second.max(1));
+        self.with_bucket()
+    }
+
+    /// Sets the number of requests which may be sent at once, at least 1.
+    ///
+    /// Has no effect without [`KeycloakLimiter::with_requests_per_second`],
+    /// which may be called before or after.
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = Some(burst.max(1));
+        self.with_bucket()
+    }
+
+    /// Replaces the token bucket according to the configured rate and burst.
+    fn with_bucket(mut self) -> Self {
+        if let Some(requests_per_second) = self.requests_per_second {
+            let rate = f64::from(requests_per_second);
+            let burst = self.burst.map_or(rate, f64::from);
+            self.bucket = Some(Arc::new(Mutex::new(TokenBucket::new(rate, burst))));
+        }
+        self
+    }
+
+    /// Waits until a request may be sent.
+    ///
+    /// The request counts as in flight until the returned permit is dropped.
+    /// The rate limit is awaited first, so that waiting requests do not
+    /// occupy the places of requests in flight.
+    pub(crate) async fn acquire(&self) -> Option<SemaphorePermit<'_>> {
+        if let Some(bucket) = &self.bucket {
+            let delay = bucket
+                .lock()
+                .expect("token bucket lock poisoned")
+                .reserve(Instant::now());
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+        }
+        match &self.in_flight {
+            Some(in_flight) => Some(
+                in_flight
+                    .acquire()
+                    .await
+                    .expect("in flight semaphore is never closed"),
+            ),
+            None => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    /// Tokens added per second.
+    rate: f64,
+    /// Maximal number of tokens.
+    burst: f64,
+    /// Available tokens at `updated_at`, negative if requests wait for tokens.
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: f64, burst: f64) -> Self {
+        Self {
+            rate,
+            burst,
+            tokens: burst,
+            updated_at: Instant::now(),
+        }
+    }
+
+    /// Takes a token and returns how long to wait until it is available.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst) - 1.0;
+        self.updated_at = now;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{KeycloakLimiter, TokenBucket};
+
+    #[test]
+    fn token_bucket() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(10.0, 2.0);
+        bucket.updated_at = now;
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::from_millis(100));
+        assert_eq!(bucket.reserve(now), Duration::from_millis(200));
+
+        let later = now + Duration::from_secs(10);
+        assert_eq!(bucket.reserve(later), Duration::ZERO);
+        assert_eq!(bucket.reserve(later), Duration::ZERO);
+        assert_eq!(bucket.reserve(later), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn burst_in_any_order() {
+        let burst = |limiter: KeycloakLimiter| {
+            let bucket = limiter.bucket.unwrap();
+            let bucket = bucket.lock().unwrap();
+            (bucket.rate, bucket.burst)
+        };
+        let before = KeycloakLimiter::new()
+            .with_burst(3)
+            .with_requests_per_second(10);
+        let after = KeycloakLimiter::new()
+            .with_requests_per_second(10)
+            .with_burst(3);
+        assert_eq!(burst(before), (10.0, 3.0));
+        assert_eq!(burst(after), (10.0, 3.0));
+        assert!(KeycloakLimiter::new().with_burst(3).bucket.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limit_before_permit() {
+        let limiter = KeycloakLimiter::new()
+            .with_max_in_flight(2)
+            .with_requests_per_second(1);
+        let in_flight = limiter.in_flight.clone().unwrap();
+        let first = limiter.acquire().await;
+        let second = limiter.acquire();
+        tokio::pin!(second);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(500), &mut second)
+                .await
+                .is_err()
+        );
+        assert_eq!(in_flight.available_permits(), 1);
+        let second = second.await;
+        assert_eq!(in_flight.available_permits(), 0);
+        drop((first, second));
+    }
+}
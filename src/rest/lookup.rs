@@ -0,0 +1,350 @@
+use std::future::Future;
+
+use super::{KeycloakRealmAdmin, KeycloakTokenSupplier};
+use crate::{types::*, KeycloakError};
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    /// Finds the client with exactly matching `clientId`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - `clientId` of the client, not its internal id.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn doc(admin: keycloak::KeycloakAdmin) -> Result<(), keycloak::KeycloakError> {
+    /// let realm = admin.realm("test").with_lookup_cache();
+    /// if let Some(client) = realm.client_by_client_id("my-app").await? {
+    ///     println!("internal id of my-app: {:?}", client.id);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "tag-clients")]
+    pub async fn client_by_client_id(
+        &self,
+        client_id: &str,
+    ) -> Result<Option<ClientRepresentation>, KeycloakError> {
+        self.cached("client", client_id.to_string(), async {
+            let clients = self
+                .admin
+                .realm_clients_get(
+                    self.realm,
+                    Some(client_id.to_string()),
+                    None,
+                    None,
+                    None,
+                    Some(false),
+                    None,
+                )
+                .await?;
+            Ok(clients
+                .iter()
+                .find(|client| client.client_id.as_deref() == Some(client_id))
+                .cloned())
+        })
+        .await
+    }
+
+    /// Finds the user with exactly matching username, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Username of the user.
+    #[cfg(feature = "tag-users")]
+    pub async fn user_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<UserRepresentation>, KeycloakError> {
+        let username = username.to_lowercase();
+        self.cached("username", username.clone(), async {
+            let users = self
+                .admin
+                .realm_users_get(
+                    self.realm,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(true),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(username.clone()),
+                )
+                .await?;
+            Ok(users
+                .iter()
+                .find(|user| {
+                    user.username
+                        .as_deref()
+                        .is_some_and(|name| name.to_lowercase() == username)
+                })
+                .cloned())
+        })
+        .await
+    }
+
+    /// Finds the user with exactly matching email, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// * `email` - Email of the user.
+    #[cfg(feature = "tag-users")]
+    pub async fn user_by_email(
+        &self,
+        email: &str,
+    ) -> Result<Option<UserRepresentation>, KeycloakError> {
+        let email = email.to_lowercase();
+        self.cached("email", email.clone(), async {
+            let users = self
+                .admin
+                .realm_users_get(
+                    self.realm,
+                    None,
+                    None,
+                    None,
+                    Some(email.clone()),
+                    None,
+                    None,
+                    Some(true),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+            Ok(users
+                .iter()
+                .find(|user| {
+                    user.email
+                        .as_deref()
+                        .is_some_and(|value| value.to_lowercase() == email)
+                })
+                .cloned())
+        })
+        .await
+    }
+
+    /// Finds the group at `path`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the group, e.g. `/parent/child`.
+    #[cfg(feature = "tag-realms-admin")]
+    pub async fn group_by_path(
+        &self,
+        path: &str,
+    ) -> Result<Option<GroupRepresentation>, KeycloakError> {
+        self.cached("group", path.to_string(), async {
+            not_found_as_none(
+                self.admin
+                    .realm_group_by_path_with_path_get(self.realm, path)
+                    .await,
+            )
+        })
+        .await
+    }
+
+    /// Finds the realm role named `name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the role.
+    #[cfg(feature = "tag-roles")]
+    pub async fn realm_role_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<RoleRepresentation>, KeycloakError> {
+        self.cached("realm role", name.to_string(), async {
+            not_found_as_none(
+                self.admin
+                    .realm_roles_with_role_name_get(self.realm, name)
+                    .await,
+            )
+        })
+        .await
+    }
+
+    /// Finds the role named `name` of the client with `clientId`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - `clientId` of the client, not its internal id.
+    /// * `name` - Name of the role.
+    #[cfg(all(feature = "tag-clients", feature = "tag-roles"))]
+    pub async fn client_role_by_name(
+        &self,
+        client_id: &str,
+        name: &str,
+    ) -> Result<Option<RoleRepresentation>, KeycloakError> {
+        let Some(client_uuid) = self
+            .client_by_client_id(client_id)
+            .await?
+            .and_then(|client| client.id)
+        else {
+            return Ok(None);
+        };
+        self.cached("client role", format!("{client_uuid}/{name}"), async {
+            not_found_as_none(
+                self.admin
+                    .realm_clients_with_client_uuid_roles_with_role_name_get(
+                        self.realm,
+                        &client_uuid,
+                        name,
+                    )
+                    .await,
+            )
+        })
+        .await
+    }
+
+    /// Returns the cached entity of `kind` at `key`, or fetches and caches it.
+    async fn cached<T: Clone + Send + Sync + 'static>(
+        &self,
+        kind: &'static str,
+        key: String,
+        fetch: impl Future<Output = Result<Option<T>, KeycloakError>>,
+    ) -> Result<Option<T>, KeycloakError> {
+        let Some(cache) = &self.lookup_cache else {
+            return fetch.await;
+        };
+        let key = (kind, key);
+        if let Some(value) = cache
+            .lock()
+            .expect("lookup cache lock")
+            .get(&key)
+            .and_then(|value| value.downcast_ref::<T>())
+        {
+            return Ok(Some(value.clone()));
+        }
+        let value = fetch.await?;
+        if let Some(value) = &value {
+            cache
+                .lock()
+                .expect("lookup cache lock")
+                .insert(key, Box::new(value.clone()));
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(any(feature = "tag-realms-admin", feature = "tag-roles"))]
+fn not_found_as_none<T>(result: Result<T, KeycloakError>) -> Result<Option<T>, KeycloakError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(error) if error.is_not_found() => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(all(test, feature = "mock-server", feature = "tags-all"))]
+mod tests {
+    use crate::{mock_server::KeycloakMockServer, types::*};
+
+    #[tokio::test]
+    async fn lookup() {
+        let admin = KeycloakMockServer::admin_with_realm("test").await;
+        let client_uuid = admin
+            .realm_clients_post(
+                "test",
+                ClientRepresentation {
+                    client_id: Some("app".into()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        let client_uuid = client_uuid.to_id().unwrap();
+        admin
+            .realm_clients_with_client_uuid_roles_post(
+                "test",
+                client_uuid,
+                RoleRepresentation {
+                    name: Some("admin".into()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        admin
+            .realm_users_post(
+                "test",
+                UserRepresentation {
+                    username: Some("alice".into()),
+                    email: Some("alice@example.com".into()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        let parent = admin
+            .realm_groups_post(
+                "test",
+                GroupRepresentation {
+                    name: Some("parent".into()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        admin
+            .realm_groups_with_group_id_children_post(
+                "test",
+                parent.to_id().unwrap(),
+                GroupRepresentation {
+                    name: Some("child".into()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let realm = admin.realm("test").with_lookup_cache();
+        let client = realm.client_by_client_id("app").await.unwrap().unwrap();
+        assert_eq!(client.id.as_deref(), Some(client_uuid));
+        assert!(realm.client_by_client_id("ap").await.unwrap().is_none());
+        let user = realm.user_by_username("Alice").await.unwrap().unwrap();
+        assert_eq!(
+            realm
+                .user_by_email("ALICE@example.com")
+                .await
+                .unwrap()
+                .unwrap()
+                .id,
+            user.id
+        );
+        assert!(realm.user_by_username("alic").await.unwrap().is_none());
+        let group = realm.group_by_path("/parent/child").await.unwrap().unwrap();
+        assert_eq!(group.name.as_deref(), Some("child"));
+        assert!(realm.group_by_path("/child").await.unwrap().is_none());
+        assert!(realm.realm_role_by_name("admin").await.unwrap().is_none());
+        let role = realm.client_role_by_name("app", "admin").await.unwrap();
+        assert_eq!(role.unwrap().client_role, Some(true));
+        assert!(realm
+            .client_role_by_name("other", "admin")
+            .await
+            .unwrap()
+            .is_none());
+
+        admin
+            .realm_clients_with_client_uuid_delete("test", client_uuid)
+            .await
+            .unwrap();
+        assert!(realm.client_by_client_id("app").await.unwrap().is_some());
+        realm.clear_lookup_cache();
+        assert!(realm.client_by_client_id("app").await.unwrap().is_none());
+    }
+}
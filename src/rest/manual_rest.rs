@@ -16,28 +16,38 @@ impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
     ///
     /// Documentation: <https://www.keycloak.org/docs-api/26.2.0/rest-api/index.html#_post_adminrealmsrealmidentity_providerimport_config>
     #[cfg(all(feature = "tag-identity-providers", feature = "multipart"))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = "POST",
+            http.route = "/admin/realms/{realm}/identity-provider/import-config",
+            realm = %realm,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn realm_identity_provider_import_config_post_form(
         &self,
         realm: &str,
         provider_id: String,
         file: Vec<u8>,
     ) -> Result<TypeMap<String, TypeString>, KeycloakError> {
-        use reqwest::multipart::{Form, Part};
-
         let realm = p(realm);
-        let file = Part::bytes(file).mime_str("application/octet-stream")?;
-        let form = Form::new()
-            .text("providerId", provider_id)
-            .part("file", file);
-        let builder = self
-            .client
-            .post(format!(
-                "{}/admin/realms/{realm}/identity-provider/import-config",
-                self.url
-            ))
-            .multipart(form)
-            .bearer_auth(self.token_supplier.get(&self.url).await?);
-        let response = builder.send().await?;
-        Ok(error_check(response).await?.json().await?)
+        let builder = KeycloakRequest::post(format!(
+            "{}/admin/realms/{realm}/identity-provider/import-config",
+            self.url
+        ))
+        .multipart(vec![
+            KeycloakMultipartPart::text("providerId", provider_id),
+            KeycloakMultipartPart::bytes("file", file, "application/octet-stream"),
+        ]);
+        let response = self
+            .call(
+                "/admin/realms/{realm}/identity-provider/import-config",
+                builder,
+            )
+            .await?;
+        response.json()
     }
 }
@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::{transmit, KeycloakRequest, KeycloakResponse, KeycloakTransport};
+use crate::KeycloakError;
+
+/// Interceptor of the requests sent by [`KeycloakAdmin`](crate::KeycloakAdmin).
+///
+/// A middleware receives every request before it is sent and the remaining
+/// chain as `next`. It may modify the request, pass it on with
+/// [`KeycloakTransport::send`] on `next` and observe or replace the result,
+/// or answer without calling `next` at all.
+///
+/// Middlewares added with [`KeycloakAdmin::with_middleware`](crate::KeycloakAdmin::with_middleware)
+/// run around Admin API calls and token acquisition calls of the token
+/// supplier. Retried requests pass through the chain on every attempt.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Instant;
+///
+/// use keycloak::{
+///     prelude::http::HeaderValue, KeycloakError, KeycloakMiddleware, KeycloakNext,
+///     KeycloakRequest, KeycloakResponse, KeycloakTransport,
+/// };
+///
+/// /// Adds a correlation id and logs the duration of requests.
+/// struct Audit;
+///
+/// #[async_trait::async_trait]
+/// impl KeycloakMiddleware for Audit {
+///     async fn handle(
+///         &self,
+///         request: KeycloakRequest,
+///         next: KeycloakNext<'_>,
+///     ) -> Result<KeycloakResponse, KeycloakError> {
+///         let request = request.header(
+///             "x-correlation-id".parse().unwrap(),
+///             HeaderValue::from_static("provisioning"),
+///         );
+///         let description = format!("{} {}", request.method(), request.url());
+///         let started = Instant::now();
+///         let result = next.send(request).await;
+///         eprintln!("{description} took {:?}", started.elapsed());
+///         result
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait KeycloakMiddleware: Send + Sync {
+    /// Handles `request`, usually by sending it with `next`.
+    async fn handle(
+        &self,
+        request: KeycloakRequest,
+        next: KeycloakNext<'_>,
+    ) -> Result<KeycloakResponse, KeycloakError>;
+}
+
+/// Remaining middlewares of the chain, followed by the transport.
+#[derive(Clone, Copy)]
+pub struct KeycloakNext<'a> {
+    middlewares: &'a [Arc<dyn KeycloakMiddleware>],
+    transport: &'a dyn KeycloakTransport,
+}
+
+#[async_trait]
+impl KeycloakTransport for KeycloakNext<'_> {
+    async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+        match self.middlewares.split_first() {
+            Some((middleware, middlewares)) => {
+                let next = KeycloakNext {
+                    middlewares,
+                    transport: self.transport,
+                };
+                middleware.handle(request, next).await
+            }
+            None => transmit(self.transport, request).await,
+        }
+    }
+}
+
+/// Middlewares in the order they were added, the first one is the outermost.
+#[derive(Clone, Default)]
+pub(crate) struct Middlewares(Vec<Arc<dyn KeycloakMiddleware>>);
+
+impl Middlewares {
+    pub(crate) fn push(&mut self, middleware: Arc<dyn KeycloakMiddleware>) {
+        self.0.push(middleware);
+    }
+
+    /// Returns the chain of the middlewares in front of `transport`.
+    pub(crate) fn chain<'a>(&'a self, transport: &'a dyn KeycloakTransport) -> KeycloakNext<'a> {
+        KeycloakNext {
+            middlewares: &self.0,
+            transport,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use http::{HeaderMap, HeaderName, HeaderValue, StatusCode};
+
+    use super::{KeycloakMiddleware, KeycloakNext, Middlewares};
+    use crate::{KeycloakError, KeycloakRequest, KeycloakResponse, KeycloakTransport};
+
+    struct Echo;
+
+    #[async_trait]
+    impl KeycloakTransport for Echo {
+        async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+            Ok(KeycloakResponse::new(
+                StatusCode::OK,
+                request.headers().clone(),
+                Vec::new(),
+            ))
+        }
+    }
+
+    struct Tag(&'static str);
+
+    #[async_trait]
+    impl KeycloakMiddleware for Tag {
+        async fn handle(
+            &self,
+            request: KeycloakRequest,
+            next: KeycloakNext<'_>,
+        ) -> Result<KeycloakResponse, KeycloakError> {
+            // appends the tag, so the header records the order of the middlewares
+            let order = match request.headers().get("x-order") {
+                Some(order) => format!("{},{}", order.to_str().unwrap(), self.0),
+                None => self.0.to_string(),
+            };
+            let request = request.header(
+                HeaderName::from_static("x-order"),
+                HeaderValue::try_from(order).unwrap(),
+            );
+            next.send(request).await
+        }
+    }
+
+    struct Offline;
+
+    #[async_trait]
+    impl KeycloakMiddleware for Offline {
+        async fn handle(
+            &self,
+            _request: KeycloakRequest,
+            _next: KeycloakNext<'_>,
+        ) -> Result<KeycloakResponse, KeycloakError> {
+            Ok(KeycloakResponse::new(
+                StatusCode::SERVICE_UNAVAILABLE,
+                HeaderMap::new(),
+                Vec::new(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn chain() {
+        let mut middlewares = Middlewares::default();
+        middlewares.push(Arc::new(Tag("first")));
+        middlewares.push(Arc::new(Tag("second")));
+        middlewares.push(Arc::new(Tag("third")));
+        let response = middlewares
+            .chain(&Echo)
+            .send(KeycloakRequest::get("http://keycloak"))
+            .await
+            .unwrap();
+        assert_eq!(response.headers()["x-order"], "first,second,third");
+
+        middlewares.push(Arc::new(Offline));
+        let response = middlewares
+            .chain(&Echo)
+            .send(KeycloakRequest::get("http://keycloak"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -1,42 +1,129 @@
-use std::future::Future;
+use std::{borrow::Cow, collections::HashMap, future::Future, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
-use crate::{prelude::reqwest, types::*, KeycloakError};
+use crate::{types::*, KeycloakEndpoint, KeycloakError};
 
+mod cassette;
+mod client_auth;
 mod default_response;
+mod device_flow;
+pub(crate) mod ensure;
 mod generated_rest;
+mod limiter;
+#[cfg(any(
+    feature = "tag-clients",
+    feature = "tag-realms-admin",
+    feature = "tag-roles",
+    feature = "tag-users"
+))]
+mod lookup;
 mod manual_rest;
-mod url_enc;
+mod middleware;
+mod password_retriever;
+mod raw_request;
+mod retry;
+mod revocation;
+mod server_location;
+mod token_cache;
+mod token_exchange;
+#[cfg(feature = "jwt")]
+mod token_verifier;
+mod transport;
+pub(crate) mod url_enc;
 
+pub use cassette::{
+    KeycloakCassette, KeycloakInteraction, KeycloakRecordedRequest, KeycloakRecordedResponse,
+    KeycloakRecorder, KeycloakReplay,
+};
+pub use client_auth::KeycloakClientAuth;
 pub use default_response::DefaultResponse;
+pub use device_flow::{KeycloakDeviceAuthorization, KeycloakDeviceFlow};
+pub use ensure::{KeycloakEnsureAction, KeycloakEnsured};
+pub use limiter::KeycloakLimiter;
+pub use middleware::{KeycloakMiddleware, KeycloakNext};
+pub use password_retriever::KeycloakPasswordAdminTokenRetriever;
+pub use raw_request::KeycloakAdminRequest;
+pub use retry::KeycloakRetryPolicy;
+pub use server_location::{KeycloakOpenIdConfiguration, KeycloakServerLocation};
+pub use token_exchange::KeycloakTokenExchangeRetriever;
+#[cfg(feature = "jwt")]
+pub use token_verifier::KeycloakTokenVerifier;
+pub use transport::{
+    KeycloakMultipartPart, KeycloakRequest, KeycloakRequestBody, KeycloakResponse,
+    KeycloakTransport,
+};
+
+use middleware::Middlewares;
+use token_cache::{TokenCache, DEFAULT_EXPIRY_SKEW};
 
 pub struct KeycloakAdmin<TS: KeycloakTokenSupplier = KeycloakAdminToken> {
+    /// Base URL of the Admin API.
     url: String,
-    client: reqwest::Client,
+    location: KeycloakServerLocation,
+    client: Arc<dyn KeycloakTransport>,
     token_supplier: TS,
+    retry_policy: KeycloakRetryPolicy,
+    middlewares: Middlewares,
+    limiter: KeycloakLimiter,
 }
 
 #[async_trait]
 pub trait KeycloakTokenSupplier {
     async fn get(&self, url: &str) -> Result<String, KeycloakError>;
+
+    /// Called when Keycloak rejected `token` with `401 Unauthorized`.
+    ///
+    /// Suppliers which are able to provide another token (e.g. after dropping
+    /// a cached one) should return `true`: [`KeycloakAdmin`] then calls
+    /// [`KeycloakTokenSupplier::get`] again and replays the request once.
+    ///
+    /// Default implementation returns `false`, so the `401` is passed to the caller.
+    fn token_rejected(&self, _url: &str, _token: &str) -> bool {
+        false
+    }
+
+    /// Called by [`KeycloakAdmin::with_middleware`] with each added middleware.
+    ///
+    /// Suppliers which request tokens from Keycloak should send these requests
+    /// through the middlewares too. Default implementation ignores them.
+    fn add_middleware(&mut self, _middleware: Arc<dyn KeycloakMiddleware>) {}
 }
 
+/// Token supplier for a [service account] using the `client_credentials` grant.
+///
+/// The token is cached until shortly before it expires. Clones share the same
+/// cached token, and concurrent callers wait for a single token request
+/// instead of each acquiring their own.
+///
+/// [service account]: https://www.keycloak.org/docs/latest/server_development/#authenticating-with-a-service-account
 #[derive(Clone)]
 pub struct KeycloakServiceAccountAdminTokenRetriever {
     client_id: String,
-    client_secret: String,
+    client_auth: KeycloakClientAuth,
     realm: String,
-    reqwest_client: reqwest::Client,
+    client: Arc<dyn KeycloakTransport>,
+    middlewares: Middlewares,
+    location: Option<KeycloakServerLocation>,
+    cache: Arc<TokenCache>,
 }
 
 #[async_trait]
 impl KeycloakTokenSupplier for KeycloakServiceAccountAdminTokenRetriever {
     async fn get(&self, url: &str) -> Result<String, KeycloakError> {
-        let admin_token = self.acquire(url).await?;
+        let admin_token = self.token(url).await?;
         Ok(admin_token.access_token)
     }
+
+    fn token_rejected(&self, _url: &str, token: &str) -> bool {
+        self.cache.invalidate_token(token);
+        true
+    }
+
+    fn add_middleware(&mut self, middleware: Arc<dyn KeycloakMiddleware>) {
+        self.middlewares.push(middleware);
+    }
 }
 
 impl KeycloakServiceAccountAdminTokenRetriever {
@@ -57,7 +144,7 @@ impl KeycloakServiceAccountAdminTokenRetriever {
     ///   2. `confidential` access type.
     ///   3. `Service Accounts` option is enabled.
     /// * `client_secret` - The secret credential assigned to the given `client_id`.
-    /// * `client` - A reqwest `Client` used to perform the token retrieval call.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the token retrieval call.
     ///
     /// # Example
     ///
@@ -79,13 +166,12 @@ impl KeycloakServiceAccountAdminTokenRetriever {
     /// # let _ = admin;
     /// # Ok(()) }
     /// ```
-    pub fn create(client_id: &str, client_secret: &str, client: reqwest::Client) -> Self {
-        Self {
-            client_id: client_id.into(),
-            client_secret: client_secret.into(),
-            realm: "master".into(),
-            reqwest_client: client,
-        }
+    pub fn create(
+        client_id: &str,
+        client_secret: &str,
+        client: impl KeycloakTransport + 'static,
+    ) -> Self {
+        Self::create_with_custom_realm(client_id, client_secret, "master", client)
     }
 
     /// Creates a token retriever for a [service account] in a caller-specified realm.
@@ -103,7 +189,7 @@ impl KeycloakServiceAccountAdminTokenRetriever {
     ///   3. `Service Accounts` option is enabled.
     /// * `client_secret` - The secret credential assigned to the given `client_id`.
     /// * `realm` - The Keycloak realm the `client_id` lives in.
-    /// * `client` - A reqwest `Client` used to perform the token retrieval call.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the token retrieval call.
     ///
     /// # Example
     ///
@@ -130,42 +216,167 @@ impl KeycloakServiceAccountAdminTokenRetriever {
         client_id: &str,
         client_secret: &str,
         realm: &str,
-        client: reqwest::Client,
+        client: impl KeycloakTransport + 'static,
+    ) -> Self {
+        Self::create_with_client_auth(
+            client_id,
+            KeycloakClientAuth::ClientSecretPost(client_secret.into()),
+            realm,
+            client,
+        )
+    }
+
+    /// Creates a token retriever for a [service account] authenticating with
+    /// the given [`KeycloakClientAuth`] method.
+    ///
+    /// Use this when the client is configured with a `Client Authenticator`
+    /// other than `Client Id and Secret` sent in the form body, e.g. with
+    /// `Signed Jwt` (`private_key_jwt`) to avoid shared secrets.
+    ///
+    /// [service account]: https://www.keycloak.org/docs/latest/server_development/#authenticating-with-a-service-account
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - The client id of a confidential client with `Service Accounts` option enabled.
+    /// * `client_auth` - How the client authenticates at the token endpoint.
+    /// * `realm` - The Keycloak realm the `client_id` lives in.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the token retrieval call.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "jwt")]
+    /// # async fn doc() -> Result<(), keycloak::KeycloakError> {
+    /// use keycloak::{
+    ///     prelude::reqwest, KeycloakAdmin, KeycloakClientAuth,
+    ///     KeycloakServiceAccountAdminTokenRetriever,
+    /// };
+    ///
+    /// let client = reqwest::Client::new();
+    /// let url = "https://keycloak.example.com";
+    ///
+    /// let client_auth = KeycloakClientAuth::private_key_jwt_from_rsa_pem(
+    ///     &std::fs::read("my-client.key.pem").unwrap(),
+    /// )?;
+    /// let retriever = KeycloakServiceAccountAdminTokenRetriever::create_with_client_auth(
+    ///     "my-client",
+    ///     client_auth,
+    ///     "master",
+    ///     client.clone(),
+    /// );
+    ///
+    /// let admin = KeycloakAdmin::new(url, retriever, client);
+    /// // ... use `admin` to call the Admin REST API.
+    /// # let _ = admin;
+    /// # Ok(()) }
+    /// ```
+    pub fn create_with_client_auth(
+        client_id: &str,
+        client_auth: KeycloakClientAuth,
+        realm: &str,
+        client: impl KeycloakTransport + 'static,
     ) -> Self {
         Self {
             client_id: client_id.into(),
-            client_secret: client_secret.into(),
+            client_auth,
             realm: realm.into(),
-            reqwest_client: client,
+            client: Arc::new(client),
+            middlewares: Middlewares::default(),
+            location: None,
+            cache: Arc::new(TokenCache::new(DEFAULT_EXPIRY_SKEW)),
         }
     }
 
+    /// Sets the server location to take the token endpoint from, instead of
+    /// deriving it from the URL passed by [`KeycloakAdmin`].
+    pub fn with_location(mut self, location: KeycloakServerLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Sets how long before expiry the access token gets renewed (10 seconds by default).
+    ///
+    /// The skew is capped to half of the token lifetime.
+    pub fn with_expiry_skew(mut self, skew: Duration) -> Self {
+        self.cache = Arc::new(TokenCache::new(skew));
+        self
+    }
+
+    /// Returns the cached [`KeycloakAdminToken`], acquiring a new one if it
+    /// is missing or about to expire.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    pub async fn token(&self, url: &str) -> Result<KeycloakAdminToken, KeycloakError> {
+        self.cache.get_or_renew(|_| self.acquire(url)).await
+    }
+
+    /// Drops the cached token, so that the next call acquires a new one.
+    ///
+    /// Use it when the server revoked the token before its expiry.
+    pub fn invalidate(&self) {
+        self.cache.invalidate();
+    }
+
+    /// Drops the cached token and ends its Keycloak session.
+    ///
+    /// Does nothing if no token is cached. Call it on shutdown so that the
+    /// session does not linger until it expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    pub async fn logout(&self, url: &str) -> Result<(), KeycloakError> {
+        let Some(token) = self.cache.take() else {
+            return Ok(());
+        };
+        token
+            .logout_at(
+                &KeycloakServerLocation::resolve(self.location.as_ref(), url),
+                &self.realm,
+                &self.client_id,
+                &self.client_auth,
+                &self.middlewares.chain(self.client.as_ref()),
+            )
+            .await
+    }
+
     /// Fetches a fresh [`KeycloakAdminToken`] for the configured service account.
     ///
     /// Each call performs a new HTTP request against Keycloak's token endpoint
-    /// using the `client_credentials` grant. To avoid the per-request round-trip,
-    /// cache the returned [`KeycloakAdminToken`] yourself (e.g. using its
-    /// [`KeycloakAdminToken::expires_in`] field) or implement a custom
-    /// [`KeycloakTokenSupplier`].
+    /// using the `client_credentials` grant, bypassing the cache used by
+    /// [`KeycloakServiceAccountAdminTokenRetriever::token`].
     ///
     /// # Arguments
     ///
     /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "acquire_token",
+        skip_all,
+        fields(
+            grant_type = "client_credentials",
+            client_id = %self.client_id,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
     pub async fn acquire(&self, url: &str) -> Result<KeycloakAdminToken, KeycloakError> {
-        let realm = &self.realm;
+        let token_endpoint = KeycloakServerLocation::resolve(self.location.as_ref(), url)
+            .token_endpoint(&self.realm);
+        let request = self.client_auth.authenticate(
+            KeycloakRequest::post(&token_endpoint),
+            &token_endpoint,
+            &self.client_id,
+            &[("grant_type", "client_credentials")],
+        )?;
         let response = self
-            .reqwest_client
-            .post(format!(
-                "{url}/realms/{realm}/protocol/openid-connect/token",
-            ))
-            .form(&[
-                ("client_id", self.client_id.as_str()),
-                ("client_secret", self.client_secret.as_str()),
-                ("grant_type", "client_credentials"),
-            ])
-            .send()
+            .middlewares
+            .chain(self.client.as_ref())
+            .send(request)
             .await?;
-        Ok(error_check(response).await?.json().await?)
+        error_check(response)?.json()
     }
 }
 
@@ -177,6 +388,7 @@ pub struct KeycloakAdminToken {
     not_before_policy: Option<usize>,
     refresh_expires_in: Option<usize>,
     refresh_token: Option<String>,
+    #[serde(default)]
     scope: String,
     session_state: Option<String>,
     token_type: String,
@@ -237,7 +449,7 @@ impl KeycloakAdminToken {
         url: &str,
         username: &str,
         password: &str,
-        client: &reqwest::Client,
+        client: &dyn KeycloakTransport,
     ) -> Result<KeycloakAdminToken, KeycloakError> {
         Self::acquire_custom_realm(
             url,
@@ -258,57 +470,291 @@ impl KeycloakAdminToken {
         realm: &str,
         client_id: &str,
         grant_type: &str,
-        client: &reqwest::Client,
+        client: &dyn KeycloakTransport,
     ) -> Result<KeycloakAdminToken, KeycloakError> {
-        let response = client
-            .post(format!(
-                "{url}/realms/{realm}/protocol/openid-connect/token",
-            ))
-            .form(&[
-                ("username", username),
-                ("password", password),
-                ("client_id", client_id),
-                ("grant_type", grant_type),
-            ])
-            .send()
-            .await?;
-        Ok(error_check(response).await?.json().await?)
+        Self::acquire_with_token_endpoint(
+            &KeycloakServerLocation::new(url).token_endpoint(realm),
+            username,
+            password,
+            client_id,
+            grant_type,
+            client,
+        )
+        .await
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "acquire_token",
+        skip_all,
+        fields(
+            grant_type = %grant_type,
+            client_id = %client_id,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
+    pub(crate) async fn acquire_with_token_endpoint(
+        token_endpoint: &str,
+        username: &str,
+        password: &str,
+        client_id: &str,
+        grant_type: &str,
+        client: &dyn KeycloakTransport,
+    ) -> Result<KeycloakAdminToken, KeycloakError> {
+        let request = KeycloakRequest::post(token_endpoint).form(&[
+            ("username", username),
+            ("password", password),
+            ("client_id", client_id),
+            ("grant_type", grant_type),
+        ])?;
+        let response = transmit(client, request).await?;
+        error_check(response)?.json()
     }
 }
 
-async fn error_check(response: reqwest::Response) -> Result<reqwest::Response, KeycloakError> {
+fn error_check(response: KeycloakResponse) -> Result<KeycloakResponse, KeycloakError> {
     if !response.status().is_success() {
         let status = response.status().into();
-        let text = response.text().await?;
+        let text = response.text();
+        let body: Option<crate::error::KeycloakHttpError> = serde_json::from_str(&text).ok();
+        #[cfg(feature = "tracing")]
+        {
+            // the body is not recorded, it may contain secrets
+            let message = body.as_ref().and_then(|body| body.message());
+            let reason = response.status().canonical_reason().unwrap_or_default();
+            tracing::Span::current().record("error", message.as_deref().unwrap_or(reason));
+        }
         return Err(KeycloakError::HttpFailure {
             status,
-            body: serde_json::from_str(&text).ok(),
+            body,
             text,
+            endpoint: None,
         });
     }
 
     Ok(response)
 }
 
+/// Response of an Admin API endpoint, errors carry the endpoint.
+struct EndpointResponse {
+    endpoint: KeycloakEndpoint,
+    response: KeycloakResponse,
+}
+
+impl EndpointResponse {
+    /// Fails with [`KeycloakError::HttpFailure`] for error responses.
+    fn check(self) -> Result<KeycloakResponse, KeycloakError> {
+        error_check(self.response).map_err(|e| e.with_endpoint(&self.endpoint))
+    }
+
+    fn json<T: serde::de::DeserializeOwned>(self) -> Result<T, KeycloakError> {
+        let endpoint = self.endpoint.clone();
+        serde_json::from_slice(self.check()?.body())
+            .map_err(|source| KeycloakError::DecodeFailure { endpoint, source })
+    }
+
+    #[cfg(any(
+        feature = "tag-client-attribute-certificate",
+        feature = "tag-realms-admin"
+    ))]
+    fn text(self) -> Result<String, KeycloakError> {
+        Ok(self.check()?.text())
+    }
+}
+
+/// Sends `request`, recording status code and latency on the current span
+/// if the `tracing` feature is enabled.
+async fn transmit(
+    transport: &dyn KeycloakTransport,
+    request: KeycloakRequest,
+) -> Result<KeycloakResponse, KeycloakError> {
+    #[cfg(feature = "tracing")]
+    let started = std::time::Instant::now();
+    let result = transport.send(request).await;
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        span.record("latency_ms", started.elapsed().as_millis() as u64);
+        match &result {
+            Ok(response) => span.record("http.response.status_code", response.status().as_u16()),
+            Err(e) => span.record("error", tracing::field::display(e)),
+        };
+    }
+    result
+}
+
 impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
-    pub fn new(url: &str, token_supplier: TS, client: reqwest::Client) -> Self {
+    /// Sends request authorized with a token of the token supplier.
+    ///
+    /// If Keycloak answers with `401 Unauthorized` and the token supplier is
+    /// able to provide another token, the request is replayed once.
+    async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+        let token = self.token_supplier.get(self.location.url()).await?;
+        let response = self
+            .send_with_retry(request.clone().bearer_auth(&token)?)
+            .await?;
+        if response.status() != http::StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+        if !self
+            .token_supplier
+            .token_rejected(self.location.url(), &token)
+        {
+            return Ok(response);
+        }
+        let token = self.token_supplier.get(self.location.url()).await?;
+        self.send_with_retry(request.bearer_auth(&token)?).await
+    }
+
+    /// Sends request to the Admin API endpoint at `path` (with placeholders, e.g.
+    /// `/admin/realms/{realm}/users`), errors carry the endpoint.
+    async fn call(
+        &self,
+        path: impl Into<Cow<'static, str>>,
+        request: KeycloakRequest,
+    ) -> Result<EndpointResponse, KeycloakError> {
+        let endpoint = KeycloakEndpoint {
+            method: request.method().clone(),
+            path: path.into(),
+        };
+        match self.send(request).await {
+            Ok(response) => Ok(EndpointResponse { endpoint, response }),
+            Err(e) => Err(e.with_endpoint(&endpoint)),
+        }
+    }
+
+    /// Sends request, retrying transient failures according to the retry policy.
+    async fn send_with_retry(
+        &self,
+        request: KeycloakRequest,
+    ) -> Result<KeycloakResponse, KeycloakError> {
+        let mut attempt = 1;
+        loop {
+            let permit = self.limiter.acquire().await;
+            let result = self
+                .middlewares
+                .chain(self.client.as_ref())
+                .send(request.clone())
+                .await;
+            drop(permit);
+            let Some(delay) = self
+                .retry_policy
+                .retry_delay(request.method(), attempt, &result)
+            else {
+                return result;
+            };
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    pub fn new(url: &str, token_supplier: TS, client: impl KeycloakTransport + 'static) -> Self {
+        Self::new_with_location(KeycloakServerLocation::new(url), token_supplier, client)
+    }
+
+    /// Creates an admin client for the server at `location`.
+    ///
+    /// Admin API calls go to [`KeycloakServerLocation::admin_url`], while the
+    /// token supplier receives [`KeycloakServerLocation::url`].
+    ///
+    /// # Arguments
+    ///
+    /// * `location` - Where the Keycloak server is reachable.
+    /// * `token_supplier` - Supplier of the access tokens used for the Admin API calls.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the Admin API calls.
+    pub fn new_with_location(
+        location: KeycloakServerLocation,
+        token_supplier: TS,
+        client: impl KeycloakTransport + 'static,
+    ) -> Self {
         Self {
-            url: url.into(),
-            client,
+            url: location.admin_url().into(),
+            location,
+            client: Arc::new(client),
             token_supplier,
+            retry_policy: KeycloakRetryPolicy::none(),
+            middlewares: Middlewares::default(),
+            limiter: KeycloakLimiter::new(),
         }
     }
 
+    /// Sets the limiter of the requests sent to Keycloak.
+    ///
+    /// By default requests are not limited.
+    pub fn with_limiter(mut self, limiter: KeycloakLimiter) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
+    /// Adds a middleware running around every request.
+    ///
+    /// Middlewares run in the order they were added, the first added is the
+    /// outermost. The middleware is passed to the token supplier as well (see
+    /// [`KeycloakTokenSupplier::add_middleware`]), so that it also runs around
+    /// token acquisition calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `middleware` - The [`KeycloakMiddleware`] to add.
+    pub fn with_middleware(mut self, middleware: impl KeycloakMiddleware + 'static) -> Self {
+        let middleware: Arc<dyn KeycloakMiddleware> = Arc::new(middleware);
+        self.token_supplier.add_middleware(middleware.clone());
+        self.middlewares.push(middleware);
+        self
+    }
+
+    /// Sets the policy for retrying requests after transient failures.
+    ///
+    /// By default requests are not retried.
+    pub fn with_retry_policy(mut self, retry_policy: KeycloakRetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Returns the location of the Keycloak server.
+    pub fn location(&self) -> &KeycloakServerLocation {
+        &self.location
+    }
+
     pub fn realm<'a>(&'a self, realm: &'a str) -> KeycloakRealmAdmin<'a, TS> {
-        KeycloakRealmAdmin { realm, admin: self }
+        KeycloakRealmAdmin {
+            realm,
+            admin: self,
+            lookup_cache: None,
+        }
     }
 }
 
 pub struct KeycloakRealmAdmin<'a, TS: KeycloakTokenSupplier> {
     pub realm: &'a str,
     pub(crate) admin: &'a KeycloakAdmin<TS>,
+    lookup_cache: Option<LookupCache>,
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'_, TS> {
+    /// Enables caching of the entities found by the lookup methods (e.g.
+    /// [`KeycloakRealmAdmin::client_by_client_id`]) for the lifetime of this handle.
+    ///
+    /// Only found entities are cached, so lookups of missing entities are
+    /// always sent to the server. Cached entities are not refreshed, use
+    /// [`KeycloakRealmAdmin::clear_lookup_cache`] after changing them.
+    pub fn with_lookup_cache(mut self) -> Self {
+        self.lookup_cache = Some(Default::default());
+        self
+    }
+
+    /// Removes all entities from the lookup cache.
+    pub fn clear_lookup_cache(&self) {
+        if let Some(cache) = &self.lookup_cache {
+            cache.lock().expect("lookup cache lock").clear();
+        }
+    }
 }
 
+type LookupCache =
+    std::sync::Mutex<HashMap<(&'static str, String), Box<dyn std::any::Any + Send + Sync>>>;
+
 pub trait KeycloakRealmAdminMethod {
     type Output;
     type Args: Default;
@@ -338,3 +784,305 @@ pub trait KeycloakRealmAdminMethod {
         From::from(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+    use http::{HeaderMap, StatusCode};
+    use serde_json::json;
+
+    use super::{
+        KeycloakAdmin, KeycloakRequest, KeycloakResponse,
+        KeycloakServiceAccountAdminTokenRetriever, KeycloakTokenSupplier, KeycloakTransport,
+    };
+    use crate::KeycloakError;
+
+    const URL: &str = "http://keycloak";
+
+    /// Keycloak issuing numbered access tokens, rejecting the first `rejected`
+    /// Admin API calls with `401 Unauthorized` and counting the requests.
+    #[derive(Clone)]
+    struct Keycloak {
+        expires_in: u64,
+        rejected: usize,
+        tokens: Arc<AtomicUsize>,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Keycloak {
+        fn new(expires_in: u64) -> Self {
+            Self {
+                expires_in,
+                rejected: 0,
+                tokens: Default::default(),
+                calls: Default::default(),
+            }
+        }
+
+        fn tokens(&self) -> usize {
+            self.tokens.load(Ordering::SeqCst)
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl KeycloakTransport for Keycloak {
+        async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+            if !request.url().ends_with("/protocol/openid-connect/token") {
+                let call = self.calls.fetch_add(1, Ordering::SeqCst);
+                let status = if call < self.rejected {
+                    StatusCode::UNAUTHORIZED
+                } else {
+                    StatusCode::NO_CONTENT
+                };
+                return Ok(KeycloakResponse::new(status, HeaderMap::new(), Vec::new()));
+            }
+            let issued = self.tokens.fetch_add(1, Ordering::SeqCst) + 1;
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            let token = json!({
+                "access_token": format!("access-{issued}"),
+                "expires_in": self.expires_in,
+                "token_type": "Bearer"
+            });
+            Ok(KeycloakResponse::new(
+                StatusCode::OK,
+                HeaderMap::new(),
+                serde_json::to_vec(&token).unwrap(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn service_account_token_cache() {
+        let keycloak = Keycloak::new(60);
+        let retriever =
+            KeycloakServiceAccountAdminTokenRetriever::create("app", "secret", keycloak.clone());
+
+        let (first, second, third) =
+            tokio::join!(retriever.get(URL), retriever.get(URL), retriever.get(URL),);
+        assert_eq!(first.unwrap(), "access-1");
+        assert_eq!(second.unwrap(), "access-1");
+        assert_eq!(third.unwrap(), "access-1");
+        assert_eq!(keycloak.tokens(), 1);
+
+        retriever.invalidate();
+        assert_eq!(retriever.get(URL).await.unwrap(), "access-2");
+        assert_eq!(retriever.get(URL).await.unwrap(), "access-2");
+        assert_eq!(keycloak.tokens(), 2);
+    }
+
+    #[tokio::test]
+    async fn service_account_expiry_skew() {
+        // the default skew is capped to half of the 1 second lifetime
+        let keycloak = Keycloak::new(1);
+        let retriever =
+            KeycloakServiceAccountAdminTokenRetriever::create("app", "secret", keycloak.clone());
+        let unskewed = Keycloak::new(1);
+        let unskewed_retriever =
+            KeycloakServiceAccountAdminTokenRetriever::create("app", "secret", unskewed.clone())
+                .with_expiry_skew(Duration::ZERO);
+
+        retriever.get(URL).await.unwrap();
+        unskewed_retriever.get(URL).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(600)).await;
+        assert_eq!(retriever.get(URL).await.unwrap(), "access-2");
+        assert_eq!(unskewed_retriever.get(URL).await.unwrap(), "access-1");
+        assert_eq!(keycloak.tokens(), 2);
+        assert_eq!(unskewed.tokens(), 1);
+    }
+
+    #[tokio::test]
+    async fn rejected_token_replayed_once() {
+        let keycloak = Keycloak {
+            rejected: 1,
+            ..Keycloak::new(60)
+        };
+        let retriever =
+            KeycloakServiceAccountAdminTokenRetriever::create("app", "secret", keycloak.clone());
+        let admin = KeycloakAdmin::new(URL, retriever, keycloak.clone());
+        admin
+            .request(http::Method::DELETE, "/admin/realms/test")
+            .send_default()
+            .await
+            .unwrap();
+        assert_eq!(keycloak.calls(), 2);
+        assert_eq!(keycloak.tokens(), 2);
+
+        let keycloak = Keycloak {
+            rejected: usize::MAX,
+            ..Keycloak::new(60)
+        };
+        let retriever =
+            KeycloakServiceAccountAdminTokenRetriever::create("app", "secret", keycloak.clone());
+        let admin = KeycloakAdmin::new(URL, retriever, keycloak.clone());
+        let error = admin
+            .request(http::Method::DELETE, "/admin/realms/test")
+            .send_default()
+            .await
+            .err()
+            .unwrap();
+        assert!(error.is_unauthorized());
+        assert_eq!(keycloak.calls(), 2);
+        assert_eq!(keycloak.tokens(), 2);
+    }
+
+    /// Recorded spans with their fields, and the stack of entered spans.
+    #[cfg(feature = "tracing")]
+    type SpanLog = (
+        Vec<(&'static tracing::Metadata<'static>, Vec<(String, String)>)>,
+        Vec<tracing::span::Id>,
+    );
+
+    /// Subscriber recording the fields of every span.
+    #[cfg(feature = "tracing")]
+    #[derive(Clone, Default)]
+    struct Spans(Arc<std::sync::Mutex<SpanLog>>);
+
+    #[cfg(feature = "tracing")]
+    impl Spans {
+        fn fields(&self, name: &str) -> Vec<(String, String)> {
+            let log = self.0.lock().unwrap();
+            let (_, fields) = log.0.iter().find(|(span, _)| span.name() == name).unwrap();
+            fields.clone()
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    impl tracing::Subscriber for Spans {
+        fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let mut log = self.0.lock().unwrap();
+            let mut fields = Vec::new();
+            span.record(&mut FieldValues(&mut fields));
+            log.0.push((span.metadata(), fields));
+            tracing::span::Id::from_u64(log.0.len() as u64)
+        }
+
+        fn record(&self, span: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+            let mut log = self.0.lock().unwrap();
+            let (_, fields) = &mut log.0[span.into_u64() as usize - 1];
+            values.record(&mut FieldValues(fields));
+        }
+
+        fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+        fn event(&self, _event: &tracing::Event<'_>) {}
+
+        fn enter(&self, span: &tracing::span::Id) {
+            self.0.lock().unwrap().1.push(span.clone());
+        }
+
+        fn exit(&self, _span: &tracing::span::Id) {
+            self.0.lock().unwrap().1.pop();
+        }
+
+        fn current_span(&self) -> tracing_core::span::Current {
+            let log = self.0.lock().unwrap();
+            match log.1.last() {
+                Some(span) => {
+                    let (metadata, _) = log.0[span.into_u64() as usize - 1];
+                    tracing_core::span::Current::new(span.clone(), metadata)
+                }
+                None => tracing_core::span::Current::none(),
+            }
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    struct FieldValues<'a>(&'a mut Vec<(String, String)>);
+
+    #[cfg(feature = "tracing")]
+    impl tracing::field::Visit for FieldValues<'_> {
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            self.0.push((field.name().into(), value.into()));
+        }
+
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0.push((field.name().into(), format!("{value:?}")));
+        }
+    }
+
+    #[cfg(all(feature = "tracing", feature = "tag-users"))]
+    #[tokio::test]
+    async fn tracing_spans() {
+        let spans = Spans::default();
+        let _subscriber = tracing::subscriber::set_default(spans.clone());
+        let keycloak = Keycloak {
+            rejected: 1,
+            ..Keycloak::new(60)
+        };
+
+        let token = super::KeycloakAdminToken::acquire(URL, "admin", "p4ssw0rd", &keycloak)
+            .await
+            .unwrap();
+        let admin = KeycloakAdmin::new(URL, token, keycloak);
+        let error = admin
+            .realm_users_with_user_id_delete("test", "u1")
+            .await
+            .err()
+            .unwrap();
+        assert!(error.is_unauthorized());
+
+        let field = |fields: &[(String, String)], name: &str| {
+            fields
+                .iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, value)| value.clone())
+        };
+        let token_fields = spans.fields("acquire_token");
+        assert_eq!(
+            field(&token_fields, "grant_type").as_deref(),
+            Some("password")
+        );
+        assert_eq!(
+            field(&token_fields, "client_id").as_deref(),
+            Some("admin-cli")
+        );
+        assert_eq!(
+            field(&token_fields, "http.response.status_code").as_deref(),
+            Some("200")
+        );
+        assert!(field(&token_fields, "latency_ms").is_some());
+
+        let call_fields = spans.fields("realm_users_with_user_id_delete");
+        assert_eq!(
+            field(&call_fields, "http.route").as_deref(),
+            Some("/admin/realms/{realm}/users/{user-id}")
+        );
+        assert_eq!(field(&call_fields, "realm").as_deref(), Some("test"));
+        assert_eq!(
+            field(&call_fields, "http.response.status_code").as_deref(),
+            Some("401")
+        );
+        assert_eq!(
+            field(&call_fields, "error").as_deref(),
+            Some("Unauthorized")
+        );
+        assert!(field(&call_fields, "latency_ms").is_some());
+
+        // neither credentials nor tokens are recorded
+        let log = spans.0.lock().unwrap();
+        for (_, fields) in &log.0 {
+            for (name, value) in fields {
+                assert!(
+                    !value.contains("p4ssw0rd") && !value.contains("access-1"),
+                    "{name} = {value}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+
+use super::{
+    error_check, KeycloakMiddleware, KeycloakRequest, KeycloakTransport, Middlewares, TokenCache,
+    DEFAULT_EXPIRY_SKEW,
+};
+use crate::{
+    KeycloakAdminToken, KeycloakClientAuth, KeycloakError, KeycloakServerLocation,
+    KeycloakTokenSupplier,
+};
+
+/// Token supplier which logs in with username and password and keeps the
+/// admin token up to date.
+///
+/// Unlike a plain [`KeycloakAdminToken`], which returns the same access token
+/// forever, this supplier remembers when the token expires and renews it
+/// shortly before that happens:
+///
+/// 1. With the `refresh_token` grant, while the refresh token is still valid.
+/// 2. With a new `password` grant otherwise, or if the refresh was rejected.
+///
+/// Clones share the same cached token. Concurrent callers that find an expired
+/// token wait for a single renewal instead of each requesting a new token.
+#[derive(Clone)]
+pub struct KeycloakPasswordAdminTokenRetriever {
+    username: String,
+    password: String,
+    realm: String,
+    client_id: String,
+    client: Arc<dyn KeycloakTransport>,
+    middlewares: Middlewares,
+    location: Option<KeycloakServerLocation>,
+    cache: Arc<TokenCache>,
+}
+
+#[async_trait]
+impl KeycloakTokenSupplier for KeycloakPasswordAdminTokenRetriever {
+    async fn get(&self, url: &str) -> Result<String, KeycloakError> {
+        let admin_token = self.token(url).await?;
+        Ok(admin_token.access_token)
+    }
+
+    fn token_rejected(&self, _url: &str, token: &str) -> bool {
+        self.cache.invalidate_token(token);
+        true
+    }
+
+    fn add_middleware(&mut self, middleware: Arc<dyn KeycloakMiddleware>) {
+        self.middlewares.push(middleware);
+    }
+}
+
+impl KeycloakPasswordAdminTokenRetriever {
+    /// Creates a token retriever for a user of the `master` realm, logging in
+    /// through the `admin-cli` client.
+    ///
+    /// The token is acquired lazily on first use, the same way as
+    /// [`KeycloakAdminToken::acquire`] does.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Name of the admin user.
+    /// * `password` - Password of the admin user.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the token retrieval calls.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn doc() -> Result<(), keycloak::KeycloakError> {
+    /// use keycloak::{prelude::reqwest, KeycloakAdmin, KeycloakPasswordAdminTokenRetriever};
+    ///
+    /// let client = reqwest::Client::new();
+    /// let url = "https://keycloak.example.com";
+    ///
+    /// let retriever = KeycloakPasswordAdminTokenRetriever::create("admin", "password", client.clone());
+    ///
+    /// let admin = KeycloakAdmin::new(url, retriever, client);
+    /// // ... use `admin` to call the Admin REST API.
+    /// # let _ = admin;
+    /// # Ok(()) }
+    /// ```
+    pub fn create(
+        username: &str,
+        password: &str,
+        client: impl KeycloakTransport + 'static,
+    ) -> Self {
+        Self::create_with_custom_realm(username, password, "master", "admin-cli", client)
+    }
+
+    /// Creates a token retriever for a user of a caller-specified realm and client.
+    ///
+    /// This is the same as [`KeycloakPasswordAdminTokenRetriever::create`], but
+    /// the realm and the client are supplied explicitly, the same way as
+    /// [`KeycloakAdminToken::acquire_custom_realm`] does.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Name of the admin user.
+    /// * `password` - Password of the admin user.
+    /// * `realm` - The Keycloak realm the user lives in.
+    /// * `client_id` - The public client used to log in (must allow `Direct Access Grants`).
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the token retrieval calls.
+    pub fn create_with_custom_realm(
+        username: &str,
+        password: &str,
+        realm: &str,
+        client_id: &str,
+        client: impl KeycloakTransport + 'static,
+    ) -> Self {
+        Self {
+            username: username.into(),
+            password: password.into(),
+            realm: realm.into(),
+            client_id: client_id.into(),
+            client: Arc::new(client),
+            middlewares: Middlewares::default(),
+            location: None,
+            cache: Arc::new(TokenCache::new(DEFAULT_EXPIRY_SKEW)),
+        }
+    }
+
+    /// Sets the server location to take the token endpoint from, instead of
+    /// deriving it from the URL passed by [`crate::KeycloakAdmin`].
+    pub fn with_location(mut self, location: KeycloakServerLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Sets how long before expiry the access token gets renewed (10 seconds by default).
+    ///
+    /// The skew is capped to half of the token lifetime.
+    pub fn with_expiry_skew(mut self, skew: Duration) -> Self {
+        self.cache = Arc::new(TokenCache::new(skew));
+        self
+    }
+
+    /// Returns a valid [`KeycloakAdminToken`], renewing it if it is about to expire.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    pub async fn token(&self, url: &str) -> Result<KeycloakAdminToken, KeycloakError> {
+        let token_endpoint = KeycloakServerLocation::resolve(self.location.as_ref(), url)
+            .token_endpoint(&self.realm);
+        let token_endpoint = token_endpoint.as_str();
+        self.cache
+            .get_or_renew(|previous| async move {
+                let refresh_token = previous
+                    .as_ref()
+                    .and_then(|cached| cached.usable_refresh_token(Instant::now()));
+                if let Some(refresh_token) = refresh_token {
+                    match self.refresh(token_endpoint, refresh_token).await {
+                        Err(KeycloakError::HttpFailure { .. }) => {}
+                        result => return result,
+                    }
+                }
+                KeycloakAdminToken::acquire_with_token_endpoint(
+                    token_endpoint,
+                    &self.username,
+                    &self.password,
+                    &self.client_id,
+                    "password",
+                    &self.middlewares.chain(self.client.as_ref()),
+                )
+                .await
+            })
+            .await
+    }
+
+    /// Drops the cached token, so that the next call logs in again.
+    pub fn invalidate(&self) {
+        self.cache.invalidate();
+    }
+
+    /// Drops the cached token and ends its Keycloak session.
+    ///
+    /// Does nothing if no token is cached. Call it on shutdown so that the
+    /// admin session does not linger until it expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    pub async fn logout(&self, url: &str) -> Result<(), KeycloakError> {
+        let Some(token) = self.cache.take() else {
+            return Ok(());
+        };
+        token
+            .logout_at(
+                &KeycloakServerLocation::resolve(self.location.as_ref(), url),
+                &self.realm,
+                &self.client_id,
+                &KeycloakClientAuth::Public,
+                &self.middlewares.chain(self.client.as_ref()),
+            )
+            .await
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "acquire_token",
+        skip_all,
+        fields(
+            grant_type = "refresh_token",
+            client_id = %self.client_id,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
+    async fn refresh(
+        &self,
+        token_endpoint: &str,
+        refresh_token: &str,
+    ) -> Result<KeycloakAdminToken, KeycloakError> {
+        let request = KeycloakRequest::post(token_endpoint).form(&[
+            ("refresh_token", refresh_token),
+            ("client_id", self.client_id.as_str()),
+            ("grant_type", "refresh_token"),
+        ])?;
+        let response = self
+            .middlewares
+            .chain(self.client.as_ref())
+            .send(request)
+            .await?;
+        error_check(response)?.json()
+    }
+}
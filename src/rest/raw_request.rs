@@ -0,0 +1,205 @@
+use http::Method;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{
+    url_enc::encode_url_param as p, DefaultResponse, EndpointResponse, KeycloakAdmin,
+    KeycloakRealmAdmin, KeycloakRequest, KeycloakTokenSupplier,
+};
+use crate::KeycloakError;
+
+/// Request to an Admin API endpoint without generated method, e.g. endpoints
+/// of extensions or endpoints missing from the OpenAPI description.
+///
+/// Created by [`KeycloakAdmin::request`] or [`KeycloakRealmAdmin::request`].
+/// The request is authorized, retried, limited and passed through middlewares
+/// like the requests of generated methods, and fails the same way on error
+/// responses.
+///
+/// Errors of the builder methods (e.g. a body which can not be serialized)
+/// are returned by [`KeycloakAdminRequest::send`] and
+/// [`KeycloakAdminRequest::send_default`].
+pub struct KeycloakAdminRequest<'a, TS: KeycloakTokenSupplier> {
+    admin: &'a KeycloakAdmin<TS>,
+    path: String,
+    path_params: Vec<(String, String)>,
+    request: Result<KeycloakRequest, KeycloakError>,
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdmin<TS> {
+    /// Creates a request to the Admin API endpoint at `path`.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP method of the request.
+    /// * `path` - Path below the Admin API base URL, starting with `/admin`.
+    ///   Segments like `{id}` are placeholders for parameters set with
+    ///   [`KeycloakAdminRequest::path_param`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn doc(admin: keycloak::KeycloakAdmin) -> Result<(), keycloak::KeycloakError> {
+    /// use keycloak::prelude::http::Method;
+    ///
+    /// let server_info: serde_json::Value = admin
+    ///     .request(Method::GET, "/admin/serverinfo")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// admin
+    ///     .realm("test")
+    ///     .request(Method::POST, "/admin/realms/{realm}/user-storage/{id}/sync")
+    ///     .path_param("id", "ldap-provider-id")
+    ///     .query(&[("action", "triggerFullSync")])
+    ///     .send_default()
+    ///     .await?;
+    /// # let _ = server_info;
+    /// # Ok(()) }
+    /// ```
+    pub fn request(&self, method: Method, path: &str) -> KeycloakAdminRequest<'_, TS> {
+        KeycloakAdminRequest {
+            admin: self,
+            path: path.into(),
+            path_params: Vec::new(),
+            request: Ok(KeycloakRequest::new(method, "")),
+        }
+    }
+}
+
+impl<'a, TS: KeycloakTokenSupplier> KeycloakRealmAdmin<'a, TS> {
+    /// Creates a request to the Admin API endpoint at `path`, with the
+    /// `{realm}` placeholder set to the realm.
+    ///
+    /// See [`KeycloakAdmin::request`].
+    pub fn request(&self, method: Method, path: &str) -> KeycloakAdminRequest<'a, TS> {
+        self.admin
+            .request(method, path)
+            .path_param("realm", self.realm)
+    }
+}
+
+impl<TS: KeycloakTokenSupplier> KeycloakAdminRequest<'_, TS> {
+    /// Sets the value of placeholder `{name}` in the path, the value is percent-encoded.
+    pub fn path_param(mut self, name: &str, value: &str) -> Self {
+        self.path_params
+            .push((format!("{{{name}}}"), p(value).to_string()));
+        self
+    }
+
+    /// Appends query parameters, e.g. `&[("first", 0)]`.
+    pub fn query<Q: Serialize + ?Sized>(mut self, query: &Q) -> Self {
+        self.request = self.request.and_then(|request| request.query(query));
+        self
+    }
+
+    /// Sets JSON body.
+    pub fn json<B: Serialize + ?Sized>(mut self, body: &B) -> Self {
+        self.request = self.request.and_then(|request| request.json(body));
+        self
+    }
+
+    /// Sends the request and deserializes the JSON response.
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = self.request.as_ref().map_or("", |request| request.method().as_str()),
+            http.route = %self.path,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
+    pub async fn send<T: DeserializeOwned>(self) -> Result<T, KeycloakError> {
+        self.call().await?.json()
+    }
+
+    /// Sends the request and returns the response for further processing.
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        skip_all,
+        fields(
+            http.request.method = self.request.as_ref().map_or("", |request| request.method().as_str()),
+            http.route = %self.path,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
+    pub async fn send_default(self) -> Result<DefaultResponse, KeycloakError> {
+        self.call().await?.check().map(From::from)
+    }
+
+    async fn call(self) -> Result<EndpointResponse, KeycloakError> {
+        let admin = self.admin;
+        let endpoint = self.path.clone();
+        admin.call(endpoint, self.into_request()?).await
+    }
+
+    fn into_request(self) -> Result<KeycloakRequest, KeycloakError> {
+        let path = self
+            .path_params
+            .iter()
+            .fold(self.path, |path, (placeholder, value)| {
+                path.replace(placeholder, value)
+            });
+        if let Some((_, placeholder)) = path.split_once('{') {
+            let name = placeholder.split('}').next().unwrap_or(placeholder);
+            return Err(KeycloakError::InvalidRequest(format!(
+                "path parameter {{{name}}} is not set"
+            )));
+        }
+        Ok(self.request?.with_url(format!("{}{path}", self.admin.url)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use http::{HeaderMap, Method, StatusCode};
+
+    use crate::{
+        test_util::StaticToken, KeycloakAdmin, KeycloakError, KeycloakRequest, KeycloakResponse,
+        KeycloakTransport,
+    };
+
+    struct LdapSync;
+
+    #[async_trait]
+    impl KeycloakTransport for LdapSync {
+        async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+            assert_eq!(request.method(), Method::POST);
+            assert_eq!(
+                request.url_with_query(),
+                "http://keycloak/admin/realms/my%20realm/user-storage/a%2Fb/sync?action=triggerFullSync"
+            );
+            Ok(KeycloakResponse::new(
+                StatusCode::OK,
+                HeaderMap::new(),
+                br#"{"added":1}"#.to_vec(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn request() {
+        let admin = KeycloakAdmin::new("http://keycloak", StaticToken, LdapSync);
+        let result: serde_json::Value = admin
+            .realm("my realm")
+            .request(Method::POST, "/admin/realms/{realm}/user-storage/{id}/sync")
+            .path_param("id", "a/b")
+            .query(&[("action", "triggerFullSync")])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(result["added"], 1);
+
+        let error = admin
+            .request(Method::GET, "/admin/realms/{realm}/users")
+            .send_default()
+            .await
+            .err()
+            .unwrap();
+        assert!(
+            matches!(error, KeycloakError::InvalidRequest(message) if message == "path parameter {realm} is not set")
+        );
+    }
+}
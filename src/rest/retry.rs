@@ -0,0 +1,272 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use http::{header::RETRY_AFTER, Method, StatusCode};
+
+use super::KeycloakResponse;
+use crate::KeycloakError;
+
+/// Policy for retrying Admin API requests after transient failures.
+///
+/// Requests are retried after transport failures (e.g. connection resets)
+/// and after `429 Too Many Requests`, `502 Bad Gateway`, `503 Service Unavailable`
+/// and `504 Gateway Timeout` responses.
+///
+/// Only idempotent requests (`GET`, `HEAD`, `OPTIONS`, `PUT`, `DELETE`) are
+/// retried, unless retrying `POST` requests is enabled with
+/// [`KeycloakRetryPolicy::with_retry_post`].
+///
+/// The delay between attempts grows exponentially from the initial backoff
+/// up to the maximal backoff, with [full jitter] if enabled. `Retry-After`
+/// headers of `429` and `503` responses replace the computed delay; if the
+/// server asks to wait longer than the maximal backoff, the response is
+/// returned to the caller instead.
+///
+/// Delays are awaited with [`tokio::time::sleep`], so a policy with retries
+/// requires a tokio runtime with the timer enabled, even with a
+/// [`KeycloakTransport`](crate::KeycloakTransport) not based on tokio. The
+/// default [`KeycloakRetryPolicy::none`] never sleeps.
+///
+/// [full jitter]: https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeycloakRetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    retry_post: bool,
+}
+
+impl Default for KeycloakRetryPolicy {
+    /// Up to 3 attempts, backoff from 200ms up to 10s with jitter, `POST` is not retried.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+            retry_post: false,
+        }
+    }
+}
+
+impl KeycloakRetryPolicy {
+    /// Creates the [default](KeycloakRetryPolicy::default) retry policy.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use keycloak::{prelude::reqwest, KeycloakAdmin, KeycloakAdminToken, KeycloakRetryPolicy};
+    ///
+    /// # fn doc(admin_token: KeycloakAdminToken) {
+    /// let admin = KeycloakAdmin::new("https://keycloak.example.com", admin_token, reqwest::Client::new())
+    ///     .with_retry_policy(
+    ///         KeycloakRetryPolicy::new()
+    ///             .with_max_attempts(5)
+    ///             .with_max_backoff(Duration::from_secs(30)),
+    ///     );
+    /// # let _ = admin;
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a policy which never retries, the behavior of [`KeycloakAdmin`](crate::KeycloakAdmin) by default.
+    pub fn none() -> Self {
+        Self::default().with_max_attempts(1)
+    }
+
+    /// Sets the maximal number of attempts including the first one, at least 1.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the upper bound of the delay between attempts.
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Enables or disables randomization of the delay between attempts.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Enables or disables retrying `POST` requests.
+    ///
+    /// `POST` requests create resources in the Admin API, so a retried request
+    /// may fail with `409 Conflict` if the first attempt reached Keycloak.
+    pub fn with_retry_post(mut self, retry_post: bool) -> Self {
+        self.retry_post = retry_post;
+        self
+    }
+
+    /// Returns the maximal number of attempts including the first one.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns `true` if requests with `method` may be retried.
+    pub fn is_retryable_method(&self, method: &Method) -> bool {
+        match *method {
+            Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE => true,
+            Method::POST => self.retry_post,
+            _ => false,
+        }
+    }
+
+    /// Returns the delay before the next attempt, if `result` of attempt
+    /// number `attempt` (starting at 1) should be retried.
+    pub(crate) fn retry_delay(
+        &self,
+        method: &Method,
+        attempt: u32,
+        result: &Result<KeycloakResponse, KeycloakError>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.is_retryable_method(method) {
+            return None;
+        }
+        let retry_after = match result {
+            Ok(response) => match response.status() {
+                StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE => {
+                    retry_after(response)
+                }
+                StatusCode::BAD_GATEWAY | StatusCode::GATEWAY_TIMEOUT => None,
+                _ => return None,
+            },
+            #[cfg(any(feature = "reqwest12", feature = "reqwest13"))]
+            Err(KeycloakError::ReqwestFailure(e)) if !e.is_builder() => None,
+            Err(KeycloakError::TransportFailure(_)) => None,
+            Err(_) => return None,
+        };
+        match retry_after {
+            Some(delay) if delay > self.max_backoff => None,
+            Some(delay) => Some(delay),
+            None => Some(self.backoff(attempt)),
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt - 1))
+            .min(self.max_backoff);
+        if !self.jitter {
+            return backoff;
+        }
+        let random = RandomState::new().build_hasher().finish();
+        backoff.mul_f64(random as f64 / u64::MAX as f64)
+    }
+}
+
+/// Parses `Retry-After` header given in seconds or as HTTP date.
+fn retry_after(response: &KeycloakResponse) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = UNIX_EPOCH + Duration::from_secs(parse_http_date(value)?);
+    Some(at.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+/// Parses an IMF-fixdate (e.g. `Sun, 06 Nov 1994 08:49:37 GMT`) into seconds since the epoch.
+fn parse_http_date(value: &str) -> Option<u64> {
+    let (_, date) = value.split_once(", ")?;
+    let mut parts = date.split(' ');
+    let day: u64 = parts.next()?.parse().ok()?;
+    let month = parts.next()?;
+    let year: i64 = parts.next()?.parse().ok()?;
+    let mut time = parts.next()?.split(':');
+    if parts.next()? != "GMT" {
+        return None;
+    }
+    let month = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ]
+    .iter()
+    .position(|m| *m == month)? as i64
+        + 1;
+    // days from civil date, http://howardhinnant.github.io/date_algorithms.html
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = u64::try_from(era * 146097 + doe - 719468).ok()?;
+    let mut seconds = days * 86400;
+    for unit in [3600, 60, 1] {
+        seconds += time.next()?.parse::<u64>().ok()? * unit;
+    }
+    Some(seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use http::{header::RETRY_AFTER, HeaderMap, HeaderValue, Method, StatusCode};
+
+    use super::{parse_http_date, KeycloakRetryPolicy};
+    use crate::KeycloakResponse;
+
+    #[test]
+    fn retry_delay() {
+        let policy = KeycloakRetryPolicy::new().with_jitter(false);
+        let response = |status, retry_after: Option<&'static str>| {
+            let mut headers = HeaderMap::new();
+            if let Some(retry_after) = retry_after {
+                headers.insert(RETRY_AFTER, HeaderValue::from_static(retry_after));
+            }
+            Ok(KeycloakResponse::new(status, headers, Vec::new()))
+        };
+
+        let unavailable = response(StatusCode::SERVICE_UNAVAILABLE, None);
+        assert_eq!(
+            policy.retry_delay(&Method::GET, 1, &unavailable),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(
+            policy.retry_delay(&Method::DELETE, 2, &unavailable),
+            Some(Duration::from_millis(400))
+        );
+        assert_eq!(policy.retry_delay(&Method::GET, 3, &unavailable), None);
+        assert_eq!(policy.retry_delay(&Method::POST, 1, &unavailable), None);
+        assert_eq!(
+            policy
+                .clone()
+                .with_retry_post(true)
+                .retry_delay(&Method::POST, 1, &unavailable),
+            Some(Duration::from_millis(200))
+        );
+
+        let too_many = response(StatusCode::TOO_MANY_REQUESTS, Some("2"));
+        assert_eq!(
+            policy.retry_delay(&Method::PUT, 1, &too_many),
+            Some(Duration::from_secs(2))
+        );
+        let too_many = response(StatusCode::TOO_MANY_REQUESTS, Some("60"));
+        assert_eq!(policy.retry_delay(&Method::PUT, 1, &too_many), None);
+
+        let not_found = response(StatusCode::NOT_FOUND, None);
+        assert_eq!(policy.retry_delay(&Method::GET, 1, &not_found), None);
+
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(784111777)
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use super::{error_check, KeycloakRequest, KeycloakTransport};
+use crate::{KeycloakAdminToken, KeycloakClientAuth, KeycloakError, KeycloakServerLocation};
+
+impl KeycloakAdminToken {
+    /// Ends the Keycloak session of a token acquired with [`KeycloakAdminToken::acquire`].
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the logout call.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn doc() -> Result<(), keycloak::KeycloakError> {
+    /// use keycloak::{prelude::reqwest, KeycloakAdmin, KeycloakAdminToken};
+    ///
+    /// let client = reqwest::Client::new();
+    /// let url = "https://keycloak.example.com";
+    ///
+    /// let admin_token = KeycloakAdminToken::acquire(url, "admin", "password", &client).await?;
+    /// let admin = KeycloakAdmin::new(url, admin_token.clone(), client.clone());
+    /// // ... use `admin` to call the Admin REST API.
+    /// # let _ = admin;
+    ///
+    /// admin_token.logout(url, &client).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn logout(
+        &self,
+        url: &str,
+        client: &dyn KeycloakTransport,
+    ) -> Result<(), KeycloakError> {
+        self.logout_custom_realm(url, "master", "admin-cli", client)
+            .await
+    }
+
+    /// Ends the Keycloak session of a token acquired with [`KeycloakAdminToken::acquire_custom_realm`].
+    ///
+    /// The refresh token is sent to the end session endpoint of the realm and
+    /// then revoked. Tokens without refresh token have no session to end, the
+    /// access token is revoked instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    /// * `realm` - The Keycloak realm the token was issued by.
+    /// * `client_id` - The public client the token was issued to.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the logout call.
+    pub async fn logout_custom_realm(
+        &self,
+        url: &str,
+        realm: &str,
+        client_id: &str,
+        client: &dyn KeycloakTransport,
+    ) -> Result<(), KeycloakError> {
+        self.logout_at(
+            &KeycloakServerLocation::new(url),
+            realm,
+            client_id,
+            &KeycloakClientAuth::Public,
+            client,
+        )
+        .await
+    }
+
+    /// Revokes a token acquired with [`KeycloakAdminToken::acquire`].
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the revocation call.
+    pub async fn revoke(
+        &self,
+        url: &str,
+        client: &dyn KeycloakTransport,
+    ) -> Result<(), KeycloakError> {
+        self.revoke_custom_realm(url, "master", "admin-cli", client)
+            .await
+    }
+
+    /// Revokes a token acquired with [`KeycloakAdminToken::acquire_custom_realm`].
+    ///
+    /// The refresh token, or the access token if there is no refresh token,
+    /// is sent to the [token revocation] endpoint of the realm.
+    ///
+    /// [token revocation]: https://www.rfc-editor.org/rfc/rfc7009
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    /// * `realm` - The Keycloak realm the token was issued by.
+    /// * `client_id` - The public client the token was issued to.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the revocation call.
+    pub async fn revoke_custom_realm(
+        &self,
+        url: &str,
+        realm: &str,
+        client_id: &str,
+        client: &dyn KeycloakTransport,
+    ) -> Result<(), KeycloakError> {
+        self.revoke_at(
+            &KeycloakServerLocation::new(url),
+            realm,
+            client_id,
+            &KeycloakClientAuth::Public,
+            client,
+        )
+        .await
+    }
+
+    /// Ends the session of the token if it has a refresh token, and revokes it.
+    ///
+    /// The token is revoked even if ending the session failed.
+    pub(crate) async fn logout_at(
+        &self,
+        location: &KeycloakServerLocation,
+        realm: &str,
+        client_id: &str,
+        client_auth: &KeycloakClientAuth,
+        client: &dyn KeycloakTransport,
+    ) -> Result<(), KeycloakError> {
+        let ended = match self.refresh_token() {
+            Some(refresh_token) => {
+                self.end_session_at(
+                    location,
+                    realm,
+                    client_id,
+                    client_auth,
+                    client,
+                    refresh_token,
+                )
+                .await
+            }
+            None => Ok(()),
+        };
+        let revoked = self
+            .revoke_at(location, realm, client_id, client_auth, client)
+            .await;
+        ended.and(revoked)
+    }
+
+    async fn end_session_at(
+        &self,
+        location: &KeycloakServerLocation,
+        realm: &str,
+        client_id: &str,
+        client_auth: &KeycloakClientAuth,
+        client: &dyn KeycloakTransport,
+        refresh_token: &str,
+    ) -> Result<(), KeycloakError> {
+        let request = client_auth.authenticate(
+            KeycloakRequest::post(location.end_session_endpoint(realm)),
+            &location.token_endpoint(realm),
+            client_id,
+            &[("refresh_token", refresh_token)],
+        )?;
+        error_check(client.send(request).await?)?;
+        Ok(())
+    }
+
+    /// Revokes the refresh token, or the access token if it has no refresh token.
+    pub(crate) async fn revoke_at(
+        &self,
+        location: &KeycloakServerLocation,
+        realm: &str,
+        client_id: &str,
+        client_auth: &KeycloakClientAuth,
+        client: &dyn KeycloakTransport,
+    ) -> Result<(), KeycloakError> {
+        let (token, token_type_hint) = match self.refresh_token() {
+            Some(refresh_token) => (refresh_token, "refresh_token"),
+            None => (self.access_token(), "access_token"),
+        };
+        let request = client_auth.authenticate(
+            KeycloakRequest::post(location.revocation_endpoint(realm)),
+            &location.token_endpoint(realm),
+            client_id,
+            &[("token", token), ("token_type_hint", token_type_hint)],
+        )?;
+        error_check(client.send(request).await?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use http::{HeaderMap, StatusCode};
+    use serde_json::json;
+
+    use crate::{
+        KeycloakAdminToken, KeycloakError, KeycloakRequest, KeycloakRequestBody, KeycloakResponse,
+        KeycloakTransport,
+    };
+
+    const URL: &str = "http://keycloak";
+
+    /// Keycloak logging the form posted to each endpoint, failing the end
+    /// session endpoint if `failing_logout` is set.
+    #[derive(Default)]
+    struct Keycloak {
+        failing_logout: bool,
+        requests: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl KeycloakTransport for Keycloak {
+        async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+            let KeycloakRequestBody::Form(form) = request.body() else {
+                panic!("no form posted");
+            };
+            let endpoint = request.url().rsplit('/').next().unwrap_or_default();
+            self.requests
+                .lock()
+                .unwrap()
+                .push(format!("{endpoint} {form}"));
+            let status = if self.failing_logout && endpoint == "logout" {
+                StatusCode::BAD_REQUEST
+            } else {
+                StatusCode::OK
+            };
+            Ok(KeycloakResponse::new(status, HeaderMap::new(), Vec::new()))
+        }
+    }
+
+    fn token(refresh_token: Option<&str>) -> KeycloakAdminToken {
+        serde_json::from_value(json!({
+            "access_token": "access",
+            "expires_in": 60,
+            "refresh_token": refresh_token,
+            "token_type": "Bearer"
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn revoke() {
+        let keycloak = Keycloak::default();
+        token(Some("refresh")).revoke(URL, &keycloak).await.unwrap();
+        token(None).revoke(URL, &keycloak).await.unwrap();
+        assert_eq!(
+            *keycloak.requests.lock().unwrap(),
+            [
+                "revoke client_id=admin-cli&token=refresh&token_type_hint=refresh_token",
+                "revoke client_id=admin-cli&token=access&token_type_hint=access_token",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn logout() {
+        let keycloak = Keycloak::default();
+        token(Some("refresh")).logout(URL, &keycloak).await.unwrap();
+        token(None).logout(URL, &keycloak).await.unwrap();
+        assert_eq!(
+            *keycloak.requests.lock().unwrap(),
+            [
+                "logout client_id=admin-cli&refresh_token=refresh",
+                "revoke client_id=admin-cli&token=refresh&token_type_hint=refresh_token",
+                "revoke client_id=admin-cli&token=access&token_type_hint=access_token",
+            ]
+        );
+
+        let keycloak = Keycloak {
+            failing_logout: true,
+            ..Default::default()
+        };
+        let error = token(Some("refresh"))
+            .logout(URL, &keycloak)
+            .await
+            .unwrap_err();
+        assert_eq!(error.status(), Some(400));
+        assert_eq!(keycloak.requests.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,252 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use super::{error_check, KeycloakRequest, KeycloakTransport};
+use crate::KeycloakError;
+
+/// Where a Keycloak server is reachable.
+///
+/// Keycloak may be served under a context path (e.g. `/auth` on legacy
+/// deployments or behind a reverse proxy), and the Admin API may be exposed
+/// on another host than the frontend used for logins (see `hostname-admin`
+/// in Keycloak [hostname configuration]).
+///
+/// OIDC endpoints default to the paths Keycloak uses below the frontend URL.
+/// [`KeycloakServerLocation::discover`] replaces them for a realm with the
+/// endpoints announced by its OpenID Connect discovery document.
+///
+/// [hostname configuration]: https://www.keycloak.org/server/hostname
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeycloakServerLocation {
+    url: String,
+    admin_url: Option<String>,
+    discovered: HashMap<String, KeycloakOpenIdConfiguration>,
+}
+
+/// OpenID Connect discovery document of a realm
+/// (`/realms/{realm}/.well-known/openid-configuration`).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct KeycloakOpenIdConfiguration {
+    pub issuer: String,
+    pub authorization_endpoint: Option<String>,
+    pub token_endpoint: String,
+    pub introspection_endpoint: Option<String>,
+    pub userinfo_endpoint: Option<String>,
+    pub end_session_endpoint: Option<String>,
+    pub revocation_endpoint: Option<String>,
+    pub jwks_uri: Option<String>,
+    pub device_authorization_endpoint: Option<String>,
+}
+
+impl KeycloakServerLocation {
+    /// Creates a location from the frontend URL of the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server including its context path
+    ///   (e.g. `https://keycloak.example.com` or `https://example.com/auth`).
+    ///   Trailing slashes are removed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn doc() -> Result<(), keycloak::KeycloakError> {
+    /// use keycloak::{
+    ///     prelude::reqwest, KeycloakAdmin, KeycloakServerLocation,
+    ///     KeycloakServiceAccountAdminTokenRetriever,
+    /// };
+    ///
+    /// let client = reqwest::Client::new();
+    ///
+    /// let location = KeycloakServerLocation::new("https://sso.example.com/auth/")
+    ///     .with_admin_url("https://keycloak-admin.internal/auth")
+    ///     .discover("master", &client)
+    ///     .await?;
+    ///
+    /// let retriever =
+    ///     KeycloakServiceAccountAdminTokenRetriever::create("my-client", "my-secret", client.clone())
+    ///         .with_location(location.clone());
+    ///
+    /// let admin = KeycloakAdmin::new_with_location(location, retriever, client);
+    /// // ... use `admin` to call the Admin REST API.
+    /// # let _ = admin;
+    /// # Ok(()) }
+    /// ```
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: normalize(url),
+            admin_url: None,
+            discovered: HashMap::new(),
+        }
+    }
+
+    /// Sets the base URL the Admin API is served under, if it differs from the frontend URL.
+    ///
+    /// Trailing slashes are removed.
+    pub fn with_admin_url(mut self, admin_url: &str) -> Self {
+        self.admin_url = Some(normalize(admin_url));
+        self
+    }
+
+    /// Fetches the OpenID Connect discovery document of `realm` and uses its
+    /// endpoints for the realm from now on.
+    ///
+    /// # Arguments
+    ///
+    /// * `realm` - The Keycloak realm to discover.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to fetch the discovery document.
+    pub async fn discover(
+        mut self,
+        realm: &str,
+        client: &dyn KeycloakTransport,
+    ) -> Result<Self, KeycloakError> {
+        let url = &self.url;
+        let response = client
+            .send(KeycloakRequest::get(format!(
+                "{url}/realms/{realm}/.well-known/openid-configuration"
+            )))
+            .await?;
+        let configuration = error_check(response)?.json()?;
+        self.discovered.insert(realm.into(), configuration);
+        Ok(self)
+    }
+
+    /// Returns the frontend URL of the server.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the base URL of the Admin API, the frontend URL unless configured otherwise.
+    pub fn admin_url(&self) -> &str {
+        self.admin_url.as_deref().unwrap_or(&self.url)
+    }
+
+    /// Returns the discovery document of `realm`, if it was discovered.
+    pub fn openid_configuration(&self, realm: &str) -> Option<&KeycloakOpenIdConfiguration> {
+        self.discovered.get(realm)
+    }
+
+    /// Returns the issuer (`iss` claim) of tokens of `realm`.
+    pub fn issuer(&self, realm: &str) -> String {
+        match self.openid_configuration(realm) {
+            Some(configuration) => configuration.issuer.clone(),
+            None => format!("{}/realms/{realm}", self.url),
+        }
+    }
+
+    /// Returns the token endpoint of `realm`.
+    pub fn token_endpoint(&self, realm: &str) -> String {
+        match self.openid_configuration(realm) {
+            Some(configuration) => configuration.token_endpoint.clone(),
+            None => self.openid_connect_endpoint(realm, "token"),
+        }
+    }
+
+    /// Returns the token revocation endpoint of `realm`.
+    pub fn revocation_endpoint(&self, realm: &str) -> String {
+        self.endpoint(realm, "revoke", |configuration| {
+            &configuration.revocation_endpoint
+        })
+    }
+
+    /// Returns the end session (logout) endpoint of `realm`.
+    pub fn end_session_endpoint(&self, realm: &str) -> String {
+        self.endpoint(realm, "logout", |configuration| {
+            &configuration.end_session_endpoint
+        })
+    }
+
+    /// Returns the URL of the public keys (JWKS) of `realm`.
+    pub fn jwks_uri(&self, realm: &str) -> String {
+        self.endpoint(realm, "certs", |configuration| &configuration.jwks_uri)
+    }
+
+    /// Returns the device authorization endpoint of `realm`.
+    pub fn device_authorization_endpoint(&self, realm: &str) -> String {
+        self.endpoint(realm, "auth/device", |configuration| {
+            &configuration.device_authorization_endpoint
+        })
+    }
+
+    /// Returns `location` if configured, otherwise the location at `url`.
+    pub(crate) fn resolve<'a>(location: Option<&'a Self>, url: &str) -> Cow<'a, Self> {
+        match location {
+            Some(location) => Cow::Borrowed(location),
+            None => Cow::Owned(Self::new(url)),
+        }
+    }
+
+    fn endpoint(
+        &self,
+        realm: &str,
+        path: &str,
+        discovered: impl FnOnce(&KeycloakOpenIdConfiguration) -> &Option<String>,
+    ) -> String {
+        self.openid_configuration(realm)
+            .and_then(|configuration| discovered(configuration).clone())
+            .unwrap_or_else(|| self.openid_connect_endpoint(realm, path))
+    }
+
+    fn openid_connect_endpoint(&self, realm: &str, path: &str) -> String {
+        format!("{}/realms/{realm}/protocol/openid-connect/{path}", self.url)
+    }
+}
+
+fn normalize(url: &str) -> String {
+    url.trim_end_matches('/').into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeycloakOpenIdConfiguration, KeycloakServerLocation};
+
+    #[test]
+    fn endpoints() {
+        let location = KeycloakServerLocation::new("https://sso.example.com/auth/");
+        assert_eq!(location.url(), "https://sso.example.com/auth");
+        assert_eq!(location.admin_url(), "https://sso.example.com/auth");
+        assert_eq!(
+            location.token_endpoint("test"),
+            "https://sso.example.com/auth/realms/test/protocol/openid-connect/token"
+        );
+
+        let mut location = location.with_admin_url("http://keycloak:8080//");
+        assert_eq!(location.admin_url(), "http://keycloak:8080");
+
+        location.discovered.insert(
+            "test".into(),
+            KeycloakOpenIdConfiguration {
+                issuer: "https://login.example.com/realms/test".into(),
+                authorization_endpoint: None,
+                token_endpoint: "https://login.example.com/token".into(),
+                introspection_endpoint: None,
+                userinfo_endpoint: None,
+                end_session_endpoint: None,
+                revocation_endpoint: Some("https://login.example.com/revoke".into()),
+                jwks_uri: None,
+                device_authorization_endpoint: None,
+            },
+        );
+        assert_eq!(
+            location.issuer("test"),
+            "https://login.example.com/realms/test"
+        );
+        assert_eq!(
+            location.token_endpoint("test"),
+            "https://login.example.com/token"
+        );
+        assert_eq!(
+            location.revocation_endpoint("test"),
+            "https://login.example.com/revoke"
+        );
+        assert_eq!(
+            location.end_session_endpoint("test"),
+            "https://sso.example.com/auth/realms/test/protocol/openid-connect/logout"
+        );
+        assert_eq!(
+            location.token_endpoint("other"),
+            "https://sso.example.com/auth/realms/other/protocol/openid-connect/token"
+        );
+    }
+}
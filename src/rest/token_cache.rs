@@ -0,0 +1,221 @@
+use std::{
+    future::Future,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{KeycloakAdminToken, KeycloakError};
+
+/// Default margin before token expiry at which a cached token is renewed.
+pub(crate) const DEFAULT_EXPIRY_SKEW: Duration = Duration::from_secs(10);
+
+/// [`KeycloakAdminToken`] together with the instants computed from its lifetimes.
+#[derive(Debug, Clone)]
+pub(crate) struct CachedToken {
+    pub(crate) token: KeycloakAdminToken,
+    /// Instant after which the access token must be renewed.
+    renew_at: Instant,
+    /// Instant after which the refresh token must not be used anymore,
+    /// `None` if the refresh token has no known expiry.
+    refresh_until: Option<Instant>,
+}
+
+impl CachedToken {
+    /// Computes renewal instants of `token` issued at `issued_at`.
+    ///
+    /// The skew is capped to half of the lifetime, so that short-lived tokens
+    /// are still reused for a while instead of being renewed on every call.
+    pub(crate) fn new(token: KeycloakAdminToken, issued_at: Instant, skew: Duration) -> Self {
+        let deadline = |lifetime: usize| {
+            let lifetime = Duration::from_secs(lifetime as u64);
+            issued_at + lifetime - skew.min(lifetime / 2)
+        };
+        let renew_at = deadline(token.expires_in());
+        let refresh_until = token
+            .refresh_expires_in()
+            .filter(|&lifetime| lifetime > 0)
+            .map(deadline);
+        Self {
+            token,
+            renew_at,
+            refresh_until,
+        }
+    }
+
+    pub(crate) fn is_fresh(&self, now: Instant) -> bool {
+        now < self.renew_at
+    }
+
+    /// Returns the refresh token if it can still be exchanged for a new access token.
+    pub(crate) fn usable_refresh_token(&self, now: Instant) -> Option<&str> {
+        self.token
+            .refresh_token()
+            .filter(|_| self.refresh_until.is_none_or(|until| now < until))
+    }
+}
+
+/// Shared cache of a single admin token.
+///
+/// Renewal is serialized: when many callers find an expired token at once,
+/// only the first one renews it and the others reuse its result.
+#[derive(Debug)]
+pub(crate) struct TokenCache {
+    skew: Duration,
+    token: Mutex<Option<CachedToken>>,
+    renewal: tokio::sync::Mutex<()>,
+}
+
+impl TokenCache {
+    pub(crate) fn new(skew: Duration) -> Self {
+        Self {
+            skew,
+            token: Mutex::new(None),
+            renewal: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Returns the cached token if it is still fresh.
+    pub(crate) fn fresh(&self, now: Instant) -> Option<KeycloakAdminToken> {
+        self.token
+            .lock()
+            .expect("token cache lock")
+            .as_ref()
+            .filter(|cached| cached.is_fresh(now))
+            .map(|cached| cached.token.clone())
+    }
+
+    /// Returns a fresh token, renewing it with `renew` if required.
+    ///
+    /// `renew` receives the previously cached token (if any) and returns the
+    /// new token. Errors are passed to the caller and leave the cache untouched.
+    pub(crate) async fn get_or_renew<F, Fut>(
+        &self,
+        renew: F,
+    ) -> Result<KeycloakAdminToken, KeycloakError>
+    where
+        F: FnOnce(Option<CachedToken>) -> Fut,
+        Fut: Future<Output = Result<KeycloakAdminToken, KeycloakError>>,
+    {
+        if let Some(token) = self.fresh(Instant::now()) {
+            return Ok(token);
+        }
+
+        let _renewal = self.renewal.lock().await;
+
+        // Another caller may have renewed the token while we were waiting.
+        if let Some(token) = self.fresh(Instant::now()) {
+            return Ok(token);
+        }
+
+        let previous = self.token.lock().expect("token cache lock").clone();
+        let issued_at = Instant::now();
+        let token = renew(previous).await?;
+        *self.token.lock().expect("token cache lock") =
+            Some(CachedToken::new(token.clone(), issued_at, self.skew));
+        Ok(token)
+    }
+
+    /// Drops the cached token, so that the next call renews it.
+    pub(crate) fn invalidate(&self) {
+        self.token.lock().expect("token cache lock").take();
+    }
+
+    /// Drops the cached token and returns it.
+    pub(crate) fn take(&self) -> Option<KeycloakAdminToken> {
+        self.token
+            .lock()
+            .expect("token cache lock")
+            .take()
+            .map(|cached| cached.token)
+    }
+
+    /// Drops the cached token if its access token is `access_token`.
+    ///
+    /// A token already renewed by a concurrent caller is kept.
+    pub(crate) fn invalidate_token(&self, access_token: &str) {
+        let mut token = self.token.lock().expect("token cache lock");
+        if token
+            .as_ref()
+            .is_some_and(|cached| cached.token.access_token() == access_token)
+        {
+            token.take();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::{Duration, Instant},
+    };
+
+    use super::{CachedToken, TokenCache, DEFAULT_EXPIRY_SKEW};
+    use crate::KeycloakAdminToken;
+
+    fn token(expires_in: usize, refresh_expires_in: Option<usize>) -> KeycloakAdminToken {
+        serde_json::from_value(serde_json::json!({
+            "access_token": "access",
+            "expires_in": expires_in,
+            "refresh_expires_in": refresh_expires_in,
+            "refresh_token": "refresh",
+            "scope": "profile email",
+            "token_type": "Bearer",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn renewal_deadlines() {
+        let issued_at = Instant::now();
+        let skew = Duration::from_secs(10);
+
+        let cached = CachedToken::new(token(60, Some(1800)), issued_at, skew);
+        assert!(cached.is_fresh(issued_at + Duration::from_secs(49)));
+        assert!(!cached.is_fresh(issued_at + Duration::from_secs(50)));
+        assert!(cached
+            .usable_refresh_token(issued_at + Duration::from_secs(1789))
+            .is_some());
+        assert!(cached
+            .usable_refresh_token(issued_at + Duration::from_secs(1790))
+            .is_none());
+
+        // skew is capped to half of a short lifetime
+        let cached = CachedToken::new(token(10, Some(0)), issued_at, skew);
+        assert!(cached.is_fresh(issued_at + Duration::from_secs(4)));
+        assert!(!cached.is_fresh(issued_at + Duration::from_secs(5)));
+        // offline tokens report no refresh expiry
+        assert!(cached
+            .usable_refresh_token(issued_at + Duration::from_secs(86400))
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn single_renewal() {
+        let cache = TokenCache::new(DEFAULT_EXPIRY_SKEW);
+        let renewals = AtomicUsize::new(0);
+        let renew = |previous: Option<CachedToken>| {
+            let renewals = &renewals;
+            async move {
+                assert!(previous.is_none());
+                renewals.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                Ok(token(60, Some(1800)))
+            }
+        };
+        let (first, second, third) = tokio::join!(
+            cache.get_or_renew(renew),
+            cache.get_or_renew(renew),
+            cache.get_or_renew(renew),
+        );
+        assert_eq!(first.unwrap(), second.unwrap());
+        assert!(third.is_ok());
+        assert_eq!(renewals.load(Ordering::SeqCst), 1);
+
+        // a token renewed meanwhile is kept
+        cache.invalidate_token("other");
+        assert!(cache.fresh(Instant::now()).is_some());
+        cache.invalidate_token("access");
+        assert!(cache.fresh(Instant::now()).is_none());
+    }
+}
@@ -0,0 +1,346 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+use async_trait::async_trait;
+
+use super::{
+    error_check, KeycloakMiddleware, KeycloakRequest, KeycloakTransport, Middlewares, TokenCache,
+    DEFAULT_EXPIRY_SKEW,
+};
+use crate::{
+    KeycloakAdminToken, KeycloakClientAuth, KeycloakError, KeycloakServerLocation,
+    KeycloakTokenSupplier,
+};
+
+/// Value of `grant_type` for token exchange.
+const TOKEN_EXCHANGE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:token-exchange";
+
+/// Value of `subject_token_type` for access tokens.
+const ACCESS_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:access_token";
+
+/// Parameters identifying an exchanged token in the cache.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+struct ExchangeKey {
+    subject_token: Option<String>,
+    requested_subject: Option<String>,
+    audience: Option<String>,
+    requested_token_type: Option<String>,
+}
+
+/// Token supplier acting on behalf of another principal with [OAuth 2.0 Token Exchange].
+///
+/// The backend authenticates as its own client and exchanges either the token
+/// of the end user (`subject_token`) or, for impersonation, the id or username
+/// of a user (`requested_subject`) for a token which is then used to call the
+/// Admin API with the permissions of that principal.
+///
+/// Exchanged tokens are cached per subject until shortly before they expire.
+/// Retrievers derived with [`KeycloakTokenExchangeRetriever::for_subject_token`]
+/// and [`KeycloakTokenExchangeRetriever::for_requested_subject`] share the cache.
+///
+/// The client must be allowed to exchange tokens (see [token exchange] in
+/// Keycloak documentation).
+///
+/// [OAuth 2.0 Token Exchange]: https://www.rfc-editor.org/rfc/rfc8693
+/// [token exchange]: https://www.keycloak.org/securing-apps/token-exchange
+#[derive(Clone)]
+pub struct KeycloakTokenExchangeRetriever {
+    client_id: String,
+    client_auth: KeycloakClientAuth,
+    realm: String,
+    client: Arc<dyn KeycloakTransport>,
+    middlewares: Middlewares,
+    location: Option<KeycloakServerLocation>,
+    key: ExchangeKey,
+    caches: Arc<Mutex<HashMap<ExchangeKey, Arc<TokenCache>>>>,
+}
+
+#[async_trait]
+impl KeycloakTokenSupplier for KeycloakTokenExchangeRetriever {
+    async fn get(&self, url: &str) -> Result<String, KeycloakError> {
+        let admin_token = self.token(url).await?;
+        Ok(admin_token.access_token)
+    }
+
+    fn token_rejected(&self, _url: &str, token: &str) -> bool {
+        self.cache().invalidate_token(token);
+        true
+    }
+
+    fn add_middleware(&mut self, middleware: Arc<dyn KeycloakMiddleware>) {
+        self.middlewares.push(middleware);
+    }
+}
+
+impl KeycloakTokenExchangeRetriever {
+    /// Creates a token exchange retriever for a client in a caller-specified realm.
+    ///
+    /// Use [`KeycloakTokenExchangeRetriever::for_subject_token`] or
+    /// [`KeycloakTokenExchangeRetriever::for_requested_subject`] to select the
+    /// principal to act on behalf of.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - The client id of the client performing the exchange.
+    /// * `client_auth` - How the client authenticates at the token endpoint.
+    /// * `realm` - The Keycloak realm the `client_id` lives in.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to perform the token exchange calls.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn doc(user_access_token: &str) -> Result<(), keycloak::KeycloakError> {
+    /// use keycloak::{
+    ///     prelude::reqwest, KeycloakAdmin, KeycloakClientAuth, KeycloakTokenExchangeRetriever,
+    /// };
+    ///
+    /// let client = reqwest::Client::new();
+    /// let url = "https://keycloak.example.com";
+    ///
+    /// let exchange = KeycloakTokenExchangeRetriever::create(
+    ///     "admin-portal",
+    ///     KeycloakClientAuth::ClientSecretBasic("my-secret".into()),
+    ///     "master",
+    ///     client.clone(),
+    /// );
+    ///
+    /// let admin = KeycloakAdmin::new(url, exchange.for_subject_token(user_access_token), client);
+    /// // ... use `admin` to call the Admin REST API with permissions of the user.
+    /// # let _ = admin;
+    /// # Ok(()) }
+    /// ```
+    pub fn create(
+        client_id: &str,
+        client_auth: KeycloakClientAuth,
+        realm: &str,
+        client: impl KeycloakTransport + 'static,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_auth,
+            realm: realm.into(),
+            client: Arc::new(client),
+            middlewares: Middlewares::default(),
+            location: None,
+            key: ExchangeKey::default(),
+            caches: Default::default(),
+        }
+    }
+
+    /// Sets the server location to take the token endpoint from, instead of
+    /// deriving it from the URL passed by [`crate::KeycloakAdmin`].
+    pub fn with_location(mut self, location: KeycloakServerLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Returns a retriever exchanging `subject_token`, the access token of the
+    /// principal to act on behalf of.
+    pub fn for_subject_token(&self, subject_token: &str) -> Self {
+        let mut retriever = self.clone();
+        retriever.key.subject_token = Some(subject_token.into());
+        retriever
+    }
+
+    /// Returns a retriever impersonating the user with id or username `requested_subject`.
+    pub fn for_requested_subject(&self, requested_subject: &str) -> Self {
+        let mut retriever = self.clone();
+        retriever.key.requested_subject = Some(requested_subject.into());
+        retriever
+    }
+
+    /// Sets the client id of the audience the exchanged token is issued for.
+    pub fn with_audience(mut self, audience: &str) -> Self {
+        self.key.audience = Some(audience.into());
+        self
+    }
+
+    /// Sets the type of the requested token, e.g. `urn:ietf:params:oauth:token-type:refresh_token`.
+    pub fn with_requested_token_type(mut self, requested_token_type: &str) -> Self {
+        self.key.requested_token_type = Some(requested_token_type.into());
+        self
+    }
+
+    /// Returns the cached exchanged token, exchanging again if it is missing
+    /// or about to expire.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    pub async fn token(&self, url: &str) -> Result<KeycloakAdminToken, KeycloakError> {
+        self.cache().get_or_renew(|_| self.exchange(url)).await
+    }
+
+    /// Drops the cached token of the subject, so that the next call exchanges again.
+    pub fn invalidate(&self) {
+        self.cache().invalidate();
+    }
+
+    /// Drops the cached token of the subject and revokes it.
+    ///
+    /// Does nothing if no token is cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    pub async fn logout(&self, url: &str) -> Result<(), KeycloakError> {
+        let Some(token) = self.cache().take() else {
+            return Ok(());
+        };
+        token
+            .revoke_at(
+                &KeycloakServerLocation::resolve(self.location.as_ref(), url),
+                &self.realm,
+                &self.client_id,
+                &self.client_auth,
+                &self.middlewares.chain(self.client.as_ref()),
+            )
+            .await
+    }
+
+    /// Performs the token exchange, bypassing the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "acquire_token",
+        skip_all,
+        fields(
+            grant_type = TOKEN_EXCHANGE_GRANT_TYPE,
+            client_id = %self.client_id,
+            http.response.status_code,
+            latency_ms,
+            error,
+        )
+    ))]
+    pub async fn exchange(&self, url: &str) -> Result<KeycloakAdminToken, KeycloakError> {
+        let token_endpoint = KeycloakServerLocation::resolve(self.location.as_ref(), url)
+            .token_endpoint(&self.realm);
+        let ExchangeKey {
+            subject_token,
+            requested_subject,
+            audience,
+            requested_token_type,
+        } = &self.key;
+        let mut params = vec![("grant_type", TOKEN_EXCHANGE_GRANT_TYPE)];
+        if let Some(subject_token) = subject_token {
+            params.push(("subject_token", subject_token));
+            params.push(("subject_token_type", ACCESS_TOKEN_TYPE));
+        }
+        if let Some(requested_subject) = requested_subject {
+            params.push(("requested_subject", requested_subject));
+        }
+        if let Some(audience) = audience {
+            params.push(("audience", audience));
+        }
+        if let Some(requested_token_type) = requested_token_type {
+            params.push(("requested_token_type", requested_token_type));
+        }
+        let request = self.client_auth.authenticate(
+            KeycloakRequest::post(&token_endpoint),
+            &token_endpoint,
+            &self.client_id,
+            &params,
+        )?;
+        let response = self
+            .middlewares
+            .chain(self.client.as_ref())
+            .send(request)
+            .await?;
+        error_check(response)?.json()
+    }
+
+    /// Returns the cache of the subject, dropping caches of other subjects
+    /// which are not in use and hold no fresh token anymore.
+    fn cache(&self) -> Arc<TokenCache> {
+        let mut caches = self.caches.lock().expect("token exchange caches lock");
+        if let Some(cache) = caches.get(&self.key) {
+            return cache.clone();
+        }
+        let now = Instant::now();
+        caches.retain(|_, cache| Arc::strong_count(cache) > 1 || cache.fresh(now).is_some());
+        caches
+            .entry(self.key.clone())
+            .or_insert_with(|| Arc::new(TokenCache::new(DEFAULT_EXPIRY_SKEW)))
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use http::{HeaderMap, StatusCode};
+    use serde_json::json;
+
+    use super::KeycloakTokenExchangeRetriever;
+    use crate::{
+        KeycloakClientAuth, KeycloakError, KeycloakRequest, KeycloakRequestBody, KeycloakResponse,
+        KeycloakTokenSupplier, KeycloakTransport,
+    };
+
+    const URL: &str = "http://keycloak";
+
+    /// Keycloak issuing numbered tokens and logging the exchange requests.
+    #[derive(Clone, Default)]
+    struct Keycloak(Arc<Mutex<Vec<String>>>);
+
+    #[async_trait]
+    impl KeycloakTransport for Keycloak {
+        async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+            let KeycloakRequestBody::Form(form) = request.body() else {
+                panic!("no form posted");
+            };
+            let mut requests = self.0.lock().unwrap();
+            requests.push(form.clone());
+            let token = json!({
+                "access_token": format!("access-{}", requests.len()),
+                "expires_in": 60,
+                "token_type": "Bearer"
+            });
+            Ok(KeycloakResponse::new(
+                StatusCode::OK,
+                HeaderMap::new(),
+                serde_json::to_vec(&token).unwrap(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn cache_per_audience() {
+        let keycloak = Keycloak::default();
+        let exchange = KeycloakTokenExchangeRetriever::create(
+            "portal",
+            KeycloakClientAuth::ClientSecretPost("secret".into()),
+            "master",
+            keycloak.clone(),
+        )
+        .for_subject_token("user");
+        let first = exchange.clone().with_audience("first");
+        let second = exchange.clone().with_audience("second");
+        let refresh = exchange
+            .clone()
+            .with_audience("first")
+            .with_requested_token_type("urn:ietf:params:oauth:token-type:refresh_token");
+
+        assert_eq!(first.get(URL).await.unwrap(), "access-1");
+        assert_eq!(second.get(URL).await.unwrap(), "access-2");
+        assert_eq!(refresh.get(URL).await.unwrap(), "access-3");
+        assert_eq!(first.get(URL).await.unwrap(), "access-1");
+        assert_eq!(second.get(URL).await.unwrap(), "access-2");
+        assert_eq!(refresh.get(URL).await.unwrap(), "access-3");
+
+        let requests = keycloak.0.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].contains("&audience=first"));
+        assert!(requests[1].contains("&audience=second"));
+        assert!(requests[2].contains(
+            "&audience=first&requested_token_type=urn%3Aietf%3Aparams%3Aoauth%3Atoken-type%3Arefresh_token"
+        ));
+    }
+}
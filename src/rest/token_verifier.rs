@@ -0,0 +1,467 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use jsonwebtoken::{
+    decode, decode_header,
+    jwk::{AlgorithmParameters, EllipticCurve, Jwk, PublicKeyUse},
+    Algorithm, DecodingKey, Validation,
+};
+use serde::Deserialize;
+
+use super::{error_check, KeycloakRequest, KeycloakTransport};
+use crate::{error::KeycloakTokenError, types::AccessToken, KeycloakError, KeycloakServerLocation};
+
+/// Default leeway applied to `exp` and `nbf` claims.
+const DEFAULT_LEEWAY: Duration = Duration::from_secs(30);
+
+/// Default minimal time between two fetches of the realm keys.
+const DEFAULT_MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Public key of the realm used to verify token signatures.
+#[derive(Clone)]
+struct VerificationKey {
+    key: DecodingKey,
+    /// Algorithms accepted for the key: the `alg` of the JWK or, without
+    /// `alg`, the algorithms compatible with its key type and curve.
+    algorithms: Vec<Algorithm>,
+}
+
+/// Key set returned by the certs endpoint.
+///
+/// Keys are parsed one by one, so that a single unsupported key does not
+/// prevent verification with the others.
+#[derive(Deserialize)]
+struct JwkSet {
+    keys: Vec<serde_json::Value>,
+}
+
+/// Cached realm keys, by `kid`.
+#[derive(Default)]
+struct KeyStore {
+    keys: HashMap<String, VerificationKey>,
+    fetched_at: Option<Instant>,
+}
+
+/// Verifier of access tokens issued by a Keycloak realm, for resource servers.
+///
+/// Public keys of the realm are fetched from the `certs` endpoint and cached
+/// by key id (`kid`). A token signed with an unknown key triggers a refetch,
+/// so that key rotation is picked up without restart. Refetches are limited
+/// to one per [`KeycloakTokenVerifier::with_min_refresh_interval`].
+///
+/// Clones share the same cached keys.
+#[derive(Clone)]
+pub struct KeycloakTokenVerifier {
+    certs_url: String,
+    issuer: String,
+    audiences: Vec<String>,
+    authorized_parties: Vec<String>,
+    leeway: Duration,
+    min_refresh_interval: Duration,
+    client: Arc<dyn KeycloakTransport>,
+    keys: Arc<Mutex<KeyStore>>,
+    refresh: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl KeycloakTokenVerifier {
+    /// Creates a verifier for tokens issued by `realm`.
+    ///
+    /// The expected issuer is `{url}/realms/{realm}`, use
+    /// [`KeycloakTokenVerifier::with_issuer`] if Keycloak is reached with
+    /// another URL than the one it issues tokens for.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the Keycloak server (e.g. `https://keycloak.example.com`).
+    /// * `realm` - The Keycloak realm issuing the tokens.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to fetch the realm keys.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn doc(bearer_token: &str) -> Result<(), keycloak::KeycloakError> {
+    /// use keycloak::{prelude::reqwest, KeycloakTokenVerifier};
+    ///
+    /// let verifier = KeycloakTokenVerifier::create(
+    ///     "https://keycloak.example.com",
+    ///     "my-realm",
+    ///     reqwest::Client::new(),
+    /// )
+    /// .with_audience("orders-api");
+    ///
+    /// let token = verifier.verify(bearer_token).await?;
+    /// token.ensure_client_role("orders-api", "orders:write")?;
+    /// # Ok(()) }
+    /// ```
+    pub fn create(url: &str, realm: &str, client: impl KeycloakTransport + 'static) -> Self {
+        Self::create_with_location(&KeycloakServerLocation::new(url), realm, client)
+    }
+
+    /// Creates a verifier for tokens issued by `realm` of the server at `location`.
+    ///
+    /// Issuer and key set URL are taken from the discovery document of the
+    /// realm, if [`KeycloakServerLocation::discover`] was called for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `location` - Where the Keycloak server is reachable.
+    /// * `realm` - The Keycloak realm issuing the tokens.
+    /// * `client` - A [`KeycloakTransport`] (e.g. a reqwest `Client`) used to fetch the realm keys.
+    pub fn create_with_location(
+        location: &KeycloakServerLocation,
+        realm: &str,
+        client: impl KeycloakTransport + 'static,
+    ) -> Self {
+        Self {
+            certs_url: location.jwks_uri(realm),
+            issuer: location.issuer(realm),
+            audiences: Vec::new(),
+            authorized_parties: Vec::new(),
+            leeway: DEFAULT_LEEWAY,
+            min_refresh_interval: DEFAULT_MIN_REFRESH_INTERVAL,
+            client: Arc::new(client),
+            keys: Default::default(),
+            refresh: Default::default(),
+        }
+    }
+
+    /// Sets the expected issuer (`iss` claim), e.g. the frontend URL of the realm.
+    pub fn with_issuer(mut self, issuer: &str) -> Self {
+        self.issuer = issuer.into();
+        self
+    }
+
+    /// Adds an accepted audience (`aud` claim).
+    ///
+    /// Without audiences the `aud` claim is not checked.
+    pub fn with_audience(mut self, audience: &str) -> Self {
+        self.audiences.push(audience.into());
+        self
+    }
+
+    /// Adds an accepted authorized party (`azp` claim), the client the token was issued to.
+    ///
+    /// Without authorized parties the `azp` claim is not checked.
+    pub fn with_authorized_party(mut self, client_id: &str) -> Self {
+        self.authorized_parties.push(client_id.into());
+        self
+    }
+
+    /// Sets the leeway applied to `exp` and `nbf` claims, 30 seconds by default.
+    pub fn with_leeway(mut self, leeway: Duration) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    /// Sets the minimal time between two fetches of the realm keys, 10 seconds by default.
+    pub fn with_min_refresh_interval(mut self, interval: Duration) -> Self {
+        self.min_refresh_interval = interval;
+        self
+    }
+
+    /// Verifies signature and claims of access `token` and returns its claims.
+    pub async fn verify(&self, token: &str) -> Result<AccessToken, KeycloakError> {
+        let header = decode_header(token)?;
+        let kid = header
+            .kid
+            .ok_or(KeycloakError::TokenFailure(KeycloakTokenError::UnknownKey(
+                None,
+            )))?;
+        let verification_key = self.key(&kid).await?;
+
+        // the `alg` of the token header is only trusted if the key accepts it
+        let mut validation = Validation::new(header.alg);
+        validation.algorithms = verification_key.algorithms;
+        validation.leeway = self.leeway.as_secs();
+        validation.validate_nbf = true;
+        validation.set_issuer(&[&self.issuer]);
+        if self.audiences.is_empty() {
+            validation.validate_aud = false;
+            validation.set_required_spec_claims(&["exp", "iss"]);
+        } else {
+            validation.set_audience(&self.audiences);
+            validation.set_required_spec_claims(&["exp", "iss", "aud"]);
+        }
+
+        let claims = decode::<AccessToken>(token, &verification_key.key, &validation)?.claims;
+
+        if !self.authorized_parties.is_empty()
+            && !claims
+                .azp
+                .as_deref()
+                .is_some_and(|azp| self.authorized_parties.iter().any(|party| party == azp))
+        {
+            return Err(KeycloakError::TokenFailure(
+                KeycloakTokenError::UnauthorizedParty(claims.azp.as_deref().map(Into::into)),
+            ));
+        }
+
+        Ok(claims)
+    }
+
+    /// Returns the key `kid`, fetching the realm keys if it is unknown.
+    async fn key(&self, kid: &str) -> Result<VerificationKey, KeycloakError> {
+        if let Some(key) = self.cached_key(kid) {
+            return Ok(key);
+        }
+
+        let _refresh = self.refresh.lock().await;
+
+        // Another caller may have fetched the keys while we were waiting.
+        if let Some(key) = self.cached_key(kid) {
+            return Ok(key);
+        }
+
+        let fetched_at = self
+            .keys
+            .lock()
+            .expect("token verifier keys lock")
+            .fetched_at;
+        if fetched_at.is_none_or(|fetched_at| fetched_at.elapsed() >= self.min_refresh_interval) {
+            let keys = self.fetch_keys().await?;
+            *self.keys.lock().expect("token verifier keys lock") = KeyStore {
+                keys,
+                fetched_at: Some(Instant::now()),
+            };
+        }
+
+        self.cached_key(kid)
+            .ok_or(KeycloakError::TokenFailure(KeycloakTokenError::UnknownKey(
+                Some(kid.into()),
+            )))
+    }
+
+    fn cached_key(&self, kid: &str) -> Option<VerificationKey> {
+        self.keys
+            .lock()
+            .expect("token verifier keys lock")
+            .keys
+            .get(kid)
+            .cloned()
+    }
+
+    async fn fetch_keys(&self) -> Result<HashMap<String, VerificationKey>, KeycloakError> {
+        let response = self
+            .client
+            .send(KeycloakRequest::get(&self.certs_url))
+            .await?;
+        let jwk_set: JwkSet = error_check(response)?.json()?;
+        Ok(signature_keys(jwk_set))
+    }
+}
+
+/// Returns the signature keys of `jwk_set` by `kid`, skipping encryption and unsupported keys.
+fn signature_keys(jwk_set: JwkSet) -> HashMap<String, VerificationKey> {
+    jwk_set
+        .keys
+        .into_iter()
+        .filter_map(|jwk| serde_json::from_value::<Jwk>(jwk).ok())
+        .filter(|jwk| jwk.common.public_key_use != Some(PublicKeyUse::Encryption))
+        .filter_map(|jwk| {
+            let kid = jwk.common.key_id.clone()?;
+            let algorithms = match jwk.common.key_algorithm {
+                Some(algorithm) => vec![algorithm.to_string().parse::<Algorithm>().ok()?],
+                None => key_type_algorithms(&jwk.algorithm),
+            };
+            if algorithms.is_empty() {
+                return None;
+            }
+            let key = DecodingKey::from_jwk(&jwk).ok()?;
+            Some((kid, VerificationKey { key, algorithms }))
+        })
+        .collect()
+}
+
+/// Returns the algorithms compatible with the key type (`kty`) and curve (`crv`) of a JWK.
+fn key_type_algorithms(parameters: &AlgorithmParameters) -> Vec<Algorithm> {
+    match parameters {
+        AlgorithmParameters::RSA(_) => vec![
+            Algorithm::RS256,
+            Algorithm::RS384,
+            Algorithm::RS512,
+            Algorithm::PS256,
+            Algorithm::PS384,
+            Algorithm::PS512,
+        ],
+        AlgorithmParameters::EllipticCurve(parameters) => match parameters.curve {
+            EllipticCurve::P256 => vec![Algorithm::ES256],
+            EllipticCurve::P384 => vec![Algorithm::ES384],
+            _ => Vec::new(),
+        },
+        AlgorithmParameters::OctetKeyPair(parameters) => match parameters.curve {
+            EllipticCurve::Ed25519 => vec![Algorithm::EdDSA],
+            _ => Vec::new(),
+        },
+        AlgorithmParameters::OctetKey(_) => {
+            vec![Algorithm::HS256, Algorithm::HS384, Algorithm::HS512]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use jsonwebtoken::{encode, EncodingKey, Header};
+
+    use jsonwebtoken::Algorithm;
+
+    use super::{signature_keys, KeyStore, KeycloakTokenVerifier};
+    use crate::{error::KeycloakTokenError, prelude::reqwest, KeycloakError};
+
+    #[tokio::test]
+    async fn verify_claims() {
+        let url = "http://localhost:8080";
+        let verifier = KeycloakTokenVerifier::create(url, "test", reqwest::Client::new())
+            .with_audience("orders-api")
+            .with_authorized_party("web-app");
+        let keys = serde_json::from_value(serde_json::json!({
+            "keys": [
+                { "kid": "hmac", "kty": "oct", "alg": "HS256", "use": "sig", "k": URL_SAFE_NO_PAD.encode("secret") },
+                { "kid": "enc", "kty": "oct", "alg": "HS256", "use": "enc", "k": URL_SAFE_NO_PAD.encode("secret") },
+                { "kid": "unsupported", "kty": "xyz" },
+            ]
+        }))
+        .unwrap();
+        let keys = signature_keys(keys);
+        assert_eq!(keys.keys().collect::<Vec<_>>(), ["hmac"]);
+        *verifier.keys.lock().unwrap() = KeyStore {
+            keys,
+            fetched_at: Some(std::time::Instant::now()),
+        };
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let sign = |kid: &str, claims: serde_json::Value| {
+            let header = Header {
+                kid: Some(kid.into()),
+                ..Header::default()
+            };
+            encode(&header, &claims, &EncodingKey::from_secret(b"secret")).unwrap()
+        };
+        let claims = |aud: &str, azp: &str| {
+            serde_json::json!({
+                "iss": "http://localhost:8080/realms/test",
+                "aud": aud,
+                "azp": azp,
+                "exp": now + 60,
+                "realm_access": { "roles": ["user"] },
+            })
+        };
+
+        let token = verifier
+            .verify(&sign("hmac", claims("orders-api", "web-app")))
+            .await
+            .unwrap();
+        assert!(token.has_realm_role("user"));
+
+        assert!(matches!(
+            verifier
+                .verify(&sign("hmac", claims("orders-api", "other-app")))
+                .await,
+            Err(KeycloakError::TokenFailure(
+                KeycloakTokenError::UnauthorizedParty(Some(_))
+            ))
+        ));
+        assert!(matches!(
+            verifier
+                .verify(&sign("hmac", claims("billing-api", "web-app")))
+                .await,
+            Err(KeycloakError::JwtFailure(_))
+        ));
+        // keys were just fetched, so unknown keys are rejected without refetch
+        assert!(matches!(
+            verifier
+                .verify(&sign("enc", claims("orders-api", "web-app")))
+                .await,
+            Err(KeycloakError::TokenFailure(KeycloakTokenError::UnknownKey(
+                Some(_)
+            )))
+        ));
+    }
+
+    #[test]
+    fn key_algorithms() {
+        let n = URL_SAFE_NO_PAD.encode([0xab; 256]);
+        let e = URL_SAFE_NO_PAD.encode([1, 0, 1]);
+        let coordinate = URL_SAFE_NO_PAD.encode([0xcd; 32]);
+        let keys = serde_json::from_value(serde_json::json!({
+            "keys": [
+                { "kid": "rsa", "kty": "RSA", "use": "sig", "n": n, "e": e },
+                { "kid": "rs256", "kty": "RSA", "alg": "RS256", "n": n, "e": e },
+                { "kid": "p256", "kty": "EC", "crv": "P-256", "x": coordinate, "y": coordinate },
+                { "kid": "p521", "kty": "EC", "crv": "P-521", "x": coordinate, "y": coordinate },
+                { "kid": "hmac", "kty": "oct", "k": URL_SAFE_NO_PAD.encode("secret") },
+            ]
+        }))
+        .unwrap();
+        let keys = signature_keys(keys);
+        let algorithms = |kid: &str| keys.get(kid).map(|key| key.algorithms.clone());
+
+        assert_eq!(
+            algorithms("rsa"),
+            Some(vec![
+                Algorithm::RS256,
+                Algorithm::RS384,
+                Algorithm::RS512,
+                Algorithm::PS256,
+                Algorithm::PS384,
+                Algorithm::PS512,
+            ])
+        );
+        assert_eq!(algorithms("rs256"), Some(vec![Algorithm::RS256]));
+        assert_eq!(algorithms("p256"), Some(vec![Algorithm::ES256]));
+        // no supported algorithm for the curve
+        assert_eq!(algorithms("p521"), None);
+        assert_eq!(
+            algorithms("hmac"),
+            Some(vec![Algorithm::HS256, Algorithm::HS384, Algorithm::HS512])
+        );
+    }
+
+    #[tokio::test]
+    async fn reject_incompatible_algorithm() {
+        let verifier =
+            KeycloakTokenVerifier::create("http://localhost:8080", "test", reqwest::Client::new());
+        let keys = serde_json::from_value(serde_json::json!({
+            "keys": [
+                { "kid": "hmac", "kty": "oct", "k": URL_SAFE_NO_PAD.encode("secret") },
+            ]
+        }))
+        .unwrap();
+        *verifier.keys.lock().unwrap() = KeyStore {
+            keys: signature_keys(keys),
+            fetched_at: Some(std::time::Instant::now()),
+        };
+
+        let exp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 60;
+        let claims = serde_json::json!({ "iss": "http://localhost:8080/realms/test", "exp": exp });
+        let sign = |alg: Algorithm| {
+            let header = Header {
+                kid: Some("hmac".into()),
+                ..Header::new(alg)
+            };
+            encode(&header, &claims, &EncodingKey::from_secret(b"secret")).unwrap()
+        };
+        assert!(verifier.verify(&sign(Algorithm::HS384)).await.is_ok());
+
+        // header claims an algorithm of another key type
+        let token = sign(Algorithm::HS256);
+        let (_, rest) = token.split_once('.').unwrap();
+        let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"RS256","kid":"hmac"}"#);
+        assert!(matches!(
+            verifier.verify(&format!("{header}.{rest}")).await,
+            Err(KeycloakError::JwtFailure(_))
+        ));
+    }
+}
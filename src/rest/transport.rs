@@ -0,0 +1,507 @@
+use std::{fmt::Debug, sync::Arc};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+#[cfg(any(feature = "reqwest12", feature = "reqwest13"))]
+use http::header::CONTENT_TYPE;
+use http::{header::AUTHORIZATION, HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::KeycloakError;
+
+/// HTTP client used by [`KeycloakAdmin`](crate::KeycloakAdmin) to perform requests.
+///
+/// Implemented for `reqwest::Client` of the enabled reqwest versions.
+/// Implement it to use another HTTP stack (e.g. hyper, `fetch` in WASM) or
+/// to answer requests in-process in tests.
+///
+/// Retries of [`KeycloakRetryPolicy`](crate::KeycloakRetryPolicy) and rate
+/// limits of [`KeycloakLimiter`](crate::KeycloakLimiter) sleep with the tokio
+/// timer, leave them disabled where no tokio runtime is available.
+///
+/// # Example
+///
+/// ```
+/// use keycloak::{KeycloakError, KeycloakRequest, KeycloakResponse, KeycloakTransport};
+///
+/// /// Answers every request with an empty JSON array.
+/// struct EmptyList;
+///
+/// #[async_trait::async_trait]
+/// impl KeycloakTransport for EmptyList {
+///     async fn send(&self, _request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+///         Ok(KeycloakResponse::new(
+///             http::StatusCode::OK,
+///             http::HeaderMap::new(),
+///             b"[]".to_vec(),
+///         ))
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait KeycloakTransport: Send + Sync {
+    /// Performs `request` and returns the response, whatever its status is.
+    async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError>;
+}
+
+#[async_trait]
+impl<T: KeycloakTransport + ?Sized> KeycloakTransport for Arc<T> {
+    async fn send(&self, request: KeycloakRequest) -> Result<KeycloakResponse, KeycloakError> {
+        (**self).send(request).await
+    }
+}
+
+/// Description of an HTTP request to Keycloak.
+///
+/// The `Debug` output omits the body, which may contain credentials.
+#[derive(Clone, PartialEq, Eq)]
+pub struct KeycloakRequest {
+    method: Method,
+    url: String,
+    query: Vec<(String, String)>,
+    headers: HeaderMap,
+    body: KeycloakRequestBody,
+}
+
+/// Body of a [`KeycloakRequest`].
+///
+/// The variant determines the `Content-Type` the transport has to send.
+/// The `Debug` output shows only the size of the content.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub enum KeycloakRequestBody {
+    #[default]
+    Empty,
+    /// Serialized JSON, `application/json`.
+    Json(Vec<u8>),
+    /// URL encoded form, `application/x-www-form-urlencoded`.
+    Form(String),
+    /// Plain text, sent without content type.
+    Text(String),
+    /// `multipart/form-data` parts.
+    Multipart(Vec<KeycloakMultipartPart>),
+}
+
+/// Part of a `multipart/form-data` body.
+#[derive(Clone, PartialEq, Eq)]
+pub struct KeycloakMultipartPart {
+    name: String,
+    data: Vec<u8>,
+    mime: Option<String>,
+}
+
+impl Debug for KeycloakRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeycloakRequest")
+            .field("method", &self.method)
+            .field("url", &self.url)
+            .field("query", &self.query)
+            .field("headers", &self.headers)
+            .field("body", &self.body)
+            .finish()
+    }
+}
+
+impl Debug for KeycloakRequestBody {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeycloakRequestBody::Empty => f.write_str("Empty"),
+            KeycloakRequestBody::Json(body) => {
+                f.debug_tuple("Json").field(&Redacted(body.len())).finish()
+            }
+            KeycloakRequestBody::Form(body) => {
+                f.debug_tuple("Form").field(&Redacted(body.len())).finish()
+            }
+            KeycloakRequestBody::Text(body) => {
+                f.debug_tuple("Text").field(&Redacted(body.len())).finish()
+            }
+            KeycloakRequestBody::Multipart(parts) => {
+                f.debug_tuple("Multipart").field(parts).finish()
+            }
+        }
+    }
+}
+
+impl Debug for KeycloakMultipartPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeycloakMultipartPart")
+            .field("name", &self.name)
+            .field("data", &Redacted(self.data.len()))
+            .field("mime", &self.mime)
+            .finish()
+    }
+}
+
+/// Content of the given size, left out of `Debug` output.
+struct Redacted(usize);
+
+impl Debug for Redacted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{} bytes>", self.0)
+    }
+}
+
+impl KeycloakMultipartPart {
+    /// Text field `name`.
+    pub fn text(name: &str, value: String) -> Self {
+        Self {
+            name: name.into(),
+            data: value.into_bytes(),
+            mime: None,
+        }
+    }
+
+    /// File field `name` with content type `mime`.
+    pub fn bytes(name: &str, data: Vec<u8>, mime: &str) -> Self {
+        Self {
+            name: name.into(),
+            data,
+            mime: Some(mime.into()),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the content type of the part, `None` for text fields.
+    pub fn mime(&self) -> Option<&str> {
+        self.mime.as_deref()
+    }
+}
+
+impl KeycloakRequest {
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            query: Vec::new(),
+            headers: HeaderMap::new(),
+            body: KeycloakRequestBody::Empty,
+        }
+    }
+
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new(Method::GET, url)
+    }
+
+    pub fn post(url: impl Into<String>) -> Self {
+        Self::new(Method::POST, url)
+    }
+
+    pub fn put(url: impl Into<String>) -> Self {
+        Self::new(Method::PUT, url)
+    }
+
+    pub fn delete(url: impl Into<String>) -> Self {
+        Self::new(Method::DELETE, url)
+    }
+
+    pub fn patch(url: impl Into<String>) -> Self {
+        Self::new(Method::PATCH, url)
+    }
+
+    pub fn head(url: impl Into<String>) -> Self {
+        Self::new(Method::HEAD, url)
+    }
+
+    /// Appends query parameters, e.g. `&[("first", 0)]`.
+    pub fn query<T: Serialize + ?Sized>(mut self, query: &T) -> Result<Self, KeycloakError> {
+        let encoded = serde_urlencoded::to_string(query)
+            .map_err(|e| KeycloakError::InvalidRequest(e.to_string()))?;
+        self.query
+            .extend(form_urlencoded::parse(encoded.as_bytes()).into_owned());
+        Ok(self)
+    }
+
+    /// Sets header `name`, replacing previous values.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Sets `Authorization: Bearer {token}` header.
+    ///
+    /// Fails if the token contains characters not allowed in headers.
+    pub fn bearer_auth(self, token: &str) -> Result<Self, KeycloakError> {
+        self.authorization(format!("Bearer {token}"))
+    }
+
+    /// Sets `Authorization: Basic ...` header.
+    pub fn basic_auth(self, username: &str, password: &str) -> Result<Self, KeycloakError> {
+        self.authorization(format!(
+            "Basic {}",
+            STANDARD.encode(format!("{username}:{password}"))
+        ))
+    }
+
+    /// Sets JSON body.
+    pub fn json<T: Serialize + ?Sized>(mut self, body: &T) -> Result<Self, KeycloakError> {
+        self.body = KeycloakRequestBody::Json(serde_json::to_vec(body)?);
+        Ok(self)
+    }
+
+    /// Sets URL encoded form body.
+    pub fn form<T: Serialize + ?Sized>(mut self, body: &T) -> Result<Self, KeycloakError> {
+        let encoded = serde_urlencoded::to_string(body)
+            .map_err(|e| KeycloakError::InvalidRequest(e.to_string()))?;
+        self.body = KeycloakRequestBody::Form(encoded);
+        Ok(self)
+    }
+
+    /// Sets plain text body.
+    pub fn text(mut self, body: impl Into<String>) -> Self {
+        self.body = KeycloakRequestBody::Text(body.into());
+        self
+    }
+
+    /// Sets `multipart/form-data` body.
+    pub fn multipart(mut self, parts: Vec<KeycloakMultipartPart>) -> Self {
+        self.body = KeycloakRequestBody::Multipart(parts);
+        self
+    }
+
+    /// Replaces the URL, keeping query parameters.
+    pub(crate) fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Returns the URL without query parameters.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the decoded query parameters.
+    pub fn query_pairs(&self) -> &[(String, String)] {
+        &self.query
+    }
+
+    /// Returns the URL with encoded query parameters.
+    pub fn url_with_query(&self) -> String {
+        if self.query.is_empty() {
+            return self.url.clone();
+        }
+        let query = form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(&self.query)
+            .finish();
+        format!("{}?{query}", self.url)
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        &mut self.headers
+    }
+
+    pub fn body(&self) -> &KeycloakRequestBody {
+        &self.body
+    }
+
+    /// Returns the content type implied by the body, if any.
+    pub fn content_type(&self) -> Option<&'static str> {
+        match self.body {
+            KeycloakRequestBody::Json(_) => Some("application/json"),
+            KeycloakRequestBody::Form(_) => Some("application/x-www-form-urlencoded"),
+            KeycloakRequestBody::Empty
+            | KeycloakRequestBody::Text(_)
+            | KeycloakRequestBody::Multipart(_) => None,
+        }
+    }
+
+    fn authorization(self, value: String) -> Result<Self, KeycloakError> {
+        // the value is not part of the error, it contains credentials
+        let mut value = HeaderValue::try_from(value).map_err(|_| {
+            KeycloakError::InvalidRequest("invalid characters in authorization header".into())
+        })?;
+        value.set_sensitive(true);
+        Ok(self.header(AUTHORIZATION, value))
+    }
+}
+
+/// HTTP response of Keycloak, with the body read completely.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeycloakResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+impl KeycloakResponse {
+    pub fn new(status: StatusCode, headers: HeaderMap, body: Vec<u8>) -> Self {
+        Self {
+            status,
+            headers,
+            body,
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    pub fn into_body(self) -> Vec<u8> {
+        self.body
+    }
+
+    /// Deserializes the JSON body.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, KeycloakError> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+
+    /// Returns the body as text, replacing invalid UTF-8 sequences.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Returns the body as raw bytes.
+    pub fn bytes(&self) -> Vec<u8> {
+        self.body.clone()
+    }
+}
+
+/// Implements [`KeycloakTransport`] for `Client` of reqwest crate `$reqwest`.
+#[cfg(any(feature = "reqwest12", feature = "reqwest13"))]
+macro_rules! reqwest_transport {
+    ($reqwest:ident, $failure:expr) => {
+        #[async_trait]
+        impl KeycloakTransport for $reqwest::Client {
+            async fn send(
+                &self,
+                request: KeycloakRequest,
+            ) -> Result<KeycloakResponse, KeycloakError> {
+                let failure = $failure;
+                let content_type = request.content_type();
+                let KeycloakRequest {
+                    method,
+                    url,
+                    query,
+                    headers,
+                    body,
+                } = request;
+                let mut builder = self.request(method, url).headers(headers);
+                if !query.is_empty() {
+                    builder = builder.query(&query);
+                }
+                if let Some(content_type) = content_type {
+                    builder = builder.header(CONTENT_TYPE, content_type);
+                }
+                builder = match body {
+                    KeycloakRequestBody::Empty => builder,
+                    KeycloakRequestBody::Json(body) => builder.body(body),
+                    KeycloakRequestBody::Form(body) | KeycloakRequestBody::Text(body) => {
+                        builder.body(body)
+                    }
+                    #[cfg(feature = "multipart")]
+                    KeycloakRequestBody::Multipart(parts) => {
+                        use $reqwest::multipart::{Form, Part};
+
+                        let mut form = Form::new();
+                        for KeycloakMultipartPart { name, data, mime } in parts {
+                            let mut part = Part::bytes(data);
+                            if let Some(mime) = mime {
+                                part = part.mime_str(&mime).map_err(failure)?;
+                            }
+                            form = form.part(name, part);
+                        }
+                        builder.multipart(form)
+                    }
+                    #[cfg(not(feature = "multipart"))]
+                    KeycloakRequestBody::Multipart(_) => {
+                        return Err(KeycloakError::InvalidRequest(
+                            "multipart body requires feature `multipart`".into(),
+                        ))
+                    }
+                };
+                let response = builder.send().await.map_err(failure)?;
+                let status = response.status();
+                let headers = response.headers().clone();
+                let body = response.bytes().await.map_err(failure)?;
+                Ok(KeycloakResponse::new(status, headers, body.into()))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "reqwest13")]
+reqwest_transport!(reqwest13, KeycloakError::from);
+#[cfg(all(feature = "reqwest12", not(feature = "reqwest13")))]
+reqwest_transport!(reqwest12, KeycloakError::from);
+#[cfg(all(feature = "reqwest12", feature = "reqwest13"))]
+reqwest_transport!(reqwest12, |e: reqwest12::Error| {
+    KeycloakError::TransportFailure(Box::new(e))
+});
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use super::{KeycloakRequest, KeycloakRequestBody};
+
+    #[test]
+    fn request_description() {
+        let request = KeycloakRequest::get("http://localhost:8080/admin/realms/test/users")
+            .query(&[("first", 0)])
+            .unwrap()
+            .query(&[("search", "a&b")])
+            .unwrap()
+            .query(
+                &["view", "manage"]
+                    .into_iter()
+                    .map(|e| ("scope", e))
+                    .collect::<Vec<_>>(),
+            )
+            .unwrap()
+            .bearer_auth("token")
+            .unwrap();
+
+        assert_eq!(request.method(), Method::GET);
+        assert_eq!(
+            request.url_with_query(),
+            "http://localhost:8080/admin/realms/test/users?first=0&search=a%26b&scope=view&scope=manage"
+        );
+        assert_eq!(request.query_pairs()[1], ("search".into(), "a&b".into()));
+        assert_eq!(request.headers()["authorization"], "Bearer token");
+
+        let request = KeycloakRequest::post("http://localhost:8080/token")
+            .form(&[("client_id", "admin-cli"), ("grant_type", "password")])
+            .unwrap();
+        assert_eq!(
+            request.body(),
+            &KeycloakRequestBody::Form("client_id=admin-cli&grant_type=password".into())
+        );
+        assert_eq!(
+            request.content_type(),
+            Some("application/x-www-form-urlencoded")
+        );
+
+        let request = KeycloakRequest::post("http://localhost:8080/token")
+            .form(&[("password", "secret")])
+            .unwrap()
+            .basic_auth("admin-cli", "secret")
+            .unwrap();
+        let debug = format!("{request:?}");
+        assert!(debug.contains("Form(<15 bytes>)"), "{debug}");
+        assert!(!debug.contains("secret"), "{debug}");
+        assert!(KeycloakRequest::get("http://localhost:8080")
+            .bearer_auth("line\nbreak")
+            .is_err());
+    }
+}
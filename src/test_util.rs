@@ -0,0 +1,15 @@
+//! Helpers shared by the unit tests.
+
+use async_trait::async_trait;
+
+use crate::{KeycloakError, KeycloakTokenSupplier};
+
+/// Token supplier returning the same token for every request.
+pub(crate) struct StaticToken;
+
+#[async_trait]
+impl KeycloakTokenSupplier for StaticToken {
+    async fn get(&self, _url: &str) -> Result<String, KeycloakError> {
+        Ok("token".into())
+    }
+}
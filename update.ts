@@ -128,6 +128,24 @@ class Cargo {
     ]);
   }
 
+  async generateBlockingByTag(tag: string): Promise<string> {
+    return await this.generateBlocking(false, tag);
+  }
+
+  async generateBlockingWithoutTag(): Promise<string> {
+    return await this.generateBlocking(true);
+  }
+
+  private async generateBlocking(
+    noTag: boolean,
+    tag?: string,
+  ): Promise<string> {
+    return await this.generate("blocking", [
+      ...tag ? ["--tag", tag] : [],
+      ...noTag ? ["--no-tag"] : [],
+    ]);
+  }
+
   async generateTags(): Promise<string[]> {
     return (await this.generate("tags")).trim().split("\n");
   }
@@ -140,9 +158,13 @@ class Cargo {
     return await this.generateTagsMod(otherMethodsMod, "mod-rest");
   }
 
+  async generateTagsModBlocking(otherMethodsMod: string): Promise<string> {
+    return await this.generateTagsMod(otherMethodsMod, "mod-blocking");
+  }
+
   private async generateTagsMod(
     otherMethodsMod: string,
-    modType: "mod-resource" | "mod-rest",
+    modType: "mod-resource" | "mod-rest" | "mod-blocking",
   ): Promise<string> {
     return await this.generate("tags", [modType], {
       OTHER_METHODS_MOD: otherMethodsMod,
@@ -165,7 +187,7 @@ class Cargo {
   }
 
   private async generate(
-    kind: "rest" | "types" | "tags" | "methods",
+    kind: "rest" | "types" | "tags" | "methods" | "blocking",
     extraArgs: string[] = [],
     env?: Record<string, string>,
   ): Promise<string> {
@@ -891,6 +913,7 @@ class Updater {
         "src/types.rs",
         "src/rest/generated_rest",
         "src/resource",
+        "src/blocking/generated_blocking",
       ]);
 
       this.info("Generating new...");
@@ -904,6 +927,9 @@ class Updater {
         otherMethodsMod,
       );
       const codeRestMod = await this.cargo.generateTagsModRest(otherMethodsMod);
+      const codeBlockingMod = await this.cargo.generateTagsModBlocking(
+        otherMethodsMod,
+      );
 
       const tagResourceModules: Record<string, string> = {};
       tagResourceModules[otherMethodsMod] = await this.cargo
@@ -911,11 +937,16 @@ class Updater {
       const tagRestModules: Record<string, string> = {};
       tagRestModules[otherMethodsMod] = await this.cargo
         .generateRestWithoutTag();
+      const tagBlockingModules: Record<string, string> = {};
+      tagBlockingModules[otherMethodsMod] = await this.cargo
+        .generateBlockingWithoutTag();
       for (const tag of codeTags) {
         const codeTagResourceMod = await this.cargo.generateMethodsByTag(tag);
         tagResourceModules[tag] = codeTagResourceMod;
         const codeTagRestMod = await this.cargo.generateRestByTag(tag);
         tagRestModules[tag] = codeTagRestMod;
+        const codeTagBlockingMod = await this.cargo.generateBlockingByTag(tag);
+        tagBlockingModules[tag] = codeTagBlockingMod;
       }
 
       Deno.writeTextFileSync("src/types.rs", codeTypes);
@@ -931,9 +962,18 @@ class Updater {
           `src/rest/generated_rest/${tag.replaceAll("-", "_")}.rs`,
           codeTagRestMod,
         );
+        const codeTagBlockingMod = tagBlockingModules[tag];
+        Deno.writeTextFileSync(
+          `src/blocking/generated_blocking/${tag.replaceAll("-", "_")}.rs`,
+          codeTagBlockingMod,
+        );
       }
       Deno.writeTextFileSync("src/rest/generated_rest/mod.rs", codeRestMod);
       Deno.writeTextFileSync("src/resource/mod.rs", codeResourceMod);
+      Deno.writeTextFileSync(
+        "src/blocking/generated_blocking/mod.rs",
+        codeBlockingMod,
+      );
 
       this.info("Formatting...");
 